@@ -0,0 +1,460 @@
+// crates/mira-server/src/audit.rs
+// Signed audit export of tool executions for compliance review
+//
+// An export is a JSONL file (one tool execution per line) plus a sidecar
+// `<file>.manifest.json` carrying the filters, row count, SHA-256 of the
+// content, and an HMAC-SHA256 keyed by MIRA_AUDIT_KEY over the content and
+// the manifest fields, so neither the rows nor the claimed scope can change.
+
+use crate::db::audit::{AuditFilter, AuditRow, for_each_audit_row_sync};
+use crate::utils::redact_sensitive;
+use anyhow::{Context, Result};
+use hmac::{Hmac, Mac};
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+
+/// Environment variable holding the HMAC signing key.
+pub const AUDIT_KEY_ENV: &str = "MIRA_AUDIT_KEY";
+
+/// Export format version, bumped on incompatible record or signature changes.
+/// Version 2 signs the manifest fields alongside the content.
+pub const AUDIT_FORMAT_VERSION: u32 = 2;
+
+/// HMAC-SHA256 (RFC 2104) from the `hmac` crate.
+pub type HmacSha256 = Hmac<Sha256>;
+
+/// Sidecar manifest describing an export.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditManifest {
+    pub version: u32,
+    pub generated_at: String,
+    pub since: String,
+    pub until: String,
+    pub project: Option<String>,
+    pub tool: Option<String>,
+    pub row_count: usize,
+    pub content_sha256: String,
+    pub hmac_sha256: String,
+}
+
+/// Manifest fields covered by the signature, in signing order.
+#[derive(Serialize)]
+struct SignedFields<'a> {
+    version: u32,
+    generated_at: &'a str,
+    since: &'a str,
+    until: &'a str,
+    project: Option<&'a str>,
+    tool: Option<&'a str>,
+    row_count: usize,
+    content_sha256: &'a str,
+}
+
+impl AuditManifest {
+    /// Sign the content MAC together with this manifest's fields.
+    ///
+    /// The fields are appended after the content as one canonical JSON line,
+    /// so editing the project, date range, or row count invalidates the MAC.
+    fn sign(&self, mut mac: HmacSha256) -> Result<String> {
+        let fields = SignedFields {
+            version: self.version,
+            generated_at: &self.generated_at,
+            since: &self.since,
+            until: &self.until,
+            project: self.project.as_deref(),
+            tool: self.tool.as_deref(),
+            row_count: self.row_count,
+            content_sha256: &self.content_sha256,
+        };
+        mac.update(&serde_json::to_vec(&fields)?);
+        Ok(format!("{:x}", mac.finalize().into_bytes()))
+    }
+}
+
+/// One exported tool execution.
+#[derive(Serialize)]
+struct AuditRecord<'a> {
+    id: i64,
+    timestamp: &'a str,
+    tool: &'a str,
+    arguments: Value,
+    success: bool,
+    duration_ms: Option<i64>,
+    project: Option<&'a str>,
+    session_id: Option<&'a str>,
+}
+
+/// Outcome of verifying an export against its manifest.
+#[derive(Debug)]
+pub struct VerifyReport {
+    pub row_count: usize,
+    pub problems: Vec<String>,
+}
+
+impl VerifyReport {
+    pub fn is_valid(&self) -> bool {
+        self.problems.is_empty()
+    }
+}
+
+/// Read the signing key from `MIRA_AUDIT_KEY`.
+pub fn audit_key_from_env() -> Result<Vec<u8>> {
+    std::env::var(AUDIT_KEY_ENV)
+        .ok()
+        .filter(|k| !k.trim().is_empty())
+        .map(|k| k.into_bytes())
+        .with_context(|| format!("{AUDIT_KEY_ENV} is not set (add it to ~/.mira/.env)"))
+}
+
+/// Path of the manifest that accompanies an export file.
+pub fn manifest_path(export_path: &Path) -> PathBuf {
+    let mut name = export_path.as_os_str().to_owned();
+    name.push(".manifest.json");
+    PathBuf::from(name)
+}
+
+/// Redact tool arguments for export.
+///
+/// JSON arguments are walked so redaction can never corrupt their structure:
+/// values under secret-looking keys are replaced outright, and every other
+/// string goes through `redact_sensitive`. Non-JSON input is redacted as text.
+pub fn redact_arguments(raw: &str) -> Value {
+    match serde_json::from_str::<Value>(raw) {
+        Ok(value) => redact_value(value, false),
+        Err(_) => Value::String(redact_sensitive(raw)),
+    }
+}
+
+fn redact_value(value: Value, sensitive_key: bool) -> Value {
+    match value {
+        Value::String(_) if sensitive_key => Value::String("<REDACTED>".to_string()),
+        Value::String(s) => Value::String(redact_sensitive(&s)),
+        Value::Array(items) => Value::Array(
+            items
+                .into_iter()
+                .map(|v| redact_value(v, sensitive_key))
+                .collect(),
+        ),
+        Value::Object(map) => Value::Object(
+            map.into_iter()
+                .map(|(k, v)| {
+                    let sensitive = is_sensitive_key(&k);
+                    (k, redact_value(v, sensitive))
+                })
+                .collect(),
+        ),
+        other => other,
+    }
+}
+
+fn is_sensitive_key(key: &str) -> bool {
+    let key = key.to_ascii_lowercase();
+    key.ends_with("key")
+        || ["secret", "token", "password", "credential"]
+            .iter()
+            .any(|s| key.contains(s))
+}
+
+/// Stream matching tool executions into `writer` as JSONL and return the manifest.
+///
+/// Content is hashed and signed as it is written, so the export never has to
+/// be held in memory or re-read.
+pub fn write_export<W: Write>(
+    conn: &Connection,
+    filter: &AuditFilter,
+    key: &[u8],
+    mut writer: W,
+) -> Result<AuditManifest> {
+    let mut sha = Sha256::new();
+    let mut mac = new_mac(key);
+
+    let row_count = for_each_audit_row_sync(conn, filter, |row: AuditRow| {
+        let record = AuditRecord {
+            id: row.id,
+            timestamp: &row.created_at,
+            tool: &row.tool_name,
+            arguments: row
+                .arguments
+                .as_deref()
+                .map(redact_arguments)
+                .unwrap_or(Value::Null),
+            success: row.success,
+            duration_ms: row.duration_ms,
+            project: row.project_path.as_deref(),
+            session_id: row.session_id.as_deref(),
+        };
+        let mut line = serde_json::to_vec(&record)?;
+        line.push(b'\n');
+        sha.update(&line);
+        mac.update(&line);
+        writer.write_all(&line)?;
+        Ok(())
+    })?;
+    writer.flush()?;
+
+    let mut manifest = AuditManifest {
+        version: AUDIT_FORMAT_VERSION,
+        generated_at: chrono::Utc::now().to_rfc3339(),
+        since: filter.since.clone(),
+        until: filter.until.clone(),
+        project: filter.project_path.clone(),
+        tool: filter.tool_name.clone(),
+        row_count,
+        content_sha256: format!("{:x}", sha.finalize()),
+        hmac_sha256: String::new(),
+    };
+    manifest.hmac_sha256 = manifest.sign(mac)?;
+    Ok(manifest)
+}
+
+fn new_mac(key: &[u8]) -> HmacSha256 {
+    <HmacSha256 as Mac>::new_from_slice(key).expect("HMAC accepts any key length")
+}
+
+/// Verify an export file against its sidecar manifest and signing key.
+pub fn verify_export(export_path: &Path, key: &[u8]) -> Result<VerifyReport> {
+    let manifest_file = manifest_path(export_path);
+    let manifest: AuditManifest = serde_json::from_str(
+        &std::fs::read_to_string(&manifest_file)
+            .with_context(|| format!("reading {}", manifest_file.display()))?,
+    )
+    .with_context(|| format!("parsing {}", manifest_file.display()))?;
+
+    let file = std::fs::File::open(export_path)
+        .with_context(|| format!("opening {}", export_path.display()))?;
+    let mut reader = BufReader::new(file);
+    let mut sha = Sha256::new();
+    let mut mac = new_mac(key);
+    let mut row_count = 0usize;
+    let mut line = Vec::new();
+    loop {
+        line.clear();
+        if reader.read_until(b'\n', &mut line)? == 0 {
+            break;
+        }
+        sha.update(&line);
+        mac.update(&line);
+        row_count += 1;
+    }
+
+    let mut problems = Vec::new();
+    if manifest.version != AUDIT_FORMAT_VERSION {
+        problems.push(format!(
+            "unsupported format version {} (expected {})",
+            manifest.version, AUDIT_FORMAT_VERSION
+        ));
+    }
+    if row_count != manifest.row_count {
+        problems.push(format!(
+            "row count mismatch: file has {}, manifest says {}",
+            row_count, manifest.row_count
+        ));
+    }
+    if format!("{:x}", sha.finalize()) != manifest.content_sha256 {
+        problems.push("content hash does not match manifest".to_string());
+    }
+    if !constant_time_eq(
+        manifest.sign(mac)?.as_bytes(),
+        manifest.hmac_sha256.as_bytes(),
+    ) {
+        problems
+            .push("HMAC signature invalid (content or manifest altered, or wrong key)".to_string());
+    }
+
+    Ok(VerifyReport {
+        row_count,
+        problems,
+    })
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::test_support::{seed_session, setup_test_connection};
+    use crate::db::{get_or_create_project_sync, log_tool_call_sync};
+
+    const KEY: &[u8] = b"test-audit-key";
+
+    fn filter_all() -> AuditFilter {
+        AuditFilter {
+            since: "2000-01-01".to_string(),
+            until: "2999-12-31".to_string(),
+            project_path: None,
+            tool_name: None,
+        }
+    }
+
+    fn seeded_conn() -> Connection {
+        let conn = setup_test_connection();
+        let (pid, _) = get_or_create_project_sync(&conn, "/audit/proj", None).unwrap();
+        seed_session(&conn, "s1", pid, "active");
+        log_tool_call_sync(
+            &conn,
+            "s1",
+            "run",
+            r#"{"code": "search(\"auth\")", "api_key": "plain-secret", "note": "OPENAI_API_KEY=sk-abcdefghijklmnopqrstuvwx"}"#,
+            "ok",
+            None,
+            true,
+        )
+        .unwrap();
+        log_tool_call_sync(&conn, "s1", "index", "{}", "boom", None, false).unwrap();
+        conn
+    }
+
+    #[test]
+    fn test_hmac_sha256_rfc4231_case_2() {
+        let mut mac = new_mac(b"Jefe");
+        mac.update(b"what do ya want ");
+        mac.update(b"for nothing?");
+        assert_eq!(
+            format!("{:x}", mac.finalize().into_bytes()),
+            "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+    }
+
+    #[test]
+    fn test_redact_arguments_masks_secret_keys_and_values() {
+        let redacted = redact_arguments(
+            r#"{"api_key": "plain-secret", "nested": {"auth_token": "abc"}, "note": "key sk-abcdefghijklmnopqrstuvwx"}"#,
+        );
+        assert_eq!(redacted["api_key"], "<REDACTED>");
+        assert_eq!(redacted["nested"]["auth_token"], "<REDACTED>");
+        let note = redacted["note"].as_str().unwrap();
+        assert!(!note.contains("abcdefghijklmnopqrstuvwx"), "got: {note}");
+    }
+
+    #[test]
+    fn test_redact_arguments_non_json_falls_back_to_text() {
+        let redacted = redact_arguments("Bearer abc.def.ghi");
+        assert_eq!(redacted, Value::String("Bearer <REDACTED>".to_string()));
+    }
+
+    #[test]
+    fn test_export_redacts_and_filters() {
+        let conn = seeded_conn();
+        let mut out = Vec::new();
+        let manifest = write_export(&conn, &filter_all(), KEY, &mut out).unwrap();
+        assert_eq!(manifest.row_count, 2);
+
+        let text = String::from_utf8(out).unwrap();
+        assert!(!text.contains("plain-secret"));
+        assert!(!text.contains("sk-abcdefghijklmnopqrstuvwx"));
+        let first: Value = serde_json::from_str(text.lines().next().unwrap()).unwrap();
+        assert_eq!(first["tool"], "run");
+        assert_eq!(first["project"], "/audit/proj");
+        assert_eq!(first["arguments"]["code"], "search(\"auth\")");
+
+        let mut filter = filter_all();
+        filter.tool_name = Some("index".to_string());
+        let mut out = Vec::new();
+        let manifest = write_export(&conn, &filter, KEY, &mut out).unwrap();
+        assert_eq!(manifest.row_count, 1);
+        assert!(
+            String::from_utf8(out)
+                .unwrap()
+                .contains("\"success\":false")
+        );
+
+        let mut filter = filter_all();
+        filter.until = "2001-01-01".to_string();
+        let manifest = write_export(&conn, &filter, KEY, std::io::sink()).unwrap();
+        assert_eq!(manifest.row_count, 0);
+    }
+
+    fn export_to_dir(dir: &Path) -> PathBuf {
+        let conn = seeded_conn();
+        let path = dir.join("audit.jsonl");
+        let file = std::fs::File::create(&path).unwrap();
+        let manifest = write_export(&conn, &filter_all(), KEY, file).unwrap();
+        std::fs::write(
+            manifest_path(&path),
+            serde_json::to_string_pretty(&manifest).unwrap(),
+        )
+        .unwrap();
+        path
+    }
+
+    #[test]
+    fn test_verify_accepts_untouched_export() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = export_to_dir(dir.path());
+        let report = verify_export(&path, KEY).unwrap();
+        assert!(report.is_valid(), "problems: {:?}", report.problems);
+        assert_eq!(report.row_count, 2);
+    }
+
+    #[test]
+    fn test_verify_rejects_tampered_export() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = export_to_dir(dir.path());
+        let content = std::fs::read_to_string(&path).unwrap();
+        std::fs::write(
+            &path,
+            content.replace("\"success\":false", "\"success\":true"),
+        )
+        .unwrap();
+
+        let report = verify_export(&path, KEY).unwrap();
+        assert!(!report.is_valid());
+        assert!(report.problems.iter().any(|p| p.contains("HMAC")));
+    }
+
+    #[test]
+    fn test_verify_rejects_edited_manifest_scope() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = export_to_dir(dir.path());
+        let manifest_file = manifest_path(&path);
+        let original: AuditManifest =
+            serde_json::from_str(&std::fs::read_to_string(&manifest_file).unwrap()).unwrap();
+
+        let edits: [fn(&mut AuditManifest); 3] = [
+            |m| m.project = Some("/other/proj".to_string()),
+            |m| m.since = "2020-01-01".to_string(),
+            |m| m.until = "2020-12-31".to_string(),
+        ];
+        for edit in edits {
+            let mut manifest = original.clone();
+            edit(&mut manifest);
+            std::fs::write(
+                &manifest_file,
+                serde_json::to_string_pretty(&manifest).unwrap(),
+            )
+            .unwrap();
+
+            let report = verify_export(&path, KEY).unwrap();
+            assert!(report.problems.iter().any(|p| p.contains("HMAC")));
+        }
+    }
+
+    #[test]
+    fn test_verify_rejects_wrong_key() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = export_to_dir(dir.path());
+        let report = verify_export(&path, b"other-key").unwrap();
+        assert!(!report.is_valid());
+    }
+
+    #[test]
+    fn test_verify_rejects_dropped_row() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = export_to_dir(dir.path());
+        let content = std::fs::read_to_string(&path).unwrap();
+        let first_line = content.lines().next().unwrap();
+        std::fs::write(&path, format!("{first_line}\n")).unwrap();
+
+        let report = verify_export(&path, KEY).unwrap();
+        assert!(report.problems.iter().any(|p| p.contains("row count")));
+    }
+}
@@ -1,18 +1,30 @@
 // crates/mira-server/src/background/embeddings.rs
 // Background processing of pending embeddings queue
 
+use crate::db::dead_letters::record_embedding_failure_sync;
 use crate::db::pool::DatabasePool;
 use crate::db::{
-    delete_pending_embedding_sync, get_pending_embeddings_sync, insert_chunk_embedding_sync,
+    PendingEmbedding, delete_pending_embedding_sync, get_pending_embeddings_sync,
+    insert_chunk_embedding_sync,
 };
-use crate::embeddings::EmbeddingClient;
+use crate::embeddings::{EmbeddingClient, is_provider_error};
 use crate::search::embedding_to_bytes;
 use std::sync::Arc;
 
 /// Maximum embeddings to process per batch
 const BATCH_SIZE: usize = 100;
 
+/// Failed attempts before a chunk is moved to the dead-letter table
+pub(super) const MAX_EMBEDDING_ATTEMPTS: i64 = 5;
+
 /// Process pending embeddings from the queue
+///
+/// Provider errors (unreachable, 429, 5xx, bad credentials) fail the cycle
+/// without touching the chunks. Any other batch failure means the provider
+/// answered, so it is retried one chunk at a time and only the chunks that
+/// fail on their own count an attempt. This holds for connectivity probes
+/// too: a chunk the provider keeps rejecting is dead-lettered instead of
+/// failing every probe and keeping the queue paused.
 pub async fn process_pending_embeddings(
     pool: &Arc<DatabasePool>,
     embeddings: Option<&Arc<EmbeddingClient>>,
@@ -36,27 +48,80 @@ pub async fn process_pending_embeddings(
     // Extract texts for batch embedding
     let texts: Vec<String> = pending.iter().map(|p| p.chunk_content.clone()).collect();
 
-    // Generate embeddings in batch.
-    // On failure, log and skip this cycle rather than blocking all future embeddings.
-    let embeddings_result = match emb.embed_batch(&texts).await {
-        Ok(result) => result,
+    let embedded = match emb.embed_batch(&texts).await {
+        Ok(result) => pending.into_iter().zip(result).collect(),
+        Err(e) if is_provider_error(&e) => {
+            return Err(format!("Embedding provider unavailable: {}", e));
+        }
         Err(e) => {
             tracing::warn!(
-                "Embedding batch failed for {} chunks, skipping cycle: {}",
+                "Embedding batch failed for {} chunks, retrying individually: {}",
                 pending.len(),
                 e
             );
-            return Ok(0);
+            embed_individually(pool, emb, pending).await?
         }
     };
 
-    // Store embeddings and cleanup pending queue
+    let count = store_embeddings(pool, embedded).await?;
+    tracing::info!("Stored {} embeddings from pending queue", count);
+    Ok(count)
+}
+
+/// Embed chunks one at a time after a batch failure, counting a failed
+/// attempt only against the chunks that fail by themselves. Stops at the
+/// first provider error; the rest stay queued untouched.
+async fn embed_individually(
+    pool: &Arc<DatabasePool>,
+    emb: &EmbeddingClient,
+    pending: Vec<PendingEmbedding>,
+) -> Result<Vec<(PendingEmbedding, Vec<f32>)>, String> {
+    let mut embedded = Vec::new();
+    for chunk in pending {
+        match emb.embed(&chunk.chunk_content).await {
+            Ok(embedding) => embedded.push((chunk, embedding)),
+            Err(e) if is_provider_error(&e) => {
+                if embedded.is_empty() {
+                    return Err(format!("Embedding provider unavailable: {}", e));
+                }
+                break;
+            }
+            Err(e) => {
+                let id = chunk.id;
+                let error = e.to_string();
+                let dead = pool
+                    .run(move |conn| {
+                        record_embedding_failure_sync(conn, &[id], &error, MAX_EMBEDDING_ATTEMPTS)
+                    })
+                    .await?;
+                if dead > 0 {
+                    tracing::warn!(
+                        "Moved embedding chunk {}:{} to dead letters after {} failed attempts",
+                        chunk.file_path,
+                        chunk.start_line,
+                        MAX_EMBEDDING_ATTEMPTS
+                    );
+                }
+            }
+        }
+    }
+    Ok(embedded)
+}
+
+/// Store embeddings and remove their chunks from the pending queue
+async fn store_embeddings(
+    pool: &Arc<DatabasePool>,
+    embedded: Vec<(PendingEmbedding, Vec<f32>)>,
+) -> Result<usize, String> {
+    if embedded.is_empty() {
+        return Ok(0);
+    }
     let count = pool
         .run(move |conn| {
             let tx = conn.unchecked_transaction()?;
             let mut stored = 0;
 
-            for (chunk, embedding) in pending.iter().zip(embeddings_result.iter()) {
+            for (chunk, embedding) in &embedded {
                 let embedding_bytes = embedding_to_bytes(embedding);
 
                 // Insert into vec_code
@@ -79,8 +144,6 @@ pub async fn process_pending_embeddings(
             Ok::<_, rusqlite::Error>(stored)
         })
         .await?;
-
-    tracing::info!("Stored {} embeddings from pending queue", count);
     Ok(count)
 }
 
@@ -6,8 +6,8 @@
 
 use crate::db::pool::DatabasePool;
 use crate::embeddings::EmbeddingClient;
-use std::sync::Arc;
-use std::time::Duration;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use tokio::sync::{Notify, watch};
 
 use super::embeddings;
@@ -18,6 +18,57 @@ const INITIAL_DELAY_SECS: u64 = 5;
 const ACTIVE_DELAY_MS: u64 = 100;
 /// Periodic check interval when idle (no notification)
 const IDLE_CHECK_SECS: u64 = 10;
+/// Upper bound on embedding batches processed while draining on shutdown
+const SHUTDOWN_MAX_BATCHES: usize = 20;
+/// Consecutive failed embedding batches before the provider is treated as unreachable
+const UNREACHABLE_FAILURE_THRESHOLD: u32 = 3;
+/// While unreachable, how often a single probe batch is attempted
+const UNREACHABLE_PROBE_SECS: u64 = 120;
+
+/// Tracks embedding failures so a lost network pauses the queue with one log
+/// line instead of a warning every cycle. Pending rows stay queued and are
+/// processed once a probe batch succeeds.
+#[derive(Debug, Default)]
+struct Connectivity {
+    consecutive_failures: u32,
+    /// When the queue was paused (or last probed) while unreachable
+    paused_at: Option<Instant>,
+}
+
+impl Connectivity {
+    /// Whether a batch should run now. While paused, one probe per interval.
+    fn should_attempt(&self, now: Instant) -> bool {
+        match self.paused_at {
+            None => true,
+            Some(at) => now.duration_since(at) >= Duration::from_secs(UNREACHABLE_PROBE_SECS),
+        }
+    }
+
+    fn is_paused(&self) -> bool {
+        self.paused_at.is_some()
+    }
+
+    /// Record a failed batch. Returns `true` when this failure paused the queue.
+    fn record_failure(&mut self, now: Instant) -> bool {
+        self.consecutive_failures += 1;
+        if self.paused_at.is_some() {
+            // Failed probe: wait a full interval before the next one
+            self.paused_at = Some(now);
+            return false;
+        }
+        if self.consecutive_failures >= UNREACHABLE_FAILURE_THRESHOLD {
+            self.paused_at = Some(now);
+            return true;
+        }
+        false
+    }
+
+    /// Record a successful batch. Returns `true` when this success resumed a paused queue.
+    fn record_success(&mut self) -> bool {
+        self.consecutive_failures = 0;
+        self.paused_at.take().is_some()
+    }
+}
 
 /// Fast lane worker for time-sensitive background tasks
 pub struct FastLaneWorker {
@@ -25,6 +76,7 @@ pub struct FastLaneWorker {
     embeddings: Option<Arc<EmbeddingClient>>,
     shutdown: watch::Receiver<bool>,
     notify: Arc<Notify>,
+    connectivity: Mutex<Connectivity>,
 }
 
 impl FastLaneWorker {
@@ -39,6 +91,7 @@ impl FastLaneWorker {
             embeddings,
             shutdown,
             notify,
+            connectivity: Mutex::new(Connectivity::default()),
         }
     }
 
@@ -47,12 +100,21 @@ impl FastLaneWorker {
         tracing::info!("Fast lane worker started");
 
         // Short initial delay
-        tokio::time::sleep(Duration::from_secs(INITIAL_DELAY_SECS)).await;
+        if !super::sleep_or_shutdown(&mut self.shutdown, Duration::from_secs(INITIAL_DELAY_SECS))
+            .await
+        {
+            self.run_until_shutdown().await;
+        }
+
+        tracing::info!("Fast lane worker shutting down");
+        self.drain().await;
+    }
 
+    /// Main loop; returns once shutdown has been signalled
+    async fn run_until_shutdown(&mut self) {
         loop {
             // Check for shutdown
             if *self.shutdown.borrow() {
-                tracing::info!("Fast lane worker shutting down");
                 break;
             }
 
@@ -82,18 +144,327 @@ impl FastLaneWorker {
         }
     }
 
+    /// Finish queued embeddings before exiting so they aren't dropped
+    async fn drain(&self) {
+        let drained = super::drain_queue(SHUTDOWN_MAX_BATCHES, || self.process_embeddings()).await;
+        if drained > 0 {
+            tracing::info!("Fast lane: drained {} embeddings on shutdown", drained);
+        }
+    }
+
     /// Process pending embeddings
     async fn process_embeddings(&self) -> usize {
-        if let Some(ref emb) = self.embeddings {
-            match embeddings::process_pending_embeddings(&self.pool, Some(emb)).await {
-                Ok(count) => count,
-                Err(e) => {
+        let Some(ref emb) = self.embeddings else {
+            return 0;
+        };
+        let should_attempt = self
+            .connectivity
+            .lock()
+            .map(|c| c.should_attempt(Instant::now()))
+            .unwrap_or(true);
+        if !should_attempt {
+            return 0;
+        }
+
+        let result = embeddings::process_pending_embeddings(&self.pool, Some(emb)).await;
+
+        let Ok(mut connectivity) = self.connectivity.lock() else {
+            return result.unwrap_or(0);
+        };
+        match result {
+            Ok(count) => {
+                if connectivity.record_success() {
+                    tracing::info!("Fast lane: embedding provider reachable again, resuming queue");
+                }
+                count
+            }
+            Err(e) => {
+                if connectivity.record_failure(Instant::now()) {
+                    tracing::warn!(
+                        "Fast lane: {} consecutive embedding failures ({}); pausing embeddings and retrying every {}s. Queued work is kept.",
+                        UNREACHABLE_FAILURE_THRESHOLD,
+                        e,
+                        UNREACHABLE_PROBE_SECS
+                    );
+                } else if connectivity.is_paused() {
+                    tracing::debug!("Fast lane: embedding probe failed: {}", e);
+                } else {
                     tracing::warn!("Fast lane embedding error: {}", e);
-                    0
                 }
+                0
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{ApiKeys, EmbeddingsConfig};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    /// Ollama-style endpoint that counts requests and answers each with the
+    /// JSON body `respond` builds from the request body
+    async fn mock_server(respond: fn(&str) -> String) -> (String, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let hits = Arc::new(AtomicUsize::new(0));
+        let counter = hits.clone();
+        tokio::spawn(async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                let counter = counter.clone();
+                tokio::spawn(async move {
+                    let Some(request) = read_request(&mut stream).await else {
+                        return;
+                    };
+                    counter.fetch_add(1, Ordering::SeqCst);
+                    let body = respond(&request);
+                    let resp = format!(
+                        "HTTP/1.1 200 OK\r\n\
+                         Content-Type: application/json\r\n\
+                         Content-Length: {}\r\n\
+                         Connection: close\r\n\r\n\
+                         {}",
+                        body.len(),
+                        body
+                    );
+                    let _ = stream.write_all(resp.as_bytes()).await;
+                });
+            }
+        });
+        (url, hits)
+    }
+
+    /// Read one HTTP request and return its body
+    async fn read_request(stream: &mut tokio::net::TcpStream) -> Option<String> {
+        let mut buf = Vec::new();
+        let mut chunk = [0u8; 8192];
+        loop {
+            let n = stream.read(&mut chunk).await.ok()?;
+            if n == 0 {
+                return None;
+            }
+            buf.extend_from_slice(&chunk[..n]);
+            let text = String::from_utf8_lossy(&buf);
+            let Some(header_end) = text.find("\r\n\r\n") else {
+                continue;
+            };
+            let content_length = text[..header_end]
+                .lines()
+                .find_map(|l| {
+                    let (name, value) = l.split_once(':')?;
+                    name.eq_ignore_ascii_case("content-length")
+                        .then(|| value.trim().parse::<usize>().ok())
+                        .flatten()
+                })
+                .unwrap_or(0);
+            if buf.len() >= header_end + 4 + content_length {
+                return Some(text[header_end + 4..].to_string());
+            }
+        }
+    }
+
+    /// Provider that answers with something that isn't an embedding response,
+    /// so every chunk fails on its own
+    async fn mock_provider() -> (String, Arc<AtomicUsize>) {
+        mock_server(|_| "not an embedding".to_string()).await
+    }
+
+    /// Provider that embeds every input as a vector matching vec_code's dimensions
+    async fn embedding_provider() -> (String, Arc<AtomicUsize>) {
+        mock_server(|request| {
+            let request: serde_json::Value = serde_json::from_str(request).unwrap_or_default();
+            let inputs = request["input"].as_array().map_or(1, |a| a.len());
+            let data: Vec<serde_json::Value> = (0..inputs)
+                .map(|index| serde_json::json!({"embedding": vec![0.1f32; 1536], "index": index}))
+                .collect();
+            serde_json::json!({ "data": data }).to_string()
+        })
+        .await
+    }
+
+    /// Code pool with one chunk waiting in pending_embeddings
+    async fn pool_with_queued_chunk() -> Arc<DatabasePool> {
+        pool_with_queued_chunks(1).await
+    }
+
+    /// Code pool with `n` chunks waiting in pending_embeddings
+    async fn pool_with_queued_chunks(n: usize) -> Arc<DatabasePool> {
+        let pool = Arc::new(DatabasePool::open_code_db_in_memory().await.unwrap());
+        pool.run(move |conn| {
+            for line in 1..=n {
+                conn.execute(
+                    "INSERT INTO pending_embeddings (project_id, file_path, chunk_content, start_line)
+                     VALUES (NULL, 'src/lib.rs', ?1, ?2)",
+                    rusqlite::params![format!("fn f{}() {{}}", line), line as i64],
+                )?;
             }
-        } else {
-            0
+            Ok::<_, rusqlite::Error>(())
+        })
+        .await
+        .unwrap();
+        pool
+    }
+
+    async fn count_rows(pool: &Arc<DatabasePool>, table: &'static str) -> i64 {
+        pool.run(move |conn| {
+            conn.query_row(&format!("SELECT COUNT(*) FROM {}", table), [], |r| {
+                r.get::<_, i64>(0)
+            })
+        })
+        .await
+        .unwrap()
+    }
+
+    /// Embedding client resolved the way the server does, with the mock as OLLAMA_HOST
+    fn client_for(url: &str, offline: bool) -> Option<Arc<EmbeddingClient>> {
+        let keys = ApiKeys::from_lookup(offline, false, |name| {
+            (name == "OLLAMA_HOST").then(|| url.to_string())
+        });
+        EmbeddingClient::from_config(&keys, &EmbeddingsConfig::default(), None).map(Arc::new)
+    }
+
+    fn worker(pool: Arc<DatabasePool>, embeddings: Option<Arc<EmbeddingClient>>) -> FastLaneWorker {
+        let (_tx, rx) = watch::channel(false);
+        FastLaneWorker::new(pool, embeddings, rx, Arc::new(Notify::new()))
+    }
+
+    #[tokio::test]
+    async fn offline_mode_makes_no_embedding_requests() {
+        let (url, hits) = mock_provider().await;
+        let pool = pool_with_queued_chunk().await;
+
+        let offline = worker(pool.clone(), client_for(&url, true));
+        assert_eq!(offline.process_embeddings().await, 0);
+        offline.drain().await;
+        assert_eq!(
+            hits.load(Ordering::SeqCst),
+            0,
+            "offline mode reached the provider"
+        );
+        assert_eq!(
+            count_rows(&pool, "pending_embeddings").await,
+            1,
+            "work stays queued"
+        );
+
+        // Same keys without the flag do reach the mock, so the zero above is meaningful
+        let online = worker(pool.clone(), client_for(&url, false));
+        online.process_embeddings().await;
+        assert!(hits.load(Ordering::SeqCst) > 0);
+    }
+
+    /// Run the real worker loop, signal shutdown, and wait for it to exit
+    async fn run_and_shut_down(pool: Arc<DatabasePool>, embeddings: Option<Arc<EmbeddingClient>>) {
+        let (tx, rx) = watch::channel(false);
+        let handle =
+            tokio::spawn(FastLaneWorker::new(pool, embeddings, rx, Arc::new(Notify::new())).run());
+        tx.send(true).unwrap();
+        tokio::time::timeout(Duration::from_secs(30), handle)
+            .await
+            .expect("fast lane should exit after shutdown")
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn shutdown_drains_queued_embeddings() {
+        let (url, hits) = embedding_provider().await;
+        let pool = pool_with_queued_chunks(3).await;
+
+        run_and_shut_down(pool.clone(), client_for(&url, false)).await;
+
+        assert!(hits.load(Ordering::SeqCst) > 0);
+        assert_eq!(count_rows(&pool, "pending_embeddings").await, 0);
+        assert_eq!(count_rows(&pool, "vec_code").await, 3);
+    }
+
+    #[tokio::test]
+    async fn shutdown_keeps_queue_when_provider_is_unreachable() {
+        // Bind then drop a listener so the port refuses connections
+        let url = {
+            let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            format!("http://{}", listener.local_addr().unwrap())
+        };
+        let pool = pool_with_queued_chunks(3).await;
+
+        run_and_shut_down(pool.clone(), client_for(&url, false)).await;
+
+        assert_eq!(
+            count_rows(&pool, "pending_embeddings").await,
+            3,
+            "queued chunks must survive shutdown"
+        );
+        assert_eq!(count_rows(&pool, "background_dead_letters").await, 0);
+    }
+
+    #[tokio::test]
+    async fn probe_dead_letters_a_poison_chunk_and_resumes() {
+        let (url, _hits) = mock_provider().await;
+        let pool = pool_with_queued_chunk().await;
+        let worker = worker(pool.clone(), client_for(&url, false));
+
+        for probe in 1..=embeddings::MAX_EMBEDDING_ATTEMPTS {
+            {
+                let mut c = worker.connectivity.lock().unwrap();
+                c.consecutive_failures = UNREACHABLE_FAILURE_THRESHOLD;
+                c.paused_at = Some(Instant::now() - Duration::from_secs(UNREACHABLE_PROBE_SECS));
+            }
+            assert_eq!(worker.process_embeddings().await, 0);
+            assert!(
+                !worker.connectivity.lock().unwrap().is_paused(),
+                "probe {} answered by the provider should resume the queue",
+                probe
+            );
+        }
+
+        assert_eq!(count_rows(&pool, "pending_embeddings").await, 0);
+        assert_eq!(count_rows(&pool, "background_dead_letters").await, 1);
+    }
+
+    #[test]
+    fn connectivity_pauses_after_threshold() {
+        let mut c = Connectivity::default();
+        let now = Instant::now();
+        for _ in 1..UNREACHABLE_FAILURE_THRESHOLD {
+            assert!(!c.record_failure(now));
+            assert!(c.should_attempt(now));
+        }
+        assert!(c.record_failure(now), "threshold failure should pause");
+        assert!(c.is_paused());
+        assert!(!c.should_attempt(now));
+        assert!(!c.record_failure(now), "pause is reported once");
+    }
+
+    #[test]
+    fn connectivity_probes_after_interval_and_resumes() {
+        let mut c = Connectivity::default();
+        let start = Instant::now();
+        for _ in 0..UNREACHABLE_FAILURE_THRESHOLD {
+            c.record_failure(start);
+        }
+        let later = start + Duration::from_secs(UNREACHABLE_PROBE_SECS);
+        assert!(c.should_attempt(later));
+
+        // Failed probe restarts the interval
+        c.record_failure(later);
+        assert!(!c.should_attempt(later + Duration::from_secs(1)));
+
+        assert!(c.record_success(), "success should resume a paused queue");
+        assert!(!c.is_paused());
+        assert!(c.should_attempt(later));
+        assert!(!c.record_success(), "resume is reported once");
+    }
+
+    #[test]
+    fn success_resets_failure_count() {
+        let mut c = Connectivity::default();
+        let now = Instant::now();
+        for _ in 1..UNREACHABLE_FAILURE_THRESHOLD {
+            c.record_failure(now);
         }
+        c.record_success();
+        assert!(!c.record_failure(now), "count restarts after a success");
     }
 }
@@ -12,6 +12,7 @@ pub mod diff_analysis;
 pub mod documentation;
 mod embeddings;
 mod fast_lane;
+pub(crate) mod module_cards;
 pub(crate) mod outcome_scanner;
 mod pondering;
 pub mod session_summaries;
@@ -52,6 +53,69 @@ impl FastLaneNotify {
     }
 }
 
+/// Running background workers, returned by [`spawn`] / [`spawn_with_pools`]
+pub struct BackgroundWorkers {
+    shutdown_tx: watch::Sender<bool>,
+    fast_lane_notify: FastLaneNotify,
+    supervisors: Vec<tokio::task::JoinHandle<()>>,
+}
+
+impl BackgroundWorkers {
+    /// Handle for waking the fast lane (call .wake() after queuing embeddings)
+    pub fn fast_lane_notify(&self) -> FastLaneNotify {
+        self.fast_lane_notify.clone()
+    }
+
+    /// Signal all workers to stop and wait for them to finish.
+    ///
+    /// The fast lane drains its pending queue before exiting. Returns false if
+    /// the workers did not finish within `timeout`; they are left running
+    /// detached in that case.
+    pub async fn shutdown(self, timeout: Duration) -> bool {
+        let _ = self.shutdown_tx.send(true);
+        // Wake the fast lane in case it is idle-waiting
+        self.fast_lane_notify.wake();
+        let all = futures::future::join_all(self.supervisors);
+        tokio::time::timeout(timeout, all).await.is_ok()
+    }
+}
+
+/// Sleep for `duration`, returning early with true if shutdown is signalled.
+/// A dropped sender can never signal again, so it also counts as shutdown.
+pub(crate) async fn sleep_or_shutdown(
+    shutdown: &mut watch::Receiver<bool>,
+    duration: Duration,
+) -> bool {
+    if *shutdown.borrow() {
+        return true;
+    }
+    tokio::select! {
+        _ = tokio::time::sleep(duration) => false,
+        _ = shutdown.wait_for(|stop| *stop) => true,
+    }
+}
+
+/// Run `step` repeatedly until it reports no work (returns 0) or `max_batches`
+/// batches have run. Returns the total number of items processed.
+///
+/// Used on shutdown so queued work is finished rather than dropped, without
+/// letting a queue that keeps refilling block exit forever.
+pub(crate) async fn drain_queue<F, Fut>(max_batches: usize, mut step: F) -> usize
+where
+    F: FnMut() -> Fut,
+    Fut: std::future::Future<Output = usize>,
+{
+    let mut total = 0;
+    for _ in 0..max_batches {
+        let processed = step().await;
+        if processed == 0 {
+            break;
+        }
+        total += processed;
+    }
+    total
+}
+
 /// Spawn both background workers
 ///
 /// The `pool` parameter is the code index pool (used by fast lane for embeddings).
@@ -61,13 +125,11 @@ impl FastLaneNotify {
 /// passed here, we pass the same pool. Callers that need separate pools
 /// should use `spawn_with_pools` instead.
 ///
-/// Returns:
-/// - shutdown sender (send true to stop all workers)
-/// - fast lane notify handle (call .wake() after queuing embeddings)
+/// Call [`BackgroundWorkers::shutdown`] to stop them.
 pub fn spawn(
     pool: Arc<DatabasePool>,
     embeddings: Option<Arc<EmbeddingClient>>,
-) -> (watch::Sender<bool>, FastLaneNotify) {
+) -> BackgroundWorkers {
     // When called with a single pool, use it for both (backwards compat for tests)
     spawn_with_pools(pool.clone(), pool, embeddings)
 }
@@ -80,9 +142,10 @@ pub fn spawn_with_pools(
     code_pool: Arc<DatabasePool>,
     main_pool: Arc<DatabasePool>,
     embeddings: Option<Arc<EmbeddingClient>>,
-) -> (watch::Sender<bool>, FastLaneNotify) {
+) -> BackgroundWorkers {
     let (shutdown_tx, shutdown_rx) = watch::channel(false);
     let notify = Arc::new(Notify::new());
+    let mut supervisors = Vec::with_capacity(2);
 
     // Spawn supervised fast lane worker (restarts on panic)
     {
@@ -90,7 +153,7 @@ pub fn spawn_with_pools(
         let embeddings = embeddings.clone();
         let shutdown_rx = shutdown_rx.clone();
         let notify = notify.clone();
-        tokio::spawn(async move {
+        supervisors.push(tokio::spawn(async move {
             supervise_worker("fast_lane", shutdown_rx.clone(), || {
                 let worker = FastLaneWorker::new(
                     code_pool.clone(),
@@ -101,26 +164,28 @@ pub fn spawn_with_pools(
                 tokio::spawn(async move { worker.run().await })
             })
             .await;
-        });
+        }));
     }
 
     // Spawn supervised slow lane worker (restarts on panic)
     {
         let code_pool = code_pool.clone();
         let main_pool = main_pool.clone();
-        tokio::spawn(async move {
+        supervisors.push(tokio::spawn(async move {
             supervise_worker("slow_lane", shutdown_rx.clone(), || {
                 let worker =
                     SlowLaneWorker::new(main_pool.clone(), code_pool.clone(), shutdown_rx.clone());
                 tokio::spawn(async move { worker.run().await })
             })
             .await;
-        });
+        }));
     }
 
-    let fast_lane_notify = FastLaneNotify { notify };
-
-    (shutdown_tx, fast_lane_notify)
+    BackgroundWorkers {
+        shutdown_tx,
+        fast_lane_notify: FastLaneNotify { notify },
+        supervisors,
+    }
 }
 
 /// Supervise a background worker, restarting it if it panics.
@@ -200,3 +265,57 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[tokio::test]
+    async fn test_drain_queue_processes_everything_queued() {
+        let queued = AtomicUsize::new(23);
+        let drained = drain_queue(100, || async {
+            let batch = queued.load(Ordering::SeqCst).min(5);
+            queued.fetch_sub(batch, Ordering::SeqCst);
+            batch
+        })
+        .await;
+
+        assert_eq!(drained, 23);
+        assert_eq!(queued.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn test_drain_queue_stops_at_batch_budget() {
+        let calls = AtomicUsize::new(0);
+        // A queue that never empties
+        let drained = drain_queue(3, || async {
+            calls.fetch_add(1, Ordering::SeqCst);
+            10
+        })
+        .await;
+
+        assert_eq!(drained, 30);
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_sleep_or_shutdown_treats_dropped_sender_as_shutdown() {
+        let (tx, mut rx) = watch::channel(false);
+        drop(tx);
+        assert!(sleep_or_shutdown(&mut rx, Duration::from_secs(60)).await);
+    }
+
+    #[tokio::test]
+    async fn test_sleep_or_shutdown_times_out_without_signal() {
+        let (_tx, mut rx) = watch::channel(false);
+        assert!(!sleep_or_shutdown(&mut rx, Duration::from_millis(10)).await);
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_waits_for_workers() {
+        let pool = Arc::new(DatabasePool::open_code_db_in_memory().await.unwrap());
+        let workers = spawn(pool, None);
+        assert!(workers.shutdown(Duration::from_secs(10)).await);
+    }
+}
@@ -0,0 +1,392 @@
+// crates/mira-server/src/background/module_cards.rs
+// Per-module cards, regenerated only when a module's indexed content changes
+
+use super::HEURISTIC_PREFIX;
+use crate::cartographer::{self, Module, ModuleCard};
+use crate::db::get_project_paths_by_ids_sync;
+use crate::db::module_cards::{
+    delete_orphaned_module_cards_sync, get_module_card_hashes_sync, get_module_entry_points_sync,
+    get_module_key_types_sync, get_project_ids_with_modules_sync, module_content_hash_sync,
+    module_path_pattern, upsert_module_card_sync,
+};
+use crate::db::pool::DatabasePool;
+use crate::llm::{LlmClient, PromptBuilder, chat_with_usage};
+use crate::utils::truncate_at_boundary;
+use rusqlite::Connection;
+use std::path::Path;
+use std::sync::Arc;
+
+/// Maximum cards generated per project per cycle (bounds LLM spend)
+const BATCH_SIZE: usize = 20;
+/// Max key types / entry points listed on a card
+const MAX_CARD_ITEMS: usize = 8;
+/// Max characters of an LLM-generated summary
+const MAX_SUMMARY_LEN: usize = 600;
+
+/// A module whose card is missing or out of date
+#[derive(Debug, Clone)]
+struct StaleModule {
+    module: Module,
+    content_hash: String,
+    key_types: Vec<String>,
+    entry_points: Vec<String>,
+}
+
+/// Refresh module cards for every indexed project.
+///
+/// A card is regenerated only when the hash of its module's indexed chunks
+/// differs from the stored one, so unchanged modules never cost a second LLM
+/// call. With `llm` set, cards previously built by the heuristic fallback are
+/// upgraded too.
+pub async fn process_module_cards(
+    code_pool: &Arc<DatabasePool>,
+    main_pool: &Arc<DatabasePool>,
+    llm: Option<&dyn LlmClient>,
+) -> Result<usize, String> {
+    let project_ids = code_pool.run(get_project_ids_with_modules_sync).await?;
+    if project_ids.is_empty() {
+        return Ok(0);
+    }
+
+    let ids_clone = project_ids.clone();
+    let projects = main_pool
+        .run(move |conn| get_project_paths_by_ids_sync(conn, &ids_clone))
+        .await?;
+
+    let upgrade_fallbacks = llm.is_some();
+    let mut total = 0;
+
+    for (project_id, project_path) in projects {
+        let mut stale = code_pool
+            .run(move |conn| find_stale_modules_sync(conn, project_id, upgrade_fallbacks))
+            .await?;
+        if stale.is_empty() {
+            continue;
+        }
+        stale.truncate(BATCH_SIZE);
+
+        let mut cards = Vec::with_capacity(stale.len());
+        for item in stale {
+            let summary = match llm {
+                Some(client) => {
+                    match generate_llm_summary(client, main_pool, project_id, &project_path, &item)
+                        .await
+                    {
+                        Ok(summary) => summary,
+                        Err(e) => {
+                            tracing::warn!(
+                                "Module card generation failed for {}: {}",
+                                item.module.id,
+                                e
+                            );
+                            heuristic_summary(&item)
+                        }
+                    }
+                }
+                None => heuristic_summary(&item),
+            };
+            cards.push(build_card(item, summary));
+        }
+
+        let count = code_pool
+            .run(move |conn| {
+                for card in &cards {
+                    upsert_module_card_sync(conn, project_id, card)?;
+                }
+                Ok::<_, rusqlite::Error>(cards.len())
+            })
+            .await?;
+        tracing::debug!(
+            "Refreshed {} module cards for project {}",
+            count,
+            project_id
+        );
+        total += count;
+    }
+
+    Ok(total)
+}
+
+/// Modules whose stored card is missing, has a different content hash, or (with
+/// `upgrade_fallbacks`) was produced by the heuristic generator. Cards for
+/// modules that no longer exist are deleted along the way.
+fn find_stale_modules_sync(
+    conn: &Connection,
+    project_id: i64,
+    upgrade_fallbacks: bool,
+) -> rusqlite::Result<Vec<StaleModule>> {
+    delete_orphaned_module_cards_sync(conn, project_id)?;
+    let stored = get_module_card_hashes_sync(conn, project_id)?;
+
+    let mut stale = Vec::new();
+    for module in crate::db::get_cached_modules_sync(conn, project_id)? {
+        let pattern = module_path_pattern(&module.path);
+        let Some(content_hash) = module_content_hash_sync(conn, project_id, &pattern)? else {
+            continue;
+        };
+        let needs_refresh = match stored.get(&module.id) {
+            None => true,
+            Some((hash, is_fallback)) => {
+                *hash != content_hash || (upgrade_fallbacks && *is_fallback)
+            }
+        };
+        if !needs_refresh {
+            continue;
+        }
+        stale.push(StaleModule {
+            key_types: get_module_key_types_sync(conn, project_id, &pattern, MAX_CARD_ITEMS)?,
+            entry_points: get_module_entry_points_sync(conn, project_id, &pattern, MAX_CARD_ITEMS)?,
+            module,
+            content_hash,
+        });
+    }
+    Ok(stale)
+}
+
+fn build_card(item: StaleModule, summary: String) -> ModuleCard {
+    ModuleCard {
+        module_id: item.module.id,
+        name: item.module.name,
+        path: item.module.path,
+        summary,
+        key_types: item.key_types,
+        entry_points: item.entry_points,
+        dependencies: item.module.depends_on,
+        content_hash: item.content_hash,
+        updated_at: None,
+    }
+}
+
+/// Card summary from index metadata alone (no LLM required)
+fn heuristic_summary(item: &StaleModule) -> String {
+    let module = &item.module;
+    let mut summary = format!(
+        "{}{} module ({} lines)",
+        HEURISTIC_PREFIX, module.name, module.line_count
+    );
+    if !item.key_types.is_empty() {
+        summary.push_str(&format!(". Key types: {}", item.key_types.join(", ")));
+    }
+    if !item.entry_points.is_empty() {
+        summary.push_str(&format!(". Entry points: {}", item.entry_points.join(", ")));
+    }
+    summary
+}
+
+/// Ask the LLM for a short purpose statement for one module
+async fn generate_llm_summary(
+    client: &dyn LlmClient,
+    main_pool: &Arc<DatabasePool>,
+    project_id: i64,
+    project_path: &str,
+    item: &StaleModule,
+) -> Result<String, String> {
+    let module = &item.module;
+    let preview = cartographer::get_module_code_preview(Path::new(project_path), &module.path);
+    let list = |items: &[String]| {
+        if items.is_empty() {
+            "(none)".to_string()
+        } else {
+            items.join(", ")
+        }
+    };
+    let prompt = format!(
+        "Describe the purpose of module `{}` ({}) in one or two sentences. \
+         Say what it is responsible for; do not list its types or functions.\n\n\
+         Key types: {}\nEntry points: {}\nDepends on: {}\n\nCode preview:\n{}",
+        module.id,
+        module.path,
+        list(&item.key_types),
+        list(&item.entry_points),
+        list(&module.depends_on),
+        preview
+    );
+    let messages = PromptBuilder::for_summaries().build_messages(prompt);
+
+    let content = chat_with_usage(
+        client,
+        main_pool,
+        messages,
+        "background:module_cards",
+        Some(project_id),
+        None,
+    )
+    .await?;
+
+    let summary = content.trim();
+    if summary.is_empty() {
+        return Err("Empty module summary".to_string());
+    }
+    Ok(truncate_at_boundary(summary, MAX_SUMMARY_LEN).to_string())
+}
+
+/// First sentence of a card summary without the fallback marker, for one-line listings
+pub fn card_one_liner(summary: &str) -> &str {
+    let text = summary.strip_prefix(HEURISTIC_PREFIX).unwrap_or(summary);
+    let end = text
+        .find(". ")
+        .or_else(|| text.find('\n'))
+        .unwrap_or(text.len());
+    text[..end].trim_end_matches('.')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::module_cards::{get_module_card_sync, get_module_cards_sync};
+    use crate::llm::{ChatResult, Message, Provider, Tool};
+    use async_trait::async_trait;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// LLM stand-in that counts calls and answers with a fixed summary
+    struct StubLlm {
+        calls: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl LlmClient for StubLlm {
+        async fn chat(
+            &self,
+            _messages: Vec<Message>,
+            _tools: Option<Vec<Tool>>,
+        ) -> anyhow::Result<ChatResult> {
+            let n = self.calls.fetch_add(1, Ordering::SeqCst) + 1;
+            Ok(ChatResult {
+                request_id: format!("stub-{n}"),
+                content: Some(format!("Owns database access (revision {n}).")),
+                reasoning_content: None,
+                tool_calls: None,
+                usage: None,
+                duration_ms: 0,
+            })
+        }
+
+        fn provider_type(&self) -> Provider {
+            Provider::DeepSeek
+        }
+
+        fn model_name(&self) -> String {
+            "stub".to_string()
+        }
+    }
+
+    async fn setup() -> (Arc<DatabasePool>, Arc<DatabasePool>, i64) {
+        let main = Arc::new(DatabasePool::open_in_memory().await.unwrap());
+        let code = Arc::new(DatabasePool::open_code_db_in_memory().await.unwrap());
+        let project_id = main
+            .interact(|conn| {
+                Ok(crate::db::get_or_create_project_sync(conn, "/test/cards", None)?.0)
+            })
+            .await
+            .unwrap();
+        code.interact(move |conn| {
+            for (id, path) in [("db", "src/db"), ("cli", "src/cli")] {
+                crate::db::upsert_module_sync(conn, project_id, &Module::new(id, id, path))?;
+                conn.execute(
+                    "INSERT INTO code_chunks (project_id, file_path, chunk_content, start_line)
+                     VALUES (?1, ?2, 'fn run() {}', 1)",
+                    rusqlite::params![project_id, format!("{path}/mod.rs")],
+                )?;
+            }
+            Ok(())
+        })
+        .await
+        .unwrap();
+        (main, code, project_id)
+    }
+
+    #[tokio::test]
+    async fn test_cards_regenerate_only_on_content_change() {
+        let (main, code, project_id) = setup().await;
+        let stub = StubLlm {
+            calls: AtomicUsize::new(0),
+        };
+
+        let first = process_module_cards(&code, &main, Some(&stub))
+            .await
+            .unwrap();
+        assert_eq!(first, 2);
+        assert_eq!(stub.calls.load(Ordering::SeqCst), 2);
+
+        // Nothing changed: no regeneration, no LLM calls
+        let second = process_module_cards(&code, &main, Some(&stub))
+            .await
+            .unwrap();
+        assert_eq!(second, 0);
+        assert_eq!(stub.calls.load(Ordering::SeqCst), 2);
+
+        // Edit one module's indexed content
+        code.interact(move |conn| {
+            conn.execute(
+                "UPDATE code_chunks SET chunk_content = 'fn run() { todo!() }'
+                 WHERE file_path = 'src/db/mod.rs'",
+                [],
+            )?;
+            Ok(())
+        })
+        .await
+        .unwrap();
+
+        let third = process_module_cards(&code, &main, Some(&stub))
+            .await
+            .unwrap();
+        assert_eq!(third, 1);
+        assert_eq!(stub.calls.load(Ordering::SeqCst), 3);
+
+        let card = code
+            .run(move |conn| get_module_card_sync(conn, project_id, "db"))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(card.summary, "Owns database access (revision 3).");
+    }
+
+    #[tokio::test]
+    async fn test_heuristic_cards_upgrade_when_llm_available() {
+        let (main, code, project_id) = setup().await;
+
+        assert_eq!(process_module_cards(&code, &main, None).await.unwrap(), 2);
+        // Heuristic cards are hash-gated too
+        assert_eq!(process_module_cards(&code, &main, None).await.unwrap(), 0);
+
+        let cards = code
+            .run(move |conn| get_module_cards_sync(conn, project_id))
+            .await
+            .unwrap();
+        assert!(
+            cards
+                .iter()
+                .all(|c| c.summary.starts_with(HEURISTIC_PREFIX))
+        );
+
+        let stub = StubLlm {
+            calls: AtomicUsize::new(0),
+        };
+        assert_eq!(
+            process_module_cards(&code, &main, Some(&stub))
+                .await
+                .unwrap(),
+            2
+        );
+        assert_eq!(
+            process_module_cards(&code, &main, Some(&stub))
+                .await
+                .unwrap(),
+            0
+        );
+        assert_eq!(stub.calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_card_one_liner() {
+        assert_eq!(
+            card_one_liner("[heuristic] db module (10 lines). Key types: Pool"),
+            "db module (10 lines)"
+        );
+        assert_eq!(
+            card_one_liner("Owns database access. Also pooling."),
+            "Owns database access"
+        );
+        assert_eq!(card_one_liner("Short summary."), "Short summary");
+    }
+}
@@ -4,29 +4,32 @@
 use super::HEURISTIC_PREFIX;
 use crate::db::pool::DatabasePool;
 use crate::db::{
+    CLOSE_REASON_IDLE_TIMEOUT, CLOSE_REASON_SESSION_END, build_session_activity_recap_sync,
     close_session_sync, get_session_behavior_summary_sync, get_session_tool_summary_sync,
-    get_sessions_needing_summary_sync, get_stale_sessions_sync, update_session_summary_sync,
+    get_sessions_needing_summary_sync, get_stale_sessions_sync, set_session_recap_sync,
+    update_session_summary_sync,
 };
 use crate::utils::truncate_at_boundary;
 use std::collections::HashMap;
 use std::sync::Arc;
 
-/// Minutes of inactivity before a session is considered stale
-const STALE_SESSION_MINUTES: i64 = 30;
-
 /// Minimum tool calls required to generate a summary (otherwise just close)
 const MIN_TOOLS_FOR_SUMMARY: i64 = 3;
 
 /// Max files to list in heuristic summary
 const MAX_FILES_IN_SUMMARY: usize = 5;
 
-/// Process stale sessions: close them and optionally generate summaries
-/// Also generates summaries for already-closed sessions that don't have one
-pub async fn process_stale_sessions(pool: &Arc<DatabasePool>) -> Result<usize, String> {
+/// Process stale sessions: close those inactive for `close_minutes` and
+/// optionally generate summaries. Also generates summaries for already-closed
+/// sessions that don't have one.
+pub async fn process_stale_sessions(
+    pool: &Arc<DatabasePool>,
+    close_minutes: i64,
+) -> Result<usize, String> {
     let mut processed = 0;
 
     // First, close stale active sessions
-    processed += close_stale_sessions(pool).await?;
+    processed += close_stale_sessions(pool, close_minutes).await?;
 
     // Then, generate summaries for completed sessions that need them
     processed += generate_missing_summaries(pool).await?;
@@ -34,10 +37,14 @@ pub async fn process_stale_sessions(pool: &Arc<DatabasePool>) -> Result<usize, S
     Ok(processed)
 }
 
-/// Close stale active sessions
-async fn close_stale_sessions(pool: &Arc<DatabasePool>) -> Result<usize, String> {
+/// Close stale active sessions, recording the idle timeout as the close reason
+/// and storing a recap of what each session did
+async fn close_stale_sessions(
+    pool: &Arc<DatabasePool>,
+    close_minutes: i64,
+) -> Result<usize, String> {
     let stale = pool
-        .run(move |conn| get_stale_sessions_sync(conn, STALE_SESSION_MINUTES))
+        .run(move |conn| get_stale_sessions_sync(conn, close_minutes))
         .await?;
 
     if stale.is_empty() {
@@ -54,6 +61,9 @@ async fn close_stale_sessions(pool: &Arc<DatabasePool>) -> Result<usize, String>
             None
         };
 
+        // Recap before closing: closing moves last_activity to now
+        let recap = build_activity_recap(pool, &session_id).await;
+
         // Close the session (scoped to project for defense-in-depth)
         let session_id_clone = session_id.clone();
         let summary_clone = summary.clone();
@@ -64,6 +74,7 @@ async fn close_stale_sessions(pool: &Arc<DatabasePool>) -> Result<usize, String>
                     &session_id_clone,
                     summary_clone.as_deref(),
                     project_id,
+                    CLOSE_REASON_IDLE_TIMEOUT,
                 )
             })
             .await
@@ -77,6 +88,8 @@ async fn close_stale_sessions(pool: &Arc<DatabasePool>) -> Result<usize, String>
             continue;
         }
 
+        store_activity_recap(pool, &session_id, recap).await;
+
         let summary_status = if summary.is_some() {
             "with summary"
         } else {
@@ -100,6 +113,39 @@ async fn close_stale_sessions(pool: &Arc<DatabasePool>) -> Result<usize, String>
     Ok(processed)
 }
 
+/// Build the session's activity recap, logging (not failing) on error
+async fn build_activity_recap(pool: &Arc<DatabasePool>, session_id: &str) -> Option<String> {
+    let session_id_clone = session_id.to_string();
+    pool.run(move |conn| build_session_activity_recap_sync(conn, &session_id_clone))
+        .await
+        .unwrap_or_else(|e| {
+            tracing::warn!(
+                "Failed to build recap for session {}: {}",
+                truncate_at_boundary(session_id, 8),
+                e
+            );
+            None
+        })
+}
+
+/// Store a recap built by `build_activity_recap` so the work isn't lost
+async fn store_activity_recap(pool: &Arc<DatabasePool>, session_id: &str, recap: Option<String>) {
+    let Some(recap) = recap else {
+        return;
+    };
+    let session_id_clone = session_id.to_string();
+    if let Err(e) = pool
+        .run(move |conn| set_session_recap_sync(conn, &session_id_clone, &recap))
+        .await
+    {
+        tracing::warn!(
+            "Failed to store recap for session {}: {}",
+            truncate_at_boundary(session_id, 8),
+            e
+        );
+    }
+}
+
 /// Generate summaries for completed sessions that don't have one
 async fn generate_missing_summaries(pool: &Arc<DatabasePool>) -> Result<usize, String> {
     let sessions = pool.run(get_sessions_needing_summary_sync).await?;
@@ -349,6 +395,8 @@ pub async fn close_session_now(
         }
     };
 
+    let recap = build_activity_recap(pool, session_id).await;
+
     // Close the session (scoped to project for defense-in-depth)
     let session_id_clone = session_id.to_string();
     let summary_clone = summary.clone();
@@ -358,10 +406,13 @@ pub async fn close_session_now(
             &session_id_clone,
             summary_clone.as_deref(),
             project_id,
+            CLOSE_REASON_SESSION_END,
         )
     })
     .await?;
 
+    store_activity_recap(pool, session_id, recap).await;
+
     Ok(summary)
 }
 
@@ -422,4 +473,83 @@ mod tests {
         assert_eq!(shorten_path("main.rs"), "main.rs");
         assert_eq!(shorten_path("src/lib.rs"), "src/lib.rs");
     }
+
+    #[tokio::test]
+    async fn test_idle_sessions_are_closed_with_summary_and_reason() {
+        use crate::db::test_support::seed_tool_history;
+
+        let pool = Arc::new(DatabasePool::open_in_memory().await.unwrap());
+        pool.interact(|conn| {
+            crate::db::create_session_sync(conn, "sess-stale", None)?;
+            crate::db::create_session_sync(conn, "sess-recent", None)?;
+            for file in ["src/a.rs", "src/b.rs", "src/c.rs"] {
+                seed_tool_history(conn, "sess-stale", "Edit", file, "ok");
+            }
+            seed_tool_history(conn, "sess-recent", "Bash", "ls", "ok");
+            conn.execute(
+                "UPDATE sessions SET last_activity = datetime('now', '-45 minutes') WHERE id = 'sess-stale'",
+                [],
+            )?;
+            conn.execute(
+                "UPDATE sessions SET last_activity = datetime('now', '-5 minutes') WHERE id = 'sess-recent'",
+                [],
+            )?;
+            Ok(())
+        })
+        .await
+        .unwrap();
+
+        let processed = process_stale_sessions(&pool, 30).await.unwrap();
+        assert_eq!(processed, 1);
+
+        type Row = (
+            String,
+            String,
+            Option<String>,
+            Option<String>,
+            Option<String>,
+        );
+        let rows: Vec<Row> = pool
+            .interact(|conn| {
+                let mut stmt = conn.prepare(
+                    "SELECT id, status, close_reason, summary, recap FROM sessions ORDER BY id",
+                )?;
+                let rows = stmt
+                    .query_map([], |r| {
+                        Ok((r.get(0)?, r.get(1)?, r.get(2)?, r.get(3)?, r.get(4)?))
+                    })?
+                    .collect::<rusqlite::Result<Vec<_>>>()?;
+                Ok(rows)
+            })
+            .await
+            .unwrap();
+
+        let (_, status, reason, _, recap) = &rows[0];
+        assert_eq!(rows[0].0, "sess-recent");
+        assert_eq!(status, "active");
+        assert!(reason.is_none());
+        assert!(recap.is_none());
+
+        let (_, status, reason, summary, recap) = &rows[1];
+        assert_eq!(rows[1].0, "sess-stale");
+        assert_eq!(status, "completed");
+        assert_eq!(reason.as_deref(), Some(CLOSE_REASON_IDLE_TIMEOUT));
+        let summary = summary
+            .as_deref()
+            .expect("auto-closed session should keep a summary");
+        assert!(summary.starts_with(HEURISTIC_PREFIX));
+        assert!(summary.contains("Edit"));
+        let recap = recap
+            .as_deref()
+            .expect("auto-closed session should store a recap");
+        assert!(
+            recap.starts_with("--- Session sess-sta"),
+            "recap was: {recap}"
+        );
+        assert!(recap.contains("Tools used: Edit x3"), "recap was: {recap}");
+        assert!(
+            !recap.contains("Bash"),
+            "recap must only cover the closed session's own activity: {recap}"
+        );
+    }
 }
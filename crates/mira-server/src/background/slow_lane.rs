@@ -12,8 +12,8 @@ use tokio::sync::watch;
 use tokio::time::timeout;
 
 use super::{
-    briefings, code_health, documentation, outcome_scanner, pondering, session_summaries,
-    summaries, team_monitor,
+    briefings, code_health, documentation, module_cards, outcome_scanner, pondering,
+    session_summaries, summaries, team_monitor,
 };
 
 /// Delay before first cycle to let the service start up
@@ -41,8 +41,12 @@ const PONDERING_CYCLE_INTERVAL: u64 = 10;
 const OUTCOME_SCAN_CYCLE_INTERVAL: u64 = 5;
 /// Run team monitoring every Nth cycle
 const TEAM_MONITOR_CYCLE_INTERVAL: u64 = 3;
+/// Run module card refresh every Nth cycle (hash-gated, so cheap when nothing changed)
+const MODULE_CARDS_CYCLE_INTERVAL: u64 = 5;
 /// Run data retention every Nth cycle (~10 min interval at 60s idle)
 const DATA_RETENTION_CYCLE_INTERVAL: u64 = 10;
+/// Check database growth every Nth cycle (samples at most once per day)
+const DB_HEALTH_CYCLE_INTERVAL: u64 = 10;
 
 /// Priority level for background tasks. Lower numeric value = higher priority.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -69,7 +73,9 @@ enum BackgroundTask {
     PonderingInsights,
     InsightCleanup,
     DiffOutcomes,
+    ModuleCards,
     DataRetention,
+    DbHealth,
 }
 
 impl std::fmt::Display for BackgroundTask {
@@ -84,7 +90,9 @@ impl std::fmt::Display for BackgroundTask {
             Self::PonderingInsights => write!(f, "pondering insights"),
             Self::InsightCleanup => write!(f, "insight cleanup"),
             Self::DiffOutcomes => write!(f, "diff outcomes"),
+            Self::ModuleCards => write!(f, "module cards"),
             Self::DataRetention => write!(f, "data retention"),
+            Self::DbHealth => write!(f, "db health"),
         }
     }
 }
@@ -159,11 +167,21 @@ fn task_schedule() -> Vec<ScheduledTask> {
             priority: TaskPriority::Low,
             cycle_interval: Some(OUTCOME_SCAN_CYCLE_INTERVAL),
         },
+        ScheduledTask {
+            task: BackgroundTask::ModuleCards,
+            priority: TaskPriority::Low,
+            cycle_interval: Some(MODULE_CARDS_CYCLE_INTERVAL),
+        },
         ScheduledTask {
             task: BackgroundTask::DataRetention,
             priority: TaskPriority::Low,
             cycle_interval: Some(DATA_RETENTION_CYCLE_INTERVAL),
         },
+        ScheduledTask {
+            task: BackgroundTask::DbHealth,
+            priority: TaskPriority::Low,
+            cycle_interval: Some(DB_HEALTH_CYCLE_INTERVAL),
+        },
     ]
 }
 
@@ -211,7 +229,12 @@ impl SlowLaneWorker {
         tracing::info!("Slow lane worker started");
 
         // Initial delay to let the service start up
-        tokio::time::sleep(Duration::from_secs(INITIAL_DELAY_SECS)).await;
+        if super::sleep_or_shutdown(&mut self.shutdown, Duration::from_secs(INITIAL_DELAY_SECS))
+            .await
+        {
+            tracing::info!("Slow lane worker shutting down");
+            return;
+        }
 
         loop {
             // Check for shutdown
@@ -225,21 +248,20 @@ impl SlowLaneWorker {
             let processed = self.process_batch().await;
             self.last_cycle_duration = start.elapsed();
 
-            if processed > 0 {
+            // Short delay between batches when there's work, longer when idle
+            let delay = if processed > 0 {
                 tracing::info!(
                     "Slow lane: processed {} items in {:.1}s",
                     processed,
                     self.last_cycle_duration.as_secs_f64()
                 );
-                // Short delay between batches when there's work
-                tokio::time::sleep(Duration::from_secs(ACTIVE_DELAY_SECS)).await;
+                ACTIVE_DELAY_SECS
             } else {
-                // No work found, sleep longer
-                tokio::time::sleep(Duration::from_secs(IDLE_DELAY_SECS)).await;
-            }
+                IDLE_DELAY_SECS
+            };
 
-            // Check shutdown again before next iteration
-            if self.shutdown.has_changed().unwrap_or(false) && *self.shutdown.borrow() {
+            if super::sleep_or_shutdown(&mut self.shutdown, Duration::from_secs(delay)).await {
+                tracing::info!("Slow lane worker shutting down");
                 break;
             }
         }
@@ -318,8 +340,13 @@ impl SlowLaneWorker {
 
         match task {
             BackgroundTask::StaleSessions => {
-                self.run_task(&name, session_summaries::process_stale_sessions(&pool))
-                    .await
+                // Closing acts on every project's sessions: global config only
+                let close_minutes = crate::config::MiraConfig::load().sessions.close_minutes();
+                self.run_task(
+                    &name,
+                    session_summaries::process_stale_sessions(&pool, close_minutes),
+                )
+                .await
             }
             BackgroundTask::Summaries => {
                 self.run_task(&name, summaries::process_queue(&code_pool, &pool))
@@ -358,12 +385,21 @@ impl SlowLaneWorker {
                 )
                 .await
             }
+            BackgroundTask::ModuleCards => {
+                // No background LLM is configured, so cards use the heuristic generator
+                self.run_task(
+                    &name,
+                    module_cards::process_module_cards(&code_pool, &pool, None),
+                )
+                .await
+            }
             BackgroundTask::TeamMonitor => {
                 self.run_task(&name, team_monitor::process_team_monitor(&pool))
                     .await
             }
             BackgroundTask::DataRetention => {
                 self.run_task(&name, async move {
+                    // Database-wide policy: global config only, never a project's file
                     let config = crate::config::MiraConfig::load().retention;
 
                     // Retention rules only run if explicitly enabled
@@ -388,6 +424,18 @@ impl SlowLaneWorker {
                 })
                 .await
             }
+            BackgroundTask::DbHealth => {
+                let config = crate::config::MiraConfig::load().db_health;
+                if !config.enabled {
+                    return 0;
+                }
+                self.run_task(&name, async move {
+                    Ok(pool
+                        .run(move |conn| crate::db::db_health::check_db_health_sync(conn, &config))
+                        .await?)
+                })
+                .await
+            }
         }
     }
 
@@ -590,7 +638,9 @@ mod tests {
         assert!(names.contains(&"pondering insights".to_string()));
         assert!(names.contains(&"insight cleanup".to_string()));
         assert!(names.contains(&"diff outcomes".to_string()));
+        assert!(names.contains(&"module cards".to_string()));
         assert!(names.contains(&"data retention".to_string()));
+        assert!(names.contains(&"db health".to_string()));
     }
 
     #[test]
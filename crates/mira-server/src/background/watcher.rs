@@ -38,12 +38,19 @@ static LAST_DROP_WARNING: AtomicU64 = AtomicU64::new(0);
 /// Pending change entry: (change_type, last_attempt, retry_count)
 type PendingChange = (ChangeType, Instant, u32);
 
+/// Attached project roots: root_path -> (project_id, root_name)
+type WatchedRoots = HashMap<PathBuf, (i64, String)>;
+
 /// File watcher manages watching multiple project directories
 pub struct FileWatcher {
     pool: Arc<DatabasePool>,
+    /// Main database, for syncing configured markdown checklists with tasks
+    main_pool: Option<Arc<DatabasePool>>,
     fuzzy_cache: Option<Arc<FuzzyCache>>,
     /// Map of project_id -> project_path for active watches
     watched_projects: Arc<RwLock<HashMap<i64, PathBuf>>>,
+    /// Additional roots of watched projects, indexed as `@name/...`
+    watched_roots: Arc<RwLock<WatchedRoots>>,
     /// Pending file changes (debounced)
     pending_changes: Arc<RwLock<HashMap<PathBuf, PendingChange>>>,
     shutdown: watch::Receiver<bool>,
@@ -61,14 +68,17 @@ pub enum ChangeType {
 impl FileWatcher {
     pub fn new(
         pool: Arc<DatabasePool>,
+        main_pool: Option<Arc<DatabasePool>>,
         fuzzy_cache: Option<Arc<FuzzyCache>>,
         shutdown: watch::Receiver<bool>,
         fast_lane_notify: Option<FastLaneNotify>,
     ) -> Self {
         Self {
             pool,
+            main_pool,
             fuzzy_cache,
             watched_projects: Arc::new(RwLock::new(HashMap::new())),
+            watched_roots: Arc::new(RwLock::new(HashMap::new())),
             pending_changes: Arc::new(RwLock::new(HashMap::new())),
             shutdown,
             fast_lane_notify,
@@ -88,7 +98,19 @@ impl FileWatcher {
         });
     }
 
-    /// Stop watching a project
+    /// Start watching an additional root of a project
+    pub async fn watch_root(&self, project_id: i64, root_name: String, root_path: PathBuf) {
+        tracing::info!(
+            "Starting file watch for root '{}' of project {} at {:?}",
+            root_name,
+            project_id,
+            root_path
+        );
+        let mut roots = self.watched_roots.write().await;
+        roots.insert(root_path, (project_id, root_name));
+    }
+
+    /// Stop watching a project and its attached roots
     pub async fn unwatch_project(&self, project_id: i64) {
         let mut projects = self.watched_projects.write().await;
         if let Some(path) = projects.remove(&project_id) {
@@ -98,6 +120,8 @@ impl FileWatcher {
                 path
             );
         }
+        let mut roots = self.watched_roots.write().await;
+        roots.retain(|_, (pid, _)| *pid != project_id);
     }
 
     /// Run the file watcher loop
@@ -124,7 +148,10 @@ impl FileWatcher {
 
                         if let Some(ct) = change_type {
                             for path in event.paths {
-                                if Self::should_process_path(&path) {
+                                if Self::should_process_path(&path)
+                                    || (Self::is_markdown_path(&path)
+                                        && !Self::in_skipped_dir(&path))
+                                {
                                     // Use try_send to avoid blocking the notify callback
                                     // thread when the channel is full
                                     if let Err(e) = tx_clone.try_send((path, ct)) {
@@ -201,22 +228,24 @@ impl FileWatcher {
                 break;
             }
 
-            // Update watched directories based on registered projects
+            // Update watched directories based on registered projects and their roots
             {
                 let projects = self.watched_projects.read().await;
-                for (_, project_path) in projects.iter() {
-                    if !watched_paths.contains(project_path) {
-                        if let Err(e) = watcher.watch(project_path, RecursiveMode::Recursive) {
-                            tracing::warn!("Failed to watch {:?}: {}", project_path, e);
+                let roots = self.watched_roots.read().await;
+                let current_paths: HashSet<PathBuf> =
+                    projects.values().chain(roots.keys()).cloned().collect();
+                for path in &current_paths {
+                    if !watched_paths.contains(path) {
+                        if let Err(e) = watcher.watch(path, RecursiveMode::Recursive) {
+                            tracing::warn!("Failed to watch {:?}: {}", path, e);
                         } else {
-                            tracing::info!("Watcher: now watching {:?}", project_path);
-                            watched_paths.insert(project_path.clone());
+                            tracing::info!("Watcher: now watching {:?}", path);
+                            watched_paths.insert(path.clone());
                         }
                     }
                 }
 
-                // Unwatch removed projects
-                let current_paths: HashSet<_> = projects.values().cloned().collect();
+                // Unwatch removed projects and roots
                 watched_paths.retain(|path| {
                     if current_paths.contains(path) {
                         true
@@ -246,6 +275,13 @@ impl FileWatcher {
                 }
             }
         }
+
+        // Pick up events that were already delivered, then index everything
+        // still pending so edits made just before shutdown aren't lost.
+        while let Ok((path, change_type)) = rx.try_recv() {
+            self.queue_change(path, change_type).await;
+        }
+        self.flush_pending_changes().await;
     }
 
     /// Check if a path should be processed
@@ -256,17 +292,20 @@ impl FileWatcher {
             return false;
         }
 
-        // Check for skip directories in path
-        for component in path.components() {
-            if let std::path::Component::Normal(name) = component {
-                let name_str = name.to_string_lossy();
-                if ignore::should_skip(&name_str) {
-                    return false;
-                }
-            }
-        }
+        !Self::in_skipped_dir(path)
+    }
 
-        true
+    /// Markdown files are never indexed, but may be synced checklists
+    fn is_markdown_path(path: &Path) -> bool {
+        path.extension().and_then(|e| e.to_str()) == Some("md")
+    }
+
+    /// Check for skip directories in path
+    fn in_skipped_dir(path: &Path) -> bool {
+        path.components().any(|component| match component {
+            std::path::Component::Normal(name) => ignore::should_skip(&name.to_string_lossy()),
+            _ => false,
+        })
     }
 
     /// Queue a file change for processing (with debounce)
@@ -284,8 +323,25 @@ impl FileWatcher {
 
     /// Process pending changes after debounce period
     async fn process_pending_changes(&self) {
+        self.process_changes_older_than(Duration::from_millis(DEBOUNCE_MS))
+            .await;
+    }
+
+    /// Process every pending change immediately, ignoring the debounce window.
+    ///
+    /// Used on shutdown. Failed changes are retried up to `MAX_RETRIES` times.
+    async fn flush_pending_changes(&self) {
+        for _ in 0..Self::MAX_RETRIES {
+            if self.pending_changes.read().await.is_empty() {
+                return;
+            }
+            self.process_changes_older_than(Duration::ZERO).await;
+        }
+    }
+
+    /// Process pending changes whose last update is at least `debounce` old
+    async fn process_changes_older_than(&self, debounce: Duration) {
         let now = Instant::now();
-        let debounce = Duration::from_millis(DEBOUNCE_MS);
 
         // Collect changes that have passed debounce period
         let ready: Vec<(PathBuf, ChangeType, u32)> = {
@@ -354,20 +410,32 @@ impl FileWatcher {
         change_type: ChangeType,
     ) -> Result<(), String> {
         // Find which project this file belongs to
-        let (project_id, relative_path) = {
+        let (project_id, rel_path_str) = {
             let projects = self.watched_projects.read().await;
-            let mut found: Option<(usize, i64, PathBuf)> = None;
-            for (pid, project_path) in projects.iter() {
-                if path.starts_with(project_path)
-                    && let Ok(rel) = path.strip_prefix(project_path)
-                {
-                    // Prefer the most specific (longest) matching project root.
-                    let depth = project_path.components().count();
+            let roots = self.watched_roots.read().await;
+            let candidates = projects
+                .iter()
+                .map(|(pid, project_path)| (project_path, *pid, None))
+                .chain(
+                    roots
+                        .iter()
+                        .map(|(root_path, (pid, name))| (root_path, *pid, Some(name))),
+                );
+            let mut found: Option<(usize, i64, String)> = None;
+            for (root, pid, root_name) in candidates {
+                if let Ok(rel) = path.strip_prefix(root) {
+                    // Prefer the most specific (longest) matching root.
+                    let depth = root.components().count();
                     if found
                         .as_ref()
                         .is_none_or(|(best_depth, _, _)| depth > *best_depth)
                     {
-                        found = Some((depth, *pid, rel.to_path_buf()));
+                        let rel = crate::utils::path_to_string(rel);
+                        let rel = match root_name {
+                            Some(name) => crate::project_roots::root_file_path(name, &rel),
+                            None => rel,
+                        };
+                        found = Some((depth, pid, rel));
                     }
                 }
             }
@@ -376,7 +444,12 @@ impl FileWatcher {
             (pid, rel)
         };
 
-        let rel_path_str = crate::utils::path_to_string(&relative_path);
+        if Self::is_markdown_path(path) {
+            if change_type != ChangeType::Deleted {
+                self.sync_todo_file(project_id, &rel_path_str).await;
+            }
+            return Ok(());
+        }
 
         match change_type {
             ChangeType::Deleted => {
@@ -409,6 +482,41 @@ impl FileWatcher {
         Ok(())
     }
 
+    /// Sync a markdown file with tasks if the project lists it under `[todo_sync]`
+    async fn sync_todo_file(&self, project_id: i64, rel_path: &str) {
+        let Some(main_pool) = self.main_pool.as_ref() else {
+            return;
+        };
+        let Some(root) = self.watched_projects.read().await.get(&project_id).cloned() else {
+            return;
+        };
+        let config = crate::config::MiraConfig::load_layered(Some(&root))
+            .config
+            .todo_sync;
+        let Some(file) = config
+            .files
+            .iter()
+            .find(|f| f.trim_start_matches("./") == rel_path)
+        else {
+            return;
+        };
+        match crate::tasks::todo_sync::sync_todo_file(
+            main_pool,
+            project_id,
+            &root,
+            file,
+            config.write_back,
+        )
+        .await
+        {
+            Ok(Some(report)) if !report.is_noop() => {
+                tracing::info!("Synced {} with tasks: {:?}", rel_path, report);
+            }
+            Ok(_) => {}
+            Err(e) => tracing::warn!("Task sync failed for {}: {}", rel_path, e),
+        }
+    }
+
     /// Delete all data associated with a file (runs on pool connection)
     async fn delete_file_data(&self, project_id: i64, file_path: &str) -> Result<(), String> {
         let file_path = file_path.to_string();
@@ -592,6 +700,9 @@ impl FileWatcher {
 #[derive(Clone)]
 pub struct WatcherHandle {
     watched_projects: Arc<RwLock<HashMap<i64, PathBuf>>>,
+    watched_roots: Arc<RwLock<WatchedRoots>>,
+    /// Flips to true once the watcher task has flushed and exited
+    stopped: watch::Receiver<bool>,
 }
 
 impl WatcherHandle {
@@ -608,25 +719,56 @@ impl WatcherHandle {
         });
     }
 
-    /// Unregister a project from watching
+    /// Register an additional root of a project for watching.
+    /// Files under it are indexed as `@root_name/...`.
+    pub async fn watch_root(&self, project_id: i64, root_name: String, root_path: PathBuf) {
+        let mut roots = self.watched_roots.write().await;
+        if roots.get(&root_path) != Some(&(project_id, root_name.clone())) {
+            tracing::info!(
+                "Registering root '{}' of project {} for file watching at {:?}",
+                root_name,
+                project_id,
+                root_path
+            );
+            roots.insert(root_path, (project_id, root_name));
+        }
+    }
+
+    /// Unregister a project and its attached roots from watching
     pub async fn unwatch(&self, project_id: i64) {
         let mut projects = self.watched_projects.write().await;
         projects.remove(&project_id);
+        let mut roots = self.watched_roots.write().await;
+        roots.retain(|_, (pid, _)| *pid != project_id);
+    }
+
+    /// Wait until the watcher has processed pending changes and exited.
+    ///
+    /// Resolves after the shutdown signal passed to [`spawn`] has been sent
+    /// and the final flush has completed.
+    pub async fn wait_stopped(&self) {
+        let mut stopped = self.stopped.clone();
+        let _ = stopped.wait_for(|done| *done).await;
     }
 }
 
 /// Spawn the file watcher and return a handle for registering projects
 pub fn spawn(
     pool: Arc<DatabasePool>,
+    main_pool: Option<Arc<DatabasePool>>,
     fuzzy_cache: Option<Arc<FuzzyCache>>,
     shutdown: watch::Receiver<bool>,
     fast_lane_notify: Option<FastLaneNotify>,
 ) -> WatcherHandle {
     let watched_projects = Arc::new(RwLock::new(HashMap::new()));
+    let watched_roots = Arc::new(RwLock::new(HashMap::new()));
     let pending_changes: Arc<RwLock<HashMap<PathBuf, PendingChange>>> =
         Arc::new(RwLock::new(HashMap::new()));
+    let (stopped_tx, stopped_rx) = watch::channel(false);
     let handle = WatcherHandle {
         watched_projects: watched_projects.clone(),
+        watched_roots: watched_roots.clone(),
+        stopped: stopped_rx,
     };
 
     tokio::spawn(async move {
@@ -646,8 +788,10 @@ pub fn spawn(
 
             let watcher = FileWatcher {
                 pool: pool.clone(),
+                main_pool: main_pool.clone(),
                 fuzzy_cache: fuzzy_cache.clone(),
                 watched_projects: watched_projects.clone(),
+                watched_roots: watched_roots.clone(),
                 pending_changes: pending_changes.clone(),
                 shutdown: shutdown.clone(),
                 fast_lane_notify: fast_lane_notify.clone(),
@@ -700,6 +844,7 @@ pub fn spawn(
                 }
             }
         }
+        let _ = stopped_tx.send(true);
     });
 
     handle
@@ -839,4 +984,143 @@ mod tests {
         let path = Path::new("/project/__pycache__/module.py");
         assert!(!FileWatcher::should_process_path(path));
     }
+
+    // ============================================================================
+    // Shutdown flush tests
+    // ============================================================================
+
+    async fn watcher_for(dir: &Path) -> (FileWatcher, Arc<DatabasePool>) {
+        let pool = Arc::new(DatabasePool::open_code_db_in_memory().await.unwrap());
+        let (_tx, rx) = watch::channel(false);
+        let watcher = FileWatcher::new(pool.clone(), None, None, rx, None);
+        watcher.watch_project(1, dir.to_path_buf()).await;
+        (watcher, pool)
+    }
+
+    #[cfg(feature = "parsers")]
+    #[tokio::test]
+    async fn test_flush_ignores_debounce() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("lib.rs");
+        std::fs::write(&file, "pub fn flushed_on_shutdown() {}\n").unwrap();
+        let (watcher, pool) = watcher_for(dir.path()).await;
+
+        watcher.queue_change(file, ChangeType::Created).await;
+
+        // Still inside the debounce window: the regular cycle leaves it queued
+        watcher.process_pending_changes().await;
+        assert_eq!(watcher.pending_changes.read().await.len(), 1);
+
+        watcher.flush_pending_changes().await;
+        assert!(watcher.pending_changes.read().await.is_empty());
+
+        let symbols: i64 = pool
+            .run(|conn| {
+                conn.query_row(
+                    "SELECT COUNT(*) FROM code_symbols WHERE project_id = 1 AND file_path = 'lib.rs'",
+                    [],
+                    |row| row.get(0),
+                )
+            })
+            .await
+            .unwrap();
+        assert!(symbols > 0, "flushed file should be indexed");
+    }
+
+    #[cfg(feature = "parsers")]
+    #[tokio::test]
+    async fn test_attached_root_changes_are_indexed_with_root_prefix() {
+        let api = tempfile::tempdir().unwrap();
+        let web = tempfile::tempdir().unwrap();
+        let api_file = api.path().join("main.rs");
+        let web_file = web.path().join("app.ts");
+        std::fs::write(&api_file, "fn serve_api() {}\n").unwrap();
+        std::fs::write(&web_file, "export function renderPage() {}\n").unwrap();
+        let (watcher, pool) = watcher_for(api.path()).await;
+        watcher
+            .watch_root(1, "web".into(), web.path().to_path_buf())
+            .await;
+
+        watcher.queue_change(api_file, ChangeType::Created).await;
+        watcher.queue_change(web_file, ChangeType::Created).await;
+        watcher.flush_pending_changes().await;
+        assert!(watcher.pending_changes.read().await.is_empty());
+
+        let files: Vec<String> = pool
+            .run(|conn| {
+                let mut stmt = conn.prepare(
+                    "SELECT DISTINCT file_path FROM code_symbols WHERE project_id = 1 ORDER BY file_path",
+                )?;
+                stmt.query_map([], |row| row.get(0))?.collect()
+            })
+            .await
+            .unwrap();
+        assert_eq!(
+            files,
+            vec!["@web/app.ts".to_string(), "main.rs".to_string()]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_configured_markdown_checklist_syncs_tasks() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir(dir.path().join(".mira")).unwrap();
+        std::fs::write(
+            dir.path().join(".mira/config.toml"),
+            "[todo_sync]\nfiles = [\"TODO.md\"]\n",
+        )
+        .unwrap();
+        let todo = dir.path().join("TODO.md");
+        let notes = dir.path().join("NOTES.md");
+        std::fs::write(&todo, "- [ ] Watched item\n").unwrap();
+        std::fs::write(&notes, "- [ ] Not configured\n").unwrap();
+
+        let code_pool = Arc::new(DatabasePool::open_code_db_in_memory().await.unwrap());
+        let (main_pool, project_id) = crate::db::test_support::setup_test_pool_with_project().await;
+        let (_tx, rx) = watch::channel(false);
+        let watcher = FileWatcher::new(code_pool, Some(main_pool.clone()), None, rx, None);
+        watcher
+            .watch_project(project_id, dir.path().to_path_buf())
+            .await;
+
+        watcher.queue_change(todo, ChangeType::Modified).await;
+        watcher.queue_change(notes, ChangeType::Modified).await;
+        watcher.flush_pending_changes().await;
+        assert!(watcher.pending_changes.read().await.is_empty());
+
+        let titles: Vec<String> = main_pool
+            .run(move |conn| {
+                let mut stmt = conn.prepare("SELECT title FROM tasks WHERE project_id = ?")?;
+                stmt.query_map([project_id], |row| row.get(0))?.collect()
+            })
+            .await
+            .unwrap();
+        assert_eq!(titles, vec!["Watched item".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_flush_gives_up_on_unresolvable_paths() {
+        let dir = tempfile::tempdir().unwrap();
+        let (watcher, _pool) = watcher_for(dir.path()).await;
+
+        // Outside every watched project, so it can never succeed
+        watcher
+            .queue_change(PathBuf::from("/elsewhere/main.rs"), ChangeType::Modified)
+            .await;
+        watcher.flush_pending_changes().await;
+
+        assert!(watcher.pending_changes.read().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_spawned_watcher_reports_stopped() {
+        let pool = Arc::new(DatabasePool::open_code_db_in_memory().await.unwrap());
+        let (tx, rx) = watch::channel(false);
+        let handle = spawn(pool, None, None, rx, None);
+
+        tx.send(true).unwrap();
+        tokio::time::timeout(Duration::from_secs(5), handle.wait_stopped())
+            .await
+            .expect("watcher should stop after shutdown signal");
+    }
 }
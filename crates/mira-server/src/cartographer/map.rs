@@ -2,7 +2,7 @@
 // Codebase map generation, enrichment, and caching
 
 use super::detection::{
-    count_lines_in_module, detect_modules, find_entry_points, resolve_import_to_module,
+    count_lines_in_module, detect_modules_for_types, find_entry_points, resolve_import_to_module,
 };
 use super::types::{CodebaseMap, Module};
 use crate::db::pool::DatabasePool;
@@ -69,20 +69,26 @@ pub async fn get_modules_with_purposes_pool(
 }
 
 /// Pool-based async version of get_or_generate_map
+///
+/// `project_type` is the primary language (entry points, line counts, import
+/// resolution); modules are detected for every entry in `languages`.
 pub async fn get_or_generate_map_pool(
     pool: Arc<DatabasePool>,
     project_id: i64,
     project_path: String,
     project_name: String,
     project_type: String,
+    languages: Vec<String>,
 ) -> Result<CodebaseMap> {
     pool.interact(move |conn| {
+        let languages: Vec<&str> = languages.iter().map(String::as_str).collect();
         get_or_generate_map_sync(
             conn,
             project_id,
             &project_path,
             &project_name,
             &project_type,
+            &languages,
         )
     })
     .await
@@ -95,6 +101,7 @@ fn get_or_generate_map_sync(
     project_path: &str,
     project_name: &str,
     project_type: &str,
+    languages: &[&str],
 ) -> Result<CodebaseMap> {
     tracing::info!(
         "get_or_generate_map_sync: project_id={}, path={}",
@@ -110,7 +117,11 @@ fn get_or_generate_map_sync(
     if cached_count == 0 {
         // Generate fresh using polyglot detection
         let path = Path::new(project_path);
-        let modules = detect_modules(path, project_type);
+        let modules = if languages.is_empty() {
+            detect_modules_for_types(path, &[project_type])
+        } else {
+            detect_modules_for_types(path, languages)
+        };
 
         // Enrich with database data and store
         let enriched =
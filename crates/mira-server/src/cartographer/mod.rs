@@ -18,7 +18,7 @@ pub use summaries::{
     build_summary_prompt, get_module_code_preview, get_module_full_code,
     get_modules_needing_summaries, parse_summary_response, update_module_purposes,
 };
-pub use types::{CodebaseMap, Module, ModuleSummaryContext};
+pub use types::{CodebaseMap, Module, ModuleCard, ModuleSummaryContext};
 
 /// Format codebase map in compact text format
 pub fn format_compact(map: &CodebaseMap) -> String {
@@ -68,3 +68,21 @@ pub struct ModuleSummaryContext {
     pub code_preview: String,
     pub line_count: u32,
 }
+
+/// Per-module card: a short summary plus the module's key types, entry points
+/// and dependencies. Regenerated only when `content_hash` changes.
+#[derive(Debug, Clone)]
+pub struct ModuleCard {
+    pub module_id: String,
+    pub name: String,
+    pub path: String,
+    /// One-paragraph purpose (LLM-generated or heuristic)
+    pub summary: String,
+    pub key_types: Vec<String>,
+    pub entry_points: Vec<String>,
+    /// Module IDs this depends on
+    pub dependencies: Vec<String>,
+    /// Hash of the module's indexed chunks when the card was generated
+    pub content_hash: String,
+    pub updated_at: Option<String>,
+}
@@ -0,0 +1,84 @@
+// crates/mira-server/src/cli/audit.rs
+// CLI handlers for `mira audit export` and `mira audit verify`
+
+use anyhow::{Context, Result, bail};
+use chrono::NaiveDate;
+use mira::audit::{audit_key_from_env, manifest_path, verify_export, write_export};
+use mira::db::audit::AuditFilter;
+use mira::db::pool::DatabasePool;
+use mira::utils::normalize_project_path;
+use std::io::BufWriter;
+use std::path::PathBuf;
+
+fn parse_date(label: &str, value: &str) -> Result<()> {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .map(|_| ())
+        .with_context(|| format!("--{label} must be YYYY-MM-DD, got '{value}'"))
+}
+
+/// Run `mira audit export`
+pub async fn run_audit_export(
+    since: String,
+    until: Option<String>,
+    project: Option<String>,
+    tool: Option<String>,
+    out: PathBuf,
+) -> Result<()> {
+    let until = until.unwrap_or_else(|| chrono::Utc::now().format("%Y-%m-%d").to_string());
+    parse_date("since", &since)?;
+    parse_date("until", &until)?;
+    if since > until {
+        bail!("--since ({since}) is after --until ({until})");
+    }
+    let key = audit_key_from_env()?;
+
+    let db_path = super::get_db_path();
+    if !db_path.exists() {
+        bail!("No Mira database found at {}", db_path.display());
+    }
+    let pool = DatabasePool::open(&db_path).await?;
+
+    let filter = AuditFilter {
+        since,
+        until,
+        project_path: project.as_deref().map(normalize_project_path),
+        tool_name: tool,
+    };
+    let file =
+        std::fs::File::create(&out).with_context(|| format!("creating {}", out.display()))?;
+    let manifest = pool
+        .interact(move |conn| write_export(conn, &filter, &key, BufWriter::new(file)))
+        .await?;
+
+    let manifest_file = manifest_path(&out);
+    std::fs::write(&manifest_file, serde_json::to_string_pretty(&manifest)?)
+        .with_context(|| format!("writing {}", manifest_file.display()))?;
+
+    println!(
+        "Exported {} tool executions ({} .. {}) to {}",
+        manifest.row_count,
+        manifest.since,
+        manifest.until,
+        out.display()
+    );
+    println!("Manifest: {}", manifest_file.display());
+    Ok(())
+}
+
+/// Run `mira audit verify`
+pub fn run_audit_verify(file: PathBuf) -> Result<()> {
+    let key = audit_key_from_env()?;
+    let report = verify_export(&file, &key)?;
+    if report.is_valid() {
+        println!(
+            "OK: {} verified ({} rows, signature valid)",
+            file.display(),
+            report.row_count
+        );
+        return Ok(());
+    }
+    for problem in &report.problems {
+        eprintln!("  - {problem}");
+    }
+    bail!("{} failed verification", file.display());
+}
@@ -26,6 +26,7 @@ pub async fn run_cleanup(dry_run: bool, yes: bool, category: Option<String>) ->
     }
 
     let pool = Arc::new(DatabasePool::open(&db_path).await?);
+    // Retention is database-wide, so a project's .mira/config.toml can't change it
     let config = MiraConfig::load().retention;
 
     // Show current retention policy
@@ -44,7 +45,7 @@ pub async fn run_cleanup(dry_run: bool, yes: bool, category: Option<String>) ->
     println!();
 
     // Show what would be cleaned (dry-run preview)
-    let preview_config = MiraConfig::load().retention;
+    let preview_config = config.clone();
     let candidates = pool
         .interact(move |conn| Ok(retention::count_retention_candidates(conn, &preview_config)))
         .await?;
@@ -124,7 +125,7 @@ pub async fn run_cleanup(dry_run: bool, yes: bool, category: Option<String>) ->
     println!("\nRunning cleanup...");
 
     if config.is_enabled() {
-        let exec_config = MiraConfig::load().retention;
+        let exec_config = config.clone();
         let retention_count = pool
             .interact(move |conn| {
                 retention::run_data_retention_sync(conn, &exec_config)
@@ -5,6 +5,7 @@ use anyhow::{Result, bail};
 use mira::config::MiraConfig;
 use mira::llm::Provider;
 use std::io::Write;
+use std::path::PathBuf;
 
 /// Valid config keys that can be set
 const VALID_KEYS: &[&str] = &["background_provider", "default_provider"];
@@ -41,6 +42,37 @@ pub fn run_config_show() -> Result<()> {
     Ok(())
 }
 
+/// Run `mira config show --effective`: every value after layering the
+/// project's `.mira/config.toml` over the global file, with its source
+pub fn run_config_show_effective(project: Option<PathBuf>) -> Result<()> {
+    let root = match project {
+        Some(p) => p,
+        None => std::env::current_dir()?,
+    };
+    let layered = MiraConfig::load_layered(Some(&root));
+
+    println!("Global config:  {}", MiraConfig::config_path().display());
+    println!(
+        "Project config: {}",
+        MiraConfig::project_config_path(&root).display()
+    );
+    println!();
+
+    let width = layered.values.keys().map(String::len).max().unwrap_or(0);
+    for (key, (value, source)) in &layered.values {
+        println!("{:<width$} = {}  ({})", key, value, source, width = width);
+    }
+
+    if !layered.warnings.is_empty() {
+        println!();
+        for warning in &layered.warnings {
+            println!("warning: {}", warning);
+        }
+    }
+
+    Ok(())
+}
+
 /// Run `mira config set <key> <value>`
 pub fn run_config_set(key: &str, value: &str) -> Result<()> {
     if !VALID_KEYS.contains(&key) {
@@ -87,6 +87,7 @@ pub async fn run_debug_carto(path: Option<PathBuf>) -> Result<()> {
         project_path_str,
         name.unwrap_or_else(|| "unknown".to_string()),
         "rust".to_string(),
+        vec!["rust".to_string()],
     )
     .await
     {
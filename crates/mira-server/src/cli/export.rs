@@ -0,0 +1,102 @@
+// crates/mira-server/src/cli/export.rs
+// CLI handlers for `mira export` and `mira import`
+
+use anyhow::{Context, Result, bail};
+use mira::db::export::{ProjectBundle, export_project_sync, import_project_sync};
+use mira::db::pool::DatabasePool;
+use std::path::PathBuf;
+
+fn resolve_path(path: Option<PathBuf>) -> Result<String> {
+    let path = match path {
+        Some(p) => p,
+        None => std::env::current_dir()?,
+    };
+    Ok(path.to_string_lossy().into_owned())
+}
+
+/// Run `mira export`
+pub async fn run_export(path: Option<PathBuf>, out: PathBuf) -> Result<()> {
+    let project_path = resolve_path(path)?;
+
+    let db_path = super::get_db_path();
+    if !db_path.exists() {
+        bail!("No Mira database found at {}", db_path.display());
+    }
+    let pool = DatabasePool::open(&db_path).await?;
+    let bundle = pool
+        .interact(move |conn| export_project_sync(conn, &project_path))
+        .await?;
+
+    std::fs::write(&out, serde_json::to_string_pretty(&bundle)?)
+        .with_context(|| format!("writing {}", out.display()))?;
+
+    let milestones: usize = bundle.goals.iter().map(|g| g.milestones.len()).sum();
+    println!(
+        "Exported {}: {} goals ({} milestones), {} tasks, {} templates, {} checklist links, {} roots, {} observations, {} error patterns -> {}",
+        bundle.project.path,
+        bundle.goals.len(),
+        milestones,
+        bundle.tasks.len(),
+        bundle.task_templates.len(),
+        bundle.todo_sync_items.len(),
+        bundle.project.roots.len(),
+        bundle.observations.len(),
+        bundle.error_patterns.len(),
+        out.display()
+    );
+    Ok(())
+}
+
+/// Run `mira import`
+pub async fn run_import(file: PathBuf, path: Option<PathBuf>) -> Result<()> {
+    let bundle: ProjectBundle = serde_json::from_str(
+        &std::fs::read_to_string(&file).with_context(|| format!("reading {}", file.display()))?,
+    )
+    .with_context(|| format!("parsing {}", file.display()))?;
+    let target = path.map(|p| p.to_string_lossy().into_owned());
+
+    let db_path = super::get_db_path();
+    if let Some(parent) = db_path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let pool = DatabasePool::open(&db_path).await?;
+    let target_label = target
+        .clone()
+        .unwrap_or_else(|| bundle.project.path.clone());
+    let report = pool
+        .interact(move |conn| import_project_sync(conn, &bundle, target.as_deref()))
+        .await?;
+
+    println!(
+        "Imported into {}: {} goals ({} milestones), {} tasks, {} dependencies, {} templates, {} checklist links, {} roots, {} observations, {} error patterns",
+        target_label,
+        report.goals,
+        report.milestones,
+        report.tasks,
+        report.dependencies,
+        report.task_templates,
+        report.todo_sync_items,
+        report.roots,
+        report.observations,
+        report.error_patterns
+    );
+    if report.duplicates_skipped > 0 {
+        println!(
+            "  Skipped {} rows already imported earlier",
+            report.duplicates_skipped
+        );
+    }
+    if report.roots_skipped > 0 {
+        println!(
+            "  Skipped {} roots whose path or name is already attached",
+            report.roots_skipped
+        );
+    }
+    if report.error_patterns_skipped > 0 {
+        println!(
+            "  Skipped {} error patterns already known for this project",
+            report.error_patterns_skipped
+        );
+    }
+    Ok(())
+}
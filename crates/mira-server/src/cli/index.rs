@@ -12,7 +12,12 @@ use std::sync::Arc;
 use tracing::info;
 
 /// Run the index command to index a project
-pub async fn run_index(path: Option<PathBuf>, no_embed: bool, quiet: bool) -> Result<()> {
+pub async fn run_index(
+    path: Option<PathBuf>,
+    no_embed: bool,
+    force: bool,
+    quiet: bool,
+) -> Result<()> {
     let path =
         path.unwrap_or_else(|| std::env::current_dir().unwrap_or_else(|_| PathBuf::from(".")));
 
@@ -52,6 +57,18 @@ pub async fn run_index(path: Option<PathBuf>, no_embed: bool, quiet: bool) -> Re
         })
         .await?;
 
+    // A path inside an attached root resolves to the owning project: index the
+    // project's primary path plus every attached root.
+    let (primary_path, root_rows) = main_pool
+        .interact(move |conn| {
+            let primary = mira::db::get_project_path_sync(conn, project_id)?;
+            let roots = mira::db::get_project_roots_sync(conn, project_id)?;
+            Ok::<_, anyhow::Error>((primary, roots))
+        })
+        .await?;
+    let path = PathBuf::from(primary_path);
+    let roots = mira::project_roots::roots_from_rows(root_rows);
+
     // Alias code_pool as pool so the rest of the function is unchanged
     let pool = code_pool;
 
@@ -84,12 +101,16 @@ pub async fn run_index(path: Option<PathBuf>, no_embed: bool, quiet: bool) -> Re
     }
     #[cfg(feature = "parsers")]
     {
-        let stats = mira::indexer::index_project(&path, pool, embeddings, Some(project_id)).await?;
+        let stats =
+            mira::indexer::index_project(&path, &roots, pool, embeddings, Some(project_id), force)
+                .await?;
 
         if !quiet {
             println!(
-                "Indexed {} files, {} symbols, {} code chunks",
-                stats.files, stats.symbols, stats.chunks
+                "{} ({} symbols, {} code chunks)",
+                stats.file_summary(),
+                stats.symbols,
+                stats.chunks
             );
         }
 
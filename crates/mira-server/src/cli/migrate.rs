@@ -0,0 +1,98 @@
+// crates/mira-server/src/cli/migrate.rs
+// CLI handler for `mira migrate` command
+
+use anyhow::{Context, Result};
+use mira::db::pool::DatabasePool;
+use mira::db::{MigrationStatus, migration_status};
+use rusqlite::{Connection, OpenFlags};
+use std::path::Path;
+
+/// Read migration state without modifying (or creating) the database.
+fn read_status(db_path: &Path) -> Result<Vec<MigrationStatus>> {
+    if !db_path.exists() {
+        let conn = Connection::open_in_memory()?;
+        return migration_status(&conn);
+    }
+    let conn = Connection::open_with_flags(db_path, OpenFlags::SQLITE_OPEN_READ_ONLY)
+        .with_context(|| format!("opening {}", db_path.display()))?;
+    migration_status(&conn)
+}
+
+/// Run `mira migrate`: the main database's versioned migrations, then the
+/// code index database's schema setup.
+pub async fn run_migrate(dry_run: bool) -> Result<()> {
+    let db_path = super::get_db_path();
+    migrate_main(&db_path, dry_run).await?;
+    migrate_code(&db_path.with_file_name("mira-code.db"), dry_run).await
+}
+
+/// Apply pending versioned migrations to the main database
+async fn migrate_main(db_path: &Path, dry_run: bool) -> Result<()> {
+    println!("Database: {}", db_path.display());
+
+    let before = read_status(db_path)?;
+    let pending: Vec<&MigrationStatus> = before.iter().filter(|m| !m.applied).collect();
+    println!(
+        "Migrations: {} applied, {} pending",
+        before.len() - pending.len(),
+        pending.len()
+    );
+    for m in &before {
+        let mark = if m.applied { "x" } else { " " };
+        println!("  [{}] v{:<3} {}", mark, m.version, m.name);
+    }
+
+    if pending.is_empty() {
+        println!("\nDatabase is up to date.");
+        return Ok(());
+    }
+    if dry_run {
+        println!(
+            "\nDry run: {} migration(s) would be applied.",
+            pending.len()
+        );
+        return Ok(());
+    }
+
+    // Opening the pool runs the same migration path as `mira serve`,
+    // including the pre-migration backup.
+    println!();
+    DatabasePool::open(db_path).await?;
+
+    let after = read_status(db_path)?;
+    let mut applied = 0;
+    for m in after.iter().filter(|m| m.applied) {
+        if pending.iter().any(|p| p.version == m.version) {
+            println!("Applied v{}: {}", m.version, m.name);
+            applied += 1;
+        }
+    }
+    let still_pending = after.iter().filter(|m| !m.applied).count();
+    println!(
+        "\nApplied {} migration(s), {} still pending.",
+        applied, still_pending
+    );
+    Ok(())
+}
+
+/// Bring the code index database's schema up to date.
+///
+/// Its migrations are unversioned and idempotent (each checks the schema
+/// before changing it), so they can't be listed as applied or pending; they
+/// are simply re-run.
+async fn migrate_code(code_db_path: &Path, dry_run: bool) -> Result<()> {
+    println!("\nCode index database: {}", code_db_path.display());
+    if !code_db_path.exists() {
+        println!("Not created yet; `mira index` or `mira serve` will create it.");
+        return Ok(());
+    }
+    println!("Code index migrations are unversioned and not listed individually.");
+    if dry_run {
+        println!("Dry run: code index migrations would be re-run (no-op if up to date).");
+        return Ok(());
+    }
+
+    DatabasePool::open_code_db(code_db_path).await?;
+    println!("Code index schema is up to date.");
+    Ok(())
+}
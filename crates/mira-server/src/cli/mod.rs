@@ -5,14 +5,18 @@ use clap::{Parser, Subcommand};
 use std::path::PathBuf;
 
 pub mod analyze;
+pub mod audit;
 pub mod cleanup;
 pub mod clients;
 pub mod config;
 pub mod debug;
+pub mod export;
 pub mod index;
+pub mod migrate;
 pub mod serve;
 pub mod setup;
 pub mod statusline;
+pub mod task_sync;
 pub mod tool;
 
 // Re-export command handlers
@@ -57,6 +61,10 @@ pub enum Commands {
         #[arg(long)]
         no_embed: bool,
 
+        /// Re-index every file, even ones unchanged since the last index
+        #[arg(long)]
+        force: bool,
+
         /// Suppress progress output (show only final summary line)
         #[arg(short, long)]
         quiet: bool,
@@ -98,6 +106,13 @@ pub enum Commands {
         yes: bool,
     },
 
+    /// Show schema migration state and apply pending migrations (main and code index databases)
+    Migrate {
+        /// Only list applied and pending migrations, don't apply anything
+        #[arg(long)]
+        dry_run: bool,
+    },
+
     /// Run data cleanup and retention (dry-run by default)
     Cleanup {
         /// Actually execute the cleanup (default is dry-run preview)
@@ -114,6 +129,42 @@ pub enum Commands {
         category: Option<String>,
     },
 
+    /// Export or verify a signed audit log of tool executions
+    Audit {
+        #[command(subcommand)]
+        action: AuditAction,
+    },
+
+    /// Export a project's goals, tasks, templates, roots, checklist links and learned fixes to a JSON bundle
+    Export {
+        /// Project path (default: current directory)
+        #[arg(short, long)]
+        path: Option<PathBuf>,
+
+        /// Output file
+        #[arg(short, long)]
+        out: PathBuf,
+    },
+
+    /// Import a bundle written by `mira export` (IDs are remapped, nothing is overwritten)
+    Import {
+        /// Bundle file
+        #[arg(index = 1)]
+        file: PathBuf,
+
+        /// Import under this project path instead of the one recorded in the bundle
+        #[arg(short, long)]
+        path: Option<PathBuf>,
+    },
+
+    /// Sync tasks with the markdown checklists listed under `[todo_sync]`
+    #[command(name = "task-sync")]
+    TaskSync {
+        /// Project path (default: current directory)
+        #[arg(short, long)]
+        path: Option<PathBuf>,
+    },
+
     /// Output a stats line for use in Claude Code's status bar.
     /// Reads a JSON object with a "cwd" field from stdin, prints a formatted
     /// status line (goals, indexed files, alerts) to stdout.
@@ -152,7 +203,16 @@ pub enum Commands {
 #[derive(Subcommand)]
 pub enum ConfigAction {
     /// Show current provider configuration
-    Show,
+    Show {
+        /// Show every effective value with its source (default, global, project)
+        #[arg(long)]
+        effective: bool,
+
+        /// Project root whose .mira/config.toml is layered over the global file
+        /// (defaults to the current directory)
+        #[arg(long)]
+        project: Option<std::path::PathBuf>,
+    },
     /// Set a config value (e.g. `mira config set background_provider deepseek`)
     Set {
         /// Config key to set. Valid keys: background_provider, default_provider
@@ -164,6 +224,34 @@ pub enum ConfigAction {
     },
 }
 
+#[derive(Subcommand)]
+pub enum AuditAction {
+    /// Export tool executions as JSONL with a signed manifest (requires MIRA_AUDIT_KEY)
+    Export {
+        /// Start date, inclusive (YYYY-MM-DD)
+        #[arg(long)]
+        since: String,
+        /// End date, inclusive (YYYY-MM-DD, default: today)
+        #[arg(long)]
+        until: Option<String>,
+        /// Only include executions from this project path
+        #[arg(long)]
+        project: Option<String>,
+        /// Only include executions of this tool
+        #[arg(long)]
+        tool: Option<String>,
+        /// Output file (manifest is written alongside as <out>.manifest.json)
+        #[arg(short, long)]
+        out: PathBuf,
+    },
+    /// Verify an export against its manifest and signature
+    Verify {
+        /// Export file to verify
+        #[arg(index = 1)]
+        file: PathBuf,
+    },
+}
+
 #[derive(Subcommand)]
 pub enum HookAction {
     /// Handle SessionStart hooks - captures Claude's session_id
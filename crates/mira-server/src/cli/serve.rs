@@ -353,6 +353,33 @@ fn kill_stale_mira_processes() {
     }
 }
 
+/// How long each shutdown phase may take before we give up and exit
+const SHUTDOWN_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
+
+/// Resolve when the process receives SIGINT or SIGTERM (Ctrl-C elsewhere)
+async fn shutdown_signal() -> &'static str {
+    #[cfg(unix)]
+    {
+        use tokio::signal::unix::{SignalKind, signal};
+        match signal(SignalKind::terminate()) {
+            Ok(mut sigterm) => tokio::select! {
+                _ = tokio::signal::ctrl_c() => "SIGINT",
+                _ = sigterm.recv() => "SIGTERM",
+            },
+            Err(e) => {
+                tracing::warn!("Failed to install SIGTERM handler: {}", e);
+                let _ = tokio::signal::ctrl_c().await;
+                "SIGINT"
+            }
+        }
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = tokio::signal::ctrl_c().await;
+        "Ctrl-C"
+    }
+}
+
 /// Run the MCP server with stdio transport
 pub async fn run_mcp_server() -> Result<()> {
     kill_stale_mira_processes();
@@ -379,23 +406,25 @@ pub async fn run_mcp_server() -> Result<()> {
 
     // Spawn background workers with separate pools
     let bg_embeddings = server.embeddings.clone();
-    let (_shutdown_tx, fast_lane_notify) = background::spawn_with_pools(
+    let workers = background::spawn_with_pools(
         server.code_pool.inner().clone(),
         server.pool.inner().clone(),
         bg_embeddings,
     );
     info!("Background worker started");
 
-    // Spawn file watcher for incremental indexing (uses code_pool)
-    let (_watcher_shutdown_tx, watcher_shutdown_rx) = watch::channel(false);
+    // Spawn file watcher for incremental indexing (uses code_pool) and
+    // markdown checklist sync (uses the main pool)
+    let (watcher_shutdown_tx, watcher_shutdown_rx) = watch::channel(false);
     let watcher_handle = background::watcher::spawn(
         server.code_pool.inner().clone(),
+        Some(server.pool.inner().clone()),
         Some(server.fuzzy_cache.clone()),
         watcher_shutdown_rx,
-        Some(fast_lane_notify),
+        Some(workers.fast_lane_notify()),
     );
     info!("File watcher started");
-    server.watcher = Some(watcher_handle);
+    server.watcher = Some(watcher_handle.clone());
 
     // Start IPC listener (Unix socket on Unix, Named Pipe on Windows)
     let ipc_handle = {
@@ -434,7 +463,35 @@ pub async fn run_mcp_server() -> Result<()> {
     // Run with stdio transport
     let transport = rmcp::transport::io::stdio();
     let service = rmcp::serve_server(server, transport).await?;
-    service.waiting().await?;
+    let cancel = service.cancellation_token();
+    let waiting = service.waiting();
+    tokio::pin!(waiting);
+    tokio::select! {
+        result = &mut waiting => {
+            result?;
+        }
+        signal = shutdown_signal() => {
+            info!("Received {}, shutting down", signal);
+            cancel.cancel();
+            let _ = tokio::time::timeout(SHUTDOWN_TIMEOUT, &mut waiting).await;
+        }
+    }
+
+    // Flush the watcher first: its final indexing pass queues embeddings
+    // that the fast lane then drains.
+    let _ = watcher_shutdown_tx.send(true);
+    if tokio::time::timeout(SHUTDOWN_TIMEOUT, watcher_handle.wait_stopped())
+        .await
+        .is_err()
+    {
+        tracing::warn!("File watcher did not finish within {:?}", SHUTDOWN_TIMEOUT);
+    }
+    if !workers.shutdown(SHUTDOWN_TIMEOUT).await {
+        tracing::warn!(
+            "Background workers did not finish within {:?}",
+            SHUTDOWN_TIMEOUT
+        );
+    }
 
     // Cleanup IPC on shutdown
     ipc_handle.abort();
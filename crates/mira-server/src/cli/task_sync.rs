@@ -0,0 +1,65 @@
+// crates/mira-server/src/cli/task_sync.rs
+// CLI handler for `mira task-sync`
+
+use anyhow::{Result, bail};
+use mira::config::MiraConfig;
+use mira::db::get_or_create_project_sync;
+use mira::db::pool::DatabasePool;
+use mira::tasks::todo_sync::sync_todo_files;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+/// Run `mira task-sync`
+pub async fn run_task_sync(path: Option<PathBuf>) -> Result<()> {
+    let root = match path {
+        Some(p) => p,
+        None => std::env::current_dir()?,
+    };
+    let config = MiraConfig::load_layered(Some(&root)).config.todo_sync;
+    if config.files.is_empty() {
+        bail!(
+            "No checklist files configured. Add `[todo_sync] files = [\"TODO.md\"]` to {}",
+            MiraConfig::project_config_path(&root).display()
+        );
+    }
+
+    let db_path = super::get_db_path();
+    if let Some(parent) = db_path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let pool = Arc::new(DatabasePool::open(&db_path).await?);
+    let project_path = root.to_string_lossy().into_owned();
+    let (project_id, _) = pool
+        .interact(move |conn| Ok(get_or_create_project_sync(conn, &project_path, None)?))
+        .await?;
+
+    for (file, report) in sync_todo_files(&pool, project_id, &root, &config).await? {
+        let Some(report) = report else {
+            println!("{}: not found, skipped", file);
+            continue;
+        };
+        println!(
+            "{}: {} created, {} retitled, {} completed, {} reopened, {} unlinked",
+            file,
+            report.created,
+            report.retitled,
+            report.completed,
+            report.reopened,
+            report.unlinked
+        );
+        if !report.write_back.is_empty() {
+            if config.write_back {
+                println!(
+                    "  Updated {} checkbox(es) from Mira",
+                    report.write_back.len()
+                );
+            } else {
+                println!(
+                    "  {} checkbox(es) differ from Mira (write_back is off)",
+                    report.write_back.len()
+                );
+            }
+        }
+    }
+    Ok(())
+}
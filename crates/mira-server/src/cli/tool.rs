@@ -7,8 +7,9 @@ use mira::error::MiraError;
 use mira::hooks::session::read_claude_session_id;
 use mira::mcp::requests::{
     CodeAction, CodeRequest, DocumentationRequest, GoalRequest, IndexRequest, LaunchRequest,
-    ProjectRequest, SessionRequest, TeamRequest,
+    ModuleRequest, ProjectRequest, SessionRequest, TeamRequest,
 };
+use mira::mcp::validation::parse_args;
 
 /// Execute a tool directly from the command line
 pub async fn run_tool(name: String, args: String) -> Result<()> {
@@ -21,9 +22,16 @@ pub async fn run_tool(name: String, args: String) -> Result<()> {
             let req: ProjectRequest = serde_json::from_str(&args)?;
             // For start action, use provided session ID or fall back to Claude's hook-generated ID
             let session_id = req.session_id.or_else(read_claude_session_id);
-            mira::tools::project(&server, req.action, req.project_path, req.name, session_id)
-                .await
-                .map(|output| output.0.message)
+            mira::tools::project(
+                &server,
+                req.action,
+                req.project_path,
+                req.name,
+                session_id,
+                req.additional_roots,
+            )
+            .await
+            .map(|output| output.0.message)
         }
         "code" => {
             let req: CodeRequest = serde_json::from_str(&args)?;
@@ -55,22 +63,29 @@ pub async fn run_tool(name: String, args: String) -> Result<()> {
                 .map(|output| output.0.message)
         }
         "goal" => {
-            let req: GoalRequest = serde_json::from_str(&args)?;
+            let req: GoalRequest = parse_args(&args)?;
             mira::tools::goal(&server, req)
                 .await
                 .map(|output| output.0.message)
         }
         "index" => {
-            let req: IndexRequest = serde_json::from_str(&args)?;
+            let req: IndexRequest = parse_args(&args)?;
             mira::tools::index(
                 &server,
                 req.action,
                 req.path,
                 req.skip_embed.unwrap_or(false),
+                req.force.unwrap_or(false),
             )
             .await
             .map(|output| output.0.message)
         }
+        "module" => {
+            let req: ModuleRequest = parse_args(&args)?;
+            mira::tools::module(&server, req)
+                .await
+                .map(|output| output.0.message)
+        }
         "session" | "insights" => {
             let req: SessionRequest = serde_json::from_str(&args)?;
             mira::tools::handle_session(&server, req)
@@ -78,18 +93,13 @@ pub async fn run_tool(name: String, args: String) -> Result<()> {
                 .map(|output| output.0.message)
         }
         "tasks" => {
-            #[derive(serde::Deserialize)]
-            struct TasksArgs {
-                action: mira::tools::tasks::TaskAction,
-                task_id: Option<String>,
-            }
-            let req: TasksArgs = serde_json::from_str(&args)?;
+            let req: mira::tools::tasks::TasksRequest = parse_args(&args)?;
             mira::tools::tasks::handle_tasks(&server, req.action, req.task_id)
                 .await
                 .map(|output| output.0.message)
         }
         "documentation" => {
-            let req: DocumentationRequest = serde_json::from_str(&args)?;
+            let req: DocumentationRequest = parse_args(&args)?;
             mira::tools::documentation(&server, req)
                 .await
                 .map(|output| output.0.message)
@@ -156,6 +166,7 @@ fn list_cli_tool_names() -> Vec<&'static str> {
         "diff",
         "goal",
         "index",
+        "module",
         "session",
         "insights",
         "tasks",
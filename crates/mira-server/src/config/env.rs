@@ -37,22 +37,44 @@ impl std::fmt::Debug for ApiKeys {
 impl ApiKeys {
     /// Load API keys from environment variables (single source of truth)
     ///
-    /// Set `MIRA_DISABLE_LLM=1` to suppress all LLM keys (forces heuristic fallbacks)
+    /// Set `MIRA_DISABLE_LLM=1` to suppress all LLM keys (forces heuristic fallbacks).
+    /// Set `MIRA_OFFLINE=1` to suppress every key, so nothing reaches the network.
     pub fn from_env() -> Self {
-        if parse_bool_env("MIRA_DISABLE_LLM").unwrap_or(false) {
+        Self::from_lookup(
+            is_offline(),
+            parse_bool_env("MIRA_DISABLE_LLM").unwrap_or(false),
+            Self::read_key,
+        )
+    }
+
+    /// `from_env` with the mode flags and key lookup passed in, so tests can
+    /// resolve keys without touching the process environment.
+    pub(crate) fn from_lookup(
+        offline: bool,
+        disable_llm: bool,
+        read_key: impl Fn(&str) -> Option<String>,
+    ) -> Self {
+        if offline {
+            info!(
+                "MIRA_OFFLINE is set — LLM, embedding and web search providers disabled; queued embeddings resume once it is cleared"
+            );
+            return Self::default();
+        }
+
+        if disable_llm {
             info!("MIRA_DISABLE_LLM is set — LLM providers disabled, using fallbacks");
             return Self {
                 deepseek: None,
                 ollama: None,
                 openai: None,
-                brave: Self::read_key("BRAVE_API_KEY"),
+                brave: read_key("BRAVE_API_KEY"),
             };
         }
 
-        let deepseek = Self::read_key("DEEPSEEK_API_KEY");
-        let ollama = Self::read_key("OLLAMA_HOST");
-        let openai = Self::read_key("OPENAI_API_KEY");
-        let brave = Self::read_key("BRAVE_API_KEY");
+        let deepseek = read_key("DEEPSEEK_API_KEY");
+        let ollama = read_key("OLLAMA_HOST");
+        let openai = read_key("OPENAI_API_KEY");
+        let brave = read_key("BRAVE_API_KEY");
 
         let keys = Self {
             deepseek,
@@ -237,6 +259,8 @@ pub struct EnvConfig {
     pub user_id: Option<String>,
     /// Enable fuzzy search in hybrid search pipeline (MIRA_FUZZY_SEARCH)
     pub fuzzy_search: bool,
+    /// Offline mode: all providers disabled on purpose (MIRA_OFFLINE)
+    pub offline: bool,
 }
 
 impl EnvConfig {
@@ -252,6 +276,7 @@ impl EnvConfig {
                 .filter(|s| !s.is_empty()),
             user_id: std::env::var("MIRA_USER_ID").ok().filter(|s| !s.is_empty()),
             fuzzy_search: parse_bool_env("MIRA_FUZZY_SEARCH").unwrap_or(true),
+            offline: is_offline(),
         }
     }
 
@@ -259,17 +284,21 @@ impl EnvConfig {
     pub fn validate(&self) -> ConfigValidation {
         let mut validation = ConfigValidation::new();
 
-        // Check for LLM providers
-        if !self.api_keys.has_llm_provider() {
-            validation
-                .add_warning("No LLM API keys configured. Set DEEPSEEK_API_KEY or OLLAMA_HOST.");
-        }
+        // Missing providers are expected offline; from_env already logged it once
+        if !self.offline {
+            // Check for LLM providers
+            if !self.api_keys.has_llm_provider() {
+                validation.add_warning(
+                    "No LLM API keys configured. Set DEEPSEEK_API_KEY or OLLAMA_HOST.",
+                );
+            }
 
-        // Check for embeddings
-        if !self.api_keys.has_embeddings() {
-            validation.add_warning(
-                "No embeddings provider configured. Set OPENAI_API_KEY or OLLAMA_HOST for semantic search.",
-            );
+            // Check for embeddings
+            if !self.api_keys.has_embeddings() {
+                validation.add_warning(
+                    "No embeddings provider configured. Set OPENAI_API_KEY or OLLAMA_HOST for semantic search.",
+                );
+            }
         }
 
         // Validate default provider if set
@@ -296,6 +325,14 @@ impl EnvConfig {
     }
 }
 
+/// Whether offline mode is enabled (MIRA_OFFLINE).
+///
+/// Offline mode drops every provider, local Ollama included: switching the
+/// embedding provider for a flight would otherwise invalidate the code index.
+pub fn is_offline() -> bool {
+    parse_bool_env("MIRA_OFFLINE").unwrap_or(false)
+}
+
 pub(crate) fn parse_bool_env(name: &str) -> Option<bool> {
     let value = std::env::var(name).ok()?.to_lowercase();
     match value.as_str() {
@@ -367,6 +404,7 @@ mod tests {
             default_provider: None,
             user_id: None,
             fuzzy_search: true,
+            offline: false,
         };
 
         let validation = config.validate();
@@ -374,6 +412,25 @@ mod tests {
         assert!(!validation.warnings.is_empty());
     }
 
+    #[test]
+    fn test_validation_offline_skips_missing_provider_warnings() {
+        let config = EnvConfig {
+            api_keys: ApiKeys::default(),
+            embeddings: EmbeddingsConfig::default(),
+            default_provider: None,
+            user_id: None,
+            fuzzy_search: true,
+            offline: true,
+        };
+
+        let validation = config.validate();
+        assert!(
+            validation.warnings.is_empty(),
+            "offline mode should not warn about missing providers: {:?}",
+            validation.warnings
+        );
+    }
+
     #[test]
     fn test_validation_invalid_provider() {
         let config = EnvConfig {
@@ -382,6 +439,7 @@ mod tests {
             default_provider: Some("gpt4".to_string()),
             user_id: None,
             fuzzy_search: true,
+            offline: false,
         };
 
         let validation = config.validate();
@@ -402,6 +460,7 @@ mod tests {
             default_provider: Some("deepseek".to_string()),
             user_id: None,
             fuzzy_search: true,
+            offline: false,
         };
 
         let validation = config.validate();
@@ -422,6 +481,7 @@ mod tests {
             default_provider: Some("sampling".to_string()),
             user_id: None,
             fuzzy_search: true,
+            offline: false,
         };
 
         let validation = config.validate();
@@ -442,6 +502,7 @@ mod tests {
             default_provider: Some("glm".to_string()),
             user_id: None,
             fuzzy_search: true,
+            offline: false,
         };
 
         let validation = config.validate();
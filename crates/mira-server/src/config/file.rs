@@ -1,22 +1,30 @@
 // crates/mira-server/src/config/file.rs
-// File-based configuration from ~/.mira/config.toml
+// File-based configuration from ~/.mira/config.toml, optionally layered
+// under a project's .mira/config.toml
 
 use crate::llm::Provider;
-use serde::Deserialize;
-use std::path::PathBuf;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
 use tracing::{debug, warn};
 
 /// Top-level config structure
-#[derive(Debug, Deserialize, Default)]
+#[derive(Debug, Deserialize, Serialize, Default)]
 pub struct MiraConfig {
     #[serde(default)]
     pub llm: LlmConfig,
     #[serde(default)]
     pub retention: RetentionConfig,
+    #[serde(default)]
+    pub sessions: SessionsConfig,
+    #[serde(default)]
+    pub db_health: DbHealthConfig,
+    #[serde(default)]
+    pub todo_sync: TodoSyncConfig,
 }
 
 /// Data retention configuration section
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct RetentionConfig {
     /// Master switch -- enabled by default for automatic data hygiene
     #[serde(default = "RetentionConfig::default_enabled")]
@@ -87,8 +95,113 @@ impl RetentionConfig {
     }
 }
 
+/// Session liveness configuration section
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct SessionsConfig {
+    /// Minutes without activity before an active session is reported as idle
+    #[serde(default = "SessionsConfig::default_idle_after_minutes")]
+    pub idle_after_minutes: u32,
+    /// Minutes without activity before the background worker closes a session
+    #[serde(default = "SessionsConfig::default_close_after_minutes")]
+    pub close_after_minutes: u32,
+    /// Token budget for the context injected at SessionStart
+    #[serde(default = "SessionsConfig::default_start_context_tokens")]
+    pub start_context_tokens: u32,
+}
+
+impl Default for SessionsConfig {
+    fn default() -> Self {
+        Self {
+            idle_after_minutes: 10,
+            close_after_minutes: 30,
+            start_context_tokens: 625,
+        }
+    }
+}
+
+impl SessionsConfig {
+    fn default_idle_after_minutes() -> u32 {
+        10
+    }
+    fn default_close_after_minutes() -> u32 {
+        30
+    }
+    fn default_start_context_tokens() -> u32 {
+        625
+    }
+
+    /// Idle threshold in minutes (at least 1)
+    pub fn idle_minutes(&self) -> i64 {
+        self.idle_after_minutes.max(1) as i64
+    }
+
+    /// Close threshold in minutes, never shorter than the idle threshold
+    pub fn close_minutes(&self) -> i64 {
+        (self.close_after_minutes as i64).max(self.idle_minutes())
+    }
+
+    /// SessionStart context budget in characters (~4 chars per token, at least 100 tokens)
+    pub fn start_context_chars(&self) -> usize {
+        self.start_context_tokens.max(100) as usize * 4
+    }
+}
+
+/// Database growth monitoring configuration section
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct DbHealthConfig {
+    /// Sample database size and row counts daily and warn on fast growth
+    #[serde(default = "DbHealthConfig::default_enabled")]
+    pub enabled: bool,
+    /// Week-over-week file size growth (percent) that triggers a warning
+    #[serde(default = "DbHealthConfig::default_size_growth_pct")]
+    pub size_growth_pct: u32,
+    /// Size growth is ignored while the database is smaller than this
+    #[serde(default = "DbHealthConfig::default_min_size_mb")]
+    pub min_size_mb: u32,
+    /// Rows added to a single table in a week that trigger a warning
+    #[serde(default = "DbHealthConfig::default_table_growth_rows")]
+    pub table_growth_rows: u64,
+}
+
+impl Default for DbHealthConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            size_growth_pct: 50,
+            min_size_mb: 100,
+            table_growth_rows: 100_000,
+        }
+    }
+}
+
+impl DbHealthConfig {
+    fn default_enabled() -> bool {
+        true
+    }
+    fn default_size_growth_pct() -> u32 {
+        50
+    }
+    fn default_min_size_mb() -> u32 {
+        100
+    }
+    fn default_table_growth_rows() -> u64 {
+        100_000
+    }
+}
+
+/// Markdown checklist sync configuration section (usually set per project)
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+pub struct TodoSyncConfig {
+    /// Checklist files to sync with tasks, relative to the project root
+    #[serde(default)]
+    pub files: Vec<String>,
+    /// Check or uncheck boxes in the files when task status changes in Mira
+    #[serde(default)]
+    pub write_back: bool,
+}
+
 /// LLM configuration section
-#[derive(Debug, Deserialize, Default)]
+#[derive(Debug, Deserialize, Serialize, Default)]
 pub struct LlmConfig {
     /// Provider for background intelligence (summaries, briefings, capabilities, code health)
     pub background_provider: Option<String>,
@@ -145,6 +258,209 @@ impl MiraConfig {
             .join(".mira")
             .join("config.toml")
     }
+
+    /// Project-level config file: `<project>/.mira/config.toml`
+    pub fn project_config_path(project_root: &Path) -> PathBuf {
+        project_root.join(".mira").join("config.toml")
+    }
+
+    /// Load global config, then merge `<project>/.mira/config.toml` over it.
+    ///
+    /// Precedence: project > global > built-in default, per key. A project
+    /// file that fails to parse or validate is skipped with a warning, so a
+    /// bad project file never breaks activation. Keys in `GLOBAL_ONLY_KEYS`
+    /// act on the whole database and are ignored in the project file.
+    pub fn load_layered(project_root: Option<&Path>) -> LayeredConfig {
+        let project_path = project_root.map(Self::project_config_path);
+        Self::load_layered_from(&Self::config_path(), project_path.as_deref())
+    }
+
+    /// Effective config for a project path (global config when `None`).
+    ///
+    /// Re-read on every call, so runtime consumers pick up the active
+    /// project's overrides right after a project switch.
+    pub fn for_project(project_path: Option<&str>) -> Self {
+        Self::load_layered(project_path.map(Path::new)).config
+    }
+
+    /// `load_layered` with explicit file paths (for tests and tooling)
+    pub fn load_layered_from(global_path: &Path, project_path: Option<&Path>) -> LayeredConfig {
+        let mut warnings = Vec::new();
+        let global = read_config_table(global_path, &mut warnings).unwrap_or_default();
+        let project = project_path.and_then(|p| {
+            let mut table = read_config_table(p, &mut warnings)?;
+            for key in GLOBAL_ONLY_KEYS {
+                if remove_dotted_key(&mut table, key) {
+                    warnings.push(format!(
+                        "{}: `{}` applies to every project and is only read from the global config",
+                        p.display(),
+                        key
+                    ));
+                }
+            }
+            Some(table)
+        });
+
+        let global_config = match toml::Value::Table(global.clone()).try_into::<MiraConfig>() {
+            Ok(config) => config,
+            Err(e) => {
+                warnings.push(format!("{}: {}", global_path.display(), e));
+                Self::default()
+            }
+        };
+
+        let (config, project) = match project {
+            Some(project) => {
+                let mut merged = global.clone();
+                merge_tables(&mut merged, &project);
+                match toml::Value::Table(merged).try_into::<MiraConfig>() {
+                    Ok(config) => (config, Some(project)),
+                    Err(e) => {
+                        let path = project_path.map(|p| p.display().to_string());
+                        warnings.push(format!(
+                            "{}: {} (using global config)",
+                            path.unwrap_or_default(),
+                            e
+                        ));
+                        (global_config, None)
+                    }
+                }
+            }
+            None => (global_config, None),
+        };
+
+        for warning in &warnings {
+            warn!("Config: {}", warning);
+        }
+
+        let sources = flatten_keys(&config)
+            .into_iter()
+            .map(|(key, value)| {
+                let source = if project.as_ref().is_some_and(|t| table_has_key(t, &key)) {
+                    ConfigSource::Project
+                } else if table_has_key(&global, &key) {
+                    ConfigSource::Global
+                } else {
+                    ConfigSource::Default
+                };
+                (key, (value, source))
+            })
+            .collect();
+
+        LayeredConfig {
+            config,
+            values: sources,
+            warnings,
+        }
+    }
+}
+
+/// Where an effective config value came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigSource {
+    Default,
+    Global,
+    Project,
+}
+
+impl std::fmt::Display for ConfigSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            ConfigSource::Default => "default",
+            ConfigSource::Global => "global",
+            ConfigSource::Project => "project",
+        })
+    }
+}
+
+/// Effective config plus the origin of every value
+#[derive(Debug)]
+pub struct LayeredConfig {
+    pub config: MiraConfig,
+    /// Dotted key (`retention.chat_days`) -> (rendered value, source)
+    pub values: BTreeMap<String, (String, ConfigSource)>,
+    /// Parse/validation problems that caused a layer to be skipped
+    pub warnings: Vec<String>,
+}
+
+/// Read a config file as a TOML table. Missing file -> None; parse errors are
+/// recorded in `warnings` and also yield None.
+fn read_config_table(path: &Path, warnings: &mut Vec<String>) -> Option<toml::Table> {
+    let contents = std::fs::read_to_string(path).ok()?;
+    match toml::from_str(&contents) {
+        Ok(table) => Some(table),
+        Err(e) => {
+            warnings.push(format!("{}: {}", path.display(), e));
+            None
+        }
+    }
+}
+
+/// Keys that drive database-wide work (retention, closing stale sessions,
+/// health sampling). A repo's own config must not change them for every
+/// other project, so they are read from the global layer only.
+const GLOBAL_ONLY_KEYS: &[&str] = &["retention", "db_health", "sessions.close_after_minutes"];
+
+/// Remove a dotted key (`sessions.close_after_minutes`) or whole section
+/// (`retention`) from the table. Returns whether it was present.
+fn remove_dotted_key(table: &mut toml::Table, dotted: &str) -> bool {
+    match dotted.split_once('.') {
+        Some((section, rest)) => match table.get_mut(section) {
+            Some(toml::Value::Table(next)) => remove_dotted_key(next, rest),
+            _ => false,
+        },
+        None => table.remove(dotted).is_some(),
+    }
+}
+
+/// Deep-merge `overlay` into `base`; overlay values win, nested tables merge
+fn merge_tables(base: &mut toml::Table, overlay: &toml::Table) {
+    for (key, value) in overlay {
+        match (base.get_mut(key), value) {
+            (Some(toml::Value::Table(base_table)), toml::Value::Table(overlay_table)) => {
+                merge_tables(base_table, overlay_table);
+            }
+            _ => {
+                base.insert(key.clone(), value.clone());
+            }
+        }
+    }
+}
+
+/// Does a dotted key (`llm.default_provider`) exist in the table?
+fn table_has_key(table: &toml::Table, dotted: &str) -> bool {
+    let mut current = table;
+    let mut parts = dotted.split('.').peekable();
+    while let Some(part) = parts.next() {
+        match current.get(part) {
+            Some(toml::Value::Table(next)) if parts.peek().is_some() => current = next,
+            Some(_) => return parts.peek().is_none(),
+            None => return false,
+        }
+    }
+    false
+}
+
+/// Every leaf of the effective config as (dotted key, rendered value)
+fn flatten_keys(config: &MiraConfig) -> Vec<(String, String)> {
+    fn walk(prefix: &str, table: &toml::Table, out: &mut Vec<(String, String)>) {
+        for (key, value) in table {
+            let dotted = if prefix.is_empty() {
+                key.clone()
+            } else {
+                format!("{}.{}", prefix, key)
+            };
+            match value {
+                toml::Value::Table(inner) => walk(&dotted, inner, out),
+                other => out.push((dotted, other.to_string())),
+            }
+        }
+    }
+    let mut out = Vec::new();
+    if let Ok(toml::Value::Table(table)) = toml::Value::try_from(config) {
+        walk("", &table, &mut out);
+    }
+    out
 }
 
 #[cfg(test)]
@@ -329,6 +645,58 @@ tool_history_days = 14
         assert_eq!(config.retention.observations_days, 90);
     }
 
+    // ═══════════════════════════════════════
+    // SessionsConfig tests
+    // ═══════════════════════════════════════
+
+    #[test]
+    fn test_sessions_defaults() {
+        let config: MiraConfig = toml::from_str("").unwrap();
+        assert_eq!(config.sessions.idle_minutes(), 10);
+        assert_eq!(config.sessions.close_minutes(), 30);
+        assert_eq!(config.sessions.start_context_chars(), 2500);
+    }
+
+    #[test]
+    fn test_sessions_close_never_before_idle() {
+        let toml = r#"
+[sessions]
+idle_after_minutes = 45
+close_after_minutes = 20
+"#;
+        let config: MiraConfig = toml::from_str(toml).unwrap();
+        assert_eq!(config.sessions.idle_minutes(), 45);
+        assert_eq!(config.sessions.close_minutes(), 45);
+    }
+
+    #[test]
+    fn test_db_health_partial_config_uses_defaults() {
+        let toml = r#"
+[db_health]
+size_growth_pct = 25
+"#;
+        let config: MiraConfig = toml::from_str(toml).unwrap();
+        assert!(config.db_health.enabled);
+        assert_eq!(config.db_health.size_growth_pct, 25);
+        assert_eq!(config.db_health.min_size_mb, 100);
+        assert_eq!(config.db_health.table_growth_rows, 100_000);
+    }
+
+    #[test]
+    fn test_todo_sync_defaults_to_no_files_and_no_write_back() {
+        let config: MiraConfig = toml::from_str("").unwrap();
+        assert!(config.todo_sync.files.is_empty());
+        assert!(!config.todo_sync.write_back);
+
+        let toml = r#"
+[todo_sync]
+files = ["TODO.md", "docs/ROADMAP.md"]
+"#;
+        let config: MiraConfig = toml::from_str(toml).unwrap();
+        assert_eq!(config.todo_sync.files, vec!["TODO.md", "docs/ROADMAP.md"]);
+        assert!(!config.todo_sync.write_back);
+    }
+
     #[test]
     fn test_retention_unknown_keys_ignored() {
         let toml = r#"
@@ -380,4 +748,122 @@ unknown_retention_key = 42
             std::env::remove_var("MIRA_RETENTION_ENABLED");
         }
     }
+
+    // ═══════════════════════════════════════
+    // Layered (global + project) config tests
+    // ═══════════════════════════════════════
+
+    fn write(dir: &Path, name: &str, contents: &str) -> PathBuf {
+        let path = dir.join(name);
+        std::fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_layered_project_overrides_global_per_key() {
+        let dir = tempfile::tempdir().unwrap();
+        let global = write(
+            dir.path(),
+            "global.toml",
+            "[llm]\nbackground_provider = \"deepseek\"\ndefault_provider = \"deepseek\"\n\n[retention]\nchat_days = 10\n",
+        );
+        let project = write(
+            dir.path(),
+            "project.toml",
+            "[llm]\nbackground_provider = \"ollama\"\n",
+        );
+
+        let layered = MiraConfig::load_layered_from(&global, Some(&project));
+        assert!(layered.warnings.is_empty(), "{:?}", layered.warnings);
+        assert_eq!(layered.config.background_provider(), Some(Provider::Ollama));
+        assert_eq!(layered.config.default_provider(), Some(Provider::DeepSeek));
+        assert_eq!(layered.config.retention.chat_days, 10);
+        assert_eq!(layered.config.retention.sessions_days, 90);
+
+        let source = |key: &str| layered.values[key].1;
+        assert_eq!(source("llm.background_provider"), ConfigSource::Project);
+        assert_eq!(source("llm.default_provider"), ConfigSource::Global);
+        assert_eq!(source("retention.chat_days"), ConfigSource::Global);
+        assert_eq!(source("retention.sessions_days"), ConfigSource::Default);
+        assert_eq!(layered.values["llm.background_provider"].0, "\"ollama\"");
+    }
+
+    #[test]
+    fn test_layered_switching_projects_re_resolves() {
+        let dir = tempfile::tempdir().unwrap();
+        let global = write(
+            dir.path(),
+            "global.toml",
+            "[sessions]\nidle_after_minutes = 20\n",
+        );
+        let a = write(dir.path(), "a.toml", "[sessions]\nidle_after_minutes = 5\n");
+        let b = write(dir.path(), "b.toml", "");
+
+        let in_a = MiraConfig::load_layered_from(&global, Some(&a));
+        let in_b = MiraConfig::load_layered_from(&global, Some(&b));
+        let none = MiraConfig::load_layered_from(&global, None);
+        assert_eq!(in_a.config.sessions.idle_after_minutes, 5);
+        assert_eq!(in_b.config.sessions.idle_after_minutes, 20);
+        assert_eq!(none.config.sessions.idle_after_minutes, 20);
+    }
+
+    #[test]
+    fn test_layered_invalid_project_file_falls_back_to_global() {
+        let dir = tempfile::tempdir().unwrap();
+        let global = write(dir.path(), "global.toml", "[retention]\nchat_days = 10\n");
+
+        // Syntax error
+        let broken = write(dir.path(), "broken.toml", "[retention\nchat_days = ");
+        let layered = MiraConfig::load_layered_from(&global, Some(&broken));
+        assert_eq!(layered.config.retention.chat_days, 10);
+        assert_eq!(layered.warnings.len(), 1);
+
+        // Parses, but the wrong type for a known key
+        let wrong = write(
+            dir.path(),
+            "wrong.toml",
+            "[sessions]\nidle_after_minutes = \"soon\"\n",
+        );
+        let layered = MiraConfig::load_layered_from(&global, Some(&wrong));
+        assert_eq!(layered.config.sessions.idle_after_minutes, 10);
+        assert!(layered.warnings[0].contains("using global config"));
+        assert_eq!(
+            layered.values["sessions.idle_after_minutes"].1,
+            ConfigSource::Default
+        );
+
+        // Missing project file is not a warning
+        let missing = dir.path().join("missing.toml");
+        let layered = MiraConfig::load_layered_from(&global, Some(&missing));
+        assert!(layered.warnings.is_empty());
+    }
+
+    #[test]
+    fn test_layered_database_wide_keys_ignore_project_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let global = write(
+            dir.path(),
+            "global.toml",
+            "[retention]\nchat_days = 10\n\n[sessions]\nclose_after_minutes = 60\n",
+        );
+        let project = write(
+            dir.path(),
+            "project.toml",
+            "[retention]\nchat_days = 1\nsessions_days = 1\n\n[db_health]\nenabled = false\n\n\
+             [sessions]\nidle_after_minutes = 5\nclose_after_minutes = 6\n",
+        );
+
+        let layered = MiraConfig::load_layered_from(&global, Some(&project));
+        assert_eq!(layered.config.retention.chat_days, 10);
+        assert_eq!(layered.config.retention.sessions_days, 90);
+        assert!(layered.config.db_health.enabled);
+        assert_eq!(layered.config.sessions.close_after_minutes, 60);
+        // Per-project session keys still apply
+        assert_eq!(layered.config.sessions.idle_after_minutes, 5);
+        assert_eq!(
+            layered.values["retention.chat_days"].1,
+            ConfigSource::Global
+        );
+        assert_eq!(layered.warnings.len(), 3, "{:?}", layered.warnings);
+    }
 }
@@ -6,4 +6,4 @@ pub mod file;
 pub mod ignore;
 
 pub use env::{ApiKeys, ConfigValidation, EmbeddingsConfig, EnvConfig};
-pub use file::MiraConfig;
+pub use file::{ConfigSource, LayeredConfig, MiraConfig, TodoSyncConfig};
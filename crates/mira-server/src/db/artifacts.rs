@@ -0,0 +1,85 @@
+// crates/mira-server/src/db/artifacts.rs
+// Oversized tool results, stored by reference instead of returned inline
+
+use rusqlite::{Connection, OptionalExtension, params};
+
+/// A stored tool result, as returned by `get_tool_artifact_sync`
+#[derive(Debug, Clone)]
+pub struct ToolArtifact {
+    pub id: i64,
+    pub session_id: Option<String>,
+    pub tool_name: String,
+    pub content: String,
+    pub created_at: String,
+}
+
+/// Store a tool result as an artifact - sync version.
+/// Callers must redact secrets before storing.
+pub fn store_tool_artifact_sync(
+    conn: &Connection,
+    session_id: Option<&str>,
+    tool_name: &str,
+    content: &str,
+) -> rusqlite::Result<i64> {
+    conn.execute(
+        "INSERT INTO tool_artifacts (session_id, tool_name, content, size_bytes)
+         VALUES (?1, ?2, ?3, ?4)",
+        params![session_id, tool_name, content, content.len() as i64],
+    )?;
+    Ok(conn.last_insert_rowid())
+}
+
+/// Fetch a stored artifact by ID - sync version
+pub fn get_tool_artifact_sync(
+    conn: &Connection,
+    id: i64,
+) -> rusqlite::Result<Option<ToolArtifact>> {
+    conn.query_row(
+        "SELECT id, session_id, tool_name, content, created_at
+         FROM tool_artifacts WHERE id = ?1",
+        [id],
+        |row| {
+            Ok(ToolArtifact {
+                id: row.get(0)?,
+                session_id: row.get(1)?,
+                tool_name: row.get(2)?,
+                content: row.get(3)?,
+                created_at: row.get(4)?,
+            })
+        },
+    )
+    .optional()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::test_support::setup_test_connection;
+
+    #[test]
+    fn store_and_fetch_round_trip() {
+        let conn = setup_test_connection();
+        let id = store_tool_artifact_sync(&conn, None, "run", "line 1\nline 2").unwrap();
+
+        let artifact = get_tool_artifact_sync(&conn, id).unwrap().unwrap();
+        assert_eq!(artifact.id, id);
+        assert_eq!(artifact.tool_name, "run");
+        assert_eq!(artifact.content, "line 1\nline 2");
+        assert!(artifact.session_id.is_none());
+
+        let size: i64 = conn
+            .query_row(
+                "SELECT size_bytes FROM tool_artifacts WHERE id = ?1",
+                [id],
+                |r| r.get(0),
+            )
+            .unwrap();
+        assert_eq!(size, 13);
+    }
+
+    #[test]
+    fn missing_artifact_is_none() {
+        let conn = setup_test_connection();
+        assert!(get_tool_artifact_sync(&conn, 999).unwrap().is_none());
+    }
+}
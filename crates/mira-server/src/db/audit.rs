@@ -0,0 +1,76 @@
+// crates/mira-server/src/db/audit.rs
+// Streaming tool_history queries for audit export
+
+use anyhow::Result;
+use rusqlite::{Connection, params};
+
+/// Filters for an audit export. Dates are `YYYY-MM-DD`; `until` is inclusive.
+#[derive(Debug, Clone)]
+pub struct AuditFilter {
+    pub since: String,
+    pub until: String,
+    /// Normalized project path (see `utils::normalize_project_path`)
+    pub project_path: Option<String>,
+    pub tool_name: Option<String>,
+}
+
+/// One tool execution as recorded in tool_history.
+#[derive(Debug, Clone)]
+pub struct AuditRow {
+    pub id: i64,
+    pub created_at: String,
+    pub tool_name: String,
+    pub arguments: Option<String>,
+    pub success: bool,
+    pub duration_ms: Option<i64>,
+    pub session_id: Option<String>,
+    pub project_path: Option<String>,
+}
+
+/// Visit every tool_history row matching `filter`, oldest first.
+///
+/// Rows are handed to `visit` one at a time straight off the cursor so large
+/// exports never materialize in memory. Returns the number of rows visited.
+pub fn for_each_audit_row_sync<F>(
+    conn: &Connection,
+    filter: &AuditFilter,
+    mut visit: F,
+) -> Result<usize>
+where
+    F: FnMut(AuditRow) -> Result<()>,
+{
+    let mut stmt = conn.prepare(
+        "SELECT h.id, h.created_at, h.tool_name, h.arguments, h.success, h.duration_ms,
+                h.session_id, p.path
+         FROM tool_history h
+         LEFT JOIN sessions s ON s.id = h.session_id
+         LEFT JOIN projects p ON p.id = s.project_id
+         WHERE h.created_at >= ?1
+           AND h.created_at < date(?2, '+1 day')
+           AND (?3 IS NULL OR p.path = ?3)
+           AND (?4 IS NULL OR h.tool_name = ?4)
+         ORDER BY h.id ASC",
+    )?;
+    let mut rows = stmt.query(params![
+        filter.since,
+        filter.until,
+        filter.project_path,
+        filter.tool_name
+    ])?;
+
+    let mut count = 0;
+    while let Some(row) = rows.next()? {
+        visit(AuditRow {
+            id: row.get(0)?,
+            created_at: row.get(1)?,
+            tool_name: row.get(2)?,
+            arguments: row.get(3)?,
+            success: row.get::<_, Option<i32>>(4)?.unwrap_or(1) != 0,
+            duration_ms: row.get(5)?,
+            session_id: row.get(6)?,
+            project_path: row.get(7)?,
+        })?;
+        count += 1;
+    }
+    Ok(count)
+}
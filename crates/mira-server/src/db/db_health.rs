@@ -0,0 +1,470 @@
+// crates/mira-server/src/db/db_health.rs
+//! Database growth monitoring.
+//!
+//! The background worker samples the main database once a day: file size
+//! (page count x page size), WAL size, and row counts per table. Samples are
+//! compared week over week; growth past the `[db_health]` thresholds stores a
+//! global `db_health_alert` observation that session startup surfaces.
+
+use std::collections::BTreeMap;
+
+use chrono::{Duration, NaiveDate};
+use rusqlite::{Connection, OptionalExtension, params};
+
+use super::log_and_discard;
+use super::observations::{StoreObservationParams, store_observation_sync};
+use crate::config::file::DbHealthConfig;
+
+/// Observation key of the current growth warning (global scope)
+pub const DB_HEALTH_ALERT_KEY: &str = "db_health_alert";
+/// Days between the samples compared for growth
+const GROWTH_WINDOW_DAYS: i64 = 7;
+/// Samples older than this are pruned
+const SAMPLE_RETENTION_DAYS: u32 = 90;
+/// Tables named in a growth warning
+const MAX_ALERT_TABLES: usize = 3;
+
+/// One daily sample of the main database
+#[derive(Debug, Clone, PartialEq)]
+pub struct DbHealthSample {
+    /// UTC day of the sample (`YYYY-MM-DD`)
+    pub sampled_on: String,
+    pub db_size_bytes: u64,
+    pub wal_size_bytes: u64,
+    /// Row count per table
+    pub table_rows: BTreeMap<String, i64>,
+}
+
+/// Row growth of one table between two samples
+#[derive(Debug, Clone, PartialEq)]
+pub struct TableGrowth {
+    pub table: String,
+    pub baseline_rows: i64,
+    pub latest_rows: i64,
+}
+
+impl TableGrowth {
+    pub fn delta(&self) -> i64 {
+        self.latest_rows - self.baseline_rows
+    }
+}
+
+/// Growth between the latest sample and the one about a week before it
+#[derive(Debug, Clone, PartialEq)]
+pub struct DbGrowth {
+    pub baseline_on: String,
+    pub latest_on: String,
+    pub baseline_size_bytes: u64,
+    pub latest_size_bytes: u64,
+    /// Tables that gained rows, largest gain first
+    pub tables: Vec<TableGrowth>,
+}
+
+impl DbGrowth {
+    /// File size growth in percent (0 when the baseline was empty)
+    pub fn size_growth_pct(&self) -> f64 {
+        if self.baseline_size_bytes == 0 {
+            return 0.0;
+        }
+        (self.latest_size_bytes as f64 - self.baseline_size_bytes as f64)
+            / self.baseline_size_bytes as f64
+            * 100.0
+    }
+}
+
+/// Measure the database behind `conn` right now
+pub fn sample_db_health_sync(conn: &Connection) -> rusqlite::Result<DbHealthSample> {
+    let page_count: i64 = conn.query_row("PRAGMA page_count", [], |row| row.get(0))?;
+    let page_size: i64 = conn.query_row("PRAGMA page_size", [], |row| row.get(0))?;
+
+    // Empty for in-memory databases
+    let db_file: String = conn.query_row(
+        "SELECT file FROM pragma_database_list WHERE name = 'main'",
+        [],
+        |row| row.get(0),
+    )?;
+    let wal_size_bytes = if db_file.is_empty() {
+        0
+    } else {
+        std::fs::metadata(format!("{}-wal", db_file))
+            .map(|m| m.len())
+            .unwrap_or(0)
+    };
+
+    let tables: Vec<String> = {
+        let mut stmt = conn.prepare(
+            "SELECT name FROM sqlite_master
+             WHERE type = 'table' AND name NOT LIKE 'sqlite_%'
+             ORDER BY name",
+        )?;
+        stmt.query_map([], |row| row.get(0))?
+            .filter_map(log_and_discard)
+            .collect()
+    };
+    let mut table_rows = BTreeMap::new();
+    for table in tables {
+        // Virtual tables whose module isn't loaded can't be counted; skip them
+        let sql = format!("SELECT COUNT(*) FROM \"{}\"", table.replace('"', "\"\""));
+        if let Ok(count) = conn.query_row(&sql, [], |row| row.get::<_, i64>(0)) {
+            table_rows.insert(table, count);
+        }
+    }
+
+    let sampled_on: String = conn.query_row("SELECT date('now')", [], |row| row.get(0))?;
+
+    Ok(DbHealthSample {
+        sampled_on,
+        db_size_bytes: (page_count * page_size).max(0) as u64,
+        wal_size_bytes,
+        table_rows,
+    })
+}
+
+/// Store a sample, replacing any earlier sample for the same day
+pub fn record_db_health_sample_sync(
+    conn: &Connection,
+    sample: &DbHealthSample,
+) -> rusqlite::Result<()> {
+    let table_rows = serde_json::to_string(&sample.table_rows).unwrap_or_else(|_| "{}".into());
+    conn.execute(
+        "INSERT INTO db_health (sampled_on, db_size_bytes, wal_size_bytes, table_rows)
+         VALUES (?1, ?2, ?3, ?4)
+         ON CONFLICT(sampled_on) DO UPDATE SET
+            db_size_bytes = excluded.db_size_bytes,
+            wal_size_bytes = excluded.wal_size_bytes,
+            table_rows = excluded.table_rows,
+            created_at = CURRENT_TIMESTAMP",
+        params![
+            sample.sampled_on,
+            sample.db_size_bytes as i64,
+            sample.wal_size_bytes as i64,
+            table_rows
+        ],
+    )?;
+    Ok(())
+}
+
+/// Samples from the last `days` days, oldest first
+pub fn get_db_health_samples_sync(
+    conn: &Connection,
+    days: u32,
+) -> rusqlite::Result<Vec<DbHealthSample>> {
+    let mut stmt = conn.prepare(
+        "SELECT sampled_on, db_size_bytes, wal_size_bytes, table_rows FROM db_health
+         WHERE sampled_on >= date('now', '-' || ?1 || ' days')
+         ORDER BY sampled_on ASC",
+    )?;
+    let rows = stmt
+        .query_map([days], |row| {
+            let table_rows: String = row.get(3)?;
+            Ok(DbHealthSample {
+                sampled_on: row.get(0)?,
+                db_size_bytes: row.get::<_, i64>(1)?.max(0) as u64,
+                wal_size_bytes: row.get::<_, i64>(2)?.max(0) as u64,
+                table_rows: serde_json::from_str(&table_rows).unwrap_or_default(),
+            })
+        })?
+        .filter_map(log_and_discard)
+        .collect();
+    Ok(rows)
+}
+
+/// Compare the latest sample with the newest one at least a week older.
+/// Returns None until a week of history exists.
+pub fn week_over_week_growth(samples: &[DbHealthSample]) -> Option<DbGrowth> {
+    let parse = |s: &DbHealthSample| NaiveDate::parse_from_str(&s.sampled_on, "%Y-%m-%d").ok();
+    let latest = samples.iter().max_by_key(|s| s.sampled_on.as_str())?;
+    let cutoff = parse(latest)? - Duration::days(GROWTH_WINDOW_DAYS);
+    let baseline = samples
+        .iter()
+        .filter(|s| parse(s).is_some_and(|d| d <= cutoff))
+        .max_by_key(|s| s.sampled_on.as_str())?;
+
+    let mut tables: Vec<TableGrowth> = latest
+        .table_rows
+        .iter()
+        .map(|(table, &rows)| TableGrowth {
+            table: table.clone(),
+            baseline_rows: baseline.table_rows.get(table).copied().unwrap_or(0),
+            latest_rows: rows,
+        })
+        .filter(|t| t.delta() > 0)
+        .collect();
+    tables.sort_by(|a, b| b.delta().cmp(&a.delta()).then(a.table.cmp(&b.table)));
+
+    Some(DbGrowth {
+        baseline_on: baseline.sampled_on.clone(),
+        latest_on: latest.sampled_on.clone(),
+        baseline_size_bytes: baseline.db_size_bytes,
+        latest_size_bytes: latest.db_size_bytes,
+        tables,
+    })
+}
+
+/// Retention setting that bounds a table, if any
+fn retention_hint(table: &str) -> Option<&'static str> {
+    match table {
+        "tool_history" | "tool_artifacts" | "session_behavior_log" => {
+            Some("retention.tool_history_days")
+        }
+        "sessions" | "session_snapshots" | "session_tasks" | "session_goals" => {
+            Some("retention.sessions_days")
+        }
+        "llm_usage" | "embeddings_usage" => Some("retention.analytics_days"),
+        "behavior_patterns" => Some("retention.behavior_days"),
+        "system_observations" => Some("retention.observations_days"),
+        _ => None,
+    }
+}
+
+fn format_size(bytes: u64) -> String {
+    if bytes >= 1_073_741_824 {
+        format!("{:.1} GB", bytes as f64 / 1_073_741_824.0)
+    } else {
+        format!("{:.1} MB", bytes as f64 / 1_048_576.0)
+    }
+}
+
+/// One-line summary of week-over-week growth
+pub fn format_growth(growth: &DbGrowth) -> String {
+    let mut line = format!(
+        "{} -> {} ({:+.0}%) since {}",
+        format_size(growth.baseline_size_bytes),
+        format_size(growth.latest_size_bytes),
+        growth.size_growth_pct(),
+        growth.baseline_on
+    );
+    if !growth.tables.is_empty() {
+        let top: Vec<String> = growth
+            .tables
+            .iter()
+            .take(MAX_ALERT_TABLES)
+            .map(|t| format!("{} +{}", t.table, t.delta()))
+            .collect();
+        line.push_str(&format!("; top growth: {}", top.join(", ")));
+    }
+    line
+}
+
+/// Warning text when growth crosses the configured thresholds
+pub fn evaluate_growth_alert(growth: &DbGrowth, config: &DbHealthConfig) -> Option<String> {
+    let min_size = config.min_size_mb as u64 * 1_048_576;
+    let size_alert = growth.latest_size_bytes >= min_size
+        && growth.size_growth_pct() >= config.size_growth_pct as f64;
+    let table_alert = growth
+        .tables
+        .first()
+        .is_some_and(|t| t.delta() as u64 >= config.table_growth_rows);
+    if !size_alert && !table_alert {
+        return None;
+    }
+
+    let mut message = format!("Database growing fast: {}.", format_growth(growth));
+    let mut hints: Vec<&str> = Vec::new();
+    for hint in growth
+        .tables
+        .iter()
+        .take(MAX_ALERT_TABLES)
+        .filter_map(|t| retention_hint(&t.table))
+    {
+        if !hints.contains(&hint) {
+            hints.push(hint);
+        }
+    }
+    if hints.is_empty() {
+        message.push_str(" Run `mira cleanup` to preview retention cleanup.");
+    } else {
+        message.push_str(&format!(
+            " Run `mira cleanup` to preview retention cleanup, or lower {} in ~/.mira/config.toml.",
+            hints.join(" / ")
+        ));
+    }
+    Some(message)
+}
+
+/// Current growth warning, if any
+pub fn get_db_health_alert_sync(conn: &Connection) -> rusqlite::Result<Option<String>> {
+    conn.query_row(
+        "SELECT content FROM system_observations
+         WHERE key = ?1 AND project_id IS NULL AND scope = 'global'",
+        [DB_HEALTH_ALERT_KEY],
+        |row| row.get(0),
+    )
+    .optional()
+}
+
+/// Store or clear the growth warning
+fn set_db_health_alert_sync(conn: &Connection, alert: Option<&str>) -> rusqlite::Result<()> {
+    match alert {
+        Some(content) => {
+            store_observation_sync(
+                conn,
+                StoreObservationParams {
+                    project_id: None,
+                    key: Some(DB_HEALTH_ALERT_KEY),
+                    content,
+                    observation_type: "system",
+                    category: Some("system"),
+                    confidence: 1.0,
+                    source: "background",
+                    session_id: None,
+                    team_id: None,
+                    scope: "global",
+                    expires_at: Some("+7 days"),
+                },
+            )?;
+        }
+        None => {
+            conn.execute(
+                "DELETE FROM system_observations
+                 WHERE key = ?1 AND project_id IS NULL AND scope = 'global'",
+                [DB_HEALTH_ALERT_KEY],
+            )?;
+        }
+    }
+    Ok(())
+}
+
+/// Take today's sample (once per day) and refresh the growth warning.
+/// Returns 1 when a sample was taken, 0 when today's already exists.
+pub fn check_db_health_sync(conn: &Connection, config: &DbHealthConfig) -> rusqlite::Result<usize> {
+    let sampled_today: bool = conn.query_row(
+        "SELECT EXISTS(SELECT 1 FROM db_health WHERE sampled_on = date('now'))",
+        [],
+        |row| row.get(0),
+    )?;
+    if sampled_today {
+        return Ok(0);
+    }
+
+    let sample = sample_db_health_sync(conn)?;
+    record_db_health_sample_sync(conn, &sample)?;
+    conn.execute(
+        "DELETE FROM db_health WHERE sampled_on < date('now', '-' || ?1 || ' days')",
+        [SAMPLE_RETENTION_DAYS],
+    )?;
+
+    let samples = get_db_health_samples_sync(conn, GROWTH_WINDOW_DAYS as u32 * 2)?;
+    let alert = week_over_week_growth(&samples).and_then(|g| evaluate_growth_alert(&g, config));
+    set_db_health_alert_sync(conn, alert.as_deref())?;
+    Ok(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::test_support::setup_test_connection;
+
+    const MB: u64 = 1_048_576;
+
+    fn sample(day: &str, size_mb: u64, rows: &[(&str, i64)]) -> DbHealthSample {
+        DbHealthSample {
+            sampled_on: day.to_string(),
+            db_size_bytes: size_mb * MB,
+            wal_size_bytes: 0,
+            table_rows: rows.iter().map(|(t, n)| (t.to_string(), *n)).collect(),
+        }
+    }
+
+    #[test]
+    fn test_growth_needs_a_week_of_history() {
+        let samples = vec![
+            sample("2026-03-01", 200, &[]),
+            sample("2026-03-07", 400, &[]),
+        ];
+        assert!(week_over_week_growth(&samples).is_none());
+
+        let samples = vec![
+            sample("2026-03-01", 200, &[("goals", 10), ("tool_history", 1000)]),
+            sample("2026-03-02", 210, &[("goals", 10)]),
+            sample("2026-03-09", 300, &[("goals", 10), ("tool_history", 5000)]),
+        ];
+        let growth = week_over_week_growth(&samples).unwrap();
+        // Newest sample at least 7 days older than the latest
+        assert_eq!(growth.baseline_on, "2026-03-02");
+        assert_eq!(growth.latest_on, "2026-03-09");
+        // Tables missing from the baseline count from zero; unchanged tables drop out
+        assert_eq!(growth.tables.len(), 1);
+        assert_eq!(growth.tables[0].table, "tool_history");
+        assert_eq!(growth.tables[0].delta(), 5000);
+    }
+
+    #[test]
+    fn test_alert_triggers_at_size_threshold() {
+        let config = DbHealthConfig::default();
+        let growth = |to_mb| {
+            week_over_week_growth(&[
+                sample("2026-03-01", 200, &[("tool_history", 100)]),
+                sample("2026-03-08", to_mb, &[("tool_history", 200)]),
+            ])
+            .unwrap()
+        };
+
+        // 49% growth: below the default 50% threshold
+        assert!(evaluate_growth_alert(&growth(298), &config).is_none());
+
+        let alert = evaluate_growth_alert(&growth(300), &config).unwrap();
+        assert!(alert.contains("+50%"), "{alert}");
+        assert!(alert.contains("tool_history +100"), "{alert}");
+        assert!(alert.contains("retention.tool_history_days"), "{alert}");
+    }
+
+    #[test]
+    fn test_small_databases_ignore_size_growth() {
+        let config = DbHealthConfig::default();
+        let growth =
+            week_over_week_growth(&[sample("2026-03-01", 10, &[]), sample("2026-03-08", 90, &[])])
+                .unwrap();
+        // 800% growth, but still under min_size_mb
+        assert!(evaluate_growth_alert(&growth, &config).is_none());
+    }
+
+    #[test]
+    fn test_alert_triggers_at_table_row_threshold() {
+        let config = DbHealthConfig {
+            table_growth_rows: 1000,
+            ..Default::default()
+        };
+        let growth = |rows| {
+            week_over_week_growth(&[
+                sample("2026-03-01", 10, &[("llm_usage", 0)]),
+                sample("2026-03-08", 10, &[("llm_usage", rows)]),
+            ])
+            .unwrap()
+        };
+        assert!(evaluate_growth_alert(&growth(999), &config).is_none());
+        let alert = evaluate_growth_alert(&growth(1000), &config).unwrap();
+        assert!(alert.contains("retention.analytics_days"), "{alert}");
+    }
+
+    #[test]
+    fn test_check_samples_once_per_day_and_sets_alert() {
+        let conn = setup_test_connection();
+        let config = DbHealthConfig {
+            min_size_mb: 0,
+            ..Default::default()
+        };
+
+        // Seed a tiny baseline from 8 days ago so today's sample looks like fast growth
+        let mut baseline = sample_db_health_sync(&conn).unwrap();
+        baseline.sampled_on = conn
+            .query_row("SELECT date('now', '-8 days')", [], |row| row.get(0))
+            .unwrap();
+        baseline.db_size_bytes = 1;
+        record_db_health_sample_sync(&conn, &baseline).unwrap();
+
+        assert_eq!(check_db_health_sync(&conn, &config).unwrap(), 1);
+        assert_eq!(check_db_health_sync(&conn, &config).unwrap(), 0);
+
+        let samples = get_db_health_samples_sync(&conn, 30).unwrap();
+        assert_eq!(samples.len(), 2);
+        assert!(samples[1].table_rows.contains_key("db_health"));
+
+        let alert = get_db_health_alert_sync(&conn).unwrap().unwrap();
+        assert!(alert.starts_with("Database growing fast"), "{alert}");
+
+        // Clearing removes the warning
+        set_db_health_alert_sync(&conn, None).unwrap();
+        assert!(get_db_health_alert_sync(&conn).unwrap().is_none());
+    }
+}
@@ -0,0 +1,329 @@
+// crates/mira-server/src/db/dead_letters.rs
+// Dead-letter queue for background jobs that keep failing (code DB)
+
+use anyhow::{Context, bail};
+use rusqlite::{Connection, OptionalExtension, params};
+use serde::{Deserialize, Serialize};
+
+/// Job type for chunks from the pending_embeddings queue
+pub const JOB_EMBEDDING: &str = "embedding";
+
+/// A background job that exhausted its retries
+#[derive(Debug, Clone)]
+pub struct DeadLetter {
+    pub id: i64,
+    pub job_type: String,
+    pub project_id: Option<i64>,
+    /// What the job was about (file path for embeddings)
+    pub job_key: Option<String>,
+    /// JSON needed to requeue the job
+    pub payload: String,
+    pub error: Option<String>,
+    pub attempts: i64,
+    pub failed_at: String,
+}
+
+/// Payload stored for a dead-lettered embedding chunk
+#[derive(Debug, Serialize, Deserialize)]
+struct EmbeddingPayload {
+    file_path: String,
+    chunk_content: String,
+    start_line: i64,
+}
+
+/// Record a failed attempt for pending embeddings that failed on their own.
+///
+/// Callers must not pass chunks that only failed because their batch did, or
+/// because the provider was unavailable. Bumps `attempts` on every chunk in
+/// `ids`; chunks that reach `max_attempts` are moved to
+/// `background_dead_letters` with `error` and removed from the queue.
+/// Returns the number of chunks dead-lettered.
+pub fn record_embedding_failure_sync(
+    conn: &Connection,
+    ids: &[i64],
+    error: &str,
+    max_attempts: i64,
+) -> rusqlite::Result<usize> {
+    let tx = conn.unchecked_transaction()?;
+    let mut dead = 0;
+    {
+        let mut bump =
+            tx.prepare("UPDATE pending_embeddings SET attempts = attempts + 1 WHERE id = ?")?;
+        let mut fetch = tx.prepare(
+            "SELECT project_id, file_path, chunk_content, start_line, attempts
+             FROM pending_embeddings WHERE id = ?",
+        )?;
+        for &id in ids {
+            bump.execute([id])?;
+            let row = fetch
+                .query_row([id], |row| {
+                    Ok((
+                        row.get::<_, Option<i64>>(0)?,
+                        EmbeddingPayload {
+                            file_path: row.get(1)?,
+                            chunk_content: row.get(2)?,
+                            start_line: row.get(3)?,
+                        },
+                        row.get::<_, i64>(4)?,
+                    ))
+                })
+                .optional()?;
+            let Some((project_id, payload, attempts)) = row else {
+                continue;
+            };
+            if attempts < max_attempts {
+                continue;
+            }
+            let payload_json = serde_json::to_string(&payload)
+                .map_err(|e| rusqlite::Error::ToSqlConversionFailure(Box::new(e)))?;
+            tx.execute(
+                "INSERT INTO background_dead_letters
+                     (job_type, project_id, job_key, payload, error, attempts)
+                 VALUES (?, ?, ?, ?, ?, ?)",
+                params![
+                    JOB_EMBEDDING,
+                    project_id,
+                    payload.file_path,
+                    payload_json,
+                    error,
+                    attempts
+                ],
+            )?;
+            tx.execute("DELETE FROM pending_embeddings WHERE id = ?", [id])?;
+            dead += 1;
+        }
+    }
+    tx.commit()?;
+    Ok(dead)
+}
+
+/// List dead letters, newest first, optionally limited to one project
+pub fn list_dead_letters_sync(
+    conn: &Connection,
+    project_id: Option<i64>,
+    limit: usize,
+) -> rusqlite::Result<Vec<DeadLetter>> {
+    let mut stmt = conn.prepare(
+        "SELECT id, job_type, project_id, job_key, payload, error, attempts, failed_at
+         FROM background_dead_letters
+         WHERE ?1 IS NULL OR project_id = ?1
+         ORDER BY id DESC
+         LIMIT ?2",
+    )?;
+    let rows = stmt.query_map(params![project_id, limit as i64], |row| {
+        Ok(DeadLetter {
+            id: row.get(0)?,
+            job_type: row.get(1)?,
+            project_id: row.get(2)?,
+            job_key: row.get(3)?,
+            payload: row.get(4)?,
+            error: row.get(5)?,
+            attempts: row.get(6)?,
+            failed_at: row.get(7)?,
+        })
+    })?;
+    rows.collect()
+}
+
+/// Count dead letters, optionally limited to one project
+pub fn count_dead_letters_sync(
+    conn: &Connection,
+    project_id: Option<i64>,
+) -> rusqlite::Result<i64> {
+    conn.query_row(
+        "SELECT COUNT(*) FROM background_dead_letters WHERE ?1 IS NULL OR project_id = ?1",
+        params![project_id],
+        |row| row.get(0),
+    )
+}
+
+/// Put a dead-lettered job back on its queue with a fresh retry budget.
+///
+/// Returns false if no dead letter has that ID. Fails, leaving the dead letter
+/// in place, if its job type is unknown or its payload doesn't parse.
+pub fn retry_dead_letter_sync(conn: &Connection, id: i64) -> anyhow::Result<bool> {
+    let tx = conn.unchecked_transaction()?;
+    let row: Option<(String, Option<i64>, String)> = tx
+        .query_row(
+            "SELECT job_type, project_id, payload FROM background_dead_letters WHERE id = ?",
+            [id],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+        )
+        .optional()?;
+    let Some((job_type, project_id, payload)) = row else {
+        return Ok(false);
+    };
+
+    match job_type.as_str() {
+        JOB_EMBEDDING => {
+            let payload: EmbeddingPayload = serde_json::from_str(&payload)
+                .with_context(|| format!("invalid payload for dead letter {id}"))?;
+            tx.execute(
+                "INSERT INTO pending_embeddings (project_id, file_path, chunk_content, start_line)
+                 VALUES (?, ?, ?, ?)",
+                params![
+                    project_id,
+                    payload.file_path,
+                    payload.chunk_content,
+                    payload.start_line
+                ],
+            )?;
+        }
+        other => bail!("Dead letter {id} has unknown job type '{other}'; cannot requeue it"),
+    }
+
+    tx.execute("DELETE FROM background_dead_letters WHERE id = ?", [id])?;
+    tx.commit()?;
+    Ok(true)
+}
+
+/// Requeue every dead letter (optionally for one project). Returns the count requeued.
+pub fn retry_all_dead_letters_sync(
+    conn: &Connection,
+    project_id: Option<i64>,
+) -> anyhow::Result<usize> {
+    let ids: Vec<i64> = conn
+        .prepare("SELECT id FROM background_dead_letters WHERE ?1 IS NULL OR project_id = ?1")?
+        .query_map(params![project_id], |row| row.get(0))?
+        .collect::<rusqlite::Result<_>>()?;
+    let mut requeued = 0;
+    for id in ids {
+        if retry_dead_letter_sync(conn, id)? {
+            requeued += 1;
+        }
+    }
+    Ok(requeued)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::pool::DatabasePool;
+
+    const MAX: i64 = 3;
+
+    fn queue_chunk(conn: &Connection, file_path: &str) -> i64 {
+        conn.execute(
+            "INSERT INTO pending_embeddings (project_id, file_path, chunk_content, start_line)
+             VALUES (1, ?, 'fn broken() {}', 7)",
+            [file_path],
+        )
+        .unwrap();
+        conn.last_insert_rowid()
+    }
+
+    fn pending_count(conn: &Connection) -> i64 {
+        conn.query_row("SELECT COUNT(*) FROM pending_embeddings", [], |r| r.get(0))
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_job_failing_past_retry_limit_is_dead_lettered() {
+        let pool = DatabasePool::open_code_db_in_memory().await.unwrap();
+        pool.interact(|conn| {
+            let id = queue_chunk(conn, "src/lib.rs");
+
+            for _ in 0..MAX - 1 {
+                let dead = record_embedding_failure_sync(conn, &[id], "503", MAX).unwrap();
+                assert_eq!(dead, 0, "still within retry budget");
+            }
+            assert_eq!(pending_count(conn), 1);
+
+            let dead = record_embedding_failure_sync(conn, &[id], "503 again", MAX).unwrap();
+            assert_eq!(dead, 1);
+            assert_eq!(pending_count(conn), 0);
+
+            let letters = list_dead_letters_sync(conn, None, 10).unwrap();
+            assert_eq!(letters.len(), 1);
+            assert_eq!(letters[0].job_type, JOB_EMBEDDING);
+            assert_eq!(letters[0].job_key.as_deref(), Some("src/lib.rs"));
+            assert_eq!(letters[0].error.as_deref(), Some("503 again"));
+            assert_eq!(letters[0].attempts, MAX);
+            assert!(letters[0].payload.contains("fn broken()"));
+            Ok(())
+        })
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_retry_dead_letter_requeues_with_fresh_budget() {
+        let pool = DatabasePool::open_code_db_in_memory().await.unwrap();
+        pool.interact(|conn| {
+            let id = queue_chunk(conn, "src/lib.rs");
+            for _ in 0..MAX {
+                record_embedding_failure_sync(conn, &[id], "boom", MAX).unwrap();
+            }
+            let letter = list_dead_letters_sync(conn, Some(1), 10).unwrap().remove(0);
+
+            assert!(retry_dead_letter_sync(conn, letter.id).unwrap());
+            assert_eq!(count_dead_letters_sync(conn, None).unwrap(), 0);
+
+            let (file_path, content, start_line, attempts): (String, String, i64, i64) = conn
+                .query_row(
+                    "SELECT file_path, chunk_content, start_line, attempts FROM pending_embeddings",
+                    [],
+                    |r| Ok((r.get(0)?, r.get(1)?, r.get(2)?, r.get(3)?)),
+                )
+                .unwrap();
+            assert_eq!(file_path, "src/lib.rs");
+            assert_eq!(content, "fn broken() {}");
+            assert_eq!(start_line, 7);
+            assert_eq!(attempts, 0);
+
+            // Already requeued
+            assert!(!retry_dead_letter_sync(conn, letter.id).unwrap());
+            Ok(())
+        })
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_retry_all_and_reindex_cleanup() {
+        let pool = DatabasePool::open_code_db_in_memory().await.unwrap();
+        pool.interact(|conn| {
+            let a = queue_chunk(conn, "src/a.rs");
+            let b = queue_chunk(conn, "src/b.rs");
+            for _ in 0..MAX {
+                record_embedding_failure_sync(conn, &[a, b], "boom", MAX).unwrap();
+            }
+            assert_eq!(count_dead_letters_sync(conn, Some(1)).unwrap(), 2);
+
+            // Re-indexing a file supersedes its dead letters
+            crate::db::clear_file_index_sync(conn, 1, "src/a.rs").unwrap();
+            assert_eq!(count_dead_letters_sync(conn, Some(1)).unwrap(), 1);
+
+            assert_eq!(retry_all_dead_letters_sync(conn, None).unwrap(), 1);
+            assert_eq!(pending_count(conn), 1);
+            Ok(())
+        })
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_retry_unknown_job_type_keeps_dead_letter() {
+        let pool = DatabasePool::open_code_db_in_memory().await.unwrap();
+        pool.interact(|conn| {
+            conn.execute(
+                "INSERT INTO background_dead_letters (job_type, project_id, payload, attempts)
+                 VALUES ('summary', 1, '{}', 3)",
+                [],
+            )
+            .unwrap();
+            let id = conn.last_insert_rowid();
+
+            let err = retry_dead_letter_sync(conn, id).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                format!("Dead letter {id} has unknown job type 'summary'; cannot requeue it")
+            );
+            assert_eq!(count_dead_letters_sync(conn, None).unwrap(), 1);
+            assert_eq!(pending_count(conn), 0);
+            Ok(())
+        })
+        .await
+        .unwrap();
+    }
+}
@@ -0,0 +1,111 @@
+// crates/mira-server/src/db/dependency_graph.rs
+// Cycle checks shared by the goal and task dependency tables
+
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use anyhow::{Result, bail};
+use rusqlite::Connection;
+
+/// Fail if adding the edge `id -> depends_on` would close a cycle.
+///
+/// `next_sql` selects the direct dependencies of the node bound to `?`.
+/// The new edge closes a cycle iff `depends_on` already (transitively)
+/// waits on `id`.
+pub(super) fn check_dependency_cycle(
+    conn: &Connection,
+    next_sql: &str,
+    id: i64,
+    depends_on: i64,
+) -> Result<()> {
+    if let Some(path) = dependency_path(conn, next_sql, depends_on, id)? {
+        let chain: Vec<String> = path.iter().map(|id| id.to_string()).collect();
+        bail!(
+            "Dependency {} -> {} would create a cycle ({} -> {})",
+            id,
+            depends_on,
+            id,
+            chain.join(" -> ")
+        );
+    }
+    Ok(())
+}
+
+/// Path of dependency edges from `from` to `to` (both inclusive), if any.
+/// `next_sql` selects the direct dependencies of the node bound to `?`.
+fn dependency_path(
+    conn: &Connection,
+    next_sql: &str,
+    from: i64,
+    to: i64,
+) -> Result<Option<Vec<i64>>> {
+    let mut stmt = conn.prepare(next_sql)?;
+    let mut came_from: HashMap<i64, i64> = HashMap::new();
+    let mut queue = VecDeque::from([from]);
+    let mut seen = HashSet::from([from]);
+
+    while let Some(current) = queue.pop_front() {
+        if current == to {
+            let mut path = vec![to];
+            let mut node = to;
+            while let Some(&prev) = came_from.get(&node) {
+                path.push(prev);
+                node = prev;
+            }
+            path.reverse();
+            return Ok(Some(path));
+        }
+        let next: Vec<i64> = stmt
+            .query_map([current], |row| row.get(0))?
+            .collect::<rusqlite::Result<_>>()?;
+        for dep in next {
+            if seen.insert(dep) {
+                came_from.insert(dep, current);
+                queue.push_back(dep);
+            }
+        }
+    }
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NEXT_SQL: &str = "SELECT depends_on FROM edges WHERE id = ?";
+
+    fn graph(edges: &[(i64, i64)]) -> Connection {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch("CREATE TABLE edges (id INTEGER, depends_on INTEGER)")
+            .unwrap();
+        for (id, dep) in edges {
+            conn.execute("INSERT INTO edges VALUES (?, ?)", [id, dep])
+                .unwrap();
+        }
+        conn
+    }
+
+    #[test]
+    fn test_dependency_path_follows_edges() {
+        let conn = graph(&[(1, 2), (2, 3), (1, 4), (4, 3)]);
+        assert_eq!(
+            dependency_path(&conn, NEXT_SQL, 1, 3).unwrap(),
+            Some(vec![1, 2, 3])
+        );
+        assert_eq!(dependency_path(&conn, NEXT_SQL, 3, 1).unwrap(), None);
+        assert_eq!(
+            dependency_path(&conn, NEXT_SQL, 2, 2).unwrap(),
+            Some(vec![2])
+        );
+    }
+
+    #[test]
+    fn test_check_dependency_cycle_names_the_chain() {
+        let conn = graph(&[(2, 1), (3, 2)]);
+        let err = check_dependency_cycle(&conn, NEXT_SQL, 1, 3).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Dependency 1 -> 3 would create a cycle (1 -> 3 -> 2 -> 1)"
+        );
+        assert!(check_dependency_cycle(&conn, NEXT_SQL, 3, 1).is_ok());
+    }
+}
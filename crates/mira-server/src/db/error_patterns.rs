@@ -24,6 +24,58 @@ pub struct ResolvedErrorPattern {
     pub occurrence_count: i64,
 }
 
+/// How `find_similar_fix_sync` matched a past fix to the current error
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FixMatch {
+    /// Same fingerprint
+    Exact,
+    /// Same error shape (identifiers and quoted values stripped)
+    Normalized,
+    /// Shapes agree token-by-token above `SIMILAR_SHAPE_THRESHOLD`
+    Similar,
+    /// Embedding of the error close to a past error and its fix
+    Semantic,
+}
+
+impl FixMatch {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            FixMatch::Exact => "exact",
+            FixMatch::Normalized => "normalized",
+            FixMatch::Similar => "similar",
+            FixMatch::Semantic => "semantic",
+        }
+    }
+}
+
+/// A past fix for an error like the current one
+pub struct SimilarFix {
+    pub pattern: ResolvedErrorPattern,
+    pub strategy: FixMatch,
+    /// 1.0 for exact/normalized, shape similarity for `Similar`,
+    /// cosine similarity for `Semantic`
+    pub score: f64,
+}
+
+/// A resolved pattern considered for a `Semantic` match
+pub struct SemanticFixCandidate {
+    pub pattern: ResolvedErrorPattern,
+    /// Cached embedding of `fix_embedding_text`, little-endian f32s
+    pub embedding: Option<Vec<u8>>,
+}
+
+/// Minimum shape similarity for a `Similar` match
+const SIMILAR_SHAPE_THRESHOLD: f64 = 0.8;
+/// Shapes with fewer literal words are too generic to match loosely
+/// (`test failed: <_>` would match every failing test)
+const MIN_SHAPE_LITERALS: usize = 3;
+/// Resolved patterns compared per lookup
+const MAX_SHAPE_CANDIDATES: i64 = 200;
+/// Placeholder token in error shapes
+const SHAPE_PLACEHOLDER: &str = "<_>";
+/// Minimum cosine similarity for a `Semantic` match
+pub const SEMANTIC_FIX_THRESHOLD: f64 = 0.85;
+
 /// Store or update an error pattern (UPSERT on fingerprint).
 /// Increments occurrence_count, updates last_seen_session_id.
 pub fn store_error_pattern_sync(
@@ -31,12 +83,13 @@ pub fn store_error_pattern_sync(
     params: StoreErrorPatternParams,
 ) -> rusqlite::Result<i64> {
     conn.execute(
-        "INSERT INTO error_patterns (project_id, tool_name, error_fingerprint, error_template, raw_error_sample, first_seen_session_id, last_seen_session_id)
-         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?6)
+        "INSERT INTO error_patterns (project_id, tool_name, error_fingerprint, error_template, error_shape, raw_error_sample, first_seen_session_id, last_seen_session_id)
+         VALUES (?1, ?2, ?3, ?4, ?7, ?5, ?6, ?6)
          ON CONFLICT(project_id, tool_name, error_fingerprint) DO UPDATE SET
             occurrence_count = occurrence_count + 1,
             last_seen_session_id = ?6,
             raw_error_sample = ?5,
+            error_shape = ?7,
             updated_at = CURRENT_TIMESTAMP",
         params![
             params.project_id,
@@ -45,6 +98,7 @@ pub fn store_error_pattern_sync(
             params.error_template,
             params.raw_error_sample,
             params.session_id,
+            error_shape(params.error_template),
         ],
     )?;
     Ok(conn.last_insert_rowid())
@@ -80,6 +134,248 @@ pub fn lookup_resolved_pattern_sync(
     .ok()
 }
 
+/// Find a past fix for the error with this fingerprint.
+///
+/// Tries, in order: the exact fingerprint, then resolved patterns with the same
+/// error shape (an indexed lookup), then the resolved pattern whose shape is
+/// most similar (at least `SIMILAR_SHAPE_THRESHOLD`). The error itself must
+/// already be stored via `store_error_pattern_sync` so its shape is known.
+///
+/// The `Semantic` fallback needs an embedding client, so callers that have one
+/// run it after this returns `None` (see `semantic_fix_candidates_sync`).
+pub fn find_similar_fix_sync(
+    conn: &Connection,
+    project_id: i64,
+    tool_name: &str,
+    error_fingerprint: &str,
+) -> Option<SimilarFix> {
+    if let Some(pattern) =
+        lookup_resolved_pattern_sync(conn, project_id, tool_name, error_fingerprint)
+    {
+        return Some(SimilarFix {
+            pattern,
+            strategy: FixMatch::Exact,
+            score: 1.0,
+        });
+    }
+
+    let shape: String = conn
+        .query_row(
+            "SELECT error_shape FROM error_patterns
+             WHERE project_id = ?1 AND tool_name = ?2 AND error_fingerprint = ?3",
+            params![project_id, tool_name, error_fingerprint],
+            |row| row.get::<_, Option<String>>(0),
+        )
+        .ok()
+        .flatten()?;
+    if shape_literals(&shape) < MIN_SHAPE_LITERALS {
+        return None;
+    }
+
+    let same_shape = conn
+        .query_row(
+            "SELECT id, tool_name, error_template, fix_description, occurrence_count
+             FROM error_patterns
+             WHERE project_id = ?1
+               AND tool_name = ?2
+               AND error_shape = ?3
+               AND error_fingerprint != ?4
+               AND fix_description IS NOT NULL
+               AND resolved_at IS NOT NULL
+             ORDER BY occurrence_count DESC, updated_at DESC, id DESC
+             LIMIT 1",
+            params![project_id, tool_name, shape, error_fingerprint],
+            |row| {
+                Ok(ResolvedErrorPattern {
+                    id: row.get(0)?,
+                    tool_name: row.get(1)?,
+                    error_template: row.get(2)?,
+                    fix_description: row.get(3)?,
+                    occurrence_count: row.get(4)?,
+                })
+            },
+        )
+        .ok();
+    if let Some(pattern) = same_shape {
+        return Some(SimilarFix {
+            pattern,
+            strategy: FixMatch::Normalized,
+            score: 1.0,
+        });
+    }
+
+    let mut stmt = conn
+        .prepare(
+            "SELECT id, tool_name, error_template, fix_description, occurrence_count, error_shape
+             FROM error_patterns
+             WHERE project_id = ?1
+               AND tool_name = ?2
+               AND error_fingerprint != ?3
+               AND fix_description IS NOT NULL
+               AND resolved_at IS NOT NULL
+               AND error_shape IS NOT NULL
+             ORDER BY occurrence_count DESC, updated_at DESC, id DESC
+             LIMIT ?4",
+        )
+        .ok()?;
+    let candidates: Vec<(ResolvedErrorPattern, String)> = stmt
+        .query_map(
+            params![
+                project_id,
+                tool_name,
+                error_fingerprint,
+                MAX_SHAPE_CANDIDATES
+            ],
+            |row| {
+                Ok((
+                    ResolvedErrorPattern {
+                        id: row.get(0)?,
+                        tool_name: row.get(1)?,
+                        error_template: row.get(2)?,
+                        fix_description: row.get(3)?,
+                        occurrence_count: row.get(4)?,
+                    },
+                    row.get(5)?,
+                ))
+            },
+        )
+        .ok()?
+        .filter_map(|r| r.ok())
+        .collect();
+
+    let mut best: Option<(ResolvedErrorPattern, f64)> = None;
+    for (pattern, candidate_shape) in candidates {
+        if shape_literals(&candidate_shape) < MIN_SHAPE_LITERALS {
+            continue;
+        }
+        let score = shape_similarity(&shape, &candidate_shape);
+        if score >= SIMILAR_SHAPE_THRESHOLD && best.as_ref().is_none_or(|(_, s)| score > *s) {
+            best = Some((pattern, score));
+        }
+    }
+    best.map(|(pattern, score)| SimilarFix {
+        pattern,
+        strategy: FixMatch::Similar,
+        score,
+    })
+}
+
+/// Template of the stored error with this fingerprint (the query text for
+/// a semantic lookup).
+pub fn error_template_sync(
+    conn: &Connection,
+    project_id: i64,
+    tool_name: &str,
+    error_fingerprint: &str,
+) -> Option<String> {
+    conn.query_row(
+        "SELECT error_template FROM error_patterns
+         WHERE project_id = ?1 AND tool_name = ?2 AND error_fingerprint = ?3",
+        params![project_id, tool_name, error_fingerprint],
+        |row| row.get(0),
+    )
+    .ok()
+}
+
+/// Resolved patterns of this tool to compare semantically against the
+/// error with this fingerprint, with their cached embeddings.
+pub fn semantic_fix_candidates_sync(
+    conn: &Connection,
+    project_id: i64,
+    tool_name: &str,
+    error_fingerprint: &str,
+) -> rusqlite::Result<Vec<SemanticFixCandidate>> {
+    let mut stmt = conn.prepare(
+        "SELECT id, tool_name, error_template, fix_description, occurrence_count, fix_embedding
+         FROM error_patterns
+         WHERE project_id = ?1
+           AND tool_name = ?2
+           AND error_fingerprint != ?3
+           AND fix_description IS NOT NULL
+           AND resolved_at IS NOT NULL
+         ORDER BY occurrence_count DESC, updated_at DESC, id DESC
+         LIMIT ?4",
+    )?;
+    stmt.query_map(
+        params![
+            project_id,
+            tool_name,
+            error_fingerprint,
+            MAX_SHAPE_CANDIDATES
+        ],
+        |row| {
+            Ok(SemanticFixCandidate {
+                pattern: ResolvedErrorPattern {
+                    id: row.get(0)?,
+                    tool_name: row.get(1)?,
+                    error_template: row.get(2)?,
+                    fix_description: row.get(3)?,
+                    occurrence_count: row.get(4)?,
+                },
+                embedding: row.get(5)?,
+            })
+        },
+    )?
+    .collect()
+}
+
+/// Cache the embedding of a resolved pattern's `fix_embedding_text`.
+pub fn store_fix_embedding_sync(
+    conn: &Connection,
+    pattern_id: i64,
+    embedding_bytes: &[u8],
+) -> rusqlite::Result<usize> {
+    conn.execute(
+        "UPDATE error_patterns SET fix_embedding = ?1 WHERE id = ?2",
+        params![embedding_bytes, pattern_id],
+    )
+}
+
+/// Text embedded for a resolved pattern: the error and how it was fixed.
+pub fn fix_embedding_text(pattern: &ResolvedErrorPattern) -> String {
+    format!(
+        "{}\nFix: {}",
+        pattern.error_template, pattern.fix_description
+    )
+}
+
+/// Pick the candidate whose embedding is closest to `query`, if it reaches
+/// `SEMANTIC_FIX_THRESHOLD`. Embeddings of another dimension are skipped.
+pub fn best_semantic_fix(
+    query: &[f32],
+    candidates: Vec<(ResolvedErrorPattern, Vec<f32>)>,
+) -> Option<SimilarFix> {
+    let mut best: Option<(ResolvedErrorPattern, f64)> = None;
+    for (pattern, embedding) in candidates {
+        if embedding.len() != query.len() {
+            continue;
+        }
+        let score = cosine_similarity(query, &embedding);
+        if score >= SEMANTIC_FIX_THRESHOLD && best.as_ref().is_none_or(|(_, s)| score > *s) {
+            best = Some((pattern, score));
+        }
+    }
+    best.map(|(pattern, score)| SimilarFix {
+        pattern,
+        strategy: FixMatch::Semantic,
+        score,
+    })
+}
+
+fn cosine_similarity(a: &[f32], b: &[f32]) -> f64 {
+    let (mut dot, mut norm_a, mut norm_b) = (0.0f64, 0.0f64, 0.0f64);
+    for (x, y) in a.iter().zip(b) {
+        let (x, y) = (f64::from(*x), f64::from(*y));
+        dot += x * y;
+        norm_a += x * x;
+        norm_b += y * y;
+    }
+    if norm_a == 0.0 || norm_b == 0.0 {
+        return 0.0;
+    }
+    dot / (norm_a.sqrt() * norm_b.sqrt())
+}
+
 /// Mark an error pattern as resolved.
 /// Called when the same tool succeeds after repeated failures.
 pub fn resolve_error_pattern_sync(
@@ -140,6 +436,52 @@ pub fn get_unresolved_patterns_for_tool_sync(
     .unwrap_or_default()
 }
 
+/// Count `tool_failure` behavior events with the given fingerprint in a session.
+///
+/// Used to rate-limit fix hints: a hint is injected only the first time a
+/// signature fails in a session, not on every retry.
+pub fn count_fingerprint_failures_sync(
+    conn: &Connection,
+    session_id: &str,
+    error_fingerprint: &str,
+) -> i64 {
+    conn.query_row(
+        "SELECT COUNT(*) FROM session_behavior_log
+         WHERE session_id = ?1 AND event_type = 'tool_failure'
+           AND json_extract(event_data, '$.error_fingerprint') = ?2",
+        params![session_id, error_fingerprint],
+        |row| row.get::<_, i64>(0),
+    )
+    .unwrap_or(0)
+}
+
+/// Count this signature's `tool_failure` events in a session that were logged
+/// once the tool had already failed `min_tool_failures` times (counting the
+/// event itself).
+///
+/// Lets the escalated fix hint fire once per signature: only when this is the
+/// first such event.
+pub fn count_escalated_fingerprint_failures_sync(
+    conn: &Connection,
+    session_id: &str,
+    error_fingerprint: &str,
+    min_tool_failures: i64,
+) -> i64 {
+    conn.query_row(
+        "SELECT COUNT(*) FROM session_behavior_log f
+         WHERE f.session_id = ?1 AND f.event_type = 'tool_failure'
+           AND json_extract(f.event_data, '$.error_fingerprint') = ?2
+           AND (SELECT COUNT(*) FROM session_behavior_log t
+                WHERE t.session_id = f.session_id AND t.event_type = 'tool_failure'
+                  AND json_extract(t.event_data, '$.tool_name')
+                      = json_extract(f.event_data, '$.tool_name')
+                  AND t.id <= f.id) >= ?3",
+        params![session_id, error_fingerprint, min_tool_failures],
+        |row| row.get::<_, i64>(0),
+    )
+    .unwrap_or(0)
+}
+
 /// Row returned by `get_error_patterns_sync`.
 pub struct ErrorPatternRow {
     pub tool_name: String,
@@ -224,6 +566,192 @@ pub fn error_fingerprint(tool_name: &str, raw_error: &str) -> (String, String) {
     (hash, template)
 }
 
+/// Reduce an error template to its shape for loose matching.
+///
+/// On top of `error_fingerprint`'s normalization, replaces every quoted or
+/// backticked value (identifiers, types, module names) and remaining number
+/// with a placeholder, unifies placeholders, and drops punctuation, so
+/// ``cannot borrow `x` as mutable`` and ``cannot borrow `self.items` as
+/// mutable`` share a shape.
+pub fn error_shape(template: &str) -> String {
+    use regex::Regex;
+    use std::sync::LazyLock;
+
+    #[allow(clippy::expect_used)]
+    static RE_QUOTED: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r#"`[^`]*`|'[^'\s]*'|"[^"]*""#).expect("valid regex"));
+    #[allow(clippy::expect_used)]
+    static RE_PLACEHOLDER: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r"<[A-Za-z_]+>").expect("valid regex"));
+    #[allow(clippy::expect_used)]
+    static RE_NUMBER: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r"\b\d+\b").expect("valid regex"));
+
+    let shape = template.to_lowercase();
+    let shape = RE_QUOTED.replace_all(&shape, " <_> ");
+    let shape = RE_PLACEHOLDER.replace_all(&shape, " <_> ");
+    let shape = RE_NUMBER.replace_all(&shape, " <_> ");
+
+    let mut tokens: Vec<&str> = Vec::new();
+    for token in shape.split_whitespace() {
+        let token = token.trim_matches(|c: char| ",.;:()".contains(c));
+        // Collapse runs of placeholders (`<_>:<_>:<_>` from paths and line numbers)
+        if token.is_empty()
+            || (token == SHAPE_PLACEHOLDER && tokens.last() == Some(&SHAPE_PLACEHOLDER))
+        {
+            continue;
+        }
+        tokens.push(token);
+    }
+    tokens.join(" ")
+}
+
+/// Number of non-placeholder words in a shape
+fn shape_literals(shape: &str) -> usize {
+    shape
+        .split_whitespace()
+        .filter(|t| *t != SHAPE_PLACEHOLDER)
+        .count()
+}
+
+/// Similarity of two error shapes in 0.0..=1.0.
+///
+/// Same length: fraction of positions that agree, a placeholder agreeing with
+/// anything (so `` `x` `` lines up with a bare `data`). Otherwise: Jaccard
+/// overlap of the literal words.
+fn shape_similarity(a: &str, b: &str) -> f64 {
+    let ta: Vec<&str> = a.split_whitespace().collect();
+    let tb: Vec<&str> = b.split_whitespace().collect();
+    if ta.is_empty() || tb.is_empty() {
+        return 0.0;
+    }
+    if ta.len() == tb.len() {
+        let agree = ta
+            .iter()
+            .zip(&tb)
+            .filter(|(x, y)| x == y || **x == SHAPE_PLACEHOLDER || **y == SHAPE_PLACEHOLDER)
+            .count();
+        return agree as f64 / ta.len() as f64;
+    }
+    let sa: std::collections::HashSet<&str> =
+        ta.into_iter().filter(|t| *t != SHAPE_PLACEHOLDER).collect();
+    let sb: std::collections::HashSet<&str> =
+        tb.into_iter().filter(|t| *t != SHAPE_PLACEHOLDER).collect();
+    let union = sa.union(&sb).count();
+    if union == 0 {
+        return 0.0;
+    }
+    sa.intersection(&sb).count() as f64 / union as f64
+}
+
+/// Maximum number of signatures extracted from a single tool output.
+const MAX_ERROR_SIGNATURES: usize = 5;
+
+/// Kind of error signature recognized in build/test output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorSignatureKind {
+    /// rustc/cargo diagnostic, e.g. `error[E0502]: cannot borrow ...`
+    CompilerError,
+    /// Rust panic message
+    Panic,
+    /// A named failing test (cargo test, pytest, jest)
+    FailedTest,
+}
+
+/// A stable error signature pulled out of noisy tool output.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ErrorSignature {
+    pub kind: ErrorSignatureKind,
+    pub text: String,
+}
+
+/// Extract compiler/test error signatures from raw tool output.
+///
+/// Full build logs differ run-to-run (timings, progress lines, ordering), so
+/// fingerprinting the whole output rarely matches a past failure. Signatures
+/// are the stable parts: rustc error lines, panic messages, and failing test
+/// names from cargo test, pytest, and jest. Returned in order of appearance,
+/// deduplicated, capped at `MAX_ERROR_SIGNATURES`.
+pub fn extract_error_signatures(output: &str) -> Vec<ErrorSignature> {
+    use regex::Regex;
+    use std::sync::LazyLock;
+
+    // error[E0502]: ... / error: ... (excluding cargo summary lines)
+    #[allow(clippy::expect_used)]
+    static RE_RUSTC: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r"^error(\[E\d{4}\])?: (.+)$").expect("valid regex"));
+    // thread 'main' panicked at 'msg', src/x.rs:1:2  (pre-1.73)
+    #[allow(clippy::expect_used)]
+    static RE_PANIC_OLD: LazyLock<Regex> = LazyLock::new(|| {
+        Regex::new(r"^thread '[^']*' panicked at '(.+)', \S+$").expect("valid regex")
+    });
+    // thread 'main' panicked at src/x.rs:1:2:  (message on the next line)
+    #[allow(clippy::expect_used)]
+    static RE_PANIC_NEW: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r"^thread '[^']*' panicked at \S+:$").expect("valid regex"));
+    // test module::name ... FAILED
+    #[allow(clippy::expect_used)]
+    static RE_CARGO_TEST: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r"^test (\S+) \.\.\. FAILED$").expect("valid regex"));
+    // FAILED tests/test_x.py::test_y - AssertionError: ...
+    #[allow(clippy::expect_used)]
+    static RE_PYTEST: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r"^FAILED (\S+::\S+)").expect("valid regex"));
+    // ● Suite › test name
+    #[allow(clippy::expect_used)]
+    static RE_JEST: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r"^● (.+ › .+)$").expect("valid regex"));
+
+    let mut signatures: Vec<ErrorSignature> = Vec::new();
+    let mut push = |kind: ErrorSignatureKind, text: String| {
+        if !signatures.iter().any(|s| s.kind == kind && s.text == text) {
+            signatures.push(ErrorSignature { kind, text });
+        }
+    };
+
+    let lines: Vec<&str> = output.lines().map(str::trim).collect();
+    for (i, line) in lines.iter().enumerate() {
+        if let Some(caps) = RE_RUSTC.captures(line) {
+            let message = &caps[2];
+            if message.starts_with("could not compile")
+                || message.starts_with("aborting due to")
+                || message.starts_with("test failed")
+            {
+                continue;
+            }
+            let code = caps.get(1).map(|m| m.as_str()).unwrap_or("");
+            push(
+                ErrorSignatureKind::CompilerError,
+                format!("error{code}: {message}"),
+            );
+        } else if let Some(caps) = RE_PANIC_OLD.captures(line) {
+            push(ErrorSignatureKind::Panic, format!("panicked: {}", &caps[1]));
+        } else if RE_PANIC_NEW.is_match(line) {
+            if let Some(message) = lines.get(i + 1).filter(|l| !l.is_empty()) {
+                push(ErrorSignatureKind::Panic, format!("panicked: {message}"));
+            }
+        } else if let Some(caps) = RE_CARGO_TEST.captures(line) {
+            push(
+                ErrorSignatureKind::FailedTest,
+                format!("test failed: {}", &caps[1]),
+            );
+        } else if let Some(caps) = RE_PYTEST.captures(line) {
+            push(
+                ErrorSignatureKind::FailedTest,
+                format!("test failed: {}", &caps[1]),
+            );
+        } else if let Some(caps) = RE_JEST.captures(line) {
+            push(
+                ErrorSignatureKind::FailedTest,
+                format!("test failed: {}", &caps[1]),
+            );
+        }
+    }
+
+    signatures.truncate(MAX_ERROR_SIGNATURES);
+    signatures
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -244,6 +772,8 @@ mod tests {
                 tool_name TEXT NOT NULL,
                 error_fingerprint TEXT NOT NULL,
                 error_template TEXT NOT NULL,
+                error_shape TEXT,
+                fix_embedding BLOB,
                 raw_error_sample TEXT,
                 fix_description TEXT,
                 fix_session_id TEXT,
@@ -816,4 +1346,450 @@ mod tests {
             "fp2 should be resolved as most recently failing"
         );
     }
+
+    #[test]
+    fn test_count_fingerprint_failures_scoped_to_session_and_fingerprint() {
+        let conn = setup_test_db();
+        conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS session_behavior_log (
+                id INTEGER PRIMARY KEY,
+                session_id TEXT NOT NULL,
+                project_id INTEGER,
+                event_type TEXT NOT NULL,
+                event_data TEXT,
+                sequence_position INTEGER,
+                created_at TEXT DEFAULT CURRENT_TIMESTAMP
+            )",
+        )
+        .unwrap();
+
+        for (session, fp) in [("s1", "aaa"), ("s1", "aaa"), ("s1", "bbb"), ("s2", "aaa")] {
+            conn.execute(
+                "INSERT INTO session_behavior_log (session_id, project_id, event_type, event_data)
+                 VALUES (?1, 1, 'tool_failure', ?2)",
+                params![
+                    session,
+                    serde_json::json!({"tool_name": "Bash", "error_fingerprint": fp}).to_string()
+                ],
+            )
+            .unwrap();
+        }
+
+        assert_eq!(count_fingerprint_failures_sync(&conn, "s1", "aaa"), 2);
+        assert_eq!(count_fingerprint_failures_sync(&conn, "s1", "bbb"), 1);
+        assert_eq!(count_fingerprint_failures_sync(&conn, "s2", "bbb"), 0);
+
+        // Bash failed twice before its 3rd failure ("bbb"); "aaa" never failed after that
+        assert_eq!(
+            count_escalated_fingerprint_failures_sync(&conn, "s1", "aaa", 3),
+            0
+        );
+        assert_eq!(
+            count_escalated_fingerprint_failures_sync(&conn, "s1", "bbb", 3),
+            1
+        );
+    }
+
+    // ========================================================================
+    // extract_error_signatures
+    // ========================================================================
+
+    const RUSTC_OUTPUT: &str = r#"   Compiling demo v0.1.0 (/home/user/demo)
+error[E0502]: cannot borrow `v` as mutable because it is also borrowed as immutable
+ --> src/main.rs:4:5
+  |
+3 |     let first = &v[0];
+  |                  - immutable borrow occurs here
+4 |     v.push(4);
+  |     ^^^^^^^^^ mutable borrow occurs here
+
+error: aborting due to 1 previous error
+
+For more information about this error, try `rustc --explain E0502`.
+error: could not compile `demo` (bin "demo") due to 1 previous error"#;
+
+    const CARGO_TEST_OUTPUT: &str = r#"running 3 tests
+test parser::tests::parses_empty ... ok
+test parser::tests::parses_nested ... FAILED
+test lexer::tests::handles_unicode ... ok
+
+failures:
+
+---- parser::tests::parses_nested stdout ----
+
+thread 'parser::tests::parses_nested' panicked at src/parser.rs:88:9:
+assertion `left == right` failed
+  left: 2
+ right: 3
+note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace
+
+failures:
+    parser::tests::parses_nested
+
+test result: FAILED. 2 passed; 1 failed; 0 ignored; 0 measured; 0 filtered out; finished in 0.01s
+
+error: test failed, to rerun pass `--lib`"#;
+
+    const PYTEST_OUTPUT: &str = r#"============================= test session starts ==============================
+collected 4 items
+
+tests/test_api.py ..F.                                                   [100%]
+
+=================================== FAILURES ===================================
+_______________________________ test_login_fails _______________________________
+
+    def test_login_fails():
+>       assert login("bob", "wrong") is None
+E       AssertionError: assert {'user': 'bob'} is None
+
+tests/test_api.py:21: AssertionError
+=========================== short test summary info ============================
+FAILED tests/test_api.py::test_login_fails - AssertionError: assert {'user': 'bob'} is None
+========================= 1 failed, 3 passed in 0.12s =========================="#;
+
+    const JEST_OUTPUT: &str = r#" FAIL  src/cart.test.js
+  Cart
+    ✓ adds items (3 ms)
+    ✕ computes total (5 ms)
+
+  ● Cart › computes total
+
+    expect(received).toBe(expected) // Object.is equality
+
+    Expected: 30
+    Received: 25
+
+Tests:       1 failed, 1 passed, 2 total"#;
+
+    #[test]
+    fn test_extract_signatures_rustc_skips_summary_lines() {
+        let sigs = extract_error_signatures(RUSTC_OUTPUT);
+        assert_eq!(sigs.len(), 1);
+        assert_eq!(sigs[0].kind, ErrorSignatureKind::CompilerError);
+        assert_eq!(
+            sigs[0].text,
+            "error[E0502]: cannot borrow `v` as mutable because it is also borrowed as immutable"
+        );
+    }
+
+    #[test]
+    fn test_extract_signatures_cargo_test_failure_and_panic() {
+        let sigs = extract_error_signatures(CARGO_TEST_OUTPUT);
+        assert_eq!(
+            sigs,
+            vec![
+                ErrorSignature {
+                    kind: ErrorSignatureKind::FailedTest,
+                    text: "test failed: parser::tests::parses_nested".to_string(),
+                },
+                ErrorSignature {
+                    kind: ErrorSignatureKind::Panic,
+                    text: "panicked: assertion `left == right` failed".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_extract_signatures_old_style_panic() {
+        let sigs = extract_error_signatures(
+            "thread 'main' panicked at 'index out of bounds: the len is 3 but the index is 5', src/main.rs:2:5",
+        );
+        assert_eq!(sigs.len(), 1);
+        assert_eq!(sigs[0].kind, ErrorSignatureKind::Panic);
+        assert!(sigs[0].text.starts_with("panicked: index out of bounds"));
+    }
+
+    #[test]
+    fn test_extract_signatures_pytest() {
+        let sigs = extract_error_signatures(PYTEST_OUTPUT);
+        assert_eq!(sigs.len(), 1);
+        assert_eq!(sigs[0].kind, ErrorSignatureKind::FailedTest);
+        assert_eq!(
+            sigs[0].text,
+            "test failed: tests/test_api.py::test_login_fails"
+        );
+    }
+
+    #[test]
+    fn test_extract_signatures_jest() {
+        let sigs = extract_error_signatures(JEST_OUTPUT);
+        assert_eq!(sigs.len(), 1);
+        assert_eq!(sigs[0].text, "test failed: Cart › computes total");
+    }
+
+    #[test]
+    fn test_extract_signatures_none_for_plain_errors() {
+        assert!(extract_error_signatures("No such file or directory").is_empty());
+        assert!(extract_error_signatures("").is_empty());
+    }
+
+    #[test]
+    fn test_extract_signatures_dedup_and_cap() {
+        let mut output = String::new();
+        for i in 0..10 {
+            output.push_str(&format!("test t{i} ... FAILED\ntest t{i} ... FAILED\n"));
+        }
+        let sigs = extract_error_signatures(&output);
+        assert_eq!(sigs.len(), MAX_ERROR_SIGNATURES);
+        assert_eq!(sigs[0].text, "test failed: t0");
+        assert_eq!(sigs[1].text, "test failed: t1");
+    }
+
+    #[test]
+    fn test_signature_fingerprint_stable_across_noisy_runs() {
+        // Same failure, different surrounding noise and paths
+        let run_a = format!("   Compiling demo v0.1.0 (/home/a/demo)\n{RUSTC_OUTPUT}");
+        let run_b = RUSTC_OUTPUT.replace("src/main.rs:4:5", "src/main.rs:9:1");
+        let sig_a = &extract_error_signatures(&run_a)[0];
+        let sig_b = &extract_error_signatures(&run_b)[0];
+        assert_eq!(
+            error_fingerprint("Bash", &sig_a.text).0,
+            error_fingerprint("Bash", &sig_b.text).0
+        );
+    }
+
+    // ═══════════════════════════════════════
+    // Error shapes and similar-fix lookup
+    // ═══════════════════════════════════════
+
+    fn shape_of(raw: &str) -> String {
+        error_shape(&error_fingerprint("Bash", raw).1)
+    }
+
+    #[test]
+    fn test_error_shape_clusters_variants() {
+        let clusters: &[&[&str]] = &[
+            &[
+                "error[E0596]: cannot borrow `x` as mutable, as it is not declared as mutable",
+                "error[E0596]: cannot borrow `self.items` as mutable, as it is not declared as mutable",
+            ],
+            &[
+                "error[E0425]: cannot find value `foo` in this scope",
+                "error[E0425]: cannot find value `bar_baz` in this scope",
+            ],
+            &[
+                "error[E0308]: mismatched types: expected `u32`, found `String`",
+                "error[E0308]: mismatched types: expected `i64`, found `&str`",
+            ],
+            &[
+                "NameError: name 'foo' is not defined",
+                "NameError: name 'request_handler' is not defined",
+            ],
+            &[
+                "ModuleNotFoundError: No module named 'requests'",
+                "ModuleNotFoundError: No module named 'numpy.linalg'",
+            ],
+            &[
+                "AttributeError: 'NoneType' object has no attribute 'split'",
+                "AttributeError: 'dict' object has no attribute 'items'",
+            ],
+        ];
+        for cluster in clusters {
+            let first = shape_of(cluster[0]);
+            for variant in &cluster[1..] {
+                assert_eq!(shape_of(variant), first, "{} vs {}", cluster[0], variant);
+            }
+        }
+        // Representatives of different clusters stay apart
+        for (i, a) in clusters.iter().enumerate() {
+            for b in &clusters[i + 1..] {
+                let (sa, sb) = (shape_of(a[0]), shape_of(b[0]));
+                assert_ne!(sa, sb);
+                assert!(
+                    shape_similarity(&sa, &sb) < SIMILAR_SHAPE_THRESHOLD,
+                    "{sa} ~ {sb}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_shape_similarity_placeholder_matches_bare_word() {
+        let a = shape_of("cannot borrow `x` as mutable");
+        let b = shape_of("cannot borrow data as mutable");
+        assert_eq!(a, "cannot borrow <_> as mutable");
+        assert_eq!(shape_similarity(&a, &b), 1.0);
+        assert!(shape_similarity(&a, &shape_of("cannot find value `x` in this scope")) < 0.5);
+        // Too generic to match loosely
+        assert!(shape_literals(&shape_of("KeyError: 'user_id'")) < MIN_SHAPE_LITERALS);
+    }
+
+    #[test]
+    fn test_find_similar_fix_strategies() {
+        let conn = setup_test_db();
+        let store = |tool: &str, raw: &str| -> String {
+            let (fp, tmpl) = error_fingerprint(tool, raw);
+            store_error_pattern_sync(
+                &conn,
+                StoreErrorPatternParams {
+                    project_id: 1,
+                    tool_name: tool,
+                    error_fingerprint: &fp,
+                    error_template: &tmpl,
+                    raw_error_sample: raw,
+                    session_id: "s1",
+                },
+            )
+            .unwrap();
+            fp
+        };
+
+        let resolved = store(
+            "Bash",
+            "error[E0596]: cannot borrow `x` as mutable, as it is not declared as mutable",
+        );
+        resolve_error_pattern_sync(&conn, 1, "Bash", &resolved, "s1", "Declare it with let mut")
+            .unwrap();
+        let short = store("Bash", "cannot borrow `x` as mutable");
+        resolve_error_pattern_sync(&conn, 1, "Bash", &short, "s1", "Take &mut").unwrap();
+
+        let exact = find_similar_fix_sync(&conn, 1, "Bash", &resolved).unwrap();
+        assert_eq!(exact.strategy, FixMatch::Exact);
+
+        let renamed = store(
+            "Bash",
+            "error[E0596]: cannot borrow `self.items` as mutable, as it is not declared as mutable",
+        );
+        let normalized = find_similar_fix_sync(&conn, 1, "Bash", &renamed).unwrap();
+        assert_eq!(normalized.strategy, FixMatch::Normalized);
+        assert_eq!(
+            normalized.pattern.fix_description,
+            "Declare it with let mut"
+        );
+
+        let reworded = store("Bash", "cannot borrow data as mutable");
+        let similar = find_similar_fix_sync(&conn, 1, "Bash", &reworded).unwrap();
+        assert_eq!(similar.strategy, FixMatch::Similar);
+        assert_eq!(similar.pattern.fix_description, "Take &mut");
+        assert!(similar.score >= SIMILAR_SHAPE_THRESHOLD);
+
+        let unrelated = store("Bash", "permission denied (os error 13)");
+        assert!(find_similar_fix_sync(&conn, 1, "Bash", &unrelated).is_none());
+
+        // Fixes are scoped to the tool
+        let other_tool = store("Edit", "cannot borrow `y` as mutable");
+        assert!(find_similar_fix_sync(&conn, 1, "Edit", &other_tool).is_none());
+
+        // Loose matches don't mark the new error resolved
+        assert!(lookup_resolved_pattern_sync(&conn, 1, "Bash", &renamed).is_none());
+    }
+
+    #[test]
+    fn test_find_similar_fix_same_shape_beyond_candidate_cap() {
+        let conn = setup_test_db();
+        // More frequent resolved patterns than the similarity scan examines
+        for i in 0..=MAX_SHAPE_CANDIDATES {
+            conn.execute(
+                "INSERT INTO error_patterns (project_id, tool_name, error_fingerprint,
+                     error_template, error_shape, fix_description, resolved_at, occurrence_count)
+                 VALUES (1, 'Bash', ?1, 'noise', 'unrelated noise error shape', 'n/a',
+                         CURRENT_TIMESTAMP, 100)",
+                [format!("noise-{i}")],
+            )
+            .unwrap();
+        }
+
+        let store = |raw: &str| -> String {
+            let (fp, tmpl) = error_fingerprint("Bash", raw);
+            store_error_pattern_sync(
+                &conn,
+                StoreErrorPatternParams {
+                    project_id: 1,
+                    tool_name: "Bash",
+                    error_fingerprint: &fp,
+                    error_template: &tmpl,
+                    raw_error_sample: raw,
+                    session_id: "s1",
+                },
+            )
+            .unwrap();
+            fp
+        };
+        let resolved =
+            store("error[E0596]: cannot borrow `x` as mutable, as it is not declared as mutable");
+        resolve_error_pattern_sync(&conn, 1, "Bash", &resolved, "s1", "Declare it with let mut")
+            .unwrap();
+
+        let renamed = store(
+            "error[E0596]: cannot borrow `self.items` as mutable, as it is not declared as mutable",
+        );
+        let fix = find_similar_fix_sync(&conn, 1, "Bash", &renamed).unwrap();
+        assert_eq!(fix.strategy, FixMatch::Normalized);
+        assert_eq!(fix.pattern.fix_description, "Declare it with let mut");
+    }
+
+    #[test]
+    fn test_semantic_fix_candidates_cache_embeddings() {
+        let conn = setup_test_db();
+        let store = |raw: &str| -> String {
+            let (fp, tmpl) = error_fingerprint("Bash", raw);
+            store_error_pattern_sync(
+                &conn,
+                StoreErrorPatternParams {
+                    project_id: 1,
+                    tool_name: "Bash",
+                    error_fingerprint: &fp,
+                    error_template: &tmpl,
+                    raw_error_sample: raw,
+                    session_id: "s1",
+                },
+            )
+            .unwrap();
+            fp
+        };
+        let resolved = store("ModuleNotFoundError: No module named 'requests'");
+        resolve_error_pattern_sync(&conn, 1, "Bash", &resolved, "s1", "pip install it").unwrap();
+        let _unresolved = store("Connection refused");
+        let current = store("ImportError: cannot import name 'Session'");
+
+        assert!(error_template_sync(&conn, 1, "Bash", &current).is_some());
+        let candidates = semantic_fix_candidates_sync(&conn, 1, "Bash", &current).unwrap();
+        assert_eq!(candidates.len(), 1);
+        assert!(candidates[0].embedding.is_none());
+        assert!(fix_embedding_text(&candidates[0].pattern).ends_with("\nFix: pip install it"));
+
+        store_fix_embedding_sync(&conn, candidates[0].pattern.id, &[0, 0, 128, 63]).unwrap();
+        let candidates = semantic_fix_candidates_sync(&conn, 1, "Bash", &current).unwrap();
+        assert_eq!(
+            candidates[0].embedding.as_deref(),
+            Some(&[0u8, 0, 128, 63][..])
+        );
+
+        // The error itself is never its own candidate
+        assert!(
+            semantic_fix_candidates_sync(&conn, 1, "Bash", &resolved)
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_best_semantic_fix_picks_closest_above_threshold() {
+        let pattern = |id: i64| ResolvedErrorPattern {
+            id,
+            tool_name: "Bash".into(),
+            error_template: format!("error {id}"),
+            fix_description: format!("fix {id}"),
+            occurrence_count: 1,
+        };
+        let query = [1.0f32, 0.0, 0.0];
+        let fix = best_semantic_fix(
+            &query,
+            vec![
+                (pattern(1), vec![0.0, 1.0, 0.0]),
+                (pattern(2), vec![0.9, 0.3, 0.0]),
+                (pattern(3), vec![1.0, 0.1, 0.0]),
+                // Embedded with another model
+                (pattern(4), vec![1.0, 0.0]),
+            ],
+        )
+        .unwrap();
+        assert_eq!(fix.strategy, FixMatch::Semantic);
+        assert_eq!(fix.pattern.id, 3);
+        assert!(fix.score > 0.99);
+
+        assert!(best_semantic_fix(&query, vec![(pattern(1), vec![0.0, 1.0, 0.0])]).is_none());
+        assert!(best_semantic_fix(&query, vec![(pattern(1), vec![0.0, 0.0, 0.0])]).is_none());
+    }
 }
@@ -0,0 +1,955 @@
+// crates/mira-server/src/db/export.rs
+// Portable per-project export/import of goals, milestones, tasks, templates,
+// attached roots, checklist links, observations and learned fixes
+
+use anyhow::{Result, bail};
+use rusqlite::{Connection, OptionalExtension, params};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::utils::normalize_project_path;
+
+/// Bundle format version, bumped on incompatible changes.
+///
+/// Version 2 added dependencies, templates, roots and checklist links; a
+/// version 1 bundle still imports, with those left empty.
+pub const BUNDLE_VERSION: u32 = 2;
+
+/// Everything Mira knows about one project, in a database-independent form.
+///
+/// Sessions, tool history and the code index are machine-local and not
+/// included. Row IDs in the bundle are source-database IDs, used only to
+/// link rows to each other; import always assigns fresh IDs.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProjectBundle {
+    pub version: u32,
+    pub exported_at: String,
+    pub project: BundleProject,
+    pub goals: Vec<BundleGoal>,
+    pub tasks: Vec<BundleTask>,
+    pub error_patterns: Vec<BundleErrorPattern>,
+    #[serde(default)]
+    pub task_templates: Vec<BundleTaskTemplate>,
+    #[serde(default)]
+    pub todo_sync_items: Vec<BundleTodoSyncItem>,
+    #[serde(default)]
+    pub observations: Vec<BundleObservation>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BundleProject {
+    pub path: String,
+    pub name: Option<String>,
+    /// Attached roots (multi-directory projects)
+    #[serde(default)]
+    pub roots: Vec<BundleRoot>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BundleRoot {
+    pub name: String,
+    pub path: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BundleGoal {
+    pub id: i64,
+    pub title: String,
+    pub description: Option<String>,
+    pub status: Option<String>,
+    pub priority: Option<String>,
+    pub progress_percent: Option<i64>,
+    /// progress_percent was set by hand rather than rolled up
+    #[serde(default)]
+    pub progress_manual: bool,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+    pub milestones: Vec<BundleMilestone>,
+    /// Goal IDs this goal waits on
+    #[serde(default)]
+    pub depends_on: Vec<i64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BundleMilestone {
+    pub title: String,
+    pub completed: bool,
+    pub weight: Option<i64>,
+    pub created_at: Option<String>,
+    pub completed_at: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BundleTask {
+    #[serde(default)]
+    pub id: i64,
+    pub goal_id: Option<i64>,
+    pub title: String,
+    pub description: Option<String>,
+    pub status: Option<String>,
+    pub priority: Option<String>,
+    pub created_at: Option<String>,
+    #[serde(default)]
+    pub completed_at: Option<String>,
+    /// Task IDs this task waits on
+    #[serde(default)]
+    pub depends_on: Vec<i64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BundleTaskTemplate {
+    pub name: String,
+    pub items_json: String,
+    pub created_at: Option<String>,
+}
+
+/// Link between a markdown checklist item and a task
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BundleTodoSyncItem {
+    pub task_id: i64,
+    pub file_path: String,
+    pub item_text: String,
+    pub ordinal: i64,
+    pub checked: bool,
+    pub task_done: bool,
+    pub synced_at: Option<String>,
+}
+
+/// A project observation that hasn't expired yet
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BundleObservation {
+    pub key: Option<String>,
+    pub content: String,
+    pub observation_type: String,
+    pub category: Option<String>,
+    pub confidence: Option<f64>,
+    pub source: String,
+    pub scope: Option<String>,
+    pub expires_at: Option<String>,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BundleErrorPattern {
+    pub tool_name: String,
+    pub error_fingerprint: String,
+    pub error_template: String,
+    pub raw_error_sample: Option<String>,
+    pub fix_description: Option<String>,
+    pub occurrence_count: i64,
+    pub resolved_at: Option<String>,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+/// Counts of rows written by `import_project_sync`.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ImportReport {
+    pub project_id: i64,
+    pub goals: usize,
+    pub milestones: usize,
+    pub tasks: usize,
+    pub dependencies: usize,
+    pub task_templates: usize,
+    pub roots: usize,
+    /// Attached roots skipped because the path or name is already taken
+    pub roots_skipped: usize,
+    pub todo_sync_items: usize,
+    pub observations: usize,
+    /// Goals, tasks, templates, checklist links and observations already in
+    /// the target, typically from importing the same bundle before
+    pub duplicates_skipped: usize,
+    pub error_patterns: usize,
+    /// Error patterns already present in the target (same tool + fingerprint)
+    pub error_patterns_skipped: usize,
+}
+
+/// Serialize all project-scoped rows for the project at `project_path`.
+pub fn export_project_sync(conn: &Connection, project_path: &str) -> Result<ProjectBundle> {
+    let path = normalize_project_path(project_path);
+    let Some((project_id, name)) = conn
+        .query_row(
+            "SELECT id, name FROM projects WHERE path = ?1",
+            [&path],
+            |row| Ok((row.get::<_, i64>(0)?, row.get::<_, Option<String>>(1)?)),
+        )
+        .optional()?
+    else {
+        bail!("No Mira project found for {}", path);
+    };
+
+    Ok(ProjectBundle {
+        version: BUNDLE_VERSION,
+        exported_at: chrono::Utc::now().to_rfc3339(),
+        project: BundleProject {
+            path,
+            name,
+            roots: export_roots(conn, project_id)?,
+        },
+        goals: export_goals(conn, project_id)?,
+        tasks: export_tasks(conn, project_id)?,
+        error_patterns: export_error_patterns(conn, project_id)?,
+        task_templates: export_task_templates(conn, project_id)?,
+        todo_sync_items: export_todo_sync_items(conn, project_id)?,
+        observations: export_observations(conn, project_id)?,
+    })
+}
+
+/// IDs `id` depends on, from a `(<id_col>, depends_on)` edge table
+fn dependencies_of(conn: &Connection, table_sql: &str, id: i64) -> Result<Vec<i64>> {
+    let mut stmt = conn.prepare_cached(table_sql)?;
+    let ids = stmt
+        .query_map([id], |row| row.get(0))?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    Ok(ids)
+}
+
+fn export_roots(conn: &Connection, project_id: i64) -> Result<Vec<BundleRoot>> {
+    let mut stmt =
+        conn.prepare("SELECT name, path FROM project_roots WHERE project_id = ?1 ORDER BY name")?;
+    let roots = stmt
+        .query_map([project_id], |row| {
+            Ok(BundleRoot {
+                name: row.get(0)?,
+                path: row.get(1)?,
+            })
+        })?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    Ok(roots)
+}
+
+fn export_task_templates(conn: &Connection, project_id: i64) -> Result<Vec<BundleTaskTemplate>> {
+    let mut stmt = conn.prepare(
+        "SELECT name, items_json, created_at FROM task_templates
+         WHERE project_id = ?1 ORDER BY id",
+    )?;
+    let templates = stmt
+        .query_map([project_id], |row| {
+            Ok(BundleTaskTemplate {
+                name: row.get(0)?,
+                items_json: row.get(1)?,
+                created_at: row.get(2)?,
+            })
+        })?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    Ok(templates)
+}
+
+fn export_todo_sync_items(conn: &Connection, project_id: i64) -> Result<Vec<BundleTodoSyncItem>> {
+    let mut stmt = conn.prepare(
+        "SELECT task_id, file_path, item_text, ordinal, checked, task_done, synced_at
+         FROM todo_sync_items WHERE project_id = ?1 ORDER BY file_path, ordinal",
+    )?;
+    let items = stmt
+        .query_map([project_id], |row| {
+            Ok(BundleTodoSyncItem {
+                task_id: row.get(0)?,
+                file_path: row.get(1)?,
+                item_text: row.get(2)?,
+                ordinal: row.get(3)?,
+                checked: row.get::<_, i64>(4)? != 0,
+                task_done: row.get::<_, i64>(5)? != 0,
+                synced_at: row.get(6)?,
+            })
+        })?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    Ok(items)
+}
+
+/// Unexpired, non-team observations. Session IDs are machine-local and dropped.
+fn export_observations(conn: &Connection, project_id: i64) -> Result<Vec<BundleObservation>> {
+    let mut stmt = conn.prepare(
+        "SELECT key, content, observation_type, category, confidence, source, scope,
+                expires_at, created_at, updated_at
+         FROM system_observations
+         WHERE project_id = ?1 AND team_id IS NULL
+           AND (expires_at IS NULL OR expires_at > datetime('now'))
+         ORDER BY id",
+    )?;
+    let observations = stmt
+        .query_map([project_id], |row| {
+            Ok(BundleObservation {
+                key: row.get(0)?,
+                content: row.get(1)?,
+                observation_type: row.get(2)?,
+                category: row.get(3)?,
+                confidence: row.get(4)?,
+                source: row.get(5)?,
+                scope: row.get(6)?,
+                expires_at: row.get(7)?,
+                created_at: row.get(8)?,
+                updated_at: row.get(9)?,
+            })
+        })?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    Ok(observations)
+}
+
+fn export_goals(conn: &Connection, project_id: i64) -> Result<Vec<BundleGoal>> {
+    let mut stmt = conn.prepare(
+        "SELECT id, title, description, status, priority, progress_percent, progress_manual,
+                created_at, updated_at
+         FROM goals WHERE project_id = ?1 ORDER BY id",
+    )?;
+    let mut goals = stmt
+        .query_map([project_id], |row| {
+            Ok(BundleGoal {
+                id: row.get(0)?,
+                title: row.get(1)?,
+                description: row.get(2)?,
+                status: row.get(3)?,
+                priority: row.get(4)?,
+                progress_percent: row.get(5)?,
+                progress_manual: row.get::<_, Option<i64>>(6)?.unwrap_or(0) != 0,
+                created_at: row.get(7)?,
+                updated_at: row.get(8)?,
+                milestones: Vec::new(),
+                depends_on: Vec::new(),
+            })
+        })?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+
+    let mut stmt = conn.prepare(
+        "SELECT title, completed, weight, created_at, completed_at
+         FROM milestones WHERE goal_id = ?1 ORDER BY id",
+    )?;
+    for goal in &mut goals {
+        goal.milestones = stmt
+            .query_map([goal.id], |row| {
+                Ok(BundleMilestone {
+                    title: row.get(0)?,
+                    completed: row.get::<_, Option<i64>>(1)?.unwrap_or(0) != 0,
+                    weight: row.get(2)?,
+                    created_at: row.get(3)?,
+                    completed_at: row.get(4)?,
+                })
+            })?
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+        goal.depends_on = dependencies_of(
+            conn,
+            "SELECT depends_on FROM goal_dependencies WHERE goal_id = ?1 ORDER BY depends_on",
+            goal.id,
+        )?;
+    }
+    Ok(goals)
+}
+
+fn export_tasks(conn: &Connection, project_id: i64) -> Result<Vec<BundleTask>> {
+    let mut stmt = conn.prepare(
+        "SELECT id, goal_id, title, description, status, priority, created_at, completed_at
+         FROM tasks WHERE project_id = ?1 ORDER BY id",
+    )?;
+    let mut tasks = stmt
+        .query_map([project_id], |row| {
+            Ok(BundleTask {
+                id: row.get(0)?,
+                goal_id: row.get(1)?,
+                title: row.get(2)?,
+                description: row.get(3)?,
+                status: row.get(4)?,
+                priority: row.get(5)?,
+                created_at: row.get(6)?,
+                completed_at: row.get(7)?,
+                depends_on: Vec::new(),
+            })
+        })?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    for task in &mut tasks {
+        task.depends_on = dependencies_of(
+            conn,
+            "SELECT depends_on FROM task_dependencies WHERE task_id = ?1 ORDER BY depends_on",
+            task.id,
+        )?;
+    }
+    Ok(tasks)
+}
+
+fn export_error_patterns(conn: &Connection, project_id: i64) -> Result<Vec<BundleErrorPattern>> {
+    let mut stmt = conn.prepare(
+        "SELECT tool_name, error_fingerprint, error_template, raw_error_sample, fix_description,
+                occurrence_count, resolved_at, created_at, updated_at
+         FROM error_patterns WHERE project_id = ?1 ORDER BY id",
+    )?;
+    let patterns = stmt
+        .query_map([project_id], |row| {
+            Ok(BundleErrorPattern {
+                tool_name: row.get(0)?,
+                error_fingerprint: row.get(1)?,
+                error_template: row.get(2)?,
+                raw_error_sample: row.get(3)?,
+                fix_description: row.get(4)?,
+                occurrence_count: row.get::<_, Option<i64>>(5)?.unwrap_or(1),
+                resolved_at: row.get(6)?,
+                created_at: row.get(7)?,
+                updated_at: row.get(8)?,
+            })
+        })?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    Ok(patterns)
+}
+
+/// Restore a bundle into this database under `target_path` (default: the bundle's path).
+///
+/// Every row gets a fresh ID; links between rows (task → goal, dependencies,
+/// checklist items) are remapped to the new IDs. Nothing existing is
+/// overwritten, and importing the same bundle again adds nothing: goals and
+/// tasks already present with the same title and creation time are reused,
+/// and templates (by name), checklist links, observations (by key, or type,
+/// content and creation time), error patterns and roots already known for
+/// the target project are skipped. Runs in a single transaction.
+pub fn import_project_sync(
+    conn: &Connection,
+    bundle: &ProjectBundle,
+    target_path: Option<&str>,
+) -> Result<ImportReport> {
+    if bundle.version > BUNDLE_VERSION {
+        bail!(
+            "Bundle version {} is newer than supported version {}",
+            bundle.version,
+            BUNDLE_VERSION
+        );
+    }
+
+    let tx = conn.unchecked_transaction()?;
+    let path = target_path.unwrap_or(&bundle.project.path);
+    let (project_id, _) =
+        super::get_or_create_project_sync(&tx, path, bundle.project.name.as_deref())?;
+    let mut report = ImportReport {
+        project_id,
+        ..Default::default()
+    };
+
+    let mut goal_ids: HashMap<i64, i64> = HashMap::new();
+    for goal in &bundle.goals {
+        if let Some(existing) = find_existing(
+            &tx,
+            "goals",
+            project_id,
+            &goal.title,
+            &goal.created_at,
+            &goal_ids,
+        )? {
+            goal_ids.insert(goal.id, existing);
+            report.duplicates_skipped += 1;
+            continue;
+        }
+        tx.execute(
+            "INSERT INTO goals (project_id, title, description, status, priority, progress_percent,
+                                progress_manual, created_at, updated_at)
+             VALUES (?1, ?2, ?3, COALESCE(?4, 'planning'), COALESCE(?5, 'medium'), COALESCE(?6, 0),
+                     ?7, COALESCE(?8, datetime('now')), COALESCE(?9, datetime('now')))",
+            params![
+                project_id,
+                goal.title,
+                goal.description,
+                goal.status,
+                goal.priority,
+                goal.progress_percent,
+                goal.progress_manual as i32,
+                goal.created_at,
+                goal.updated_at
+            ],
+        )?;
+        let new_id = tx.last_insert_rowid();
+        goal_ids.insert(goal.id, new_id);
+        report.goals += 1;
+
+        for milestone in &goal.milestones {
+            tx.execute(
+                "INSERT INTO milestones (goal_id, title, completed, weight, created_at, completed_at)
+                 VALUES (?1, ?2, ?3, COALESCE(?4, 1), ?5, ?6)",
+                params![
+                    new_id,
+                    milestone.title,
+                    milestone.completed as i32,
+                    milestone.weight,
+                    milestone.created_at,
+                    milestone.completed_at
+                ],
+            )?;
+            report.milestones += 1;
+        }
+    }
+
+    for goal in &bundle.goals {
+        for dep in &goal.depends_on {
+            // Links to goals outside the bundle can't be remapped, so they are dropped
+            if let (Some(from), Some(to)) = (goal_ids.get(&goal.id), goal_ids.get(dep)) {
+                report.dependencies += tx.execute(
+                    "INSERT OR IGNORE INTO goal_dependencies (goal_id, depends_on) VALUES (?1, ?2)",
+                    params![from, to],
+                )?;
+            }
+        }
+    }
+
+    let mut task_ids: HashMap<i64, i64> = HashMap::new();
+    for task in &bundle.tasks {
+        // A link to a goal outside the bundle can't be remapped, so it is dropped
+        let goal_id = task.goal_id.and_then(|old| goal_ids.get(&old).copied());
+        if let Some(existing) = find_existing(
+            &tx,
+            "tasks",
+            project_id,
+            &task.title,
+            &task.created_at,
+            &task_ids,
+        )? {
+            task_ids.insert(task.id, existing);
+            report.duplicates_skipped += 1;
+            continue;
+        }
+        tx.execute(
+            "INSERT INTO tasks (project_id, goal_id, title, description, status, priority,
+                                created_at, completed_at)
+             VALUES (?1, ?2, ?3, ?4, COALESCE(?5, 'pending'), COALESCE(?6, 'medium'),
+                     COALESCE(?7, datetime('now')), ?8)",
+            params![
+                project_id,
+                goal_id,
+                task.title,
+                task.description,
+                task.status,
+                task.priority,
+                task.created_at,
+                task.completed_at
+            ],
+        )?;
+        task_ids.insert(task.id, tx.last_insert_rowid());
+        report.tasks += 1;
+    }
+    for task in &bundle.tasks {
+        for dep in &task.depends_on {
+            if let (Some(from), Some(to)) = (task_ids.get(&task.id), task_ids.get(dep)) {
+                report.dependencies += tx.execute(
+                    "INSERT OR IGNORE INTO task_dependencies (task_id, depends_on) VALUES (?1, ?2)",
+                    params![from, to],
+                )?;
+            }
+        }
+    }
+
+    for template in &bundle.task_templates {
+        // Names are unique per project; a local template of the same name wins
+        let inserted = tx.execute(
+            "INSERT INTO task_templates (project_id, name, items_json, created_at)
+             SELECT ?1, ?2, ?3, COALESCE(?4, datetime('now'))
+             WHERE NOT EXISTS (SELECT 1 FROM task_templates WHERE project_id = ?1 AND name = ?2)",
+            params![
+                project_id,
+                template.name,
+                template.items_json,
+                template.created_at
+            ],
+        )?;
+        if inserted > 0 {
+            report.task_templates += 1;
+        } else {
+            report.duplicates_skipped += 1;
+        }
+    }
+
+    for root in &bundle.project.roots {
+        let inserted = tx.execute(
+            "INSERT OR IGNORE INTO project_roots (project_id, path, name) VALUES (?1, ?2, ?3)",
+            params![project_id, root.path, root.name],
+        )?;
+        if inserted > 0 {
+            report.roots += 1;
+        } else {
+            report.roots_skipped += 1;
+        }
+    }
+
+    for item in &bundle.todo_sync_items {
+        let Some(task_id) = task_ids.get(&item.task_id) else {
+            continue;
+        };
+        // task_id is unique, so a task reused from an earlier import keeps its link
+        let inserted = tx.execute(
+            "INSERT OR IGNORE INTO todo_sync_items (project_id, file_path, task_id, item_text,
+                                                    ordinal, checked, task_done, synced_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, COALESCE(?8, datetime('now')))",
+            params![
+                project_id,
+                item.file_path,
+                task_id,
+                item.item_text,
+                item.ordinal,
+                item.checked as i32,
+                item.task_done as i32,
+                item.synced_at
+            ],
+        )?;
+        if inserted > 0 {
+            report.todo_sync_items += 1;
+        } else {
+            report.duplicates_skipped += 1;
+        }
+    }
+
+    for obs in &bundle.observations {
+        // Keyed rows are deduplicated by the idx_obs_upsert unique index
+        let inserted = tx.execute(
+            "INSERT OR IGNORE INTO system_observations (project_id, key, content, observation_type,
+                    category, confidence, source, scope, expires_at, created_at, updated_at)
+             SELECT ?1, ?2, ?3, ?4, ?5, COALESCE(?6, 0.5), ?7, COALESCE(?8, 'project'), ?9,
+                    COALESCE(?10, datetime('now')), COALESCE(?11, datetime('now'))
+             WHERE ?2 IS NOT NULL OR NOT EXISTS (
+                 SELECT 1 FROM system_observations
+                 WHERE project_id = ?1 AND key IS NULL AND observation_type = ?4
+                   AND content = ?3 AND (?10 IS NULL OR created_at = ?10))",
+            params![
+                project_id,
+                obs.key,
+                obs.content,
+                obs.observation_type,
+                obs.category,
+                obs.confidence,
+                obs.source,
+                obs.scope,
+                obs.expires_at,
+                obs.created_at,
+                obs.updated_at
+            ],
+        )?;
+        if inserted > 0 {
+            report.observations += 1;
+        } else {
+            report.duplicates_skipped += 1;
+        }
+    }
+
+    for pattern in &bundle.error_patterns {
+        let inserted = tx.execute(
+            "INSERT OR IGNORE INTO error_patterns (project_id, tool_name, error_fingerprint,
+                    error_template, raw_error_sample, fix_description, occurrence_count,
+                    resolved_at, created_at, updated_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8,
+                     COALESCE(?9, datetime('now')), COALESCE(?10, datetime('now')))",
+            params![
+                project_id,
+                pattern.tool_name,
+                pattern.error_fingerprint,
+                pattern.error_template,
+                pattern.raw_error_sample,
+                pattern.fix_description,
+                pattern.occurrence_count,
+                pattern.resolved_at,
+                pattern.created_at,
+                pattern.updated_at
+            ],
+        )?;
+        if inserted > 0 {
+            report.error_patterns += 1;
+        } else {
+            report.error_patterns_skipped += 1;
+        }
+    }
+
+    tx.commit()?;
+    Ok(report)
+}
+
+/// ID of a goal or task already in the project with this title and creation
+/// time, skipping rows another bundle entry has claimed. A bundle row without
+/// `created_at` matches on title alone.
+fn find_existing(
+    conn: &Connection,
+    table: &str,
+    project_id: i64,
+    title: &str,
+    created_at: &Option<String>,
+    claimed: &HashMap<i64, i64>,
+) -> Result<Option<i64>> {
+    let mut stmt = conn.prepare_cached(&format!(
+        "SELECT id FROM {} WHERE project_id = ?1 AND title = ?2
+           AND (?3 IS NULL OR created_at = ?3)
+         ORDER BY id",
+        table
+    ))?;
+    let ids = stmt
+        .query_map(params![project_id, title, created_at], |row| row.get(0))?
+        .collect::<rusqlite::Result<Vec<i64>>>()?;
+    Ok(ids
+        .into_iter()
+        .find(|id| !claimed.values().any(|taken| taken == id)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::test_support::setup_test_connection;
+    use crate::db::{create_goal_sync, create_task_sync, get_or_create_project_sync};
+
+    fn seed_project(conn: &Connection, path: &str) -> i64 {
+        let (pid, _) = get_or_create_project_sync(conn, path, Some("demo")).unwrap();
+        let goal = create_goal_sync(
+            conn,
+            Some(pid),
+            "Ship v2",
+            Some("big"),
+            Some("in_progress"),
+            Some("high"),
+            Some(40),
+        )
+        .unwrap();
+        conn.execute(
+            "INSERT INTO milestones (goal_id, title, completed, weight) VALUES (?1, 'design', 1, 2)",
+            [goal],
+        )
+        .unwrap();
+        conn.execute(
+            "INSERT INTO milestones (goal_id, title, completed, weight) VALUES (?1, 'build', 0, 3)",
+            [goal],
+        )
+        .unwrap();
+        conn.execute("UPDATE goals SET progress_manual = 1 WHERE id = ?1", [goal])
+            .unwrap();
+        let docs = create_goal_sync(conn, Some(pid), "Docs", None, None, None, None).unwrap();
+        conn.execute(
+            "INSERT INTO goal_dependencies (goal_id, depends_on) VALUES (?1, ?2)",
+            [docs, goal],
+        )
+        .unwrap();
+        let parser = create_task_sync(
+            conn,
+            Some(pid),
+            Some(goal),
+            "write parser",
+            None,
+            Some("completed"),
+            None,
+        )
+        .unwrap();
+        let loose =
+            create_task_sync(conn, Some(pid), None, "loose task", None, None, None).unwrap();
+        conn.execute(
+            "INSERT INTO task_dependencies (task_id, depends_on) VALUES (?1, ?2)",
+            [loose, parser],
+        )
+        .unwrap();
+        conn.execute(
+            "INSERT INTO task_templates (project_id, name, items_json)
+             VALUES (?1, 'release', '[\"bump {version}\", \"tag\"]')",
+            [pid],
+        )
+        .unwrap();
+        conn.execute(
+            "INSERT INTO project_roots (project_id, path, name) VALUES (?1, '/proj/web', 'web')",
+            [pid],
+        )
+        .unwrap();
+        conn.execute(
+            "INSERT INTO todo_sync_items (project_id, file_path, task_id, item_text, ordinal,
+                                          checked, task_done)
+             VALUES (?1, 'TODO.md', ?2, 'loose task', 0, 0, 0)",
+            rusqlite::params![pid, loose],
+        )
+        .unwrap();
+        conn.execute(
+            "INSERT INTO error_patterns (project_id, tool_name, error_fingerprint, error_template,
+                                         fix_description, occurrence_count)
+             VALUES (?1, 'Bash', 'fp1', 'error[E0308]', 'add a cast', 3)",
+            [pid],
+        )
+        .unwrap();
+        conn.execute(
+            "INSERT INTO system_observations (project_id, key, content, observation_type, source)
+             VALUES (?1, 'health:cargo', 'clippy clean', 'health', 'code_health'),
+                    (?1, NULL, 'tests flaky on CI', 'system', 'agent')",
+            [pid],
+        )
+        .unwrap();
+        conn.execute(
+            "INSERT INTO system_observations (project_id, content, observation_type, source,
+                                              expires_at)
+             VALUES (?1, 'stale', 'system', 'agent', datetime('now', '-1 day'))",
+            [pid],
+        )
+        .unwrap();
+        pid
+    }
+
+    fn count_rows(conn: &Connection, table: &str) -> i64 {
+        conn.query_row(&format!("SELECT COUNT(*) FROM {}", table), [], |r| r.get(0))
+            .unwrap()
+    }
+
+    /// Strip fields that legitimately differ between databases.
+    fn comparable(mut bundle: ProjectBundle) -> ProjectBundle {
+        let mut goal_ids: Vec<i64> = bundle.goals.iter().map(|g| g.id).collect();
+        let mut task_ids: Vec<i64> = bundle.tasks.iter().map(|t| t.id).collect();
+        goal_ids.sort();
+        task_ids.sort();
+        let goal = |id: &i64| goal_ids.binary_search(id).unwrap() as i64;
+        let task = |id: &i64| task_ids.binary_search(id).unwrap() as i64;
+        bundle.exported_at.clear();
+        for g in &mut bundle.goals {
+            g.id = goal(&g.id);
+            g.depends_on = g.depends_on.iter().map(goal).collect();
+        }
+        for t in &mut bundle.tasks {
+            t.id = task(&t.id);
+            t.goal_id = t.goal_id.as_ref().map(goal);
+            t.depends_on = t.depends_on.iter().map(task).collect();
+        }
+        for item in &mut bundle.todo_sync_items {
+            item.task_id = task(&item.task_id);
+            item.synced_at = None;
+        }
+        for template in &mut bundle.task_templates {
+            template.created_at = None;
+        }
+        bundle
+    }
+
+    #[test]
+    fn test_round_trip_into_fresh_db() {
+        let src = setup_test_connection();
+        seed_project(&src, "/proj/a");
+        let bundle = export_project_sync(&src, "/proj/a").unwrap();
+        assert_eq!(bundle.goals.len(), 2);
+        assert_eq!(bundle.goals[0].milestones.len(), 2);
+        assert_eq!(bundle.tasks.len(), 2);
+        assert_eq!(bundle.error_patterns.len(), 1);
+        assert!(bundle.goals[0].progress_manual);
+        assert_eq!(bundle.goals[1].depends_on, vec![bundle.goals[0].id]);
+        assert!(bundle.tasks[0].completed_at.is_some());
+        assert_eq!(bundle.tasks[1].depends_on, vec![bundle.tasks[0].id]);
+        assert_eq!(bundle.task_templates.len(), 1);
+        assert_eq!(bundle.project.roots.len(), 1);
+        assert_eq!(bundle.todo_sync_items.len(), 1);
+        assert_eq!(
+            bundle.observations.len(),
+            2,
+            "expired observations are left out"
+        );
+
+        // Go through JSON like the CLI does
+        let json = serde_json::to_string(&bundle).unwrap();
+        let bundle: ProjectBundle = serde_json::from_str(&json).unwrap();
+
+        let dst = setup_test_connection();
+        let report = import_project_sync(&dst, &bundle, None).unwrap();
+        assert_eq!(report.goals, 2);
+        assert_eq!(report.milestones, 2);
+        assert_eq!(report.tasks, 2);
+        assert_eq!(report.dependencies, 2);
+        assert_eq!(report.task_templates, 1);
+        assert_eq!(report.roots, 1);
+        assert_eq!(report.todo_sync_items, 1);
+        assert_eq!(report.observations, 2);
+        assert_eq!(report.error_patterns, 1);
+        assert_eq!(report.duplicates_skipped, 0);
+
+        let reexported = export_project_sync(&dst, "/proj/a").unwrap();
+        assert_eq!(comparable(reexported), comparable(bundle));
+    }
+
+    #[test]
+    fn test_import_remaps_ids_instead_of_overwriting() {
+        let src = setup_test_connection();
+        seed_project(&src, "/proj/a");
+        let bundle = export_project_sync(&src, "/proj/a").unwrap();
+
+        // Target already has unrelated goals occupying the same IDs
+        let dst = setup_test_connection();
+        let (other, _) = get_or_create_project_sync(&dst, "/proj/other", None).unwrap();
+        create_goal_sync(&dst, Some(other), "existing 1", None, None, None, None).unwrap();
+        create_goal_sync(&dst, Some(other), "existing 2", None, None, None, None).unwrap();
+
+        let report = import_project_sync(&dst, &bundle, Some("/proj/b")).unwrap();
+        assert_eq!(report.goals, 2);
+
+        let titles: Vec<String> = dst
+            .prepare("SELECT title FROM goals WHERE project_id = ?1 ORDER BY id")
+            .unwrap()
+            .query_map([other], |r| r.get(0))
+            .unwrap()
+            .collect::<rusqlite::Result<_>>()
+            .unwrap();
+        assert_eq!(titles, vec!["existing 1", "existing 2"]);
+
+        // Task still points at the (remapped) imported goal
+        let linked: String = dst
+            .query_row(
+                "SELECT g.title FROM tasks t JOIN goals g ON g.id = t.goal_id
+                 WHERE t.title = 'write parser' AND t.project_id = ?1",
+                [report.project_id],
+                |r| r.get(0),
+            )
+            .unwrap();
+        assert_eq!(linked, "Ship v2");
+    }
+
+    #[test]
+    fn test_import_twice_skips_known_error_patterns() {
+        let src = setup_test_connection();
+        seed_project(&src, "/proj/a");
+        let bundle = export_project_sync(&src, "/proj/a").unwrap();
+
+        let dst = setup_test_connection();
+        import_project_sync(&dst, &bundle, None).unwrap();
+        let second = import_project_sync(&dst, &bundle, None).unwrap();
+        assert_eq!(second.error_patterns, 0);
+        assert_eq!(second.error_patterns_skipped, 1);
+    }
+
+    #[test]
+    fn test_import_twice_adds_no_rows() {
+        const TABLES: &[&str] = &[
+            "goals",
+            "milestones",
+            "goal_dependencies",
+            "tasks",
+            "task_dependencies",
+            "task_templates",
+            "project_roots",
+            "todo_sync_items",
+            "system_observations",
+            "error_patterns",
+        ];
+        let src = setup_test_connection();
+        seed_project(&src, "/proj/a");
+        let bundle = export_project_sync(&src, "/proj/a").unwrap();
+
+        let dst = setup_test_connection();
+        import_project_sync(&dst, &bundle, None).unwrap();
+        let counts = |conn: &Connection| -> Vec<i64> {
+            TABLES.iter().map(|t| count_rows(conn, t)).collect()
+        };
+        let after_first = counts(&dst);
+
+        let second = import_project_sync(&dst, &bundle, None).unwrap();
+        assert_eq!(counts(&dst), after_first);
+        assert_eq!(
+            second,
+            ImportReport {
+                project_id: second.project_id,
+                roots_skipped: 1,
+                // 2 goals, 2 tasks, 1 template, 1 checklist link, 2 observations
+                duplicates_skipped: 8,
+                error_patterns_skipped: 1,
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_export_unknown_project_errors() {
+        let conn = setup_test_connection();
+        assert!(export_project_sync(&conn, "/nope").is_err());
+    }
+
+    #[test]
+    fn test_import_rejects_newer_version() {
+        let src = setup_test_connection();
+        seed_project(&src, "/proj/a");
+        let mut bundle = export_project_sync(&src, "/proj/a").unwrap();
+        bundle.version = BUNDLE_VERSION + 1;
+        let dst = setup_test_connection();
+        assert!(import_project_sync(&dst, &bundle, None).is_err());
+    }
+}
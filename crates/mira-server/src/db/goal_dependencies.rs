@@ -0,0 +1,298 @@
+// crates/mira-server/src/db/goal_dependencies.rs
+// "Finish A before B" links between goals and the derived blocked status
+
+use std::collections::{HashMap, HashSet};
+
+use anyhow::{Result, bail};
+use rusqlite::{Connection, params};
+
+use super::dependency_graph::check_dependency_cycle;
+
+/// Derived status for goals waiting on an unfinished dependency
+pub const EFFECTIVE_BLOCKED: &str = "blocked";
+
+/// Statuses that satisfy a dependency
+const DONE_STATUSES: &[&str] = &["completed"];
+
+/// Statuses that are never reported as blocked
+const CLOSED_STATUSES: &[&str] = &["completed", "abandoned"];
+
+/// The other end of a dependency edge
+#[derive(Debug, Clone, PartialEq)]
+pub struct GoalLink {
+    pub id: i64,
+    pub title: String,
+    pub status: String,
+}
+
+impl GoalLink {
+    /// Whether this dependency still holds its dependent back
+    pub fn is_blocking(&self) -> bool {
+        !DONE_STATUSES.contains(&self.status.as_str())
+    }
+}
+
+/// Record that `goal_id` can't finish until `depends_on` is completed.
+///
+/// Rejects self-dependencies and any edge that would close a cycle.
+/// Adding an existing edge is a no-op.
+pub fn add_goal_dependency_sync(conn: &Connection, goal_id: i64, depends_on: i64) -> Result<()> {
+    if goal_id == depends_on {
+        bail!("Goal {} cannot depend on itself", goal_id);
+    }
+    for id in [goal_id, depends_on] {
+        let exists: bool = conn.query_row(
+            "SELECT EXISTS(SELECT 1 FROM goals WHERE id = ?)",
+            [id],
+            |row| row.get(0),
+        )?;
+        if !exists {
+            bail!("Goal {} not found", id);
+        }
+    }
+    check_dependency_cycle(
+        conn,
+        "SELECT depends_on FROM goal_dependencies WHERE goal_id = ?",
+        goal_id,
+        depends_on,
+    )?;
+    conn.execute(
+        "INSERT OR IGNORE INTO goal_dependencies (goal_id, depends_on) VALUES (?, ?)",
+        params![goal_id, depends_on],
+    )?;
+    Ok(())
+}
+
+/// Remove a dependency edge. Returns whether one existed.
+pub fn remove_goal_dependency_sync(
+    conn: &Connection,
+    goal_id: i64,
+    depends_on: i64,
+) -> Result<bool> {
+    let removed = conn.execute(
+        "DELETE FROM goal_dependencies WHERE goal_id = ? AND depends_on = ?",
+        params![goal_id, depends_on],
+    )?;
+    Ok(removed > 0)
+}
+
+/// Goals `goal_id` directly depends on
+pub fn get_goal_dependencies_sync(conn: &Connection, goal_id: i64) -> Result<Vec<GoalLink>> {
+    query_links(
+        conn,
+        "SELECT g.id, g.title, g.status FROM goal_dependencies d
+         JOIN goals g ON g.id = d.depends_on
+         WHERE d.goal_id = ? ORDER BY g.id",
+        goal_id,
+    )
+}
+
+/// Goals that directly depend on `goal_id`
+pub fn get_goal_dependents_sync(conn: &Connection, goal_id: i64) -> Result<Vec<GoalLink>> {
+    query_links(
+        conn,
+        "SELECT g.id, g.title, g.status FROM goal_dependencies d
+         JOIN goals g ON g.id = d.goal_id
+         WHERE d.depends_on = ? ORDER BY g.id",
+        goal_id,
+    )
+}
+
+fn query_links(conn: &Connection, sql: &str, goal_id: i64) -> Result<Vec<GoalLink>> {
+    let mut stmt = conn.prepare(sql)?;
+    let rows = stmt.query_map([goal_id], |row| {
+        Ok(GoalLink {
+            id: row.get(0)?,
+            title: row.get(1)?,
+            status: row.get(2)?,
+        })
+    })?;
+    Ok(rows.collect::<rusqlite::Result<_>>()?)
+}
+
+/// Unfinished dependencies for each of `goal_ids`, for list views.
+/// Goals with nothing blocking them are absent from the map.
+pub fn get_goal_blockers_sync(
+    conn: &Connection,
+    goal_ids: &[i64],
+) -> Result<HashMap<i64, Vec<GoalLink>>> {
+    let wanted: HashSet<i64> = goal_ids.iter().copied().collect();
+    let mut stmt = conn.prepare(
+        "SELECT d.goal_id, g.id, g.title, g.status FROM goal_dependencies d
+         JOIN goals g ON g.id = d.depends_on
+         ORDER BY d.goal_id, g.id",
+    )?;
+    let rows = stmt.query_map([], |row| {
+        Ok((
+            row.get::<_, i64>(0)?,
+            GoalLink {
+                id: row.get(1)?,
+                title: row.get(2)?,
+                status: row.get(3)?,
+            },
+        ))
+    })?;
+
+    let mut blockers: HashMap<i64, Vec<GoalLink>> = HashMap::new();
+    for row in rows {
+        let (goal_id, dep) = row?;
+        if wanted.contains(&goal_id) && dep.is_blocking() {
+            blockers.entry(goal_id).or_default().push(dep);
+        }
+    }
+    Ok(blockers)
+}
+
+/// Status to report for a goal: `blocked` while any dependency is unfinished,
+/// otherwise the stored status. Closed goals keep their stored status.
+pub fn effective_status<'a>(status: &'a str, dependencies: &[GoalLink]) -> &'a str {
+    if !CLOSED_STATUSES.contains(&status) && dependencies.iter().any(GoalLink::is_blocking) {
+        EFFECTIVE_BLOCKED
+    } else {
+        status
+    }
+}
+
+/// Dependents of `goal_id` with no unfinished dependencies left.
+/// Call after marking `goal_id` completed.
+pub fn unblocked_by_completion_sync(conn: &Connection, goal_id: i64) -> Result<Vec<GoalLink>> {
+    let mut unblocked = Vec::new();
+    for dependent in get_goal_dependents_sync(conn, goal_id)? {
+        if CLOSED_STATUSES.contains(&dependent.status.as_str()) {
+            continue;
+        }
+        let deps = get_goal_dependencies_sync(conn, dependent.id)?;
+        if !deps.iter().any(GoalLink::is_blocking) {
+            unblocked.push(dependent);
+        }
+    }
+    Ok(unblocked)
+}
+
+/// Surface "goal X is ready to start" through the insights digest
+pub fn record_goal_unblocked_sync(
+    conn: &Connection,
+    project_id: i64,
+    goal: &GoalLink,
+    completed: &GoalLink,
+) -> Result<()> {
+    let pattern_data = serde_json::json!({
+        "description": format!(
+            "Goal \"{}\" is unblocked: its last dependency \"{}\" was completed",
+            goal.title, completed.title
+        ),
+        "evidence": format!("goal {} depended on goal {}", goal.id, completed.id),
+        "generated_by": "goal_dependencies",
+    });
+    conn.execute(
+        r#"
+        INSERT INTO behavior_patterns
+            (project_id, pattern_type, pattern_key, pattern_data, confidence,
+             occurrence_count, last_triggered_at, first_seen_at, updated_at)
+        VALUES (?, 'insight_goal_unblocked', ?, ?, 0.9, 1, datetime('now'), datetime('now'), datetime('now'))
+        ON CONFLICT(project_id, pattern_type, pattern_key) DO UPDATE SET
+            occurrence_count = occurrence_count + 1,
+            pattern_data = excluded.pattern_data,
+            dismissed = 0,
+            last_triggered_at = datetime('now')
+        "#,
+        params![
+            project_id,
+            format!("goal_{}", goal.id),
+            pattern_data.to_string()
+        ],
+    )?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::test_support::setup_test_connection;
+    use crate::db::{create_goal_sync, get_goal_by_id_sync, update_goal_sync};
+
+    fn goal(conn: &Connection, title: &str) -> i64 {
+        create_goal_sync(conn, None, title, None, Some("in_progress"), None, None).unwrap()
+    }
+
+    fn complete(conn: &Connection, id: i64) {
+        update_goal_sync(conn, id, None, None, Some("completed"), None, None).unwrap();
+    }
+
+    fn status_of(conn: &Connection, id: i64) -> String {
+        let goal = get_goal_by_id_sync(conn, id).unwrap().unwrap();
+        let deps = get_goal_dependencies_sync(conn, id).unwrap();
+        effective_status(&goal.status, &deps).to_string()
+    }
+
+    #[test]
+    fn test_three_goal_chain_unblocks_in_order() {
+        let conn = setup_test_connection();
+        let schema = goal(&conn, "schema");
+        let api = goal(&conn, "api");
+        let ui = goal(&conn, "ui");
+        add_goal_dependency_sync(&conn, api, schema).unwrap();
+        add_goal_dependency_sync(&conn, ui, api).unwrap();
+
+        assert_eq!(status_of(&conn, schema), "in_progress");
+        assert_eq!(status_of(&conn, api), EFFECTIVE_BLOCKED);
+        assert_eq!(status_of(&conn, ui), EFFECTIVE_BLOCKED);
+        // Stored status is untouched
+        let stored = get_goal_by_id_sync(&conn, api).unwrap().unwrap();
+        assert_eq!(stored.status, "in_progress");
+
+        let blockers = get_goal_blockers_sync(&conn, &[schema, api, ui]).unwrap();
+        assert!(!blockers.contains_key(&schema));
+        assert_eq!(blockers[&api][0].id, schema);
+        assert_eq!(blockers[&ui][0].id, api);
+
+        complete(&conn, schema);
+        let unblocked = unblocked_by_completion_sync(&conn, schema).unwrap();
+        assert_eq!(
+            unblocked.iter().map(|g| g.id).collect::<Vec<_>>(),
+            vec![api]
+        );
+        assert_eq!(status_of(&conn, api), "in_progress");
+        assert_eq!(status_of(&conn, ui), EFFECTIVE_BLOCKED);
+
+        complete(&conn, api);
+        let unblocked = unblocked_by_completion_sync(&conn, api).unwrap();
+        assert_eq!(unblocked.iter().map(|g| g.id).collect::<Vec<_>>(), vec![ui]);
+        assert_eq!(status_of(&conn, ui), "in_progress");
+    }
+
+    #[test]
+    fn test_goal_dependency_cycle_rejected() {
+        let conn = setup_test_connection();
+        let a = goal(&conn, "a");
+        let b = goal(&conn, "b");
+        let c = goal(&conn, "c");
+
+        add_goal_dependency_sync(&conn, b, a).unwrap();
+        add_goal_dependency_sync(&conn, c, b).unwrap();
+
+        let err = add_goal_dependency_sync(&conn, a, c).unwrap_err();
+        assert!(err.to_string().contains("cycle"), "{}", err);
+        assert!(add_goal_dependency_sync(&conn, a, a).is_err());
+        assert!(add_goal_dependency_sync(&conn, a, 99999).is_err());
+        assert!(get_goal_dependencies_sync(&conn, a).unwrap().is_empty());
+
+        // Re-adding an existing edge is fine; removal reports whether it existed
+        add_goal_dependency_sync(&conn, c, b).unwrap();
+        assert_eq!(get_goal_dependencies_sync(&conn, c).unwrap().len(), 1);
+        assert!(remove_goal_dependency_sync(&conn, c, b).unwrap());
+        assert!(!remove_goal_dependency_sync(&conn, c, b).unwrap());
+    }
+
+    #[test]
+    fn test_delete_goal_drops_dependency_edges() {
+        let conn = setup_test_connection();
+        let a = goal(&conn, "a");
+        let b = goal(&conn, "b");
+        add_goal_dependency_sync(&conn, b, a).unwrap();
+
+        crate::db::delete_goal_sync(&conn, a).unwrap();
+        assert!(get_goal_dependencies_sync(&conn, b).unwrap().is_empty());
+        assert_eq!(status_of(&conn, b), "in_progress");
+    }
+}
@@ -9,6 +9,7 @@
 use crate::db::schema::code::vec_code_create_sql;
 use crate::db::schema::vectors::current_vec_code_dims;
 use rusqlite::{Connection, params};
+use std::collections::HashMap;
 
 /// (embedding, file_path, chunk_content, project_id, start_line)
 type EmbeddingRow = (Vec<u8>, String, String, Option<i64>, i64);
@@ -56,6 +57,10 @@ pub fn clear_project_index_sync(
         "DELETE FROM pending_embeddings WHERE project_id = ?",
         params![project_id],
     )?;
+    tx.execute(
+        "DELETE FROM background_dead_letters WHERE project_id = ?",
+        params![project_id],
+    )?;
 
     // For vec_code: DROP+recreate if this is the only project to reclaim
     // sqlite-vec chunk storage. Otherwise DELETE as usual.
@@ -94,6 +99,11 @@ pub fn clear_project_index_sync(
         params![project_id],
     )?;
 
+    tx.execute(
+        "DELETE FROM indexed_files WHERE project_id = ?",
+        params![project_id],
+    )?;
+
     tx.commit()?;
     Ok(())
 }
@@ -141,6 +151,10 @@ pub fn clear_file_index_sync(
         "DELETE FROM pending_embeddings WHERE project_id = ? AND file_path = ?",
         params![project_id, file_path],
     )?;
+    conn.execute(
+        "DELETE FROM background_dead_letters WHERE project_id = ? AND job_key = ?",
+        params![project_id, file_path],
+    )?;
 
     // Delete embeddings for this file
     conn.execute(
@@ -154,9 +168,59 @@ pub fn clear_file_index_sync(
         params![project_id, file_path],
     )?;
 
+    // Forget the stored hash so the next project index re-parses this file
+    conn.execute(
+        "DELETE FROM indexed_files WHERE project_id = ? AND file_path = ?",
+        params![project_id, file_path],
+    )?;
+
     Ok(())
 }
 
+/// Stored content hashes for a project's indexed files.
+///
+/// Maps file_path to (content_hash, extractor_version).
+pub fn get_file_hashes_sync(
+    conn: &Connection,
+    project_id: i64,
+) -> rusqlite::Result<HashMap<String, (String, i64)>> {
+    let mut stmt = conn.prepare(
+        "SELECT file_path, content_hash, extractor_version
+         FROM indexed_files WHERE project_id = ?",
+    )?;
+    let hashes = stmt
+        .query_map(params![project_id], |row| {
+            Ok((row.get(0)?, (row.get(1)?, row.get(2)?)))
+        })?
+        .filter_map(super::log_and_discard)
+        .collect();
+    Ok(hashes)
+}
+
+/// Record content hashes for freshly indexed files (file_path, content_hash).
+pub fn record_file_hashes_sync(
+    conn: &Connection,
+    project_id: i64,
+    hashes: &[(String, String)],
+    extractor_version: i64,
+) -> rusqlite::Result<()> {
+    let tx = conn.unchecked_transaction()?;
+    {
+        let mut stmt = tx.prepare(
+            "INSERT INTO indexed_files (project_id, file_path, content_hash, extractor_version, indexed_at)
+             VALUES (?, ?, ?, ?, CURRENT_TIMESTAMP)
+             ON CONFLICT(project_id, file_path) DO UPDATE SET
+                 content_hash = excluded.content_hash,
+                 extractor_version = excluded.extractor_version,
+                 indexed_at = excluded.indexed_at",
+        )?;
+        for (file_path, hash) in hashes {
+            stmt.execute(params![project_id, file_path, hash, extractor_version])?;
+        }
+    }
+    tx.commit()
+}
+
 /// Count code symbols for a project (or all projects if None)
 pub fn count_symbols_sync(conn: &Connection, project_id: Option<i64>) -> i64 {
     if let Some(pid) = project_id {
@@ -187,6 +251,35 @@ pub fn count_embedded_chunks_sync(conn: &Connection, project_id: Option<i64>) ->
     }
 }
 
+/// Indexed files for a project: (file_path, indexed_at, content_hash).
+///
+/// The time and hash come from `indexed_files`, which the indexer keeps for
+/// every parsed file; older indexes fall back to the time of the file's
+/// symbols. Files with chunks but neither have None for both.
+pub fn get_indexed_files_sync(
+    conn: &Connection,
+    project_id: i64,
+) -> rusqlite::Result<Vec<(String, Option<String>, Option<String>)>> {
+    let mut stmt = conn.prepare(
+        "SELECT f.file_path, COALESCE(i.indexed_at, s.indexed_at), i.content_hash
+         FROM (SELECT file_path FROM code_chunks WHERE project_id = ?1
+               UNION SELECT file_path FROM code_symbols WHERE project_id = ?1) f
+         LEFT JOIN (SELECT file_path, MAX(indexed_at) AS indexed_at FROM code_symbols
+                    WHERE project_id = ?1 GROUP BY file_path) s
+           ON s.file_path = f.file_path
+         LEFT JOIN indexed_files i
+           ON i.project_id = ?1 AND i.file_path = f.file_path
+         ORDER BY f.file_path",
+    )?;
+    let files = stmt
+        .query_map(params![project_id], |row| {
+            Ok((row.get(0)?, row.get(1)?, row.get(2)?))
+        })?
+        .filter_map(super::log_and_discard)
+        .collect();
+    Ok(files)
+}
+
 /// Clear cached modules that don't have a purpose set
 ///
 /// Used after generating module summaries to clean up partial entries.
@@ -115,6 +115,7 @@ fn humanize_insight_type(pattern_type: &str) -> String {
         "insight_stale_goal" => "Stale Goal".to_string(),
         "insight_recurring_error" => "Recurring Error".to_string(),
         "insight_health_degrading" => "Health Degradation".to_string(),
+        "insight_goal_unblocked" => "Goal Unblocked".to_string(),
         other => other
             .strip_prefix("insight_")
             .unwrap_or(other)
@@ -185,6 +186,7 @@ fn fetch_pondering_insights(
             "insight_recurring_error" => 0.95,
             "insight_fragile_code" => 0.95,
             "insight_stale_goal" => 0.9,
+            "insight_goal_unblocked" => 0.9,
             "insight_health_degrading" => 0.85,
             _ => 0.5,
         };
@@ -205,7 +207,7 @@ fn fetch_pondering_insights(
         let category = match pattern_type.as_str() {
             "insight_revert_cluster" | "insight_fragile_code" => "quality",
             "insight_recurring_error" => "testing",
-            "insight_stale_goal" => "workflow",
+            "insight_stale_goal" | "insight_goal_unblocked" => "workflow",
             "insight_health_degrading" => "health",
             _ => "other",
         };
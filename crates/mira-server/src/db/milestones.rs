@@ -107,37 +107,67 @@ pub fn delete_milestone_sync(conn: &Connection, id: i64) -> rusqlite::Result<Opt
     Ok(goal_id)
 }
 
-/// Calculate goal progress based on completed milestones
-/// Returns progress as percentage (0-100)
-pub fn calculate_goal_progress_sync(conn: &Connection, goal_id: i64) -> rusqlite::Result<i32> {
+/// Weighted rollup of a goal's milestones and linked tasks.
+///
+/// Each milestone counts its `weight`, each linked task counts 1 and is done
+/// once its status is `completed`. Returns None when there is nothing to roll
+/// up (no milestones or tasks, or all weights zero).
+pub fn calculate_goal_rollup_sync(
+    conn: &Connection,
+    goal_id: i64,
+) -> rusqlite::Result<Option<i32>> {
     let (completed_weight, total_weight): (i64, i64) = conn.query_row(
-        "SELECT
-            COALESCE(SUM(CASE WHEN completed = 1 THEN weight ELSE 0 END), 0),
-            COALESCE(SUM(weight), 0)
-         FROM milestones WHERE goal_id = ?",
+        "SELECT COALESCE(SUM(done), 0), COALESCE(SUM(weight), 0) FROM (
+             SELECT CASE WHEN completed = 1 THEN weight ELSE 0 END AS done, weight
+             FROM milestones WHERE goal_id = ?1
+             UNION ALL
+             SELECT CASE WHEN status = 'completed' THEN 1 ELSE 0 END, 1
+             FROM tasks WHERE goal_id = ?1
+         )",
         [goal_id],
         |row| Ok((row.get(0)?, row.get(1)?)),
     )?;
 
     if total_weight == 0 {
-        return Ok(0);
+        return Ok(None);
     }
 
     let progress = (completed_weight as f64 / total_weight as f64 * 100.0).round() as i32;
-    Ok(progress.min(100))
+    Ok(Some(progress.min(100)))
+}
+
+/// Calculate goal progress from milestones and linked tasks
+/// Returns progress as percentage (0-100), 0 if there is nothing to roll up
+pub fn calculate_goal_progress_sync(conn: &Connection, goal_id: i64) -> rusqlite::Result<i32> {
+    Ok(calculate_goal_rollup_sync(conn, goal_id)?.unwrap_or(0))
 }
 
-/// Update a goal's progress based on its milestones
+/// Update a goal's progress from its milestones and linked tasks.
+///
+/// A computed rollup replaces any manual override and clears its flag. A goal
+/// with nothing to roll up keeps its stored (manual) percent, which is returned.
 pub fn update_goal_progress_from_milestones_sync(
     conn: &Connection,
     goal_id: i64,
 ) -> rusqlite::Result<i32> {
-    let progress = calculate_goal_progress_sync(conn, goal_id)?;
-    conn.execute(
-        "UPDATE goals SET progress_percent = ?, updated_at = CURRENT_TIMESTAMP WHERE id = ?",
-        params![progress, goal_id],
-    )?;
-    Ok(progress)
+    match calculate_goal_rollup_sync(conn, goal_id)? {
+        Some(progress) => {
+            conn.execute(
+                "UPDATE goals SET progress_percent = ?, progress_manual = 0, updated_at = CURRENT_TIMESTAMP WHERE id = ?",
+                params![progress, goal_id],
+            )?;
+            Ok(progress)
+        }
+        None => Ok(conn
+            .query_row(
+                "SELECT progress_percent FROM goals WHERE id = ?",
+                [goal_id],
+                |row| row.get::<_, Option<i32>>(0),
+            )
+            .optional()?
+            .flatten()
+            .unwrap_or(0)),
+    }
 }
 
 #[cfg(test)]
@@ -543,4 +573,98 @@ mod tests {
             .expect("update progress should succeed");
         assert_eq!(progress, 0);
     }
+
+    // ========================================================================
+    // Rollup with linked tasks and manual overrides
+    // ========================================================================
+
+    #[test]
+    fn test_completing_weighted_milestone_rolls_up_to_goal() {
+        let conn = setup_test_connection();
+        let pid = ensure_project(&conn);
+        let goal_id = create_test_goal(&conn);
+
+        let heavy = create_milestone_sync(&conn, goal_id, "Heavy", Some(3)).unwrap();
+        create_milestone_sync(&conn, goal_id, "Light", Some(1)).unwrap();
+        crate::db::create_task_sync(&conn, Some(pid), Some(goal_id), "Linked", None, None, None)
+            .unwrap();
+
+        complete_milestone_sync(&conn, heavy, None).unwrap();
+        // 3 of (3 + 1 + 1 task)
+        assert_eq!(
+            update_goal_progress_from_milestones_sync(&conn, goal_id).unwrap(),
+            60
+        );
+        let goal = crate::db::get_goal_by_id_sync(&conn, goal_id)
+            .unwrap()
+            .unwrap();
+        assert_eq!(goal.progress_percent, 60);
+        assert!(!goal.progress_manual);
+    }
+
+    #[test]
+    fn test_completing_linked_task_rolls_up_to_goal() {
+        let conn = setup_test_connection();
+        let pid = ensure_project(&conn);
+        let goal_id = create_test_goal(&conn);
+
+        let task = crate::db::create_task_sync(
+            &conn,
+            Some(pid),
+            Some(goal_id),
+            "Only task",
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+        crate::db::update_task_sync(&conn, task, None, Some("completed"), None).unwrap();
+
+        let goal = crate::db::get_goal_by_id_sync(&conn, goal_id)
+            .unwrap()
+            .unwrap();
+        assert_eq!(goal.progress_percent, 100);
+    }
+
+    #[test]
+    fn test_goal_without_milestones_keeps_manual_percent() {
+        let conn = setup_test_connection();
+        let _pid = ensure_project(&conn);
+        let goal_id = create_test_goal(&conn);
+        crate::db::update_goal_sync(&conn, goal_id, None, None, None, None, Some(35)).unwrap();
+
+        assert_eq!(calculate_goal_rollup_sync(&conn, goal_id).unwrap(), None);
+        assert_eq!(
+            update_goal_progress_from_milestones_sync(&conn, goal_id).unwrap(),
+            35
+        );
+        let goal = crate::db::get_goal_by_id_sync(&conn, goal_id)
+            .unwrap()
+            .unwrap();
+        assert_eq!(goal.progress_percent, 35);
+        assert!(goal.progress_manual, "manual override should stay flagged");
+    }
+
+    #[test]
+    fn test_rollup_replaces_manual_override() {
+        let conn = setup_test_connection();
+        let _pid = ensure_project(&conn);
+        let goal_id = create_test_goal(&conn);
+        let ms = create_milestone_sync(&conn, goal_id, "Only", None).unwrap();
+        crate::db::update_goal_sync(&conn, goal_id, None, None, None, None, Some(90)).unwrap();
+        assert!(
+            crate::db::get_goal_by_id_sync(&conn, goal_id)
+                .unwrap()
+                .unwrap()
+                .progress_manual
+        );
+
+        complete_milestone_sync(&conn, ms, None).unwrap();
+        update_goal_progress_from_milestones_sync(&conn, goal_id).unwrap();
+        let goal = crate::db::get_goal_by_id_sync(&conn, goal_id)
+            .unwrap()
+            .unwrap();
+        assert_eq!(goal.progress_percent, 100);
+        assert!(!goal.progress_manual);
+    }
 }
@@ -1,20 +1,28 @@
 // db/mod.rs
 // Unified database layer with rusqlite + sqlite-vec
 
+pub mod artifacts;
+pub mod audit;
 mod background;
 mod cartographer;
 mod config;
+pub mod db_health;
+pub mod dead_letters;
 pub mod dependencies;
+mod dependency_graph;
 mod diff_analysis;
 pub mod diff_outcomes;
 pub mod documentation;
 mod embeddings;
 pub mod error_patterns;
+pub mod export;
+pub mod goal_dependencies;
 mod index;
 pub mod injection;
 mod insights;
 mod migration_helpers;
 mod milestones;
+pub mod module_cards;
 pub mod observations;
 pub mod patterns;
 pub mod pool;
@@ -25,12 +33,18 @@ pub use schema::vectors::{
     check_embedding_provider_change, ensure_code_embeddings_queued,
     ensure_code_vec_table_dimensions, invalidate_code_embeddings,
 };
+pub use schema::{MigrationStatus, migration_status};
 mod search;
 mod session;
+pub mod session_delta;
 mod session_goals;
 pub mod session_tasks;
+pub mod suggestions;
+pub mod task_dependencies;
+pub mod task_templates;
 mod tasks;
 pub mod team;
+pub mod todo_sync;
 #[cfg(test)]
 #[macro_use]
 pub(crate) mod test_support;
@@ -77,9 +91,14 @@ pub use diff_analysis::{
 pub use documentation::{DocGap, DocInventory, DocTask, get_inventory_for_stale_check};
 pub use embeddings::{PendingEmbedding, get_pending_embeddings_sync};
 pub use error_patterns::{
-    ErrorPatternRow, ResolvedErrorPattern, StoreErrorPatternParams, error_fingerprint,
-    get_error_patterns_sync, get_unresolved_patterns_for_tool_sync, lookup_resolved_pattern_sync,
-    resolve_error_pattern_sync, store_error_pattern_sync,
+    ErrorPatternRow, ErrorSignature, ErrorSignatureKind, FixMatch, ResolvedErrorPattern,
+    SEMANTIC_FIX_THRESHOLD, SemanticFixCandidate, SimilarFix, StoreErrorPatternParams,
+    best_semantic_fix, count_escalated_fingerprint_failures_sync, count_fingerprint_failures_sync,
+    error_fingerprint, error_shape, error_template_sync, extract_error_signatures,
+    find_similar_fix_sync, fix_embedding_text, get_error_patterns_sync,
+    get_unresolved_patterns_for_tool_sync, lookup_resolved_pattern_sync,
+    resolve_error_pattern_sync, semantic_fix_candidates_sync, store_error_pattern_sync,
+    store_fix_embedding_sync,
 };
 pub use index::{
     CompactStats,
@@ -92,6 +111,8 @@ pub use index::{
     compact_vec_code_sync,
     count_embedded_chunks_sync,
     count_symbols_sync,
+    get_file_hashes_sync,
+    get_indexed_files_sync,
     insert_call_sync,
     insert_chunk_embedding_sync,
     insert_code_chunk_sync,
@@ -99,13 +120,15 @@ pub use index::{
     insert_import_sync,
     insert_symbol_sync,
     queue_pending_embedding_sync,
+    record_file_hashes_sync,
 };
 pub(crate) use insights::compute_age_days;
 pub use insights::{dismiss_insight_sync, get_unified_insights_sync};
 pub use milestones::{
-    calculate_goal_progress_sync, complete_milestone_sync, create_milestone_sync,
-    delete_milestone_sync, get_milestone_by_id_sync, get_milestones_for_goal_sync,
-    parse_milestone_row, update_goal_progress_from_milestones_sync, update_milestone_sync,
+    calculate_goal_progress_sync, calculate_goal_rollup_sync, complete_milestone_sync,
+    create_milestone_sync, delete_milestone_sync, get_milestone_by_id_sync,
+    get_milestones_for_goal_sync, parse_milestone_row, update_goal_progress_from_milestones_sync,
+    update_milestone_sync,
 };
 pub use observations::{
     StoreObservationParams, cleanup_expired_observations_sync, delete_observation_by_key_sync,
@@ -115,13 +138,15 @@ pub use observations::{
     query_team_observations_sync, store_observation_sync,
 };
 pub use project::{
-    clear_active_project_sync, delete_server_state_sync, get_active_project_ids_sync,
+    add_project_root_sync, clear_active_project_sync, delete_server_state_sync,
+    find_project_by_root_sync, find_root_conflicts_sync, get_active_project_ids_sync,
     get_active_projects_sync, get_indexed_project_ids_sync, get_indexed_projects_sync,
     get_last_active_project_sync, get_or_create_project_sync, get_project_briefing_sync,
-    get_project_info_sync, get_project_path_sync, get_project_paths_by_ids_sync,
-    get_projects_for_briefing_check_sync, get_server_state_sync, list_projects_sync,
-    mark_session_for_briefing_sync, save_active_project_sync, set_server_state_sync,
-    update_project_briefing_sync, update_project_name_sync, upsert_session_sync,
+    get_project_info_sync, get_project_languages_sync, get_project_path_sync,
+    get_project_paths_by_ids_sync, get_project_roots_sync, get_projects_for_briefing_check_sync,
+    get_server_state_sync, list_projects_sync, mark_session_for_briefing_sync,
+    save_active_project_sync, set_server_state_sync, update_project_briefing_sync,
+    update_project_languages_sync, update_project_name_sync, upsert_session_sync,
     upsert_session_with_branch_sync,
 };
 pub use retention::{cleanup_orphans, count_retention_candidates, run_data_retention_sync};
@@ -131,12 +156,15 @@ pub use search::{
     get_symbol_bounds_sync, semantic_code_search_sync, symbol_like_search_sync,
 };
 pub use session::{
-    LineageRow, build_session_recap_sync, close_session_sync, create_session_ext_sync,
-    create_session_sync, get_history_after_sync, get_recent_sessions_sync,
-    get_session_behavior_summary_sync, get_session_history_scoped_sync, get_session_history_sync,
-    get_session_lineage_sync, get_session_stats_sync, get_session_tool_summary_sync,
-    get_sessions_needing_summary_sync, get_stale_sessions_sync, log_tool_call_sync,
-    touch_session_sync, update_session_summary_sync,
+    CLOSE_REASON_IDLE_TIMEOUT, CLOSE_REASON_SESSION_END, LineageRow, ToolCallRecord, ToolErrorRow,
+    build_session_activity_recap_sync, build_session_recap_sync,
+    build_session_recap_with_delta_sync, close_session_sync, create_session_ext_sync,
+    create_session_sync, get_history_after_sync, get_recent_file_activity_sync,
+    get_recent_sessions_sync, get_recent_tool_errors_sync, get_session_behavior_summary_sync,
+    get_session_history_scoped_sync, get_session_history_sync, get_session_lineage_sync,
+    get_session_stats_sync, get_session_tool_summary_sync, get_sessions_needing_summary_sync,
+    get_sessions_with_liveness_sync, get_stale_sessions_sync, log_tool_call_sync,
+    record_tool_call_sync, set_session_recap_sync, touch_session_sync, update_session_summary_sync,
 };
 pub use session_goals::{
     count_sessions_for_goal_sync, delete_session_goals_for_goal_sync, get_goals_for_session_sync,
@@ -157,9 +185,10 @@ pub use team::{
 };
 pub use types::*;
 pub use usage::{
-    EmbeddingUsageRecord, EmbeddingUsageStats, LlmUsageRecord, UsageStats,
+    CostRollup, DailyCost, EmbeddingUsageRecord, EmbeddingUsageStats, LlmUsageRecord, UsageStats,
     get_embedding_usage_summary, get_llm_usage_summary, insert_embedding_usage_sync,
-    insert_llm_usage_sync, query_embedding_usage_stats, query_llm_usage_stats,
+    insert_llm_usage_sync, project_month_cost, query_daily_costs, query_embedding_usage_stats,
+    query_llm_usage_stats,
 };
 
 // All database access goes through DatabasePool (db::pool).
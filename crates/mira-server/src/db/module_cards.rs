@@ -0,0 +1,385 @@
+// crates/mira-server/src/db/module_cards.rs
+// Per-module cards (summary, key types, entry points, dependencies) in the code DB
+
+use crate::cartographer::ModuleCard;
+use rusqlite::{Connection, OptionalExtension, params};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+
+/// Symbol kinds listed as a module's key types
+const KEY_TYPE_KINDS: &str =
+    "'struct', 'enum', 'trait', 'class', 'interface', 'type', 'type_alias'";
+
+/// LIKE pattern matching every file under a module path (escaped like the cartographer does)
+pub fn module_path_pattern(module_path: &str) -> String {
+    let escaped = module_path
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_");
+    format!("{}%", escaped)
+}
+
+/// Get project IDs that have cartographer modules
+pub fn get_project_ids_with_modules_sync(conn: &Connection) -> rusqlite::Result<Vec<i64>> {
+    let mut stmt = conn.prepare(
+        "SELECT DISTINCT project_id FROM codebase_modules
+         WHERE project_id IS NOT NULL ORDER BY project_id",
+    )?;
+    let ids = stmt
+        .query_map([], |row| row.get(0))?
+        .filter_map(super::log_and_discard)
+        .collect();
+    Ok(ids)
+}
+
+/// Hash of every indexed chunk under a module path.
+/// Returns None when the module has no indexed chunks.
+pub fn module_content_hash_sync(
+    conn: &Connection,
+    project_id: i64,
+    path_pattern: &str,
+) -> rusqlite::Result<Option<String>> {
+    let mut stmt = conn.prepare(
+        "SELECT file_path, start_line, chunk_content FROM code_chunks
+         WHERE project_id = ? AND file_path LIKE ? ESCAPE '\\'
+         ORDER BY file_path, start_line, id",
+    )?;
+    let mut rows = stmt.query(params![project_id, path_pattern])?;
+
+    let mut hasher = Sha256::new();
+    let mut any = false;
+    while let Some(row) = rows.next()? {
+        let file_path: String = row.get(0)?;
+        let start_line: i64 = row.get(1)?;
+        let content: String = row.get(2)?;
+        hasher.update(file_path.as_bytes());
+        hasher.update([0]);
+        hasher.update(start_line.to_le_bytes());
+        hasher.update(content.as_bytes());
+        hasher.update([0]);
+        any = true;
+    }
+
+    Ok(any.then(|| format!("{:x}", hasher.finalize())))
+}
+
+/// Get the main type definitions in a module
+pub fn get_module_key_types_sync(
+    conn: &Connection,
+    project_id: i64,
+    path_pattern: &str,
+    limit: usize,
+) -> rusqlite::Result<Vec<String>> {
+    let sql = format!(
+        "SELECT name FROM code_symbols
+         WHERE project_id = ? AND file_path LIKE ? ESCAPE '\\' AND symbol_type IN ({})
+         GROUP BY name ORDER BY MIN(file_path), MIN(start_line) LIMIT ?",
+        KEY_TYPE_KINDS
+    );
+    let mut stmt = conn.prepare(&sql)?;
+    let names = stmt
+        .query_map(params![project_id, path_pattern, limit as i64], |row| {
+            row.get(0)
+        })?
+        .filter_map(super::log_and_discard)
+        .collect();
+    Ok(names)
+}
+
+/// Get a module's entry points: `main` plus functions defined in its root files
+/// (mod.rs, lib.rs, main.rs, index.*, __init__.py)
+pub fn get_module_entry_points_sync(
+    conn: &Connection,
+    project_id: i64,
+    path_pattern: &str,
+    limit: usize,
+) -> rusqlite::Result<Vec<String>> {
+    let mut stmt = conn.prepare(
+        "SELECT name FROM code_symbols
+         WHERE project_id = ? AND file_path LIKE ? ESCAPE '\\' AND symbol_type = 'function'
+           AND (name = 'main'
+                OR file_path LIKE '%mod.rs' OR file_path LIKE '%lib.rs' OR file_path LIKE '%main.rs'
+                OR file_path LIKE '%index.%' OR file_path LIKE '%\\_\\_init\\_\\_.py' ESCAPE '\\')
+         GROUP BY name ORDER BY name = 'main' DESC, MIN(file_path), MIN(start_line) LIMIT ?",
+    )?;
+    let names = stmt
+        .query_map(params![project_id, path_pattern, limit as i64], |row| {
+            row.get(0)
+        })?
+        .filter_map(super::log_and_discard)
+        .collect();
+    Ok(names)
+}
+
+/// Stored card state per module: module_id -> (content_hash, is_fallback)
+pub fn get_module_card_hashes_sync(
+    conn: &Connection,
+    project_id: i64,
+) -> rusqlite::Result<HashMap<String, (String, bool)>> {
+    let mut stmt = conn.prepare(
+        "SELECT module_id, content_hash, summary LIKE '[heuristic] %'
+         FROM module_summaries WHERE project_id = ?",
+    )?;
+    let hashes = stmt
+        .query_map(params![project_id], |row| {
+            Ok((row.get::<_, String>(0)?, (row.get(1)?, row.get(2)?)))
+        })?
+        .filter_map(super::log_and_discard)
+        .collect();
+    Ok(hashes)
+}
+
+/// Insert or replace a module card
+pub fn upsert_module_card_sync(
+    conn: &Connection,
+    project_id: i64,
+    card: &ModuleCard,
+) -> rusqlite::Result<()> {
+    let to_json = |v: &Vec<String>| serde_json::to_string(v).unwrap_or_else(|_| "[]".to_string());
+    conn.execute(
+        "INSERT INTO module_summaries
+         (project_id, module_id, content_hash, summary, key_types, entry_points, dependencies, updated_at)
+         VALUES (?, ?, ?, ?, ?, ?, ?, datetime('now'))
+         ON CONFLICT(project_id, module_id) DO UPDATE SET
+            content_hash = excluded.content_hash,
+            summary = excluded.summary,
+            key_types = excluded.key_types,
+            entry_points = excluded.entry_points,
+            dependencies = excluded.dependencies,
+            updated_at = excluded.updated_at",
+        params![
+            project_id,
+            card.module_id,
+            card.content_hash,
+            card.summary,
+            to_json(&card.key_types),
+            to_json(&card.entry_points),
+            to_json(&card.dependencies),
+        ],
+    )?;
+    Ok(())
+}
+
+/// Delete cards for modules the cartographer no longer reports
+pub fn delete_orphaned_module_cards_sync(
+    conn: &Connection,
+    project_id: i64,
+) -> rusqlite::Result<usize> {
+    conn.execute(
+        "DELETE FROM module_summaries
+         WHERE project_id = ?1
+           AND module_id NOT IN (SELECT module_id FROM codebase_modules WHERE project_id = ?1)",
+        params![project_id],
+    )
+}
+
+const CARD_SELECT: &str = "SELECT s.module_id, m.name, m.path, s.summary, s.key_types,
+        s.entry_points, s.dependencies, s.content_hash, s.updated_at
+     FROM module_summaries s
+     JOIN codebase_modules m ON m.project_id = s.project_id AND m.module_id = s.module_id";
+
+fn card_from_row(row: &rusqlite::Row) -> rusqlite::Result<ModuleCard> {
+    let from_json = |s: String| serde_json::from_str(&s).unwrap_or_default();
+    Ok(ModuleCard {
+        module_id: row.get(0)?,
+        name: row.get(1)?,
+        path: row.get(2)?,
+        summary: row.get(3)?,
+        key_types: from_json(row.get(4)?),
+        entry_points: from_json(row.get(5)?),
+        dependencies: from_json(row.get(6)?),
+        content_hash: row.get(7)?,
+        updated_at: row.get(8)?,
+    })
+}
+
+/// Get all module cards for a project, ordered by module ID
+pub fn get_module_cards_sync(
+    conn: &Connection,
+    project_id: i64,
+) -> rusqlite::Result<Vec<ModuleCard>> {
+    let sql = format!("{CARD_SELECT} WHERE s.project_id = ? ORDER BY s.module_id");
+    let mut stmt = conn.prepare(&sql)?;
+    let cards = stmt
+        .query_map(params![project_id], card_from_row)?
+        .filter_map(super::log_and_discard)
+        .collect();
+    Ok(cards)
+}
+
+/// Get a single module card
+pub fn get_module_card_sync(
+    conn: &Connection,
+    project_id: i64,
+    module_id: &str,
+) -> rusqlite::Result<Option<ModuleCard>> {
+    let sql = format!("{CARD_SELECT} WHERE s.project_id = ? AND s.module_id = ?");
+    conn.query_row(&sql, params![project_id, module_id], card_from_row)
+        .optional()
+}
+
+/// Keyword search over module cards. Each query term found in a card's ID,
+/// summary, key types or entry points scores a point; ID and type matches
+/// score double. Returns the best `limit` cards with their scores.
+pub fn search_module_cards_sync(
+    conn: &Connection,
+    project_id: i64,
+    query: &str,
+    limit: usize,
+) -> rusqlite::Result<Vec<(ModuleCard, usize)>> {
+    let terms: Vec<String> = query.split_whitespace().map(|t| t.to_lowercase()).collect();
+    if terms.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let mut scored: Vec<(ModuleCard, usize)> = get_module_cards_sync(conn, project_id)?
+        .into_iter()
+        .filter_map(|card| {
+            let id = card.module_id.to_lowercase();
+            let summary = card.summary.to_lowercase();
+            let types = card.key_types.join(" ").to_lowercase();
+            let entries = card.entry_points.join(" ").to_lowercase();
+            let score: usize = terms
+                .iter()
+                .map(|t| {
+                    2 * usize::from(id.contains(t.as_str()))
+                        + 2 * usize::from(types.contains(t.as_str()))
+                        + usize::from(summary.contains(t.as_str()))
+                        + usize::from(entries.contains(t.as_str()))
+                })
+                .sum();
+            (score > 0).then_some((card, score))
+        })
+        .collect();
+
+    scored.sort_by(|a, b| {
+        b.1.cmp(&a.1)
+            .then_with(|| a.0.module_id.cmp(&b.0.module_id))
+    });
+    scored.truncate(limit);
+    Ok(scored)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cartographer::Module;
+    use crate::db::pool::DatabasePool;
+
+    fn seed(conn: &Connection) {
+        let mut module = Module::new("db", "db", "src/db");
+        module.depends_on = vec!["config".into()];
+        crate::db::upsert_module_sync(conn, 1, &module).unwrap();
+        conn.execute_batch(
+            "INSERT INTO code_chunks (project_id, file_path, chunk_content, start_line)
+                 VALUES (1, 'src/db/pool.rs', 'pub struct DatabasePool;', 1);
+             INSERT INTO code_symbols (project_id, file_path, name, symbol_type, start_line, end_line)
+                 VALUES (1, 'src/db/pool.rs', 'DatabasePool', 'struct', 1, 1),
+                        (1, 'src/db/mod.rs', 'open', 'function', 1, 5),
+                        (1, 'src/db/pool.rs', 'helper', 'function', 3, 4);",
+        )
+        .unwrap();
+    }
+
+    fn card(hash: &str, summary: &str) -> ModuleCard {
+        ModuleCard {
+            module_id: "db".into(),
+            name: "db".into(),
+            path: "src/db".into(),
+            summary: summary.into(),
+            key_types: vec!["DatabasePool".into()],
+            entry_points: vec!["open".into()],
+            dependencies: vec!["config".into()],
+            content_hash: hash.into(),
+            updated_at: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_content_hash_tracks_chunks() {
+        let pool = DatabasePool::open_code_db_in_memory().await.unwrap();
+        pool.interact(|conn| {
+            seed(conn);
+            let pattern = module_path_pattern("src/db");
+            let first = module_content_hash_sync(conn, 1, &pattern)
+                .unwrap()
+                .unwrap();
+            assert_eq!(
+                module_content_hash_sync(conn, 1, &pattern)
+                    .unwrap()
+                    .unwrap(),
+                first
+            );
+
+            conn.execute(
+                "UPDATE code_chunks SET chunk_content = 'pub struct DatabasePool(u8);'",
+                [],
+            )
+            .unwrap();
+            let changed = module_content_hash_sync(conn, 1, &pattern)
+                .unwrap()
+                .unwrap();
+            assert_ne!(changed, first);
+
+            let empty = module_content_hash_sync(conn, 1, &module_path_pattern("src/none"));
+            assert!(empty.unwrap().is_none());
+            Ok(())
+        })
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_key_types_and_entry_points() {
+        let pool = DatabasePool::open_code_db_in_memory().await.unwrap();
+        pool.interact(|conn| {
+            seed(conn);
+            let pattern = module_path_pattern("src/db");
+            assert_eq!(
+                get_module_key_types_sync(conn, 1, &pattern, 8).unwrap(),
+                vec!["DatabasePool"]
+            );
+            assert_eq!(
+                get_module_entry_points_sync(conn, 1, &pattern, 8).unwrap(),
+                vec!["open"]
+            );
+            Ok(())
+        })
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_card_round_trip_search_and_orphans() {
+        let pool = DatabasePool::open_code_db_in_memory().await.unwrap();
+        pool.interact(|conn| {
+            seed(conn);
+            upsert_module_card_sync(conn, 1, &card("h1", "[heuristic] db module")).unwrap();
+            upsert_module_card_sync(conn, 1, &card("h2", "Connection pooling")).unwrap();
+
+            let hashes = get_module_card_hashes_sync(conn, 1).unwrap();
+            assert_eq!(hashes.get("db"), Some(&("h2".to_string(), false)));
+
+            let stored = get_module_card_sync(conn, 1, "db").unwrap().unwrap();
+            assert_eq!(stored.summary, "Connection pooling");
+            assert_eq!(stored.key_types, vec!["DatabasePool"]);
+            assert_eq!(stored.dependencies, vec!["config"]);
+            assert!(stored.updated_at.is_some());
+
+            let hits = search_module_cards_sync(conn, 1, "pooling", 5).unwrap();
+            assert_eq!(hits.len(), 1);
+            assert!(
+                search_module_cards_sync(conn, 1, "websocket", 5)
+                    .unwrap()
+                    .is_empty()
+            );
+
+            conn.execute("DELETE FROM codebase_modules", []).unwrap();
+            assert_eq!(delete_orphaned_module_cards_sync(conn, 1).unwrap(), 1);
+            assert!(get_module_card_hashes_sync(conn, 1).unwrap().is_empty());
+            Ok(())
+        })
+        .await
+        .unwrap();
+    }
+}
@@ -2,7 +2,7 @@
 // System observations storage -- ephemeral system-generated data (health, scans, extractions)
 //
 // Unlike memory_facts (user memories, permanent), observations are TTL-based and
-// not embedded or exported.
+// not embedded. Unexpired ones travel with `mira export`.
 
 use rusqlite::{Connection, params};
 
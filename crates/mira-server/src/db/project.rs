@@ -13,13 +13,31 @@ use crate::utils::normalize_project_path;
 ///
 /// The path is normalized via [`normalize_project_path`] before insertion,
 /// so `~/project`, `/home/user/project`, and symlinked variants all resolve
-/// to the same canonical row.
+/// to the same canonical row. A path inside a root attached to another project
+/// (see [`add_project_root_sync`]) resolves to that project instead.
 pub fn get_or_create_project_sync(
     conn: &Connection,
     path: &str,
     name: Option<&str>,
 ) -> rusqlite::Result<(i64, Option<String>)> {
     let normalized = normalize_project_path(path);
+    if let Some((id, owner_name, _)) = find_project_by_root_sync(conn, &normalized)? {
+        // A project registered at exactly this path is deeper than any root
+        // containing it, unless the path is itself the attached root.
+        let is_root: bool = conn.query_row(
+            "SELECT EXISTS(SELECT 1 FROM project_roots WHERE path = ?)",
+            [&normalized],
+            |row| row.get(0),
+        )?;
+        let is_project: bool = conn.query_row(
+            "SELECT EXISTS(SELECT 1 FROM projects WHERE path = ?)",
+            [&normalized],
+            |row| row.get(0),
+        )?;
+        if is_root || !is_project {
+            return Ok((id, owner_name));
+        }
+    }
     conn.query_row(
         "INSERT INTO projects (path, name) VALUES (?, ?)
          ON CONFLICT(path) DO UPDATE SET
@@ -44,6 +62,50 @@ pub fn update_project_name_sync(
     Ok(())
 }
 
+/// Record detected languages - sync version
+///
+/// `languages` is ordered primary-first; the first entry is also stored as
+/// `project_type`. The list is stored as a JSON array.
+pub fn update_project_languages_sync(
+    conn: &Connection,
+    project_id: i64,
+    languages: &[&str],
+) -> rusqlite::Result<()> {
+    let primary = languages.first().copied().unwrap_or("unknown");
+    let languages_json = serde_json::to_string(languages)
+        .map_err(|e| rusqlite::Error::ToSqlConversionFailure(Box::new(e)))?;
+    conn.execute(
+        "UPDATE projects SET project_type = ?, languages = ? WHERE id = ?",
+        params![primary, languages_json, project_id],
+    )?;
+    Ok(())
+}
+
+/// Get recorded (project_type, languages) by ID - sync version
+///
+/// Returns None if the project doesn't exist or languages were never detected.
+pub fn get_project_languages_sync(
+    conn: &Connection,
+    project_id: i64,
+) -> rusqlite::Result<Option<(String, Vec<String>)>> {
+    let row: Option<(Option<String>, Option<String>)> = conn
+        .query_row(
+            "SELECT project_type, languages FROM projects WHERE id = ?",
+            [project_id],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+        .optional()?;
+    Ok(match row {
+        Some((Some(project_type), languages)) => {
+            let languages = languages
+                .and_then(|json| serde_json::from_str(&json).ok())
+                .unwrap_or_else(|| vec![project_type.clone()]);
+            Some((project_type, languages))
+        }
+        _ => None,
+    })
+}
+
 /// Get project path by ID - sync version for pool.interact()
 pub fn get_project_path_sync(conn: &Connection, project_id: i64) -> rusqlite::Result<String> {
     conn.query_row(
@@ -53,6 +115,107 @@ pub fn get_project_path_sync(conn: &Connection, project_id: i64) -> rusqlite::Re
     )
 }
 
+/// Attach an additional root directory to a project - sync version.
+///
+/// The path is normalized like project paths. Re-attaching a path renames it;
+/// a root attached to another project is left where it is (check
+/// [`find_root_conflicts_sync`] first to report it).
+pub fn add_project_root_sync(
+    conn: &Connection,
+    project_id: i64,
+    path: &str,
+    name: &str,
+) -> rusqlite::Result<()> {
+    let normalized = normalize_project_path(path);
+    conn.execute(
+        "INSERT INTO project_roots (project_id, path, name) VALUES (?1, ?2, ?3)
+         ON CONFLICT(path) DO UPDATE SET name = excluded.name
+         WHERE project_roots.project_id = excluded.project_id",
+        params![project_id, normalized, name],
+    )?;
+    Ok(())
+}
+
+/// Paths of other projects that attaching `path` to `project_id` would take
+/// over - sync version.
+///
+/// Returns primary paths and attached roots of other projects that sit at or
+/// below `path`, ordered by path.
+pub fn find_root_conflicts_sync(
+    conn: &Connection,
+    project_id: i64,
+    path: &str,
+) -> rusqlite::Result<Vec<String>> {
+    let normalized = normalize_project_path(path);
+    let root = std::path::Path::new(&normalized);
+
+    let mut stmt = conn.prepare(
+        "SELECT path FROM projects WHERE id != ?1
+         UNION
+         SELECT path FROM project_roots WHERE project_id != ?1
+         ORDER BY path",
+    )?;
+    let paths = stmt.query_map([project_id], |row| row.get::<_, String>(0))?;
+    let mut conflicts = Vec::new();
+    for other in paths {
+        let other = other?;
+        if std::path::Path::new(&other).starts_with(root) {
+            conflicts.push(other);
+        }
+    }
+    Ok(conflicts)
+}
+
+/// Get a project's attached roots as (name, path), ordered by name - sync version
+pub fn get_project_roots_sync(
+    conn: &Connection,
+    project_id: i64,
+) -> rusqlite::Result<Vec<(String, String)>> {
+    let mut stmt =
+        conn.prepare("SELECT name, path FROM project_roots WHERE project_id = ? ORDER BY name")?;
+    let rows = stmt.query_map([project_id], |row| Ok((row.get(0)?, row.get(1)?)))?;
+    rows.collect()
+}
+
+/// Find the project whose attached root contains `path` - sync version.
+///
+/// The deepest matching root wins, so nested roots resolve to the most
+/// specific project. Returns the owning project's (id, name, primary path).
+pub fn find_project_by_root_sync(
+    conn: &Connection,
+    path: &str,
+) -> rusqlite::Result<Option<(i64, Option<String>, String)>> {
+    let normalized = normalize_project_path(path);
+    let target = std::path::Path::new(&normalized);
+
+    let mut stmt = conn.prepare(
+        "SELECT r.path, p.id, p.name, p.path
+         FROM project_roots r JOIN projects p ON p.id = r.project_id",
+    )?;
+    let rows = stmt.query_map([], |row| {
+        Ok((
+            row.get::<_, String>(0)?,
+            row.get::<_, i64>(1)?,
+            row.get::<_, Option<String>>(2)?,
+            row.get::<_, String>(3)?,
+        ))
+    })?;
+
+    let mut best: Option<(usize, (i64, Option<String>, String))> = None;
+    for row in rows {
+        let (root, id, name, project_path) = row?;
+        let root = std::path::Path::new(&root);
+        if !target.starts_with(root) {
+            continue;
+        }
+        let depth = root.components().count();
+        if best.as_ref().is_none_or(|(d, _)| depth > *d) {
+            best = Some((depth, (id, name, project_path)));
+        }
+    }
+    Ok(best.map(|(_, owner)| owner))
+}
+
 /// Get project info by ID (name, path) - sync version for pool.interact()
 pub fn get_project_info_sync(
     conn: &Connection,
@@ -3,11 +3,13 @@
 
 use super::test_support::setup_test_pool;
 use super::{
-    clear_active_project_sync, delete_server_state_sync, get_last_active_project_sync,
+    add_project_root_sync, clear_active_project_sync, delete_server_state_sync,
+    find_project_by_root_sync, find_root_conflicts_sync, get_last_active_project_sync,
     get_or_create_project_sync, get_project_briefing_sync, get_project_info_sync,
-    get_projects_for_briefing_check_sync, get_server_state_sync, list_projects_sync,
-    mark_session_for_briefing_sync, save_active_project_sync, set_server_state_sync,
-    update_project_briefing_sync,
+    get_project_languages_sync, get_project_roots_sync, get_projects_for_briefing_check_sync,
+    get_server_state_sync, list_projects_sync, mark_session_for_briefing_sync,
+    save_active_project_sync, set_server_state_sync, update_project_briefing_sync,
+    update_project_languages_sync,
 };
 
 #[cfg(test)]
@@ -106,6 +108,36 @@ mod tests {
         assert!(info.is_none());
     }
 
+    // ═══════════════════════════════════════
+    // project languages Tests
+    // ═══════════════════════════════════════
+
+    #[tokio::test]
+    async fn test_project_languages_roundtrip() {
+        let pool = setup_test_pool().await;
+        let (id, _) = db!(pool, |conn| get_or_create_project_sync(
+            conn,
+            "/test/path",
+            None
+        )
+        .map_err(Into::into));
+        let before = db!(pool, |conn| get_project_languages_sync(conn, id)
+            .map_err(Into::into));
+        assert!(before.is_none(), "not detected yet");
+
+        db!(pool, |conn| update_project_languages_sync(
+            conn,
+            id,
+            &["python", "rust"]
+        )
+        .map_err(Into::into));
+        let (project_type, languages) = db!(pool, |conn| get_project_languages_sync(conn, id)
+            .map_err(Into::into))
+        .unwrap();
+        assert_eq!(project_type, "python");
+        assert_eq!(languages, vec!["python", "rust"]);
+    }
+
     // ═══════════════════════════════════════
     // list_projects Tests
     // ═══════════════════════════════════════
@@ -459,6 +491,179 @@ mod tests {
         assert!(briefing.briefing_text.is_none());
     }
 
+    // ═══════════════════════════════════════
+    // Project Roots Tests
+    // ═══════════════════════════════════════
+
+    #[tokio::test]
+    async fn test_attached_root_resolves_to_owner() {
+        let pool = setup_test_pool().await;
+        let (api_id, _) = db!(pool, |conn| get_or_create_project_sync(
+            conn,
+            "/work/api",
+            Some("product")
+        )
+        .map_err(Into::into));
+        db!(pool, |conn| add_project_root_sync(
+            conn,
+            api_id,
+            "/work/web",
+            "web"
+        )
+        .map_err(Into::into));
+
+        // The attached root and anything under it resolve to the owning project
+        let (web_id, name) = db!(pool, |conn| get_or_create_project_sync(
+            conn,
+            "/work/web/src",
+            None
+        )
+        .map_err(Into::into));
+        assert_eq!(web_id, api_id);
+        assert_eq!(name, Some("product".to_string()));
+
+        let roots = db!(pool, |conn| get_project_roots_sync(conn, api_id)
+            .map_err(Into::into));
+        assert_eq!(roots, vec![("web".to_string(), "/work/web".to_string())]);
+
+        // Unrelated paths still get their own project
+        let (other_id, _) = db!(pool, |conn| get_or_create_project_sync(
+            conn,
+            "/work/other",
+            None
+        )
+        .map_err(Into::into));
+        assert_ne!(other_id, api_id);
+    }
+
+    #[tokio::test]
+    async fn test_deepest_root_wins() {
+        let pool = setup_test_pool().await;
+        let (outer_id, _) = db!(pool, |conn| get_or_create_project_sync(
+            conn,
+            "/outer-main",
+            None
+        )
+        .map_err(Into::into));
+        let (inner_id, _) = db!(pool, |conn| get_or_create_project_sync(
+            conn,
+            "/inner-main",
+            None
+        )
+        .map_err(Into::into));
+        // Registered on its own before the roots below were attached
+        let (tool_id, _) = db!(pool, |conn| get_or_create_project_sync(
+            conn,
+            "/shared/tool",
+            None
+        )
+        .map_err(Into::into));
+        db!(pool, |conn| add_project_root_sync(
+            conn, outer_id, "/shared", "shared"
+        )
+        .map_err(Into::into));
+        db!(pool, |conn| add_project_root_sync(
+            conn,
+            inner_id,
+            "/shared/nested",
+            "nested"
+        )
+        .map_err(Into::into));
+
+        let found = db!(pool, |conn| find_project_by_root_sync(
+            conn,
+            "/shared/nested/lib"
+        )
+        .map_err(Into::into));
+        assert_eq!(found.map(|(id, _, _)| id), Some(inner_id));
+        let found = db!(pool, |conn| find_project_by_root_sync(conn, "/shared/top")
+            .map_err(Into::into));
+        assert_eq!(
+            found.map(|(id, _, path)| (id, path)),
+            Some((outer_id, "/outer-main".to_string()))
+        );
+        // Prefix matching is per path component, not per character
+        let found = db!(pool, |conn| find_project_by_root_sync(
+            conn,
+            "/shared-other"
+        )
+        .map_err(Into::into));
+        assert!(found.is_none());
+
+        // A project registered exactly at a path is deeper than the root containing it
+        let (id, _) = db!(pool, |conn| get_or_create_project_sync(
+            conn,
+            "/shared/tool",
+            None
+        )
+        .map_err(Into::into));
+        assert_eq!(id, tool_id);
+    }
+
+    #[tokio::test]
+    async fn test_root_conflicts_with_other_projects() {
+        let pool = setup_test_pool().await;
+        let (api_id, _) = db!(pool, |conn| get_or_create_project_sync(
+            conn,
+            "/work/api",
+            None
+        )
+        .map_err(Into::into));
+        let (web_id, _) = db!(pool, |conn| get_or_create_project_sync(
+            conn,
+            "/work/web",
+            None
+        )
+        .map_err(Into::into));
+        db!(pool, |conn| add_project_root_sync(
+            conn,
+            web_id,
+            "/work/assets",
+            "assets"
+        )
+        .map_err(Into::into));
+
+        let conflicts = db!(pool, |conn| find_root_conflicts_sync(conn, api_id, "/work")
+            .map_err(Into::into));
+        assert_eq!(conflicts, vec!["/work/assets", "/work/web"]);
+        let conflicts = db!(pool, |conn| find_root_conflicts_sync(
+            conn,
+            api_id,
+            "/work/web"
+        )
+        .map_err(Into::into));
+        assert_eq!(conflicts, vec!["/work/web"]);
+        // The project's own paths never conflict
+        let conflicts = db!(pool, |conn| find_root_conflicts_sync(conn, web_id, "/work")
+            .map_err(Into::into));
+        assert_eq!(conflicts, vec!["/work/api"]);
+        let conflicts = db!(pool, |conn| find_root_conflicts_sync(
+            conn,
+            api_id,
+            "/work/docs"
+        )
+        .map_err(Into::into));
+        assert!(conflicts.is_empty());
+
+        // Re-attaching another project's root leaves it with its owner
+        db!(pool, |conn| add_project_root_sync(
+            conn,
+            api_id,
+            "/work/assets",
+            "stolen"
+        )
+        .map_err(Into::into));
+        let roots = db!(pool, |conn| get_project_roots_sync(conn, web_id)
+            .map_err(Into::into));
+        assert_eq!(
+            roots,
+            vec![("assets".to_string(), "/work/assets".to_string())]
+        );
+        let roots = db!(pool, |conn| get_project_roots_sync(conn, api_id)
+            .map_err(Into::into));
+        assert!(roots.is_empty());
+    }
+
     // ═══════════════════════════════════════
     // Edge Cases
     // ═══════════════════════════════════════
@@ -1,7 +1,7 @@
 //! Data retention — periodic cleanup for unbounded tables.
 //!
 //! Retention policy (configurable via `[retention]` in config.toml):
-//! - tool_history_days (default 30): tool_history,
+//! - tool_history_days (default 30): tool_history, tool_artifacts,
 //!   session_behavior_log
 //! - sessions_days (default 90): sessions (completed only), session_snapshots, session_tasks,
 //!   session_goals
@@ -52,6 +52,12 @@ fn build_rules(config: &RetentionConfig) -> Vec<RetentionRule> {
             days: config.tool_history_days,
             extra_filter: "AND session_id NOT IN (SELECT id FROM sessions WHERE status = 'active')",
         },
+        RetentionRule {
+            table: "tool_artifacts",
+            time_column: "created_at",
+            days: config.tool_history_days,
+            extra_filter: "AND (session_id IS NULL OR session_id NOT IN (SELECT id FROM sessions WHERE status = 'active'))",
+        },
         // ── Children of diff_analyses (must delete before parent) ──
         RetentionRule {
             table: "diff_outcomes",
@@ -454,6 +460,48 @@ mod tests {
         assert_eq!(count, 1, "recent tool_history row should still exist");
     }
 
+    #[test]
+    fn test_project_layer_retention_does_not_touch_other_projects() {
+        use crate::config::file::MiraConfig;
+
+        // Repo A tries to shorten retention in its own .mira/config.toml
+        let dir = tempfile::tempdir().unwrap();
+        let project_file = dir.path().join("project.toml");
+        std::fs::write(
+            &project_file,
+            "[retention]\nenabled = true\ntool_history_days = 1\n",
+        )
+        .unwrap();
+        let global_file = dir.path().join("missing-global.toml");
+        let config = MiraConfig::load_layered_from(&global_file, Some(&project_file))
+            .config
+            .retention;
+
+        // Repo B has 10-day-old history, inside the default 30-day window
+        let conn = setup_retention_test_db();
+        let (other_project, _) =
+            crate::db::get_or_create_project_sync(&conn, "/test/other", Some("other")).unwrap();
+        conn.execute(
+            "INSERT INTO sessions (id, project_id, status, last_activity, started_at)
+             VALUES ('other-session', ?1, 'completed', datetime('now', '-10 days'), datetime('now', '-10 days'))",
+            rusqlite::params![other_project],
+        )
+        .unwrap();
+        conn.execute(
+            "INSERT INTO tool_history (session_id, tool_name, created_at)
+             VALUES ('other-session', 'test_tool', datetime('now', '-10 days'))",
+            [],
+        )
+        .unwrap();
+
+        run_data_retention_sync(&conn, &config).expect("retention should succeed");
+
+        let count: i64 = conn
+            .query_row("SELECT COUNT(*) FROM tool_history", [], |r| r.get(0))
+            .unwrap();
+        assert_eq!(count, 1, "another project's history must survive");
+    }
+
     // ========================================================================
     // count_retention_candidates tests
     // ========================================================================
@@ -112,10 +112,24 @@ CREATE TABLE IF NOT EXISTS pending_embeddings (
     chunk_content TEXT NOT NULL,
     start_line INTEGER NOT NULL DEFAULT 1,
     status TEXT DEFAULT 'pending',
+    attempts INTEGER NOT NULL DEFAULT 0,
     created_at TEXT DEFAULT CURRENT_TIMESTAMP
 );
 CREATE INDEX IF NOT EXISTS idx_pending_embeddings_status ON pending_embeddings(status);
 
+-- Jobs that failed too many times; requeued manually via retry_dead_letter
+CREATE TABLE IF NOT EXISTS background_dead_letters (
+    id INTEGER PRIMARY KEY,
+    job_type TEXT NOT NULL,
+    project_id INTEGER,
+    job_key TEXT,
+    payload TEXT NOT NULL,
+    error TEXT,
+    attempts INTEGER NOT NULL DEFAULT 0,
+    failed_at TEXT DEFAULT CURRENT_TIMESTAMP
+);
+CREATE INDEX IF NOT EXISTS idx_dead_letters_project ON background_dead_letters(project_id, job_key);
+
 -- =======================================
 -- CODE CHUNKS (canonical chunk store)
 -- =======================================
@@ -163,16 +177,63 @@ pub fn run_code_migrations(conn: &Connection) -> Result<()> {
     migrate_vec_code_line_numbers(conn)?;
     migrate_vec_code_chunk_size(conn)?;
     migrate_pending_embeddings_line_numbers(conn)?;
+    migrate_pending_embeddings_attempts(conn)?;
     migrate_imports_unique(conn)?;
     migrate_fts_tokenizer(conn)?;
     migrate_code_chunks(conn)?;
     migrate_module_dependencies(conn)?;
     migrate_detected_patterns(conn)?;
     migrate_conventions_extracted_at(conn)?;
+    migrate_module_summaries(conn)?;
+    migrate_indexed_files(conn)?;
 
     Ok(())
 }
 
+/// Add indexed_files table so re-indexing can skip files whose content is unchanged
+fn migrate_indexed_files(conn: &Connection) -> Result<()> {
+    use crate::db::migration_helpers::create_table_if_missing;
+    create_table_if_missing(
+        conn,
+        "indexed_files",
+        r#"
+        CREATE TABLE IF NOT EXISTS indexed_files (
+            id INTEGER PRIMARY KEY,
+            project_id INTEGER NOT NULL,
+            file_path TEXT NOT NULL,
+            content_hash TEXT NOT NULL,
+            extractor_version INTEGER NOT NULL,
+            indexed_at TEXT DEFAULT CURRENT_TIMESTAMP,
+            UNIQUE(project_id, file_path)
+        );
+    "#,
+    )
+}
+
+/// Add module_summaries table for per-module cards (purpose, key types, entry points)
+fn migrate_module_summaries(conn: &Connection) -> Result<()> {
+    use crate::db::migration_helpers::create_table_if_missing;
+    create_table_if_missing(
+        conn,
+        "module_summaries",
+        r#"
+        CREATE TABLE IF NOT EXISTS module_summaries (
+            id INTEGER PRIMARY KEY,
+            project_id INTEGER NOT NULL,
+            module_id TEXT NOT NULL,
+            content_hash TEXT NOT NULL,
+            summary TEXT NOT NULL,
+            key_types TEXT NOT NULL DEFAULT '[]',
+            entry_points TEXT NOT NULL DEFAULT '[]',
+            dependencies TEXT NOT NULL DEFAULT '[]',
+            updated_at TEXT DEFAULT CURRENT_TIMESTAMP,
+            UNIQUE(project_id, module_id)
+        );
+        CREATE INDEX IF NOT EXISTS idx_module_summaries_project ON module_summaries(project_id);
+    "#,
+    )
+}
+
 /// Add conventions_extracted_at column to codebase_modules for incremental convention extraction
 fn migrate_conventions_extracted_at(conn: &Connection) -> Result<()> {
     add_column_if_missing(conn, "codebase_modules", "conventions_extracted_at", "TEXT")
@@ -320,6 +381,19 @@ fn migrate_pending_embeddings_line_numbers(conn: &Connection) -> Result<()> {
     )
 }
 
+/// Add attempts column to pending_embeddings for dead-lettering failed chunks
+fn migrate_pending_embeddings_attempts(conn: &Connection) -> Result<()> {
+    if !table_exists(conn, "pending_embeddings") {
+        return Ok(());
+    }
+    add_column_if_missing(
+        conn,
+        "pending_embeddings",
+        "attempts",
+        "INTEGER NOT NULL DEFAULT 0",
+    )
+}
+
 /// Migrate FTS5 tokenizer from porter-stemmed to code-aware.
 ///
 /// Detects if code_fts uses the old `porter unicode61` tokenizer and rebuilds
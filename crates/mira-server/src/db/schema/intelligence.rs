@@ -107,6 +107,41 @@ pub fn migrate_error_patterns_table(conn: &Connection) -> Result<()> {
     Ok(())
 }
 
+/// Add error_patterns.error_shape for loose fix matching and backfill it
+/// from the stored templates.
+pub fn migrate_error_patterns_shape(conn: &Connection) -> Result<()> {
+    use crate::db::migration_helpers::add_column_if_missing;
+    add_column_if_missing(conn, "error_patterns", "error_shape", "TEXT")?;
+    conn.execute(
+        "CREATE INDEX IF NOT EXISTS idx_error_patterns_shape
+            ON error_patterns(project_id, tool_name, error_shape)",
+        [],
+    )?;
+
+    let rows: Vec<(i64, String)> = {
+        let mut stmt = conn
+            .prepare("SELECT id, error_template FROM error_patterns WHERE error_shape IS NULL")?;
+        stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+            .collect::<rusqlite::Result<_>>()?
+    };
+    let mut update = conn.prepare("UPDATE error_patterns SET error_shape = ?1 WHERE id = ?2")?;
+    for (id, template) in rows {
+        update.execute(rusqlite::params![crate::db::error_shape(&template), id])?;
+    }
+    Ok(())
+}
+
+/// Add error_patterns.fix_embedding, the cached embedding used for semantic
+/// fix matching. Filled lazily the first time a lookup compares against it.
+pub fn migrate_error_patterns_fix_embedding(conn: &Connection) -> Result<()> {
+    crate::db::migration_helpers::add_column_if_missing(
+        conn,
+        "error_patterns",
+        "fix_embedding",
+        "BLOB",
+    )
+}
+
 /// Migrate to add cross-project intelligence tables.
 ///
 /// Tables dropped in migration v35. This is a no-op.
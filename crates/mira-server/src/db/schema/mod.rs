@@ -282,6 +282,81 @@ fn migration_registry() -> Vec<Migration> {
             name: "context_injections_table",
             func: injection::migrate_context_injections_table,
         },
+        Migration {
+            version: 52,
+            name: "tool_history_timing",
+            func: session::migrate_tool_history_timing,
+        },
+        Migration {
+            version: 53,
+            name: "projects_languages",
+            func: migrate_projects_languages,
+        },
+        Migration {
+            version: 54,
+            name: "goals_progress_manual",
+            func: migrate_goals_progress_manual,
+        },
+        Migration {
+            version: 55,
+            name: "error_patterns_shape",
+            func: intelligence::migrate_error_patterns_shape,
+        },
+        Migration {
+            version: 56,
+            name: "tool_artifacts_table",
+            func: session::migrate_tool_artifacts_table,
+        },
+        Migration {
+            version: 57,
+            name: "sessions_close_reason",
+            func: session::migrate_sessions_close_reason,
+        },
+        Migration {
+            version: 58,
+            name: "project_roots_table",
+            func: migrate_project_roots_table,
+        },
+        Migration {
+            version: 59,
+            name: "db_health_table",
+            func: migrate_db_health_table,
+        },
+        Migration {
+            version: 60,
+            name: "goal_dependencies_table",
+            func: migrate_goal_dependencies_table,
+        },
+        Migration {
+            version: 61,
+            name: "todo_sync_items_table",
+            func: migrate_todo_sync_items_table,
+        },
+        Migration {
+            version: 62,
+            name: "tasks_completed_at",
+            func: migrate_tasks_completed_at,
+        },
+        Migration {
+            version: 63,
+            name: "sessions_recap",
+            func: session::migrate_sessions_recap,
+        },
+        Migration {
+            version: 64,
+            name: "task_dependencies_table",
+            func: migrate_task_dependencies_table,
+        },
+        Migration {
+            version: 65,
+            name: "task_templates_table",
+            func: migrate_task_templates_table,
+        },
+        Migration {
+            version: 66,
+            name: "error_patterns_fix_embedding",
+            func: intelligence::migrate_error_patterns_fix_embedding,
+        },
     ]
 }
 
@@ -315,6 +390,33 @@ fn record_migration(conn: &Connection, version: u32, name: &str) -> Result<()> {
     Ok(())
 }
 
+/// Applied/pending state of one registered migration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MigrationStatus {
+    pub version: u32,
+    pub name: &'static str,
+    pub applied: bool,
+}
+
+/// Report every registered migration and whether it has been applied.
+///
+/// Read-only: never creates the schema_versions table, so it is safe for dry runs.
+pub fn migration_status(conn: &Connection) -> Result<Vec<MigrationStatus>> {
+    let applied = if crate::db::migration_helpers::table_exists(conn, "schema_versions") {
+        applied_versions(conn)?
+    } else {
+        HashSet::new()
+    };
+    Ok(migration_registry()
+        .into_iter()
+        .map(|m| MigrationStatus {
+            version: m.version,
+            name: m.name,
+            applied: applied.contains(&m.version),
+        })
+        .collect())
+}
+
 /// Run all schema setup and migrations.
 ///
 /// Called during database initialization. Uses a schema_versions table to track
@@ -478,6 +580,143 @@ fn migrate_milestones_temporal_tracking(conn: &Connection) -> Result<()> {
     Ok(())
 }
 
+/// Add detected project type (primary language) and full language list to projects
+fn migrate_projects_languages(conn: &Connection) -> Result<()> {
+    use crate::db::migration_helpers::add_column_if_missing;
+    add_column_if_missing(conn, "projects", "project_type", "TEXT")?;
+    add_column_if_missing(conn, "projects", "languages", "TEXT")
+}
+
+/// Additional directories that belong to a project (multi-root projects)
+fn migrate_project_roots_table(conn: &Connection) -> Result<()> {
+    use crate::db::migration_helpers::create_table_if_missing;
+    create_table_if_missing(
+        conn,
+        "project_roots",
+        r#"
+        CREATE TABLE IF NOT EXISTS project_roots (
+            id INTEGER PRIMARY KEY,
+            project_id INTEGER NOT NULL REFERENCES projects(id) ON DELETE CASCADE,
+            path TEXT UNIQUE NOT NULL,
+            name TEXT NOT NULL,
+            created_at TEXT DEFAULT CURRENT_TIMESTAMP,
+            UNIQUE(project_id, name)
+        );
+        CREATE INDEX IF NOT EXISTS idx_project_roots_project ON project_roots(project_id);
+    "#,
+    )
+}
+
+/// Daily database size and row-count samples for growth monitoring
+fn migrate_db_health_table(conn: &Connection) -> Result<()> {
+    use crate::db::migration_helpers::create_table_if_missing;
+    create_table_if_missing(
+        conn,
+        "db_health",
+        r#"
+        CREATE TABLE IF NOT EXISTS db_health (
+            id INTEGER PRIMARY KEY,
+            sampled_on TEXT UNIQUE NOT NULL,
+            db_size_bytes INTEGER NOT NULL,
+            wal_size_bytes INTEGER NOT NULL DEFAULT 0,
+            table_rows TEXT NOT NULL,
+            created_at TEXT DEFAULT CURRENT_TIMESTAMP
+        );
+    "#,
+    )
+}
+
+/// Dependency edges between goals (goal_id waits on depends_on)
+fn migrate_goal_dependencies_table(conn: &Connection) -> Result<()> {
+    use crate::db::migration_helpers::create_table_if_missing;
+    create_table_if_missing(
+        conn,
+        "goal_dependencies",
+        r#"
+        CREATE TABLE IF NOT EXISTS goal_dependencies (
+            goal_id INTEGER NOT NULL REFERENCES goals(id),
+            depends_on INTEGER NOT NULL REFERENCES goals(id),
+            created_at TEXT DEFAULT CURRENT_TIMESTAMP,
+            PRIMARY KEY (goal_id, depends_on)
+        );
+        CREATE INDEX IF NOT EXISTS idx_goal_dependencies_depends_on ON goal_dependencies(depends_on);
+    "#,
+    )
+}
+
+/// Links between markdown checklist items and tasks, with the state each
+/// side had at the last sync
+fn migrate_todo_sync_items_table(conn: &Connection) -> Result<()> {
+    use crate::db::migration_helpers::create_table_if_missing;
+    create_table_if_missing(
+        conn,
+        "todo_sync_items",
+        r#"
+        CREATE TABLE IF NOT EXISTS todo_sync_items (
+            id INTEGER PRIMARY KEY,
+            project_id INTEGER NOT NULL REFERENCES projects(id),
+            file_path TEXT NOT NULL,
+            task_id INTEGER NOT NULL UNIQUE REFERENCES tasks(id),
+            item_text TEXT NOT NULL,
+            ordinal INTEGER NOT NULL,
+            checked INTEGER NOT NULL DEFAULT 0,
+            task_done INTEGER NOT NULL DEFAULT 0,
+            synced_at TEXT DEFAULT CURRENT_TIMESTAMP
+        );
+        CREATE INDEX IF NOT EXISTS idx_todo_sync_items_file ON todo_sync_items(project_id, file_path);
+    "#,
+    )
+}
+
+/// Record when a task was completed, for "since last session" recaps
+fn migrate_tasks_completed_at(conn: &Connection) -> Result<()> {
+    use crate::db::migration_helpers::add_column_if_missing;
+    add_column_if_missing(conn, "tasks", "completed_at", "TEXT")
+}
+
+/// Dependency edges between tasks (task_id can't start until depends_on is completed)
+fn migrate_task_dependencies_table(conn: &Connection) -> Result<()> {
+    use crate::db::migration_helpers::create_table_if_missing;
+    create_table_if_missing(
+        conn,
+        "task_dependencies",
+        r#"
+        CREATE TABLE IF NOT EXISTS task_dependencies (
+            task_id INTEGER NOT NULL REFERENCES tasks(id),
+            depends_on INTEGER NOT NULL REFERENCES tasks(id),
+            created_at TEXT DEFAULT CURRENT_TIMESTAMP,
+            PRIMARY KEY (task_id, depends_on)
+        );
+        CREATE INDEX IF NOT EXISTS idx_task_dependencies_depends_on ON task_dependencies(depends_on);
+    "#,
+    )
+}
+
+/// Reusable checklists; items_json is a JSON array of task titles with {var} placeholders
+fn migrate_task_templates_table(conn: &Connection) -> Result<()> {
+    use crate::db::migration_helpers::create_table_if_missing;
+    create_table_if_missing(
+        conn,
+        "task_templates",
+        r#"
+        CREATE TABLE IF NOT EXISTS task_templates (
+            id INTEGER PRIMARY KEY,
+            project_id INTEGER REFERENCES projects(id),
+            name TEXT NOT NULL,
+            items_json TEXT NOT NULL,
+            created_at TEXT DEFAULT CURRENT_TIMESTAMP
+        );
+        CREATE INDEX IF NOT EXISTS idx_task_templates_name ON task_templates(name, project_id);
+    "#,
+    )
+}
+
+/// Flag goals whose progress_percent was set by hand rather than rolled up
+fn migrate_goals_progress_manual(conn: &Connection) -> Result<()> {
+    use crate::db::migration_helpers::add_column_if_missing;
+    add_column_if_missing(conn, "goals", "progress_manual", "INTEGER DEFAULT 0")
+}
+
 /// Drop memory system tables: memory_facts, vec_memory, memory_entities,
 /// memory_entity_links. These tables are no longer used after the memory
 /// system removal.
@@ -523,6 +762,8 @@ CREATE TABLE IF NOT EXISTS projects (
     id INTEGER PRIMARY KEY,
     path TEXT UNIQUE NOT NULL,
     name TEXT,
+    project_type TEXT,
+    languages TEXT,
     created_at TEXT DEFAULT CURRENT_TIMESTAMP
 );
 
@@ -551,6 +792,8 @@ CREATE TABLE IF NOT EXISTS tool_history (
     result_summary TEXT,
     full_result TEXT,
     success INTEGER DEFAULT 1,
+    duration_ms INTEGER,
+    error_message TEXT,
     created_at TEXT DEFAULT CURRENT_TIMESTAMP
 );
 CREATE INDEX IF NOT EXISTS idx_history_session ON tool_history(session_id);
@@ -567,6 +810,7 @@ CREATE TABLE IF NOT EXISTS goals (
     status TEXT DEFAULT 'planning',
     priority TEXT DEFAULT 'medium',
     progress_percent INTEGER DEFAULT 0,
+    progress_manual INTEGER DEFAULT 0,
     created_at TEXT DEFAULT CURRENT_TIMESTAMP,
     updated_at TEXT DEFAULT CURRENT_TIMESTAMP
 );
@@ -588,7 +832,8 @@ CREATE TABLE IF NOT EXISTS tasks (
     description TEXT,
     status TEXT DEFAULT 'pending',
     priority TEXT DEFAULT 'medium',
-    created_at TEXT DEFAULT CURRENT_TIMESTAMP
+    created_at TEXT DEFAULT CURRENT_TIMESTAMP,
+    completed_at TEXT
 );
 CREATE INDEX IF NOT EXISTS idx_tasks_status ON tasks(project_id, status);
 CREATE INDEX IF NOT EXISTS idx_tasks_project_status_created ON tasks(project_id, status, created_at DESC, id DESC);
@@ -627,3 +872,42 @@ CREATE TABLE IF NOT EXISTS system_prompts (
 );
 
 "#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::pool::ensure_sqlite_vec_registered;
+
+    #[test]
+    fn test_migration_status_fresh_db_all_pending() {
+        ensure_sqlite_vec_registered();
+        let conn = Connection::open_in_memory().unwrap();
+        let status = migration_status(&conn).unwrap();
+        assert_eq!(status.len(), migration_registry().len());
+        assert!(status.iter().all(|m| !m.applied));
+        // Dry run must not touch the database
+        assert!(!crate::db::migration_helpers::table_exists(
+            &conn,
+            "schema_versions"
+        ));
+    }
+
+    #[test]
+    fn test_migration_status_after_apply_none_pending() {
+        ensure_sqlite_vec_registered();
+        let conn = Connection::open_in_memory().unwrap();
+        run_all_migrations(&conn).unwrap();
+        let status = migration_status(&conn).unwrap();
+        assert!(status.iter().all(|m| m.applied), "pending: {:?}", status);
+
+        // Re-running is a no-op and still reports nothing pending
+        run_all_migrations(&conn).unwrap();
+        assert!(migration_status(&conn).unwrap().iter().all(|m| m.applied));
+    }
+
+    #[test]
+    fn test_migration_registry_versions_strictly_increasing() {
+        let versions: Vec<u32> = migration_registry().iter().map(|m| m.version).collect();
+        assert!(versions.windows(2).all(|w| w[0] < w[1]), "{:?}", versions);
+    }
+}
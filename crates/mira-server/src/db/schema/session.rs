@@ -16,6 +16,54 @@ pub fn migrate_tool_history_full_result(conn: &Connection) -> Result<()> {
     add_column_if_missing(conn, "tool_history", "full_result", "TEXT")
 }
 
+/// Migrate tool_history to add duration_ms and error_message, recorded for every MCP tool call
+pub fn migrate_tool_history_timing(conn: &Connection) -> Result<()> {
+    if !table_exists(conn, "tool_history") {
+        return Ok(());
+    }
+
+    add_column_if_missing(conn, "tool_history", "duration_ms", "INTEGER")?;
+    add_column_if_missing(conn, "tool_history", "error_message", "TEXT")
+}
+
+/// Create tool_artifacts table for tool results too large to return inline
+pub fn migrate_tool_artifacts_table(conn: &Connection) -> Result<()> {
+    use crate::db::migration_helpers::create_table_if_missing;
+    create_table_if_missing(
+        conn,
+        "tool_artifacts",
+        r#"
+        CREATE TABLE IF NOT EXISTS tool_artifacts (
+            id INTEGER PRIMARY KEY,
+            session_id TEXT REFERENCES sessions(id),
+            tool_name TEXT NOT NULL,
+            content TEXT NOT NULL,
+            size_bytes INTEGER NOT NULL,
+            created_at TEXT DEFAULT CURRENT_TIMESTAMP
+        );
+        CREATE INDEX IF NOT EXISTS idx_tool_artifacts_session ON tool_artifacts(session_id);
+    "#,
+    )
+}
+
+/// Migrate sessions to add close_reason, recording why a session was closed
+pub fn migrate_sessions_close_reason(conn: &Connection) -> Result<()> {
+    if !table_exists(conn, "sessions") {
+        return Ok(());
+    }
+
+    add_column_if_missing(conn, "sessions", "close_reason", "TEXT")
+}
+
+/// Migrate sessions to add recap, storing the activity recap built when a session closes
+pub fn migrate_sessions_recap(conn: &Connection) -> Result<()> {
+    if !table_exists(conn, "sessions") {
+        return Ok(());
+    }
+
+    add_column_if_missing(conn, "sessions", "recap", "TEXT")
+}
+
 /// Migrate sessions to add branch column for branch-aware context
 pub fn migrate_sessions_branch(conn: &Connection) -> Result<()> {
     if !table_exists(conn, "sessions") {
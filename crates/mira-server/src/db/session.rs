@@ -40,6 +40,7 @@ pub fn create_session_ext_sync(
          VALUES (?1, ?2, 'active', ?3, ?4, datetime('now'), datetime('now'))
          ON CONFLICT(id) DO UPDATE SET
             status = 'active',
+            close_reason = NULL,
             last_activity = datetime('now'),
             project_id = COALESCE(excluded.project_id, sessions.project_id),
             source = CASE
@@ -52,6 +53,27 @@ pub fn create_session_ext_sync(
     Ok(())
 }
 
+/// Close reason recorded when the session-end hook closes a session
+pub const CLOSE_REASON_SESSION_END: &str = "session_end";
+/// Close reason recorded when the background worker closes an inactive session
+pub const CLOSE_REASON_IDLE_TIMEOUT: &str = "idle_timeout";
+
+/// Map a row selected with the `SessionInfo` column order
+fn session_info_from_row(row: &rusqlite::Row) -> rusqlite::Result<SessionInfo> {
+    Ok(SessionInfo {
+        id: row.get(0)?,
+        project_id: row.get(1)?,
+        status: row.get(2)?,
+        summary: row.get(3)?,
+        started_at: row.get(4)?,
+        last_activity: row.get(5)?,
+        source: row.get(6)?,
+        resumed_from: row.get(7)?,
+        close_reason: row.get(8)?,
+        recap: row.get(9)?,
+    })
+}
+
 /// Get recent sessions for a project (sync version for pool.interact)
 pub fn get_recent_sessions_sync(
     conn: &Connection,
@@ -59,24 +81,48 @@ pub fn get_recent_sessions_sync(
     limit: usize,
 ) -> rusqlite::Result<Vec<SessionInfo>> {
     let mut stmt = conn.prepare(
-        "SELECT id, project_id, status, summary, started_at, last_activity, source, resumed_from
+        "SELECT id, project_id, status, summary, started_at, last_activity, source, resumed_from,
+                close_reason, recap
          FROM sessions
          WHERE project_id = ?
          ORDER BY last_activity DESC, rowid DESC
          LIMIT ?",
     )?;
-    let rows = stmt.query_map(params![project_id, limit as i64], |row| {
-        Ok(SessionInfo {
-            id: row.get(0)?,
-            project_id: row.get(1)?,
-            status: row.get(2)?,
-            summary: row.get(3)?,
-            started_at: row.get(4)?,
-            last_activity: row.get(5)?,
-            source: row.get(6)?,
-            resumed_from: row.get(7)?,
-        })
-    })?;
+    let rows = stmt.query_map(params![project_id, limit as i64], session_info_from_row)?;
+    rows.collect()
+}
+
+/// Get recent sessions for a project with their liveness:
+/// `live` (active, recent activity), `idle` (active, silent for `idle_minutes`),
+/// or `closed`. When `liveness` is given, only sessions in that state are returned.
+pub fn get_sessions_with_liveness_sync(
+    conn: &Connection,
+    project_id: i64,
+    limit: usize,
+    idle_minutes: i64,
+    liveness: Option<&str>,
+) -> rusqlite::Result<Vec<(SessionInfo, String)>> {
+    let mut stmt = conn.prepare(
+        "SELECT * FROM (
+            SELECT id, project_id, status, summary, started_at, last_activity, source,
+                   resumed_from, close_reason, recap,
+                   CASE
+                       WHEN status != 'active' THEN 'closed'
+                       WHEN last_activity < datetime('now', '-' || ?3 || ' minutes') THEN 'idle'
+                       ELSE 'live'
+                   END AS liveness,
+                   rowid AS rid
+            FROM sessions
+            WHERE project_id = ?1
+         )
+         WHERE ?4 IS NULL OR liveness = ?4
+         ORDER BY last_activity DESC, rid DESC
+         LIMIT ?2",
+    )?;
+    let rows = stmt.query_map(
+        params![project_id, limit as i64, idle_minutes, liveness],
+        |row| Ok((session_info_from_row(row)?, row.get(10)?)),
+    )?;
     rows.collect()
 }
 
@@ -191,6 +237,15 @@ pub fn get_recent_file_activity_sync(
 
 /// Build session recap - sync version for pool.interact()
 pub fn build_session_recap_sync(conn: &Connection, project_id: Option<i64>) -> String {
+    build_session_recap_with_delta_sync(conn, project_id, None)
+}
+
+/// Build session recap, leading with what changed since the previous session
+pub fn build_session_recap_with_delta_sync(
+    conn: &Connection,
+    project_id: Option<i64>,
+    delta: Option<&super::session_delta::SessionDelta>,
+) -> String {
     use super::project::get_project_info_sync;
     use super::tasks::{get_active_goals_sync, get_pending_tasks_sync};
 
@@ -237,6 +292,10 @@ pub fn build_session_recap_sync(conn: &Connection, project_id: Option<i64>) -> S
     };
     recap_parts.push(header);
 
+    if let Some(delta) = delta {
+        recap_parts.push(delta.format());
+    }
+
     // Recent sessions (excluding current)
     if let Some(pid) = project_id
         && let Ok(sessions) = get_recent_sessions_sync(conn, pid, 2)
@@ -355,17 +414,190 @@ pub fn get_session_stats_sync(
 }
 
 /// Close a session by setting its status to completed and optionally adding a summary.
+/// `reason` records why it was closed (see the `CLOSE_REASON_*` constants).
 /// When `project_id` is provided, only closes the session if it belongs to that project.
 pub fn close_session_sync(
     conn: &Connection,
     session_id: &str,
     summary: Option<&str>,
     project_id: Option<i64>,
+    reason: &str,
 ) -> rusqlite::Result<()> {
     conn.execute(
-        "UPDATE sessions SET status = 'completed', summary = COALESCE(?2, summary), last_activity = datetime('now')
+        "UPDATE sessions SET status = 'completed', summary = COALESCE(?2, summary),
+                close_reason = ?4, last_activity = datetime('now')
          WHERE id = ?1 AND (?3 IS NULL OR project_id = ?3)",
-        params![session_id, summary, project_id],
+        params![session_id, summary, project_id, reason],
+    )?;
+    Ok(())
+}
+
+/// Most items listed per section of a session activity recap
+const MAX_ACTIVITY_ITEMS: usize = 5;
+
+/// Build a recap of what one session did: goals it touched, tasks completed
+/// while it ran, files it accessed, tools it called and decisions it captured.
+/// Returns None when the session doesn't exist or recorded no activity.
+pub fn build_session_activity_recap_sync(
+    conn: &Connection,
+    session_id: &str,
+) -> rusqlite::Result<Option<String>> {
+    use rusqlite::OptionalExtension;
+
+    let Some((project_id, started_at, last_activity)) = conn
+        .query_row(
+            "SELECT project_id, started_at, last_activity FROM sessions WHERE id = ?1",
+            [session_id],
+            |row| {
+                Ok((
+                    row.get::<_, Option<i64>>(0)?,
+                    row.get::<_, Option<String>>(1)?,
+                    row.get::<_, String>(2)?,
+                ))
+            },
+        )
+        .optional()?
+    else {
+        return Ok(None);
+    };
+    let started_at = started_at.unwrap_or_else(|| last_activity.clone());
+    let limit = MAX_ACTIVITY_ITEMS as i64;
+    let mut parts = Vec::new();
+
+    let mut stmt = conn.prepare(
+        "SELECT g.title, g.progress_percent, g.status, GROUP_CONCAT(DISTINCT sg.interaction_type)
+         FROM session_goals sg
+         JOIN goals g ON g.id = sg.goal_id
+         WHERE sg.session_id = ?1
+         GROUP BY g.id
+         ORDER BY MAX(sg.created_at) DESC
+         LIMIT ?2",
+    )?;
+    let goal_lines: Vec<String> = stmt
+        .query_map(params![session_id, limit], |row| {
+            Ok(format!(
+                "• {} ({}%, {}) - {}",
+                row.get::<_, String>(0)?,
+                row.get::<_, Option<i64>>(1)?.unwrap_or(0),
+                row.get::<_, Option<String>>(2)?.unwrap_or_default(),
+                row.get::<_, String>(3)?
+            ))
+        })?
+        .collect::<rusqlite::Result<_>>()?;
+    if !goal_lines.is_empty() {
+        parts.push(format!("Goals worked on:\n{}", goal_lines.join("\n")));
+    }
+
+    if let Some(pid) = project_id {
+        let mut stmt = conn.prepare(
+            "SELECT title FROM tasks
+             WHERE project_id = ?1 AND status = 'completed'
+               AND completed_at >= ?2 AND completed_at <= ?3
+             ORDER BY completed_at DESC
+             LIMIT ?4",
+        )?;
+        let task_lines: Vec<String> = stmt
+            .query_map(params![pid, started_at, last_activity, limit], |row| {
+                Ok(format!("• [x] {}", row.get::<_, String>(0)?))
+            })?
+            .collect::<rusqlite::Result<_>>()?;
+        if !task_lines.is_empty() {
+            parts.push(format!("Tasks completed:\n{}", task_lines.join("\n")));
+        }
+    }
+
+    let mut stmt = conn.prepare(
+        "SELECT json_extract(event_data, '$.file_path') AS fp
+         FROM session_behavior_log
+         WHERE session_id = ?1 AND event_type = 'file_access'
+           AND json_extract(event_data, '$.file_path') IS NOT NULL
+         GROUP BY fp
+         ORDER BY MAX(created_at) DESC
+         LIMIT ?2",
+    )?;
+    let files: Vec<String> = stmt
+        .query_map(params![session_id, limit], |row| row.get(0))?
+        .collect::<rusqlite::Result<_>>()?;
+    if !files.is_empty() {
+        parts.push(format!("Files touched:\n* {}", files.join(", ")));
+    }
+
+    let mut stmt = conn.prepare(
+        "SELECT tool_name, COUNT(*), SUM(CASE WHEN success = 0 THEN 1 ELSE 0 END)
+         FROM tool_history
+         WHERE session_id = ?1
+         GROUP BY tool_name
+         ORDER BY COUNT(*) DESC, tool_name
+         LIMIT ?2",
+    )?;
+    let tools: Vec<String> = stmt
+        .query_map(params![session_id, limit], |row| {
+            let name: String = row.get(0)?;
+            let calls: i64 = row.get(1)?;
+            let failed: i64 = row.get(2)?;
+            Ok(if failed > 0 {
+                format!("{} x{} ({} failed)", name, calls, failed)
+            } else {
+                format!("{} x{}", name, calls)
+            })
+        })?
+        .collect::<rusqlite::Result<_>>()?;
+    if !tools.is_empty() {
+        parts.push(format!("Tools used: {}", tools.join(", ")));
+    }
+
+    let snapshot: Option<String> = conn
+        .query_row(
+            "SELECT snapshot FROM session_snapshots WHERE session_id = ?1",
+            [session_id],
+            |row| row.get(0),
+        )
+        .optional()?;
+    let mut decisions: Vec<String> = snapshot
+        .and_then(|json| serde_json::from_str::<serde_json::Value>(&json).ok())
+        .and_then(|snap| {
+            snap["compaction_context"]["decisions"]
+                .as_array()
+                .map(|items| {
+                    items
+                        .iter()
+                        .filter_map(|d| d.as_str())
+                        .map(|d| format!("• {}", d))
+                        .collect()
+                })
+        })
+        .unwrap_or_default();
+    if !decisions.is_empty() {
+        // Keep the latest decisions
+        let skip = decisions.len().saturating_sub(MAX_ACTIVITY_ITEMS);
+        decisions.drain(..skip);
+        parts.push(format!("Decisions:\n{}", decisions.join("\n")));
+    }
+
+    if parts.is_empty() {
+        return Ok(None);
+    }
+    parts.insert(
+        0,
+        format!(
+            "--- Session {} ({} .. {} UTC) ---",
+            truncate_at_boundary(session_id, 8),
+            truncate_at_boundary(&started_at, 16),
+            truncate_at_boundary(&last_activity, 16)
+        ),
+    );
+    Ok(Some(parts.join("\n\n")))
+}
+
+/// Store the activity recap generated when a session was closed
+pub fn set_session_recap_sync(
+    conn: &Connection,
+    session_id: &str,
+    recap: &str,
+) -> rusqlite::Result<()> {
+    conn.execute(
+        "UPDATE sessions SET recap = ?2 WHERE id = ?1",
+        params![session_id, recap],
     )?;
     Ok(())
 }
@@ -563,14 +795,95 @@ pub fn log_tool_call_sync(
     full_result: Option<&str>,
     success: bool,
 ) -> rusqlite::Result<i64> {
+    record_tool_call_sync(
+        conn,
+        &ToolCallRecord {
+            session_id,
+            tool_name,
+            arguments,
+            result_summary,
+            full_result,
+            success,
+            duration_ms: None,
+            error_message: None,
+        },
+    )
+}
+
+/// A completed tool call with timing, as recorded by the MCP tool call wrapper
+#[derive(Debug, Clone)]
+pub struct ToolCallRecord<'a> {
+    pub session_id: &'a str,
+    pub tool_name: &'a str,
+    pub arguments: &'a str,
+    pub result_summary: &'a str,
+    pub full_result: Option<&'a str>,
+    pub success: bool,
+    pub duration_ms: Option<i64>,
+    pub error_message: Option<&'a str>,
+}
+
+/// Record a tool call (including duration and error message) - sync version
+pub fn record_tool_call_sync(conn: &Connection, call: &ToolCallRecord) -> rusqlite::Result<i64> {
     conn.execute(
-        "INSERT INTO tool_history (session_id, tool_name, arguments, result_summary, full_result, success, created_at)
-         VALUES (?1, ?2, ?3, ?4, ?5, ?6, datetime('now'))",
-        params![session_id, tool_name, arguments, result_summary, full_result, success as i32],
+        "INSERT INTO tool_history (session_id, tool_name, arguments, result_summary, full_result,
+                                   success, duration_ms, error_message, created_at)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, datetime('now'))",
+        params![
+            call.session_id,
+            call.tool_name,
+            call.arguments,
+            call.result_summary,
+            call.full_result,
+            call.success as i32,
+            call.duration_ms,
+            call.error_message
+        ],
     )?;
     Ok(conn.last_insert_rowid())
 }
 
+/// A failed tool call, as returned by `get_recent_tool_errors_sync`
+#[derive(Debug, Clone)]
+pub struct ToolErrorRow {
+    pub id: i64,
+    pub tool_name: String,
+    pub created_at: String,
+    pub arguments: Option<String>,
+    pub error_message: Option<String>,
+}
+
+/// Most recent failed tool calls for a project, newest first - sync version.
+/// Falls back to the result summary for rows logged before error_message existed.
+pub fn get_recent_tool_errors_sync(
+    conn: &Connection,
+    project_id: i64,
+    tool_name: Option<&str>,
+    limit: usize,
+) -> rusqlite::Result<Vec<ToolErrorRow>> {
+    let mut stmt = conn.prepare(
+        "SELECT h.id, h.tool_name, h.created_at, h.arguments,
+                COALESCE(h.error_message, h.result_summary)
+         FROM tool_history h
+         JOIN sessions s ON s.id = h.session_id
+         WHERE s.project_id = ?1
+           AND h.success = 0
+           AND (?2 IS NULL OR h.tool_name = ?2)
+         ORDER BY h.created_at DESC, h.id DESC
+         LIMIT ?3",
+    )?;
+    let rows = stmt.query_map(params![project_id, tool_name, limit as i64], |row| {
+        Ok(ToolErrorRow {
+            id: row.get(0)?,
+            tool_name: row.get(1)?,
+            created_at: row.get(2)?,
+            arguments: row.get(3)?,
+            error_message: row.get(4)?,
+        })
+    })?;
+    rows.collect()
+}
+
 /// Update session's last activity timestamp - sync version
 pub fn touch_session_sync(conn: &Connection, session_id: &str) -> rusqlite::Result<()> {
     conn.execute(
@@ -0,0 +1,215 @@
+// crates/mira-server/src/db/session_delta.rs
+// What changed in a project since its previous session ended
+
+use rusqlite::{Connection, OptionalExtension, params};
+
+use crate::utils::truncate_at_boundary;
+
+/// Most items listed per recap category
+const MAX_ITEMS: usize = 5;
+
+/// A goal touched after the previous session ended
+#[derive(Debug, Clone, PartialEq)]
+pub struct GoalDelta {
+    pub id: i64,
+    pub title: String,
+    pub status: String,
+    pub progress_percent: i64,
+    /// Created after the previous session ended
+    pub is_new: bool,
+    /// Milestones completed after the previous session ended
+    pub milestones_completed: Vec<String>,
+}
+
+/// Changes since the previous session, for the session recap
+#[derive(Debug, Clone, PartialEq)]
+pub struct SessionDelta {
+    pub previous_session_id: String,
+    /// UTC `YYYY-MM-DD HH:MM:SS`
+    pub ended_at: String,
+    pub ended_at_unix: i64,
+    pub goals: Vec<GoalDelta>,
+    pub completed_tasks: Vec<String>,
+    /// Decisions captured in session snapshots since the previous session started
+    pub decisions: Vec<String>,
+    /// Filled in by the caller from git; empty when not a git repo
+    pub changed_files: Vec<String>,
+}
+
+/// Compute the delta since the most recent completed session of a project.
+/// `current_session_id` is never treated as the previous session.
+/// Returns None when the project has no earlier session.
+pub fn get_session_delta_sync(
+    conn: &Connection,
+    project_id: i64,
+    current_session_id: Option<&str>,
+) -> rusqlite::Result<Option<SessionDelta>> {
+    let previous = conn
+        .query_row(
+            "SELECT id, started_at, last_activity, CAST(strftime('%s', last_activity) AS INTEGER)
+             FROM sessions
+             WHERE project_id = ?1 AND status = 'completed' AND (?2 IS NULL OR id != ?2)
+             ORDER BY last_activity DESC
+             LIMIT 1",
+            params![project_id, current_session_id],
+            |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, Option<String>>(1)?,
+                    row.get::<_, String>(2)?,
+                    row.get::<_, i64>(3)?,
+                ))
+            },
+        )
+        .optional()?;
+    let Some((previous_session_id, started_at, ended_at, ended_at_unix)) = previous else {
+        return Ok(None);
+    };
+
+    let mut stmt = conn.prepare(
+        "SELECT id, title, status, progress_percent, created_at > ?2
+         FROM goals
+         WHERE project_id = ?1 AND updated_at > ?2
+         ORDER BY updated_at DESC
+         LIMIT ?3",
+    )?;
+    let mut goals = stmt
+        .query_map(params![project_id, ended_at, MAX_ITEMS as i64], |row| {
+            Ok(GoalDelta {
+                id: row.get(0)?,
+                title: row.get(1)?,
+                status: row.get::<_, Option<String>>(2)?.unwrap_or_default(),
+                progress_percent: row.get::<_, Option<i64>>(3)?.unwrap_or(0),
+                is_new: row.get(4)?,
+                milestones_completed: Vec::new(),
+            })
+        })?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+
+    let mut stmt = conn.prepare(
+        "SELECT title FROM milestones
+         WHERE goal_id = ?1 AND completed = 1 AND completed_at > ?2
+         ORDER BY completed_at",
+    )?;
+    for goal in &mut goals {
+        goal.milestones_completed = stmt
+            .query_map(params![goal.id, ended_at], |row| row.get(0))?
+            .collect::<rusqlite::Result<_>>()?;
+    }
+
+    let mut stmt = conn.prepare(
+        "SELECT title FROM tasks
+         WHERE project_id = ?1 AND status = 'completed' AND completed_at > ?2
+         ORDER BY completed_at DESC
+         LIMIT ?3",
+    )?;
+    let completed_tasks = stmt
+        .query_map(params![project_id, ended_at, MAX_ITEMS as i64], |row| {
+            row.get(0)
+        })?
+        .collect::<rusqlite::Result<_>>()?;
+
+    // Snapshots are rewritten at every stop, so the previous session's own
+    // snapshot is newer than its start; decisions from it are included.
+    let mut stmt = conn.prepare(
+        "SELECT ss.snapshot FROM session_snapshots ss
+         JOIN sessions s ON s.id = ss.session_id
+         WHERE s.project_id = ?1 AND ss.created_at >= ?2
+         ORDER BY ss.created_at",
+    )?;
+    let snapshots: Vec<String> = stmt
+        .query_map(
+            params![project_id, started_at.as_deref().unwrap_or(&ended_at)],
+            |row| row.get(0),
+        )?
+        .collect::<rusqlite::Result<_>>()?;
+    let mut decisions: Vec<String> = Vec::new();
+    for snapshot in snapshots {
+        let Ok(json) = serde_json::from_str::<serde_json::Value>(&snapshot) else {
+            continue;
+        };
+        let Some(items) = json["compaction_context"]["decisions"].as_array() else {
+            continue;
+        };
+        for decision in items.iter().filter_map(|d| d.as_str()) {
+            if !decisions.iter().any(|d| d == decision) {
+                decisions.push(decision.to_string());
+            }
+        }
+    }
+    let skip = decisions.len().saturating_sub(MAX_ITEMS);
+    decisions.drain(..skip);
+
+    Ok(Some(SessionDelta {
+        previous_session_id,
+        ended_at,
+        ended_at_unix,
+        goals,
+        completed_tasks,
+        decisions,
+        changed_files: Vec::new(),
+    }))
+}
+
+impl SessionDelta {
+    pub fn is_empty(&self) -> bool {
+        self.goals.is_empty()
+            && self.completed_tasks.is_empty()
+            && self.decisions.is_empty()
+            && self.changed_files.is_empty()
+    }
+
+    /// Render as a recap section
+    pub fn format(&self) -> String {
+        let header = format!(
+            "Since last session (ended {} UTC):",
+            truncate_at_boundary(&self.ended_at, 16)
+        );
+        if self.is_empty() {
+            return format!("{} no goal, task or file changes", header);
+        }
+
+        let mut lines = vec![header];
+        for goal in &self.goals {
+            let mut line = format!(
+                "• Goal {}: {} ({}%, {})",
+                if goal.is_new { "added" } else { "advanced" },
+                goal.title,
+                goal.progress_percent,
+                goal.status
+            );
+            if !goal.milestones_completed.is_empty() {
+                line.push_str(&format!(
+                    " - milestones done: {}",
+                    goal.milestones_completed.join(", ")
+                ));
+            }
+            lines.push(line);
+        }
+        for task in &self.completed_tasks {
+            lines.push(format!("• Task completed: {}", task));
+        }
+        for decision in &self.decisions {
+            lines.push(format!("• Decision: {}", decision));
+        }
+        if !self.changed_files.is_empty() {
+            let shown: Vec<&str> = self
+                .changed_files
+                .iter()
+                .take(10)
+                .map(String::as_str)
+                .collect();
+            let more = self.changed_files.len() - shown.len();
+            let mut line = format!(
+                "• Files changed ({}): {}",
+                self.changed_files.len(),
+                shown.join(", ")
+            );
+            if more > 0 {
+                line.push_str(&format!(", +{} more", more));
+            }
+            lines.push(line);
+        }
+        lines.join("\n")
+    }
+}
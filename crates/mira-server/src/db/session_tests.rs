@@ -3,9 +3,9 @@
 
 use super::test_support::{setup_second_project, setup_test_pool, setup_test_pool_with_project};
 use super::{
-    build_session_recap_sync, create_goal_sync, create_session_sync, create_task_sync,
-    get_history_after_sync, get_recent_sessions_sync, get_session_history_sync,
-    get_session_stats_sync, log_tool_call_sync, touch_session_sync,
+    build_session_activity_recap_sync, build_session_recap_sync, create_goal_sync,
+    create_session_sync, create_task_sync, get_history_after_sync, get_recent_sessions_sync,
+    get_session_history_sync, get_session_stats_sync, log_tool_call_sync, touch_session_sync,
 };
 
 #[cfg(test)]
@@ -944,4 +944,164 @@ mod tests {
         .map_err(Into::into));
         assert_eq!(history[0].arguments, Some(special_args.to_string()));
     }
+
+    // ═══════════════════════════════════════
+    // Session delta Tests
+    // ═══════════════════════════════════════
+
+    #[tokio::test]
+    async fn test_session_delta_lists_changes_between_sessions() {
+        use super::super::session_delta::get_session_delta_sync;
+        use super::super::test_support::{seed_goal, seed_session, seed_session_snapshot};
+        use super::super::{
+            build_session_recap_with_delta_sync, complete_milestone_sync, create_milestone_sync,
+            update_goal_progress_from_milestones_sync, update_task_sync,
+        };
+
+        let (pool, project_id) = setup_test_pool_with_project().await;
+
+        let delta = db!(pool, |conn| {
+            // Previous session: ran from 3h to 2h ago
+            seed_session(conn, "prev-session", project_id, "completed");
+            conn.execute(
+                "UPDATE sessions SET started_at = datetime('now', '-3 hours'),
+                     last_activity = datetime('now', '-2 hours')
+                 WHERE id = 'prev-session'",
+                [],
+            )?;
+            seed_session_snapshot(
+                conn,
+                "prev-session",
+                r#"{"compaction_context": {"decisions": ["chose SQLite"]}}"#,
+            );
+
+            // State as it was before the previous session ended
+            let stalled = seed_goal(conn, project_id, "Stalled goal", "in_progress", 20);
+            let advancing = seed_goal(conn, project_id, "Advancing goal", "in_progress", 0);
+            let milestone = create_milestone_sync(conn, advancing, "Write tests", None)?;
+            create_milestone_sync(conn, advancing, "Ship", None)?;
+            let old_task = create_task_sync(
+                conn,
+                Some(project_id),
+                None,
+                "Old task",
+                None,
+                Some("completed"),
+                None,
+            )?;
+            let finished = create_task_sync(
+                conn,
+                Some(project_id),
+                None,
+                "Finished task",
+                None,
+                None,
+                None,
+            )?;
+            conn.execute(
+                "UPDATE goals SET created_at = datetime('now', '-4 hours'),
+                     updated_at = datetime('now', '-4 hours')
+                 WHERE id IN (?1, ?2)",
+                rusqlite::params![stalled, advancing],
+            )?;
+            conn.execute(
+                "UPDATE tasks SET completed_at = datetime('now', '-4 hours') WHERE id = ?",
+                [old_task],
+            )?;
+
+            // Work done between the two sessions
+            complete_milestone_sync(conn, milestone, None)?;
+            update_goal_progress_from_milestones_sync(conn, advancing)?;
+            update_task_sync(conn, finished, None, Some("completed"), None)?;
+
+            seed_session(conn, "current-session", project_id, "active");
+            Ok::<_, anyhow::Error>(
+                get_session_delta_sync(conn, project_id, Some("current-session"))?.unwrap(),
+            )
+        });
+
+        assert_eq!(delta.previous_session_id, "prev-session");
+        let goals: Vec<&str> = delta.goals.iter().map(|g| g.title.as_str()).collect();
+        assert_eq!(goals, vec!["Advancing goal"]);
+        assert!(!delta.goals[0].is_new);
+        assert_eq!(delta.goals[0].progress_percent, 50);
+        assert_eq!(delta.goals[0].milestones_completed, vec!["Write tests"]);
+        assert_eq!(delta.completed_tasks, vec!["Finished task"]);
+        assert_eq!(delta.decisions, vec!["chose SQLite"]);
+
+        let recap = db!(pool, |conn| Ok::<_, anyhow::Error>(
+            build_session_recap_with_delta_sync(conn, Some(project_id), Some(&delta))
+        ));
+        assert!(recap.contains("Since last session"), "Recap was: {}", recap);
+        assert!(recap.contains("Goal advanced: Advancing goal (50%, in_progress)"));
+        assert!(recap.contains("Task completed: Finished task"));
+        assert!(!recap.contains("Old task"), "Recap was: {}", recap);
+    }
+
+    #[tokio::test]
+    async fn test_session_delta_none_without_previous_session() {
+        use super::super::session_delta::get_session_delta_sync;
+        use super::super::test_support::seed_session;
+
+        let (pool, project_id) = setup_test_pool_with_project().await;
+        let delta = db!(pool, |conn| {
+            seed_session(conn, "only-session", project_id, "active");
+            Ok::<_, anyhow::Error>(get_session_delta_sync(
+                conn,
+                project_id,
+                Some("only-session"),
+            )?)
+        });
+        assert!(delta.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_session_activity_recap_covers_only_that_session() {
+        use super::super::record_session_goal_sync;
+        use super::super::test_support::{seed_goal, seed_session, seed_session_snapshot};
+
+        let (pool, project_id) = setup_test_pool_with_project().await;
+        let (recap, empty) = db!(pool, |conn| {
+            seed_session(conn, "sess-a", project_id, "active");
+            seed_session(conn, "sess-b", project_id, "active");
+            let goal_id = seed_goal(conn, project_id, "Ship recaps", "in_progress", 40);
+            record_session_goal_sync(conn, "sess-a", goal_id, "updated")?;
+            log_tool_call_sync(conn, "sess-a", "Edit", "{}", "ok", None, true)?;
+            log_tool_call_sync(conn, "sess-a", "Edit", "{}", "boom", None, false)?;
+            log_tool_call_sync(conn, "sess-b", "Bash", "{}", "ok", None, true)?;
+            for (session, file) in [("sess-a", "src/recap.rs"), ("sess-b", "src/other.rs")] {
+                conn.execute(
+                    "INSERT INTO session_behavior_log (project_id, session_id, event_type, event_data)
+                     VALUES (?1, ?2, 'file_access', json_object('file_path', ?3))",
+                    rusqlite::params![project_id, session, file],
+                )?;
+            }
+            seed_session_snapshot(
+                conn,
+                "sess-a",
+                r#"{"compaction_context": {"decisions": ["Store recaps per session"]}}"#,
+            );
+            seed_session(conn, "sess-idle", project_id, "active");
+            Ok::<_, anyhow::Error>((
+                build_session_activity_recap_sync(conn, "sess-a")?,
+                build_session_activity_recap_sync(conn, "sess-idle")?,
+            ))
+        });
+
+        let recap = recap.expect("session with activity should have a recap");
+        assert!(recap.starts_with("--- Session sess-a"), "recap: {recap}");
+        assert!(recap.contains("Ship recaps (40%, in_progress) - updated"));
+        assert!(recap.contains("Edit x2 (1 failed)"));
+        assert!(recap.contains("src/recap.rs"));
+        assert!(recap.contains("Store recaps per session"));
+        assert!(
+            !recap.contains("Bash"),
+            "other session's tools leaked: {recap}"
+        );
+        assert!(
+            !recap.contains("src/other.rs"),
+            "other session's files leaked: {recap}"
+        );
+        assert!(empty.is_none());
+    }
 }
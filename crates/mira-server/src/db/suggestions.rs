@@ -0,0 +1,259 @@
+// crates/mira-server/src/db/suggestions.rs
+// Cheap SQL-driven "what's falling through the cracks" suggestions
+
+use std::collections::HashMap;
+
+use rusqlite::{Connection, params};
+
+/// Window for "recently changed" files
+const RECENT_CHANGE_DAYS: i64 = 7;
+/// A file must change in at least this many test-free diffs to be flagged
+const MIN_UNTESTED_CHANGES: usize = 2;
+
+pub const KIND_STALE_GOAL: &str = "stale_goal";
+pub const KIND_UNTOUCHED_TASK: &str = "untouched_task";
+pub const KIND_UNTESTED_CHANGE: &str = "untested_change";
+
+/// One proactive suggestion with a one-line rationale
+#[derive(Debug, Clone)]
+pub struct ProactiveSuggestion {
+    /// `stale_goal`, `untouched_task`, or `untested_change`
+    pub kind: &'static str,
+    /// Goal/task ID, or None for file suggestions
+    pub id: Option<i64>,
+    /// Goal/task title or file path
+    pub subject: String,
+    pub rationale: String,
+}
+
+/// Collect suggestions for a project: in-progress goals with no update in
+/// `stale_days`, open tasks created more than `stale_days` ago, and files
+/// changed repeatedly in the last week by diffs that touched no tests.
+///
+/// Each kind is capped at `limit_per_kind`; stalest/most-churned first.
+pub fn get_proactive_suggestions_sync(
+    conn: &Connection,
+    project_id: i64,
+    stale_days: i64,
+    limit_per_kind: usize,
+) -> rusqlite::Result<Vec<ProactiveSuggestion>> {
+    let cutoff = format!("-{} days", stale_days);
+    let mut out = Vec::new();
+
+    let mut stmt = conn.prepare(
+        "SELECT id, title, progress_percent,
+                CAST(julianday('now') - julianday(COALESCE(updated_at, created_at)) AS INTEGER)
+         FROM goals
+         WHERE project_id = ?1 AND status = 'in_progress'
+           AND COALESCE(updated_at, created_at) < datetime('now', ?2)
+         ORDER BY COALESCE(updated_at, created_at) ASC
+         LIMIT ?3",
+    )?;
+    let goals = stmt.query_map(params![project_id, cutoff, limit_per_kind as i64], |row| {
+        let progress: Option<i64> = row.get(2)?;
+        let days: i64 = row.get(3)?;
+        Ok(ProactiveSuggestion {
+            kind: KIND_STALE_GOAL,
+            id: Some(row.get(0)?),
+            subject: row.get(1)?,
+            rationale: format!(
+                "In progress at {}% with no update for {} days",
+                progress.unwrap_or(0),
+                days
+            ),
+        })
+    })?;
+    for goal in goals {
+        out.push(goal?);
+    }
+
+    let mut stmt = conn.prepare(
+        "SELECT id, title, status,
+                CAST(julianday('now') - julianday(created_at) AS INTEGER)
+         FROM tasks
+         WHERE project_id = ?1 AND status != 'completed'
+           AND created_at < datetime('now', ?2)
+         ORDER BY created_at ASC
+         LIMIT ?3",
+    )?;
+    let tasks = stmt.query_map(params![project_id, cutoff, limit_per_kind as i64], |row| {
+        let status: Option<String> = row.get(2)?;
+        let days: i64 = row.get(3)?;
+        Ok(ProactiveSuggestion {
+            kind: KIND_UNTOUCHED_TASK,
+            id: Some(row.get(0)?),
+            subject: row.get(1)?,
+            rationale: format!(
+                "Still {} {} days after it was created",
+                status.as_deref().unwrap_or("pending"),
+                days
+            ),
+        })
+    })?;
+    for task in tasks {
+        out.push(task?);
+    }
+
+    out.extend(untested_change_suggestions(
+        conn,
+        project_id,
+        limit_per_kind,
+    )?);
+    Ok(out)
+}
+
+/// Files changed in several recent diffs where none of those diffs touched a test
+fn untested_change_suggestions(
+    conn: &Connection,
+    project_id: i64,
+    limit: usize,
+) -> rusqlite::Result<Vec<ProactiveSuggestion>> {
+    let mut stmt = conn.prepare(
+        "SELECT files_json FROM diff_analyses
+         WHERE project_id = ?1 AND files_json IS NOT NULL
+           AND created_at >= datetime('now', ?2)",
+    )?;
+    let diffs = stmt.query_map(
+        params![project_id, format!("-{} days", RECENT_CHANGE_DAYS)],
+        |row| row.get::<_, String>(0),
+    )?;
+
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    for files_json in diffs {
+        let Ok(files) = serde_json::from_str::<Vec<String>>(&files_json?) else {
+            continue;
+        };
+        if files.iter().any(|f| is_test_path(f)) {
+            continue;
+        }
+        for file in files {
+            *counts.entry(file).or_insert(0) += 1;
+        }
+    }
+
+    let mut churned: Vec<(String, usize)> = counts
+        .into_iter()
+        .filter(|(_, n)| *n >= MIN_UNTESTED_CHANGES)
+        .collect();
+    churned.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    churned.truncate(limit);
+
+    Ok(churned
+        .into_iter()
+        .map(|(file, n)| ProactiveSuggestion {
+            kind: KIND_UNTESTED_CHANGE,
+            id: None,
+            rationale: format!(
+                "Changed in {} diffs in the last {} days, none of which touched tests",
+                n, RECENT_CHANGE_DAYS
+            ),
+            subject: file,
+        })
+        .collect())
+}
+
+/// Heuristic: does this path look like a test file?
+pub(crate) fn is_test_path(path: &str) -> bool {
+    let lower = path.to_lowercase();
+    let name = lower.rsplit('/').next().unwrap_or(&lower);
+    lower
+        .split('/')
+        .any(|seg| seg == "tests" || seg == "test" || seg == "__tests__")
+        || name.starts_with("test_")
+        || name.contains("_test.")
+        || name.contains(".test.")
+        || name.contains(".spec.")
+        || name.ends_with("_tests.rs")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::pool::DatabasePool;
+
+    fn insert_project(conn: &Connection) -> i64 {
+        conn.execute("INSERT INTO projects (path) VALUES ('/p')", [])
+            .unwrap();
+        conn.last_insert_rowid()
+    }
+
+    fn insert_diff(conn: &Connection, project_id: i64, files: &str, age: &str) {
+        conn.execute(
+            "INSERT INTO diff_analyses (project_id, from_commit, to_commit, files_json, created_at)
+             VALUES (?1, 'a', 'b', ?2, datetime('now', ?3))",
+            params![project_id, files, age],
+        )
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_stale_goals_and_tasks_surface_fresh_ones_dont() {
+        let pool = DatabasePool::open_in_memory().await.unwrap();
+        pool.interact(|conn| {
+            let pid = insert_project(conn);
+            conn.execute_batch(&format!(
+                "INSERT INTO goals (project_id, title, status, progress_percent, updated_at) VALUES
+                     ({pid}, 'stale goal', 'in_progress', 40, datetime('now', '-30 days')),
+                     ({pid}, 'fresh goal', 'in_progress', 10, datetime('now', '-1 days')),
+                     ({pid}, 'old planned goal', 'planning', 0, datetime('now', '-30 days'));
+                 INSERT INTO tasks (project_id, title, status, created_at) VALUES
+                     ({pid}, 'old task', 'pending', datetime('now', '-20 days')),
+                     ({pid}, 'new task', 'pending', datetime('now', '-2 days')),
+                     ({pid}, 'old done task', 'completed', datetime('now', '-20 days'));"
+            ))?;
+
+            let found = get_proactive_suggestions_sync(conn, pid, 14, 10).unwrap();
+            let subjects: Vec<(&str, &str)> =
+                found.iter().map(|s| (s.kind, s.subject.as_str())).collect();
+            assert_eq!(
+                subjects,
+                vec![
+                    (KIND_STALE_GOAL, "stale goal"),
+                    (KIND_UNTOUCHED_TASK, "old task")
+                ]
+            );
+            assert!(found[0].rationale.contains("40%"), "{}", found[0].rationale);
+            assert!(
+                found[0].rationale.contains("30 days"),
+                "{}",
+                found[0].rationale
+            );
+            Ok(())
+        })
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_untested_churn_surfaces() {
+        let pool = DatabasePool::open_in_memory().await.unwrap();
+        pool.interact(|conn| {
+            let pid = insert_project(conn);
+            insert_diff(conn, pid, r#"["src/risky.rs","src/a.rs"]"#, "-1 days");
+            insert_diff(conn, pid, r#"["src/risky.rs"]"#, "-2 days");
+            // Covered by a test change: doesn't count
+            insert_diff(conn, pid, r#"["src/a.rs","tests/a_test.rs"]"#, "-1 days");
+            // Too old to count
+            insert_diff(conn, pid, r#"["src/a.rs"]"#, "-30 days");
+
+            let found = get_proactive_suggestions_sync(conn, pid, 14, 10).unwrap();
+            assert_eq!(found.len(), 1);
+            assert_eq!(found[0].kind, KIND_UNTESTED_CHANGE);
+            assert_eq!(found[0].subject, "src/risky.rs");
+            assert!(found[0].rationale.contains("2 diffs"));
+            Ok(())
+        })
+        .await
+        .unwrap();
+    }
+
+    #[test]
+    fn test_is_test_path() {
+        assert!(is_test_path("tests/integration.rs"));
+        assert!(is_test_path("src/db/project_tests.rs"));
+        assert!(is_test_path("web/app.spec.ts"));
+        assert!(is_test_path("pkg/test_models.py"));
+        assert!(is_test_path("cmd/main_test.go"));
+        assert!(!is_test_path("src/attestation.rs"));
+    }
+}
@@ -0,0 +1,195 @@
+// crates/mira-server/src/db/task_dependencies.rs
+// "Do A before B" ordering between tasks, and the next-actionable query
+
+use std::collections::HashSet;
+
+use anyhow::{Result, bail};
+use rusqlite::{Connection, params};
+
+use super::dependency_graph::check_dependency_cycle;
+use super::tasks::parse_task_row;
+use super::types::Task;
+
+/// Status that keeps an otherwise unblocked task out of "next"
+const STATUS_BLOCKED: &str = "blocked";
+
+/// Record that `task_id` can't start until `depends_on` is completed.
+///
+/// Rejects self-dependencies and any edge that would close a cycle.
+/// Adding an existing edge is a no-op.
+pub fn add_task_dependency_sync(conn: &Connection, task_id: i64, depends_on: i64) -> Result<()> {
+    if task_id == depends_on {
+        bail!("Task {} cannot depend on itself", task_id);
+    }
+    for id in [task_id, depends_on] {
+        let exists: bool = conn.query_row(
+            "SELECT EXISTS(SELECT 1 FROM tasks WHERE id = ?)",
+            [id],
+            |row| row.get(0),
+        )?;
+        if !exists {
+            bail!("Task {} not found", id);
+        }
+    }
+    check_dependency_cycle(
+        conn,
+        "SELECT depends_on FROM task_dependencies WHERE task_id = ?",
+        task_id,
+        depends_on,
+    )?;
+    conn.execute(
+        "INSERT OR IGNORE INTO task_dependencies (task_id, depends_on) VALUES (?, ?)",
+        params![task_id, depends_on],
+    )?;
+    Ok(())
+}
+
+/// Remove a dependency edge. Returns whether one existed.
+pub fn remove_task_dependency_sync(
+    conn: &Connection,
+    task_id: i64,
+    depends_on: i64,
+) -> Result<bool> {
+    let removed = conn.execute(
+        "DELETE FROM task_dependencies WHERE task_id = ? AND depends_on = ?",
+        params![task_id, depends_on],
+    )?;
+    Ok(removed > 0)
+}
+
+/// IDs of the tasks `task_id` directly depends on
+pub fn get_task_dependencies_sync(conn: &Connection, task_id: i64) -> Result<Vec<i64>> {
+    let mut stmt = conn.prepare(
+        "SELECT depends_on FROM task_dependencies WHERE task_id = ? ORDER BY depends_on",
+    )?;
+    let rows = stmt.query_map([task_id], |row| row.get(0))?;
+    Ok(rows.collect::<rusqlite::Result<_>>()?)
+}
+
+/// Tasks that can be started now: not completed, not `blocked`, and with every
+/// dependency completed. Highest priority first, then oldest.
+///
+/// Scoped like `get_pending_tasks_sync` (project tasks plus global ones).
+/// Tasks caught in a dependency cycle are never actionable.
+pub fn get_next_actionable_tasks_sync(
+    conn: &Connection,
+    project_id: Option<i64>,
+    limit: usize,
+) -> Result<Vec<Task>> {
+    let mut stmt = conn.prepare(
+        "SELECT id, project_id, goal_id, title, description, status, priority, created_at
+         FROM tasks
+         WHERE status != 'completed' AND (project_id IS NULL OR project_id = ?1)
+         ORDER BY CASE priority
+             WHEN 'critical' THEN 0 WHEN 'urgent' THEN 0 WHEN 'high' THEN 1
+             WHEN 'medium' THEN 2 WHEN 'low' THEN 3 ELSE 4 END,
+           created_at ASC, id ASC",
+    )?;
+    let open: Vec<Task> = stmt
+        .query_map([project_id], parse_task_row)?
+        .collect::<rusqlite::Result<_>>()?;
+
+    let open_ids: HashSet<i64> = open.iter().map(|t| t.id).collect();
+    let mut stmt = conn.prepare("SELECT task_id, depends_on FROM task_dependencies")?;
+    let edges: Vec<(i64, i64)> = stmt
+        .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+        .collect::<rusqlite::Result<_>>()?;
+
+    // Only edges onto open tasks hold anything back; a completed or deleted
+    // dependency is satisfied.
+    let open_edges: Vec<(i64, i64)> = edges
+        .into_iter()
+        .filter(|(task, dep)| open_ids.contains(task) && open_ids.contains(dep))
+        .collect();
+    // A task on a cycle always waits on another open task, so it never
+    // shows up here even if imported rows slipped past the cycle check.
+    let waiting: HashSet<i64> = open_edges.iter().map(|(task, _)| *task).collect();
+
+    Ok(open
+        .into_iter()
+        .filter(|t| !waiting.contains(&t.id) && t.status != STATUS_BLOCKED)
+        .take(limit)
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::create_task_sync;
+    use crate::db::test_support::setup_test_connection;
+
+    fn task(conn: &Connection, title: &str, priority: &str) -> i64 {
+        create_task_sync(conn, None, None, title, None, None, Some(priority)).unwrap()
+    }
+
+    fn titles(tasks: &[Task]) -> Vec<&str> {
+        tasks.iter().map(|t| t.title.as_str()).collect()
+    }
+
+    #[test]
+    fn test_dependency_cycle_rejected() {
+        let conn = setup_test_connection();
+        let a = task(&conn, "a", "medium");
+        let b = task(&conn, "b", "medium");
+        let c = task(&conn, "c", "medium");
+
+        add_task_dependency_sync(&conn, b, a).unwrap();
+        add_task_dependency_sync(&conn, c, b).unwrap();
+
+        let err = add_task_dependency_sync(&conn, a, c).unwrap_err();
+        assert!(err.to_string().contains("cycle"), "{}", err);
+        assert!(add_task_dependency_sync(&conn, a, a).is_err());
+        assert!(add_task_dependency_sync(&conn, a, 99999).is_err());
+        assert!(get_task_dependencies_sync(&conn, a).unwrap().is_empty());
+
+        // Re-adding an existing edge is fine
+        add_task_dependency_sync(&conn, c, b).unwrap();
+        assert_eq!(get_task_dependencies_sync(&conn, c).unwrap(), vec![b]);
+    }
+
+    #[test]
+    fn test_next_excludes_tasks_with_incomplete_dependencies() {
+        let conn = setup_test_connection();
+        let schema = task(&conn, "schema", "low");
+        let api = task(&conn, "api", "high");
+        task(&conn, "docs", "medium");
+        let stuck = task(&conn, "stuck", "high");
+        add_task_dependency_sync(&conn, api, schema).unwrap();
+        crate::db::update_task_sync(&conn, stuck, None, Some(STATUS_BLOCKED), None).unwrap();
+
+        let next = get_next_actionable_tasks_sync(&conn, None, 10).unwrap();
+        assert_eq!(titles(&next), vec!["docs", "schema"]);
+
+        // Finishing the dependency unlocks the dependent task
+        crate::db::update_task_sync(&conn, schema, None, Some("completed"), None).unwrap();
+        let next = get_next_actionable_tasks_sync(&conn, None, 10).unwrap();
+        assert_eq!(titles(&next), vec!["api", "docs"]);
+        assert_eq!(
+            get_next_actionable_tasks_sync(&conn, None, 1)
+                .unwrap()
+                .len(),
+            1
+        );
+
+        // Deleting a task drops its edges
+        crate::db::delete_task_sync(&conn, schema).unwrap();
+        assert!(get_task_dependencies_sync(&conn, api).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_next_skips_cycles_that_bypassed_the_check() {
+        let conn = setup_test_connection();
+        let a = task(&conn, "a", "high");
+        let b = task(&conn, "b", "high");
+        task(&conn, "c", "low");
+        // Imported rows go straight into the table
+        conn.execute(
+            "INSERT INTO task_dependencies (task_id, depends_on) VALUES (?1, ?2), (?2, ?1)",
+            params![a, b],
+        )
+        .unwrap();
+
+        let next = get_next_actionable_tasks_sync(&conn, None, 10).unwrap();
+        assert_eq!(titles(&next), vec!["c"]);
+    }
+}
@@ -0,0 +1,285 @@
+// crates/mira-server/src/db/task_templates.rs
+// Reusable task checklists (release checklist, weekly audit) with {var} substitution
+
+use std::collections::HashMap;
+
+use anyhow::{Result, bail};
+use rusqlite::{Connection, OptionalExtension, params};
+
+use super::tasks::create_task_sync;
+
+/// A named checklist that can be materialized into tasks
+#[derive(Debug, Clone)]
+pub struct TaskTemplate {
+    pub id: i64,
+    pub project_id: Option<i64>,
+    pub name: String,
+    /// Task titles, may contain `{var}` placeholders
+    pub items: Vec<String>,
+    pub created_at: String,
+}
+
+/// Create or replace a template. Names are unique per project.
+pub fn save_task_template_sync(
+    conn: &Connection,
+    project_id: Option<i64>,
+    name: &str,
+    items: &[String],
+) -> Result<i64> {
+    let name = name.trim();
+    if name.is_empty() {
+        bail!("Template name is required");
+    }
+    if items.iter().all(|i| i.trim().is_empty()) {
+        bail!("Template '{}' needs at least one item", name);
+    }
+    let items: Vec<&str> = items
+        .iter()
+        .map(|i| i.trim())
+        .filter(|i| !i.is_empty())
+        .collect();
+    let items_json = serde_json::to_string(&items)?;
+
+    let tx = conn.unchecked_transaction()?;
+    tx.execute(
+        "DELETE FROM task_templates WHERE name = ?1 AND project_id IS ?2",
+        params![name, project_id],
+    )?;
+    tx.execute(
+        "INSERT INTO task_templates (project_id, name, items_json) VALUES (?, ?, ?)",
+        params![project_id, name, items_json],
+    )?;
+    let id = tx.last_insert_rowid();
+    tx.commit()?;
+    Ok(id)
+}
+
+/// Look up a template by name, preferring the project's own over a global one
+pub fn get_task_template_sync(
+    conn: &Connection,
+    project_id: Option<i64>,
+    name: &str,
+) -> Result<Option<TaskTemplate>> {
+    conn.query_row(
+        "SELECT id, project_id, name, items_json, created_at FROM task_templates
+         WHERE name = ?1 AND (project_id IS ?2 OR project_id IS NULL)
+         ORDER BY project_id IS NULL
+         LIMIT 1",
+        params![name.trim(), project_id],
+        parse_template_row,
+    )
+    .optional()
+    .map_err(Into::into)
+}
+
+/// Templates visible from a project (its own plus global ones), by name
+pub fn list_task_templates_sync(
+    conn: &Connection,
+    project_id: Option<i64>,
+) -> Result<Vec<TaskTemplate>> {
+    let mut stmt = conn.prepare(
+        "SELECT id, project_id, name, items_json, created_at FROM task_templates
+         WHERE project_id IS ?1 OR project_id IS NULL
+         ORDER BY name, project_id IS NULL",
+    )?;
+    let rows = stmt.query_map([project_id], parse_template_row)?;
+    Ok(rows.collect::<rusqlite::Result<_>>()?)
+}
+
+/// Parse TaskTemplate from a row with column order:
+/// (id, project_id, name, items_json, created_at)
+fn parse_template_row(row: &rusqlite::Row) -> rusqlite::Result<TaskTemplate> {
+    let items_json: String = row.get(3)?;
+    let items = serde_json::from_str(&items_json).map_err(|e| {
+        rusqlite::Error::FromSqlConversionFailure(3, rusqlite::types::Type::Text, Box::new(e))
+    })?;
+    Ok(TaskTemplate {
+        id: row.get(0)?,
+        project_id: row.get(1)?,
+        name: row.get(2)?,
+        items,
+        created_at: row.get(4)?,
+    })
+}
+
+/// Create one task per template item, optionally linked to a goal.
+///
+/// Every `{var}` in an item must have a value in `vars`; a missing one fails
+/// before any task is created. Returns the new task IDs in template order.
+pub fn instantiate_task_template_sync(
+    conn: &Connection,
+    project_id: Option<i64>,
+    name: &str,
+    goal_id: Option<i64>,
+    vars: &HashMap<String, String>,
+) -> Result<Vec<i64>> {
+    let Some(template) = get_task_template_sync(conn, project_id, name)? else {
+        bail!("Task template '{}' not found", name);
+    };
+
+    let titles = template
+        .items
+        .iter()
+        .map(|item| fill_placeholders(item, vars))
+        .collect::<Result<Vec<_>>>()?;
+
+    let tx = conn.unchecked_transaction()?;
+    let mut ids = Vec::with_capacity(titles.len());
+    for title in &titles {
+        ids.push(create_task_sync(
+            &tx, project_id, goal_id, title, None, None, None,
+        )?);
+    }
+    tx.commit()?;
+    Ok(ids)
+}
+
+/// Replace `{name}` placeholders. `{{` and `}}` are literal braces.
+fn fill_placeholders(text: &str, vars: &HashMap<String, String>) -> Result<String> {
+    let mut out = String::with_capacity(text.len());
+    let mut missing = Vec::new();
+    let mut rest = text;
+
+    while let Some(pos) = rest.find(['{', '}']) {
+        out.push_str(&rest[..pos]);
+        let tail = &rest[pos..];
+        if tail.starts_with("{{") || tail.starts_with("}}") {
+            out.push_str(&tail[..1]);
+            rest = &tail[2..];
+            continue;
+        }
+        if tail.starts_with('}') {
+            out.push('}');
+            rest = &tail[1..];
+            continue;
+        }
+        match tail[1..].find('}') {
+            Some(end) => {
+                let key = &tail[1..=end];
+                match vars.get(key) {
+                    Some(value) => out.push_str(value),
+                    None => {
+                        if !missing.contains(&key) {
+                            missing.push(key);
+                        }
+                    }
+                }
+                rest = &tail[end + 2..];
+            }
+            None => {
+                out.push_str(tail);
+                rest = "";
+            }
+        }
+    }
+    out.push_str(rest);
+
+    if !missing.is_empty() {
+        bail!(
+            "Missing template variable(s): {}",
+            missing
+                .iter()
+                .map(|k| format!("{{{}}}", k))
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::test_support::setup_test_connection;
+    use crate::db::{create_goal_sync, get_or_create_project_sync, get_task_by_id_sync};
+
+    fn vars(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_instantiate_creates_linked_tasks_with_variables() {
+        let conn = setup_test_connection();
+        let (pid, _) = get_or_create_project_sync(&conn, "/test/project", Some("test")).unwrap();
+        let goal = create_goal_sync(&conn, Some(pid), "Ship it", None, None, None, None).unwrap();
+        save_task_template_sync(
+            &conn,
+            Some(pid),
+            "release",
+            &[
+                "Bump version to {version}".into(),
+                "Tag v{version} and publish".into(),
+            ],
+        )
+        .unwrap();
+
+        let ids = instantiate_task_template_sync(
+            &conn,
+            Some(pid),
+            "release",
+            Some(goal),
+            &vars(&[("version", "1.4.0")]),
+        )
+        .unwrap();
+        assert_eq!(ids.len(), 2);
+
+        let first = get_task_by_id_sync(&conn, ids[0]).unwrap().unwrap();
+        let second = get_task_by_id_sync(&conn, ids[1]).unwrap().unwrap();
+        assert_eq!(first.title, "Bump version to 1.4.0");
+        assert_eq!(second.title, "Tag v1.4.0 and publish");
+        assert_eq!(first.goal_id, Some(goal));
+        assert_eq!(second.project_id, Some(pid));
+    }
+
+    #[test]
+    fn test_missing_variable_creates_nothing() {
+        let conn = setup_test_connection();
+        save_task_template_sync(
+            &conn,
+            None,
+            "audit",
+            &["Run cargo audit".into(), "Update {crate}".into()],
+        )
+        .unwrap();
+
+        let err = instantiate_task_template_sync(&conn, None, "audit", None, &HashMap::new())
+            .unwrap_err();
+        assert!(err.to_string().contains("{crate}"), "{}", err);
+        let count: i64 = conn
+            .query_row("SELECT COUNT(*) FROM tasks", [], |r| r.get(0))
+            .unwrap();
+        assert_eq!(count, 0);
+    }
+
+    #[test]
+    fn test_save_replaces_and_project_shadows_global() {
+        let conn = setup_test_connection();
+        let (pid, _) = get_or_create_project_sync(&conn, "/test/project", Some("test")).unwrap();
+        save_task_template_sync(&conn, None, "weekly", &["global".into()]).unwrap();
+        save_task_template_sync(&conn, Some(pid), "weekly", &["old".into()]).unwrap();
+        save_task_template_sync(&conn, Some(pid), "weekly", &["new".into()]).unwrap();
+
+        let found = get_task_template_sync(&conn, Some(pid), "weekly")
+            .unwrap()
+            .unwrap();
+        assert_eq!(found.items, vec!["new"]);
+        let global = get_task_template_sync(&conn, None, "weekly")
+            .unwrap()
+            .unwrap();
+        assert_eq!(global.items, vec!["global"]);
+        assert_eq!(list_task_templates_sync(&conn, Some(pid)).unwrap().len(), 2);
+        assert!(save_task_template_sync(&conn, None, "empty", &[" ".into()]).is_err());
+    }
+
+    #[test]
+    fn test_fill_placeholders_escapes() {
+        let v = vars(&[("v", "2")]);
+        assert_eq!(fill_placeholders("a {v} b", &v).unwrap(), "a 2 b");
+        assert_eq!(fill_placeholders("{{v}} {v}", &v).unwrap(), "{v} 2");
+        assert_eq!(fill_placeholders("no vars", &v).unwrap(), "no vars");
+        assert!(fill_placeholders("{x}{y}", &v).is_err());
+    }
+}
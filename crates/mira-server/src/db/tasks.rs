@@ -4,6 +4,7 @@
 use anyhow::Result;
 use rusqlite::{OptionalExtension, params};
 
+use super::milestones::update_goal_progress_from_milestones_sync;
 use super::types::{Goal, Task};
 use rusqlite::Connection;
 
@@ -23,7 +24,8 @@ pub fn parse_task_row(row: &rusqlite::Row) -> rusqlite::Result<Task> {
 }
 
 /// Parse Goal from a rusqlite Row with standard column order:
-/// (id, project_id, title, description, status, priority, progress_percent, created_at)
+/// (id, project_id, title, description, status, priority, progress_percent, created_at,
+/// progress_manual)
 pub fn parse_goal_row(row: &rusqlite::Row) -> rusqlite::Result<Goal> {
     Ok(Goal {
         id: row.get(0)?,
@@ -34,6 +36,7 @@ pub fn parse_goal_row(row: &rusqlite::Row) -> rusqlite::Result<Goal> {
         priority: row.get(5)?,
         progress_percent: row.get(6)?,
         created_at: row.get(7)?,
+        progress_manual: row.get::<_, Option<i32>>(8)?.unwrap_or(0) != 0,
     })
 }
 
@@ -123,7 +126,7 @@ pub fn get_active_goals_sync(
     project_id: Option<i64>,
     limit: usize,
 ) -> Result<Vec<Goal>> {
-    let cols = "id, project_id, title, description, status, priority, progress_percent, created_at";
+    let cols = "id, project_id, title, description, status, priority, progress_percent, created_at, progress_manual";
     let lim = limit as i64;
     match project_id {
         Some(pid) => {
@@ -167,10 +170,15 @@ pub fn create_task_sync(
     let status = status.unwrap_or("pending");
     let priority = priority.unwrap_or("medium");
     conn.execute(
-        "INSERT INTO tasks (project_id, goal_id, title, description, status, priority) VALUES (?, ?, ?, ?, ?, ?)",
+        "INSERT INTO tasks (project_id, goal_id, title, description, status, priority, completed_at)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, CASE WHEN ?5 = 'completed' THEN datetime('now') END)",
         params![project_id, goal_id, title, description, status, priority],
     )?;
-    Ok(conn.last_insert_rowid())
+    let id = conn.last_insert_rowid();
+    if let Some(goal_id) = goal_id {
+        update_goal_progress_from_milestones_sync(conn, goal_id)?;
+    }
+    Ok(id)
 }
 
 /// Get tasks with optional status filter (sync version for pool.interact)
@@ -236,7 +244,10 @@ pub fn update_task_sync(
     }
     if let Some(status) = status {
         tx.execute(
-            "UPDATE tasks SET status = ? WHERE id = ?",
+            "UPDATE tasks SET status = ?1,
+                 completed_at = CASE WHEN ?1 = 'completed'
+                     THEN COALESCE(completed_at, datetime('now')) END
+             WHERE id = ?2",
             params![status, id],
         )?;
     }
@@ -246,20 +257,54 @@ pub fn update_task_sync(
             params![priority, id],
         )?;
     }
+    if status.is_some() {
+        rollup_task_goal(&tx, id)?;
+    }
     tx.commit()?;
     Ok(())
 }
 
 /// Delete a task (sync version for pool.interact)
 pub fn delete_task_sync(conn: &Connection, id: i64) -> rusqlite::Result<()> {
-    conn.execute("DELETE FROM tasks WHERE id = ?", [id])?;
+    let tx = conn.unchecked_transaction()?;
+    let goal_id: Option<i64> = tx
+        .query_row("SELECT goal_id FROM tasks WHERE id = ?", [id], |row| {
+            row.get(0)
+        })
+        .optional()?
+        .flatten();
+    tx.execute(
+        "DELETE FROM task_dependencies WHERE task_id = ?1 OR depends_on = ?1",
+        [id],
+    )?;
+    tx.execute("DELETE FROM todo_sync_items WHERE task_id = ?", [id])?;
+    tx.execute("DELETE FROM tasks WHERE id = ?", [id])?;
+    if let Some(goal_id) = goal_id {
+        update_goal_progress_from_milestones_sync(&tx, goal_id)?;
+    }
+    tx.commit()?;
+    Ok(())
+}
+
+/// Re-roll the progress of the goal a task is linked to, if any
+fn rollup_task_goal(conn: &Connection, task_id: i64) -> rusqlite::Result<()> {
+    let goal_id: Option<i64> = conn
+        .query_row("SELECT goal_id FROM tasks WHERE id = ?", [task_id], |row| {
+            row.get(0)
+        })
+        .optional()?
+        .flatten();
+    if let Some(goal_id) = goal_id {
+        update_goal_progress_from_milestones_sync(conn, goal_id)?;
+    }
     Ok(())
 }
 
 /// Get a goal by ID (sync version for pool.interact)
 pub fn get_goal_by_id_sync(conn: &Connection, id: i64) -> Result<Option<Goal>> {
     let sql =
-        "SELECT id, project_id, title, description, status, priority, progress_percent, created_at
+        "SELECT id, project_id, title, description, status, priority, progress_percent, created_at,
+                progress_manual
                FROM goals WHERE id = ?";
     conn.query_row(sql, [id], parse_goal_row)
         .optional()
@@ -278,10 +323,11 @@ pub fn create_goal_sync(
 ) -> rusqlite::Result<i64> {
     let status = status.unwrap_or("planning");
     let priority = priority.unwrap_or("medium");
+    let progress_manual = progress_percent.is_some_and(|p| p > 0);
     let progress_percent = progress_percent.unwrap_or(0);
     conn.execute(
-        "INSERT INTO goals (project_id, title, description, status, priority, progress_percent) VALUES (?, ?, ?, ?, ?, ?)",
-        params![project_id, title, description, status, priority, progress_percent],
+        "INSERT INTO goals (project_id, title, description, status, priority, progress_percent, progress_manual) VALUES (?, ?, ?, ?, ?, ?, ?)",
+        params![project_id, title, description, status, priority, progress_percent, progress_manual],
     )?;
     Ok(conn.last_insert_rowid())
 }
@@ -295,7 +341,7 @@ pub fn get_goals_sync(
     status_filter: Option<&str>,
 ) -> rusqlite::Result<Vec<Goal>> {
     let sf = super::StatusFilter::parse(status_filter);
-    let cols = "id, project_id, title, description, status, priority, progress_percent, created_at";
+    let cols = "id, project_id, title, description, status, priority, progress_percent, created_at, progress_manual";
     let status_clause = match sf.value {
         Some(_) => format!(" AND status {} ?", sf.sql_op()),
         None => String::new(),
@@ -369,7 +415,7 @@ pub fn update_goal_sync(
     }
     if let Some(progress) = progress {
         tx.execute(
-            "UPDATE goals SET progress_percent = ?, updated_at = CURRENT_TIMESTAMP WHERE id = ?",
+            "UPDATE goals SET progress_percent = ?, progress_manual = 1, updated_at = CURRENT_TIMESTAMP WHERE id = ?",
             params![progress, id],
         )?;
     }
@@ -387,6 +433,11 @@ pub fn delete_goal_sync(conn: &Connection, id: i64) -> rusqlite::Result<()> {
     tx.execute("DELETE FROM session_goals WHERE goal_id = ?", [id])?;
     // Delete milestones (no need to orphan, just delete)
     tx.execute("DELETE FROM milestones WHERE goal_id = ?", [id])?;
+    // Drop dependency edges in both directions
+    tx.execute(
+        "DELETE FROM goal_dependencies WHERE goal_id = ?1 OR depends_on = ?1",
+        [id],
+    )?;
     // Now delete the goal
     tx.execute("DELETE FROM goals WHERE id = ?", [id])?;
     tx.commit()?;
@@ -0,0 +1,357 @@
+// crates/mira-server/src/db/todo_sync.rs
+// Two-way sync state between markdown checklist items and tasks
+
+use std::collections::HashSet;
+
+use anyhow::Result;
+use rusqlite::{Connection, OptionalExtension, params};
+
+use super::tasks::{create_task_sync, update_task_sync};
+
+const STATUS_DONE: &str = "completed";
+const STATUS_OPEN: &str = "pending";
+
+/// One `- [ ] text` line parsed from a checklist file
+#[derive(Debug, Clone, PartialEq)]
+pub struct TodoItem {
+    /// 0-based line number in the file
+    pub line: usize,
+    /// Position among the file's checklist items
+    pub ordinal: usize,
+    /// Item text, without the checkbox or any `<!-- mira:task=N -->` marker
+    pub text: String,
+    pub checked: bool,
+    /// Task ID from an embedded `<!-- mira:task=N -->` marker
+    pub task_id: Option<i64>,
+}
+
+/// What one sync pass changed
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TodoSyncReport {
+    /// Tasks created for new checklist items
+    pub created: usize,
+    /// Task titles updated from edited item text
+    pub retitled: usize,
+    /// Tasks completed because their box was checked
+    pub completed: usize,
+    /// Tasks reopened because their box was unchecked
+    pub reopened: usize,
+    /// Links dropped because the item left the file (the task is kept)
+    pub unlinked: usize,
+    /// Boxes to rewrite so the file matches Mira: `(line, checked)`
+    pub write_back: Vec<(usize, bool)>,
+}
+
+impl TodoSyncReport {
+    pub fn is_noop(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Stored link plus the task's current status
+struct LinkRow {
+    id: i64,
+    task_id: i64,
+    item_text: String,
+    ordinal: usize,
+    checked: bool,
+    task_done: bool,
+    /// None when the task has been deleted since the last sync
+    status: Option<String>,
+}
+
+fn load_links(conn: &Connection, project_id: i64, file_path: &str) -> Result<Vec<LinkRow>> {
+    let mut stmt = conn.prepare(
+        "SELECT l.id, l.task_id, l.item_text, l.ordinal, l.checked, l.task_done, t.status
+         FROM todo_sync_items l LEFT JOIN tasks t ON t.id = l.task_id
+         WHERE l.project_id = ? AND l.file_path = ?
+         ORDER BY l.ordinal",
+    )?;
+    let rows = stmt.query_map(params![project_id, file_path], |row| {
+        Ok(LinkRow {
+            id: row.get(0)?,
+            task_id: row.get(1)?,
+            item_text: row.get(2)?,
+            ordinal: row.get::<_, i64>(3)? as usize,
+            checked: row.get(4)?,
+            task_done: row.get(5)?,
+            status: row.get(6)?,
+        })
+    })?;
+    Ok(rows.collect::<rusqlite::Result<_>>()?)
+}
+
+/// Pick the stored link for each item: embedded marker, then identical text
+/// (nearest position first), then same position with edited text.
+/// Returns, per item, an index into `links` or None for a new item.
+fn match_items(items: &[TodoItem], links: &[LinkRow]) -> Vec<Option<usize>> {
+    let mut taken: HashSet<usize> = HashSet::new();
+    let mut matched = vec![None; items.len()];
+
+    for (i, item) in items.iter().enumerate() {
+        if let Some(task_id) = item.task_id
+            && let Some(j) = links.iter().position(|l| l.task_id == task_id)
+            && taken.insert(j)
+        {
+            matched[i] = Some(j);
+        }
+    }
+    for (i, item) in items.iter().enumerate() {
+        if matched[i].is_some() || item.task_id.is_some() {
+            continue;
+        }
+        let best = links
+            .iter()
+            .enumerate()
+            .filter(|(j, l)| !taken.contains(j) && l.item_text == item.text)
+            .min_by_key(|(_, l)| l.ordinal.abs_diff(item.ordinal))
+            .map(|(j, _)| j);
+        if let Some(j) = best {
+            taken.insert(j);
+            matched[i] = Some(j);
+        }
+    }
+    for (i, item) in items.iter().enumerate() {
+        if matched[i].is_some() || item.task_id.is_some() {
+            continue;
+        }
+        if let Some(j) = links
+            .iter()
+            .enumerate()
+            .position(|(j, l)| !taken.contains(&j) && l.ordinal == item.ordinal)
+        {
+            taken.insert(j);
+            matched[i] = Some(j);
+        }
+    }
+    matched
+}
+
+/// Reconcile one checklist file with its tasks.
+///
+/// The file wins on text: an edited item retitles its task. For status, each
+/// side is compared with the state recorded at the last sync. A box checked
+/// or unchecked in the file since then completes or reopens the task. When
+/// Mira's status changed (including when both sides changed), Mira wins and
+/// the box is listed in `write_back`. Items that leave the file lose their
+/// link; their tasks are kept.
+pub fn sync_todo_items_sync(
+    conn: &Connection,
+    project_id: i64,
+    file_path: &str,
+    items: &[TodoItem],
+) -> Result<TodoSyncReport> {
+    let mut links = load_links(conn, project_id, file_path)?;
+    let mut report = TodoSyncReport::default();
+
+    // Links whose task was deleted in Mira no longer hold anything
+    let (live, dead): (Vec<LinkRow>, Vec<LinkRow>) =
+        links.drain(..).partition(|l| l.status.is_some());
+    for link in dead {
+        conn.execute("DELETE FROM todo_sync_items WHERE id = ?", [link.id])?;
+    }
+    let links = live;
+
+    let matched = match_items(items, &links);
+    let mut kept: HashSet<i64> = HashSet::new();
+
+    for (item, link) in items.iter().zip(&matched) {
+        let link = link.map(|j| &links[j]);
+
+        // A marker naming a task of this project that isn't linked yet
+        let marked = match (link, item.task_id) {
+            (None, Some(task_id)) => conn
+                .query_row(
+                    "SELECT status FROM tasks WHERE id = ?1 AND project_id = ?2
+                       AND id NOT IN (SELECT task_id FROM todo_sync_items)",
+                    params![task_id, project_id],
+                    |row| row.get::<_, String>(0),
+                )
+                .optional()?
+                .map(|status| (task_id, status)),
+            _ => None,
+        };
+
+        let (task_id, status, base_checked, base_done) = match (link, marked) {
+            (Some(l), _) => (
+                l.task_id,
+                l.status.clone().unwrap_or_default(),
+                l.checked,
+                l.task_done,
+            ),
+            // Fresh marker link: no history, so only Mira's side can differ
+            (None, Some((task_id, status))) => (task_id, status, item.checked, item.checked),
+            (None, None) => {
+                let status = if item.checked {
+                    STATUS_DONE
+                } else {
+                    STATUS_OPEN
+                };
+                let description = format!("From {}:{}", file_path, item.line + 1);
+                let task_id = create_task_sync(
+                    conn,
+                    Some(project_id),
+                    None,
+                    &item.text,
+                    Some(&description),
+                    Some(status),
+                    None,
+                )?;
+                report.created += 1;
+                (task_id, status.to_string(), item.checked, item.checked)
+            }
+        };
+
+        if link.is_some_and(|l| l.item_text != item.text) {
+            update_task_sync(conn, task_id, Some(&item.text), None, None)?;
+            report.retitled += 1;
+        }
+
+        let mut done = status == STATUS_DONE;
+        if item.checked != done {
+            let mira_changed = done != base_done;
+            let file_changed = item.checked != base_checked;
+            if mira_changed || !file_changed {
+                report.write_back.push((item.line, done));
+            } else {
+                let new_status = if item.checked {
+                    STATUS_DONE
+                } else {
+                    STATUS_OPEN
+                };
+                update_task_sync(conn, task_id, None, Some(new_status), None)?;
+                if item.checked {
+                    report.completed += 1;
+                } else {
+                    report.reopened += 1;
+                }
+                done = item.checked;
+            }
+        }
+
+        conn.execute(
+            "INSERT INTO todo_sync_items
+                 (project_id, file_path, task_id, item_text, ordinal, checked, task_done, synced_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, datetime('now'))
+             ON CONFLICT(task_id) DO UPDATE SET
+                 project_id = ?1, file_path = ?2, item_text = ?4, ordinal = ?5,
+                 checked = ?6, task_done = ?7, synced_at = datetime('now')",
+            params![
+                project_id,
+                file_path,
+                task_id,
+                item.text,
+                item.ordinal as i64,
+                item.checked,
+                done
+            ],
+        )?;
+        kept.insert(task_id);
+    }
+
+    for link in links.iter().filter(|l| !kept.contains(&l.task_id)) {
+        conn.execute("DELETE FROM todo_sync_items WHERE id = ?", [link.id])?;
+        report.unlinked += 1;
+    }
+
+    Ok(report)
+}
+
+/// Task IDs linked to a checklist file, in file order
+pub fn get_linked_task_ids_sync(
+    conn: &Connection,
+    project_id: i64,
+    file_path: &str,
+) -> Result<Vec<i64>> {
+    let mut stmt = conn.prepare(
+        "SELECT task_id FROM todo_sync_items WHERE project_id = ? AND file_path = ? ORDER BY ordinal",
+    )?;
+    let rows = stmt.query_map(params![project_id, file_path], |row| row.get(0))?;
+    Ok(rows.collect::<rusqlite::Result<_>>()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::test_support::setup_test_connection;
+    use crate::db::{get_or_create_project_sync, get_task_by_id_sync};
+
+    fn item(ordinal: usize, text: &str, checked: bool) -> TodoItem {
+        TodoItem {
+            line: ordinal,
+            ordinal,
+            text: text.to_string(),
+            checked,
+            task_id: None,
+        }
+    }
+
+    fn project(conn: &Connection) -> i64 {
+        get_or_create_project_sync(conn, "/todo/project", None)
+            .unwrap()
+            .0
+    }
+
+    fn status(conn: &Connection, task_id: i64) -> String {
+        get_task_by_id_sync(conn, task_id).unwrap().unwrap().status
+    }
+
+    #[test]
+    fn test_edited_text_keeps_task_and_retitles_it() {
+        let conn = setup_test_connection();
+        let pid = project(&conn);
+        let first = vec![item(0, "Write parser", false), item(1, "Add docs", false)];
+        sync_todo_items_sync(&conn, pid, "TODO.md", &first).unwrap();
+        let ids = get_linked_task_ids_sync(&conn, pid, "TODO.md").unwrap();
+
+        // The parser item is reworded in place
+        let second = vec![
+            item(0, "Write the parser", false),
+            item(1, "Add docs", false),
+        ];
+        let report = sync_todo_items_sync(&conn, pid, "TODO.md", &second).unwrap();
+        assert_eq!(report.retitled, 1);
+        assert_eq!(report.created, 0);
+        assert_eq!(
+            get_linked_task_ids_sync(&conn, pid, "TODO.md").unwrap(),
+            ids
+        );
+        let task = get_task_by_id_sync(&conn, ids[0]).unwrap().unwrap();
+        assert_eq!(task.title, "Write the parser");
+    }
+
+    #[test]
+    fn test_mira_wins_when_both_sides_changed() {
+        let conn = setup_test_connection();
+        let pid = project(&conn);
+        sync_todo_items_sync(&conn, pid, "TODO.md", &[item(0, "Ship", true)]).unwrap();
+        let id = get_linked_task_ids_sync(&conn, pid, "TODO.md").unwrap()[0];
+        assert_eq!(status(&conn, id), STATUS_DONE);
+
+        // Mira reopens the task while the file still shows it checked
+        update_task_sync(&conn, id, None, Some("in_progress"), None).unwrap();
+        let report = sync_todo_items_sync(&conn, pid, "TODO.md", &[item(0, "Ship", true)]).unwrap();
+        assert_eq!(report.write_back, vec![(0, false)]);
+        assert_eq!(status(&conn, id), "in_progress");
+    }
+
+    #[test]
+    fn test_marker_links_existing_task_and_removed_items_unlink() {
+        let conn = setup_test_connection();
+        let pid = project(&conn);
+        let task = create_task_sync(&conn, Some(pid), None, "Existing", None, None, None).unwrap();
+        let mut marked = item(0, "Existing, renamed in file", false);
+        marked.task_id = Some(task);
+
+        let report = sync_todo_items_sync(&conn, pid, "TODO.md", &[marked]).unwrap();
+        assert_eq!(report.created, 0);
+        assert_eq!(
+            get_linked_task_ids_sync(&conn, pid, "TODO.md").unwrap(),
+            vec![task]
+        );
+
+        let report = sync_todo_items_sync(&conn, pid, "TODO.md", &[]).unwrap();
+        assert_eq!(report.unlinked, 1);
+        assert!(get_task_by_id_sync(&conn, task).unwrap().is_some());
+    }
+}
@@ -25,6 +25,9 @@ pub struct SessionInfo {
     pub last_activity: String,
     pub source: Option<String>,
     pub resumed_from: Option<String>,
+    pub close_reason: Option<String>,
+    /// Activity recap stored when the session was closed
+    pub recap: Option<String>,
 }
 
 /// Task record
@@ -51,6 +54,8 @@ pub struct Goal {
     pub priority: String,
     pub progress_percent: i32,
     pub created_at: String,
+    /// progress_percent was set by hand and hasn't been rolled up since
+    pub progress_manual: bool,
 }
 
 /// Session-goal link record (junction table)
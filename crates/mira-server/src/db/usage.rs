@@ -375,6 +375,115 @@ pub fn get_llm_usage_summary(
     Ok(stats)
 }
 
+// ============================================================================
+// Unified Cost Report (LLM + embeddings)
+// ============================================================================
+
+/// Spend for one day, cost source, and project
+#[derive(Debug, Clone)]
+pub struct DailyCost {
+    /// `YYYY-MM-DD`
+    pub day: String,
+    /// `llm:{provider}/{role}` or `embeddings:{provider}`
+    pub source: String,
+    /// Project name (or path), None for calls outside a project
+    pub project: Option<String>,
+    pub requests: u64,
+    pub cost: f64,
+}
+
+/// Daily spend across every recorded cost source, oldest day first
+pub fn query_daily_costs(
+    conn: &Connection,
+    project_id: Option<i64>,
+    since_days: u32,
+) -> Result<Vec<DailyCost>> {
+    let mut stmt = conn.prepare(
+        "SELECT u.day, u.source, COALESCE(p.name, p.path), COUNT(*), COALESCE(SUM(u.cost), 0)
+         FROM (
+             SELECT date(created_at) AS day, 'llm:' || provider || '/' || role AS source,
+                    project_id, cost_estimate AS cost, created_at
+             FROM llm_usage
+             UNION ALL
+             SELECT date(created_at), 'embeddings:' || provider,
+                    project_id, cost_estimate, created_at
+             FROM embeddings_usage
+         ) u
+         LEFT JOIN projects p ON p.id = u.project_id
+         WHERE (?1 IS NULL OR u.project_id = ?1)
+           AND u.created_at >= date('now', ?2)
+         GROUP BY u.day, u.source, u.project_id
+         ORDER BY u.day, u.source",
+    )?;
+    // since_days=1 means "today only", so the window starts (days - 1) days back
+    let window = format!("-{} days", since_days.max(1) - 1);
+    let rows = stmt.query_map(params![project_id, window], |row| {
+        Ok(DailyCost {
+            day: row.get(0)?,
+            source: row.get(1)?,
+            project: row.get(2)?,
+            requests: row.get::<_, i64>(3)? as u64,
+            cost: row.get(4)?,
+        })
+    })?;
+    Ok(rows.collect::<rusqlite::Result<_>>()?)
+}
+
+/// Cost rollups over a set of `DailyCost` rows
+#[derive(Debug, Clone, Default)]
+pub struct CostRollup {
+    pub total: f64,
+    pub requests: u64,
+    /// (day, cost), oldest first
+    pub by_day: Vec<(String, f64)>,
+    /// (source, cost), most expensive first
+    pub by_source: Vec<(String, f64)>,
+    /// (project, cost), most expensive first
+    pub by_project: Vec<(Option<String>, f64)>,
+}
+
+impl CostRollup {
+    pub fn from_rows(rows: &[DailyCost]) -> Self {
+        let mut by_day: Vec<(String, f64)> = Vec::new();
+        let mut by_source: Vec<(String, f64)> = Vec::new();
+        let mut by_project: Vec<(Option<String>, f64)> = Vec::new();
+        let mut rollup = CostRollup::default();
+
+        for row in rows {
+            rollup.total += row.cost;
+            rollup.requests += row.requests;
+            add_to(&mut by_day, &row.day, row.cost);
+            add_to(&mut by_source, &row.source, row.cost);
+            add_to(&mut by_project, &row.project, row.cost);
+        }
+        by_day.sort_by(|a, b| a.0.cmp(&b.0));
+        by_source.sort_by(|a, b| b.1.total_cmp(&a.1));
+        by_project.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+        rollup.by_day = by_day;
+        rollup.by_source = by_source;
+        rollup.by_project = by_project;
+        rollup
+    }
+}
+
+fn add_to<K: PartialEq + Clone>(totals: &mut Vec<(K, f64)>, key: &K, cost: f64) {
+    match totals.iter_mut().find(|(k, _)| k == key) {
+        Some((_, total)) => *total += cost,
+        None => totals.push((key.clone(), cost)),
+    }
+}
+
+/// Project month-end spend from month-to-date spend at a linear burn rate.
+///
+/// `day_of_month` is 1-based and counts today as a full day.
+pub fn project_month_cost(month_to_date: f64, day_of_month: u32, days_in_month: u32) -> f64 {
+    if day_of_month == 0 {
+        return month_to_date;
+    }
+    month_to_date / day_of_month as f64 * days_in_month as f64
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -692,4 +801,89 @@ mod tests {
             query_llm_usage_stats(&conn, "role", Some(99999), None).expect("query should succeed");
         assert!(stats.is_empty(), "nonexistent project should return empty");
     }
+
+    // ========================================================================
+    // Unified cost report
+    // ========================================================================
+
+    #[test]
+    fn test_query_daily_costs_merges_llm_and_embeddings() {
+        let conn = setup_usage_db();
+        insert_llm_usage_sync(&conn, &make_llm_record("pondering", Some(1))).unwrap();
+        insert_llm_usage_sync(&conn, &make_llm_record("pondering", Some(1))).unwrap();
+        insert_llm_usage_sync(&conn, &make_llm_record("summary", None)).unwrap();
+        insert_embedding_usage_sync(&conn, &make_embedding_record(Some(1))).unwrap();
+        // Outside the window
+        conn.execute(
+            "UPDATE llm_usage SET created_at = datetime('now', '-40 days') WHERE role = 'summary'",
+            [],
+        )
+        .unwrap();
+
+        let rows = query_daily_costs(&conn, None, 30).unwrap();
+        let rollup = CostRollup::from_rows(&rows);
+        assert_eq!(rollup.requests, 3);
+        assert!((rollup.total - 0.012).abs() < 1e-9, "{}", rollup.total);
+        assert_eq!(rollup.by_day.len(), 1);
+        assert_eq!(
+            rollup.by_source[0],
+            ("llm:deepseek/pondering".to_string(), 0.01)
+        );
+        assert_eq!(rollup.by_source[1].0, "embeddings:openai");
+        assert_eq!(rollup.by_project.len(), 1);
+        assert_eq!(rollup.by_project[0].0.as_deref(), Some("test"));
+
+        // Project filter and 1-day window ("today")
+        let rows = query_daily_costs(&conn, Some(99), 30).unwrap();
+        assert!(rows.is_empty());
+        let rows = query_daily_costs(&conn, Some(1), 1).unwrap();
+        assert_eq!(CostRollup::from_rows(&rows).requests, 3);
+    }
+
+    #[test]
+    fn test_cost_rollup_by_day_and_project() {
+        let row = |day: &str, source: &str, project: Option<&str>, cost: f64| DailyCost {
+            day: day.to_string(),
+            source: source.to_string(),
+            project: project.map(String::from),
+            requests: 1,
+            cost,
+        };
+        let rows = vec![
+            row("2026-03-02", "llm:deepseek/summary", Some("a"), 1.0),
+            row("2026-03-01", "embeddings:openai", Some("b"), 0.5),
+            row("2026-03-02", "embeddings:openai", None, 0.25),
+            row("2026-03-01", "llm:deepseek/summary", Some("b"), 2.0),
+        ];
+        let rollup = CostRollup::from_rows(&rows);
+        assert_eq!(rollup.total, 3.75);
+        assert_eq!(
+            rollup.by_day,
+            vec![
+                ("2026-03-01".to_string(), 2.5),
+                ("2026-03-02".to_string(), 1.25)
+            ]
+        );
+        assert_eq!(
+            rollup.by_source[0],
+            ("llm:deepseek/summary".to_string(), 3.0)
+        );
+        assert_eq!(
+            rollup.by_project,
+            vec![
+                (Some("b".to_string()), 2.5),
+                (Some("a".to_string()), 1.0),
+                (None, 0.25)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_project_month_cost() {
+        // $3 over the first 10 days of a 30-day month -> $9
+        assert!((project_month_cost(3.0, 10, 30) - 9.0).abs() < 1e-9);
+        // Last day: projection equals month-to-date
+        assert_eq!(project_month_cost(5.0, 31, 31), 5.0);
+        assert_eq!(project_month_cost(5.0, 0, 31), 5.0);
+    }
 }
@@ -12,6 +12,7 @@ pub(crate) const MAX_CONCURRENT: usize = openai::MAX_CONCURRENT;
 
 use crate::config::{ApiKeys, EmbeddingsConfig};
 use crate::db::pool::DatabasePool;
+use crate::error::MiraError;
 use anyhow::Result;
 use std::sync::Arc;
 use tracing::info;
@@ -197,3 +198,62 @@ impl EmbeddingClient {
         }
     }
 }
+
+/// Whether an embedding failure says nothing about the texts themselves:
+/// the provider was unreachable, rate limited us, failed on its side (5xx)
+/// or rejected our credentials. Callers retry these later instead of
+/// counting them against the queued chunks.
+pub fn is_provider_error(err: &anyhow::Error) -> bool {
+    err.chain().any(|cause| {
+        if let Some(e) = cause.downcast_ref::<MiraError>() {
+            return match e {
+                MiraError::RateLimited { .. } | MiraError::Http(_) => true,
+                MiraError::Backend { status, .. } => {
+                    *status >= 500 || *status == 401 || *status == 403
+                }
+                _ => false,
+            };
+        }
+        cause
+            .downcast_ref::<reqwest::Error>()
+            .is_some_and(|e| !e.is_decode() && !e.is_builder())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn provider_errors_are_not_chunk_errors() {
+        let err = |e: MiraError| anyhow::Error::from(e);
+        assert!(is_provider_error(&err(MiraError::from_http_status(
+            "OpenAI",
+            429,
+            "slow down",
+            None
+        ))));
+        assert!(is_provider_error(&err(MiraError::from_http_status(
+            "OpenAI",
+            503,
+            "unavailable",
+            None
+        ))));
+        assert!(is_provider_error(&err(MiraError::from_http_status(
+            "OpenAI", 401, "bad key", None
+        ))));
+        assert!(is_provider_error(
+            &err(MiraError::from_http_status("Ollama", 502, "", None)).context("embedding batch")
+        ));
+
+        assert!(!is_provider_error(&err(MiraError::from_http_status(
+            "OpenAI",
+            400,
+            "input too long",
+            None
+        ))));
+        assert!(!is_provider_error(&anyhow::anyhow!(
+            "Dimension mismatch: expected 1536, got 768"
+        )));
+    }
+}
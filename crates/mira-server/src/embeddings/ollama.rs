@@ -1,6 +1,7 @@
 // crates/mira-server/src/embeddings/ollama.rs
 // Ollama embeddings via OpenAI-compatible /v1/embeddings endpoint
 
+use crate::error::MiraError;
 use crate::http::create_fast_client;
 use crate::utils::truncate_at_boundary;
 use anyhow::{Context, Result};
@@ -216,14 +217,13 @@ impl OllamaEmbeddings {
                         );
                     }
 
-                    last_error = Some(anyhow::anyhow!(
-                        "Ollama embedding request failed ({}): {}",
-                        status,
-                        body_text
-                    ));
+                    last_error = Some(
+                        MiraError::from_http_status("Ollama", status.as_u16(), body_text, None)
+                            .into(),
+                    );
                 }
                 Err(e) => {
-                    last_error = Some(anyhow::anyhow!("Ollama embedding request error: {}", e));
+                    last_error = Some(MiraError::Http(e).into());
                 }
             }
         }
@@ -3,7 +3,8 @@
 
 use crate::db::pool::DatabasePool;
 use crate::db::{EmbeddingUsageRecord, insert_embedding_usage_sync};
-use crate::http::create_fast_client;
+use crate::error::MiraError;
+use crate::http::{create_fast_client, retry_after_secs};
 use crate::utils::truncate_at_boundary;
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
@@ -342,6 +343,7 @@ impl OpenAiEmbeddings {
                         return Ok(embeddings);
                     } else {
                         let status = response.status();
+                        let retry_after = retry_after_secs(response.headers());
                         let error_text = response.text().await.unwrap_or_default();
 
                         // Try to parse structured error
@@ -349,7 +351,15 @@ impl OpenAiEmbeddings {
                             .map(|e| e.error.message)
                             .unwrap_or(error_text);
 
-                        last_error = Some(anyhow::anyhow!("OpenAI API error {}: {}", status, msg));
+                        last_error = Some(
+                            MiraError::from_http_status(
+                                "OpenAI",
+                                status.as_u16(),
+                                msg,
+                                retry_after,
+                            )
+                            .into(),
+                        );
                     }
                 }
                 Err(e) => {
@@ -1,19 +1,43 @@
 // crates/mira-server/src/error.rs
 // Standardized error types for Mira
 
+use rmcp::model::{ErrorCode, ErrorData};
 use thiserror::Error;
 
 /// Main error type for the Mira library
 #[derive(Error, Debug)]
 pub enum MiraError {
+    /// A bad argument from the caller (reported as `invalid_argument`)
     #[error("invalid input: {0}")]
     InvalidInput(String),
 
+    /// A referenced goal, task, file, etc. does not exist. The message should
+    /// name what was missing and how to list valid IDs.
+    #[error("{0}")]
+    NotFound(String),
+
     #[error(
         "No active project. Auto-detection failed. Use the project tool with action=\"start\" and project_path=\"/your/path\" to set one explicitly."
     )]
     ProjectNotSet,
 
+    /// An upstream API (embeddings, LLM) answered with a non-success status
+    #[error("{provider} returned HTTP {status}: {message}")]
+    Backend {
+        provider: String,
+        status: u16,
+        message: String,
+    },
+
+    #[error(
+        "{provider} rate limit hit{}. Wait and retry.",
+        .retry_after_secs.map(|s| format!(" (retry after {s}s)")).unwrap_or_default()
+    )]
+    RateLimited {
+        provider: String,
+        retry_after_secs: Option<u64>,
+    },
+
     #[error("Database error: {0}")]
     Db(#[from] rusqlite::Error),
 
@@ -48,12 +72,135 @@ pub enum MiraError {
     Other(String),
 
     #[error(transparent)]
-    Anyhow(#[from] anyhow::Error),
+    Anyhow(anyhow::Error),
 }
 
 /// Convenience type alias for Result using MiraError
 pub type Result<T> = std::result::Result<T, MiraError>;
 
+/// Error category reported to MCP clients, each with its own error code.
+///
+/// Discriminants start at 1 so they can ride along as a Rhai `Dynamic` tag
+/// (tag 0 means "untagged").
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    Internal = 1,
+    InvalidArgument,
+    NotFound,
+    ProjectNotSet,
+    Backend,
+    RateLimited,
+}
+
+impl ErrorKind {
+    pub const ALL: [ErrorKind; 6] = [
+        ErrorKind::Internal,
+        ErrorKind::InvalidArgument,
+        ErrorKind::NotFound,
+        ErrorKind::ProjectNotSet,
+        ErrorKind::Backend,
+        ErrorKind::RateLimited,
+    ];
+
+    /// Stable snake_case name, used as `kind` in error payloads
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ErrorKind::Internal => "internal",
+            ErrorKind::InvalidArgument => "invalid_argument",
+            ErrorKind::NotFound => "not_found",
+            ErrorKind::ProjectNotSet => "project_not_set",
+            ErrorKind::Backend => "backend",
+            ErrorKind::RateLimited => "rate_limited",
+        }
+    }
+
+    /// JSON-RPC error code. Standard codes where MCP defines one, otherwise
+    /// values from the implementation-defined server range (-32000..-32099).
+    pub fn mcp_code(self) -> ErrorCode {
+        match self {
+            ErrorKind::Internal => ErrorCode::INTERNAL_ERROR,
+            ErrorKind::InvalidArgument => ErrorCode::INVALID_PARAMS,
+            ErrorKind::NotFound => ErrorCode::RESOURCE_NOT_FOUND,
+            ErrorKind::ProjectNotSet => ErrorCode(-32010),
+            ErrorKind::Backend => ErrorCode(-32011),
+            ErrorKind::RateLimited => ErrorCode(-32012),
+        }
+    }
+}
+
+impl MiraError {
+    /// Build the right error for a failed upstream HTTP call (429 -> RateLimited)
+    pub fn from_http_status(
+        provider: impl Into<String>,
+        status: u16,
+        message: impl Into<String>,
+        retry_after_secs: Option<u64>,
+    ) -> Self {
+        if status == 429 {
+            MiraError::RateLimited {
+                provider: provider.into(),
+                retry_after_secs,
+            }
+        } else {
+            MiraError::Backend {
+                provider: provider.into(),
+                status,
+                message: message.into(),
+            }
+        }
+    }
+
+    /// Category reported to MCP clients
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            MiraError::InvalidInput(_) => ErrorKind::InvalidArgument,
+            MiraError::NotFound(_) => ErrorKind::NotFound,
+            MiraError::ProjectNotSet => ErrorKind::ProjectNotSet,
+            MiraError::Backend { .. } => ErrorKind::Backend,
+            MiraError::RateLimited { .. } => ErrorKind::RateLimited,
+            _ => ErrorKind::Internal,
+        }
+    }
+
+    /// Structured details for the MCP error `data` field
+    fn details(&self) -> serde_json::Value {
+        let mut data = serde_json::json!({ "kind": self.kind().as_str() });
+        match self {
+            MiraError::Backend {
+                provider, status, ..
+            } => {
+                data["provider"] = provider.as_str().into();
+                data["status"] = (*status).into();
+            }
+            MiraError::RateLimited {
+                provider,
+                retry_after_secs,
+            } => {
+                data["provider"] = provider.as_str().into();
+                data["retry_after_secs"] = (*retry_after_secs).into();
+            }
+            _ => {}
+        }
+        data
+    }
+}
+
+impl From<MiraError> for ErrorData {
+    fn from(err: MiraError) -> Self {
+        ErrorData::new(err.kind().mcp_code(), err.to_string(), Some(err.details()))
+    }
+}
+
+impl From<anyhow::Error> for MiraError {
+    /// Unwraps a `MiraError` that travelled through anyhow so its kind survives
+    fn from(err: anyhow::Error) -> Self {
+        match err.downcast::<MiraError>() {
+            Ok(mira) => mira,
+            Err(other) => MiraError::Anyhow(other),
+        }
+    }
+}
+
 impl From<String> for MiraError {
     fn from(s: String) -> Self {
         MiraError::Other(s)
@@ -171,6 +318,92 @@ mod tests {
         assert!(err.to_string().contains("JSON"));
     }
 
+    // ============================================================================
+    // MCP error code mapping tests
+    // ============================================================================
+
+    fn to_error_data(err: MiraError) -> ErrorData {
+        err.into()
+    }
+
+    #[test]
+    fn test_invalid_input_maps_to_invalid_params() {
+        let data = to_error_data(MiraError::InvalidInput("limit must be > 0".into()));
+        assert_eq!(data.code, ErrorCode::INVALID_PARAMS);
+        assert_eq!(data.message, "invalid input: limit must be > 0");
+        assert_eq!(data.data.unwrap()["kind"], "invalid_argument");
+    }
+
+    #[test]
+    fn test_not_found_maps_to_resource_not_found() {
+        let data = to_error_data(MiraError::NotFound(
+            "Goal not found (id: 7). Use goal(action=\"list\") to see available goals.".into(),
+        ));
+        assert_eq!(data.code, ErrorCode::RESOURCE_NOT_FOUND);
+        assert!(data.message.starts_with("Goal not found (id: 7)"));
+        assert_eq!(data.data.unwrap()["kind"], "not_found");
+    }
+
+    #[test]
+    fn test_project_not_set_maps_to_own_code() {
+        let data = to_error_data(MiraError::ProjectNotSet);
+        assert_eq!(data.code, ErrorCode(-32010));
+        assert!(data.message.contains("project_path"));
+        assert_eq!(data.data.unwrap()["kind"], "project_not_set");
+    }
+
+    #[test]
+    fn test_backend_maps_with_provider_and_status() {
+        let err = MiraError::from_http_status("OpenAI", 503, "overloaded", None);
+        assert!(matches!(err, MiraError::Backend { status: 503, .. }));
+        let data = to_error_data(err);
+        assert_eq!(data.code, ErrorCode(-32011));
+        assert_eq!(data.message, "OpenAI returned HTTP 503: overloaded");
+        let details = data.data.unwrap();
+        assert_eq!(details["kind"], "backend");
+        assert_eq!(details["provider"], "OpenAI");
+        assert_eq!(details["status"], 503);
+    }
+
+    #[test]
+    fn test_rate_limited_maps_with_retry_hint() {
+        let err = MiraError::from_http_status("DeepSeek", 429, "slow down", Some(20));
+        assert!(matches!(err, MiraError::RateLimited { .. }));
+        let data = to_error_data(err);
+        assert_eq!(data.code, ErrorCode(-32012));
+        assert_eq!(
+            data.message,
+            "DeepSeek rate limit hit (retry after 20s). Wait and retry."
+        );
+        let details = data.data.unwrap();
+        assert_eq!(details["kind"], "rate_limited");
+        assert_eq!(details["retry_after_secs"], 20);
+    }
+
+    #[test]
+    fn test_other_errors_map_to_internal() {
+        let data = to_error_data(MiraError::Other("boom".into()));
+        assert_eq!(data.code, ErrorCode::INTERNAL_ERROR);
+        assert_eq!(data.data.unwrap()["kind"], "internal");
+    }
+
+    #[test]
+    fn test_error_codes_are_distinct() {
+        let codes: std::collections::HashSet<i32> =
+            ErrorKind::ALL.iter().map(|k| k.mcp_code().0).collect();
+        assert_eq!(codes.len(), ErrorKind::ALL.len());
+    }
+
+    #[test]
+    fn test_anyhow_roundtrip_preserves_kind() {
+        let wrapped = anyhow::Error::new(MiraError::NotFound("gone".into()));
+        let err: MiraError = wrapped.into();
+        assert_eq!(err.kind(), ErrorKind::NotFound);
+
+        let plain: MiraError = anyhow::anyhow!("opaque").into();
+        assert!(matches!(plain, MiraError::Anyhow(_)));
+    }
+
     // ============================================================================
     // Debug trait tests
     // ============================================================================
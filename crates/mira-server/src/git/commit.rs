@@ -142,6 +142,32 @@ pub fn get_files_for_commit(project_path: &str, commit_hash: &str) -> Vec<String
     }
 }
 
+/// Files touched by commits after a unix timestamp, plus uncommitted
+/// changes to tracked files. Most recent first, without duplicates.
+pub fn get_files_changed_since(project_path: &str, since_timestamp: i64) -> Vec<String> {
+    let path = Path::new(project_path);
+    let committed = git_cmd_opt(
+        path,
+        &[
+            "log",
+            &format!("--since=@{}", since_timestamp),
+            "--name-only",
+            "--format=",
+        ],
+    )
+    .unwrap_or_default();
+    let uncommitted = git_cmd_opt(path, &["diff", "--name-only", "HEAD"]).unwrap_or_default();
+
+    let mut files: Vec<String> = Vec::new();
+    for line in uncommitted.lines().chain(committed.lines()) {
+        let line = line.trim();
+        if !line.is_empty() && !files.iter().any(|f| f == line) {
+            files.push(line.to_string());
+        }
+    }
+    files
+}
+
 /// A commit with its associated file list (for batch operations)
 #[derive(Debug)]
 pub struct CommitWithFiles {
@@ -12,8 +12,8 @@ mod diff;
 pub use branch::{clear_branch_cache, get_git_branch, get_git_branch_uncached, is_git_repo};
 pub use commit::{
     CommitWithFiles, GitCommit, get_commit_message, get_commit_timestamp, get_commits_in_range,
-    get_commits_with_files, get_files_for_commit, get_git_head, get_recent_commits, is_ancestor,
-    parse_commit_lines,
+    get_commits_with_files, get_files_changed_since, get_files_for_commit, get_git_head,
+    get_recent_commits, is_ancestor, parse_commit_lines,
 };
 pub use diff::{
     derive_stats_from_unified_diff, get_head_commit, get_staged_diff, get_unified_diff,
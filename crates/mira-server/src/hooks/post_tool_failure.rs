@@ -44,7 +44,9 @@ impl PostToolFailureInput {
 /// This hook fires when a tool call fails. We:
 /// 1. Log the failure to session_behavior_log
 /// 2. Count repeated failures for the same tool in this session
-/// 3. If 3+ failures, recall relevant memories and inject as context
+/// 3. If a past fix matches the error signature, hint it once per signature per
+///    session, and show the full fix once per signature after the tool has
+///    failed 3+ times
 pub async fn run() -> Result<()> {
     let _timer = HookTimer::start("PostToolUseFailure");
     let input = read_hook_input().context("Failed to parse hook input from stdin")?;
@@ -76,9 +78,18 @@ pub async fn run() -> Result<()> {
     let redacted_error = crate::utils::redact_sensitive(&failure_input.error);
     let error_summary = crate::utils::truncate(&redacted_error, 300);
 
+    // Prefer a stable compiler/test signature over the raw (noisy) output so the
+    // same failure matches across runs. Extract before truncation — signatures
+    // often sit deep in build logs.
+    let signature = crate::db::extract_error_signatures(&redacted_error)
+        .into_iter()
+        .next();
+
     // Compute fingerprint once — used in both behavior log and error pattern storage
-    let (fingerprint, template) =
-        crate::db::error_fingerprint(&failure_input.tool_name, &error_summary);
+    let (fingerprint, template) = match &signature {
+        Some(sig) => crate::db::error_fingerprint(&failure_input.tool_name, &sig.text),
+        None => crate::db::error_fingerprint(&failure_input.tool_name, &error_summary),
+    };
 
     {
         let data = serde_json::json!({
@@ -115,25 +126,51 @@ pub async fn run() -> Result<()> {
         "Tool has failed in this session"
     );
 
-    // On 1st failure, check for a resolved pattern and inject a lightweight hint.
-    // For 3+ failures, inject the full context (repeat mention + fix).
+    // For 3+ failures of the tool, inject the full context (repeat mention + fix).
+    // Otherwise inject a lightweight hint. Each kind fires at most once per
+    // signature per session.
+    let fingerprint_count = client
+        .count_fingerprint_failures(&failure_input.session_id, &fingerprint)
+        .await;
+    let escalated_count = client
+        .count_escalated_fingerprint_failures(
+            &failure_input.session_id,
+            &fingerprint,
+            ESCALATE_AFTER_FAILURES,
+        )
+        .await;
+    let Some(hint) = fix_hint_kind(failure_count, fingerprint_count, escalated_count) else {
+        write_hook_output(&serde_json::json!({}));
+        return Ok(());
+    };
+
+    // Check for a resolved pattern matching this failure
     let fix_context = client
         .lookup_resolved_pattern(project_id, &failure_input.tool_name, &fingerprint)
         .await;
 
-    if let Some(fix_description) = fix_context {
-        let context = if failure_count >= 3 {
+    if let Some((fix_description, strategy)) = fix_context {
+        let mut matched = signature
+            .as_ref()
+            .map(|sig| format!(" ({})", crate::utils::truncate(&sig.text, 120)))
+            .unwrap_or_default();
+        if strategy != "exact" {
+            matched.push_str(&format!(" [{} match]", strategy));
+        }
+        let context = if hint == FixHint::Escalated {
             format!(
-                "[Mira/fix] Tool '{}' failed ({}x). A similar error was resolved before:\n  Fix: {}",
+                "[Mira/fix] Tool '{}' failed ({}x). A similar error{} was resolved before:\n  Fix: {}",
                 failure_input.tool_name,
                 failure_count,
+                matched,
                 crate::utils::truncate(&fix_description, 300),
             )
         } else {
-            // Lightweight hint on first occurrence
+            // Lightweight hint on first occurrence of the signature
             format!(
-                "[Mira/fix] Hint for '{}': {}",
+                "[Mira/fix] Hint for '{}'{}: {}",
                 failure_input.tool_name,
+                matched,
                 crate::utils::truncate(&fix_description, 200),
             )
         };
@@ -151,6 +188,37 @@ pub async fn run() -> Result<()> {
     Ok(())
 }
 
+/// Tool failures in a session before the full fix context is injected
+const ESCALATE_AFTER_FAILURES: i64 = 3;
+
+/// Kind of fix hint to inject for a failure
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FixHint {
+    /// Short hint, shown once per signature per session
+    Light,
+    /// Full context after repeated failures of the tool, shown once per
+    /// signature per session
+    Escalated,
+}
+
+/// Pick the fix hint for a tool that has failed `failure_count` times in this
+/// session. `fingerprint_count` is how often this signature has been seen, and
+/// `escalated_count` how often it was seen once the tool had failed
+/// `ESCALATE_AFTER_FAILURES` times. All three include the failure just logged;
+/// 0 means the behavior log write failed, which still hints rather than stay
+/// silent.
+fn fix_hint_kind(
+    failure_count: i64,
+    fingerprint_count: i64,
+    escalated_count: i64,
+) -> Option<FixHint> {
+    if failure_count >= ESCALATE_AFTER_FAILURES {
+        (escalated_count <= 1).then_some(FixHint::Escalated)
+    } else {
+        (fingerprint_count <= 1).then_some(FixHint::Light)
+    }
+}
+
 // =============================================================================
 // Tests
 // =============================================================================
@@ -191,6 +259,25 @@ mod tests {
         assert!(input.is_interrupt);
     }
 
+    #[test]
+    fn light_fix_hint_only_on_first_occurrence() {
+        // 0 = behavior log write failed; still hint rather than stay silent
+        assert_eq!(fix_hint_kind(1, 0, 0), Some(FixHint::Light));
+        assert_eq!(fix_hint_kind(1, 1, 0), Some(FixHint::Light));
+        assert_eq!(fix_hint_kind(2, 2, 0), None);
+    }
+
+    #[test]
+    fn escalation_fires_once_per_signature() {
+        // Identical failures: the 3rd escalates, the 4th and 5th stay quiet
+        assert_eq!(fix_hint_kind(3, 3, 1), Some(FixHint::Escalated));
+        assert_eq!(fix_hint_kind(4, 4, 2), None);
+        assert_eq!(fix_hint_kind(5, 5, 3), None);
+        // A new signature after the tool already failed twice still escalates once
+        assert_eq!(fix_hint_kind(3, 1, 1), Some(FixHint::Escalated));
+        assert_eq!(fix_hint_kind(4, 2, 2), None);
+    }
+
     #[test]
     fn failure_input_ignores_wrong_types() {
         let input = PostToolFailureInput::from_json(&serde_json::json!({
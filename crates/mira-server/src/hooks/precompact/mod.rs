@@ -11,6 +11,7 @@ use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 use std::fs;
+use std::path::Path;
 
 #[cfg(test)]
 pub(crate) use extract::{extract_and_save_context, extract_compaction_context};
@@ -55,6 +56,12 @@ pub(crate) struct CompactionContext {
     /// Extracted by detecting markdown headers, tables, and severity/priority markers.
     #[serde(default)]
     pub findings: Vec<String>,
+    /// Active goals read from the database when compaction fired.
+    #[serde(default)]
+    pub active_goals: Vec<String>,
+    /// Pending and in-progress native tasks when compaction fired.
+    #[serde(default)]
+    pub open_tasks: Vec<String>,
 }
 
 impl CompactionContext {
@@ -66,6 +73,8 @@ impl CompactionContext {
             && self.user_intent.is_none()
             && self.files_referenced.is_empty()
             && self.findings.is_empty()
+            && self.active_goals.is_empty()
+            && self.open_tasks.is_empty()
     }
 
     pub(super) fn total_items(&self) -> usize {
@@ -76,6 +85,8 @@ impl CompactionContext {
             + self.user_intent.as_ref().map_or(0, |_| 1)
             + self.files_referenced.len()
             + self.findings.len()
+            + self.active_goals.len()
+            + self.open_tasks.len()
     }
 }
 
@@ -87,6 +98,9 @@ impl CompactionContext {
 ///
 /// `user_intent`: keep the FIRST one (the original intent from the earliest
 /// compaction). Only set if the existing value is `None`.
+///
+/// `active_goals` / `open_tasks`: point-in-time state, so the newest non-empty
+/// snapshot replaces the old one instead of accumulating.
 pub(crate) fn merge_compaction_contexts(
     existing: &serde_json::Value,
     new: &serde_json::Value,
@@ -126,6 +140,8 @@ pub(crate) fn merge_compaction_contexts(
             MAX_FILE_REFS,
         ),
         findings: merge_vec_field(&old.findings, &incoming.findings, MAX_ITEMS_PER_CATEGORY),
+        active_goals: newest_non_empty(old.active_goals, incoming.active_goals),
+        open_tasks: newest_non_empty(old.open_tasks, incoming.open_tasks),
     };
 
     serde_json::to_value(&merged).unwrap_or_else(|_| new.clone())
@@ -152,6 +168,53 @@ fn merge_vec_field(old: &[String], new: &[String], max: usize) -> Vec<String> {
     combined
 }
 
+/// Prefer the incoming snapshot unless it is empty.
+fn newest_non_empty(old: Vec<String>, new: Vec<String>) -> Vec<String> {
+    if new.is_empty() { old } else { new }
+}
+
+/// Snapshot volatile state that compaction would otherwise lose: active goals
+/// from the database and open tasks from the native task list.
+pub(crate) async fn snapshot_state(
+    client: &mut HookClient,
+    project_id: Option<i64>,
+    task_list_dir: Option<&Path>,
+) -> CompactionContext {
+    let active_goals = match project_id {
+        Some(pid) => client
+            .get_active_goals(pid, MAX_ITEMS_PER_CATEGORY)
+            .await
+            .into_iter()
+            .map(|g| g.trim_start_matches("- ").to_string())
+            .collect(),
+        None => Vec::new(),
+    };
+
+    let open_tasks = task_list_dir
+        .and_then(|dir| {
+            crate::tasks::get_pending_tasks(dir)
+                .map_err(|e| tracing::debug!(error = %e, "Failed to read task list"))
+                .ok()
+        })
+        .unwrap_or_default()
+        .into_iter()
+        .take(MAX_ITEMS_PER_CATEGORY)
+        .map(|t| {
+            if t.status == "in_progress" {
+                format!("{} (in progress)", t.subject)
+            } else {
+                t.subject
+            }
+        })
+        .collect();
+
+    CompactionContext {
+        active_goals,
+        open_tasks,
+        ..Default::default()
+    }
+}
+
 /// Handle PreCompact hook from Claude Code
 /// Fires before context compaction (summarization) occurs
 /// Input: { session_id, transcript_path, trigger: "manual"|"auto", custom_instructions }
@@ -246,6 +309,15 @@ async fn save_pre_compaction_state(
         )
         .await;
 
+    // Snapshot goals and open tasks so the post-compaction recap can re-anchor on them
+    let task_list_dir = crate::tasks::find_current_task_list();
+    let state = snapshot_state(client, project_id, task_list_dir.as_deref()).await;
+    if !state.is_empty() {
+        client
+            .save_compaction_context(session_id, serde_json::to_value(&state)?)
+            .await;
+    }
+
     // Parse transcript JSONL, extract structured context, store in session_snapshots
     if let Some(transcript) = transcript
         && let Err(e) = extract::extract_and_save_context(client, session_id, transcript).await
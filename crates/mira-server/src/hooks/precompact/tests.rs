@@ -359,9 +359,11 @@ fn total_items_counts_all_categories() {
         user_intent: Some("intent".into()),
         files_referenced: vec!["src/main.rs".into()],
         findings: vec!["f1".into()],
+        active_goals: vec!["g1".into()],
+        open_tasks: vec!["t1".into(), "t2".into()],
     };
-    // 2 + 1 + 1 + 3 + 1 (intent) + 1 (file) + 1 (finding) = 10
-    assert_eq!(ctx.total_items(), 10);
+    // 2 + 1 + 1 + 3 + 1 (intent) + 1 (file) + 1 (finding) + 1 (goal) + 2 (open tasks) = 13
+    assert_eq!(ctx.total_items(), 13);
 }
 
 // ── Serialization round-trip ──────────────────────────────────────────
@@ -376,6 +378,8 @@ fn compaction_context_serializes_and_deserializes() {
         user_intent: Some("Fix the auth bug".into()),
         files_referenced: vec!["src/main.rs".into(), "src/lib.rs".into()],
         findings: vec![],
+        active_goals: vec!["Ship auth rewrite [in_progress] (40%)".into()],
+        open_tasks: vec!["Write migration".into()],
     };
     let json = serde_json::to_value(&ctx).unwrap();
     let roundtrip: CompactionContext = serde_json::from_value(json).unwrap();
@@ -385,6 +389,8 @@ fn compaction_context_serializes_and_deserializes() {
     assert_eq!(roundtrip.pending_tasks, ctx.pending_tasks);
     assert_eq!(roundtrip.user_intent, ctx.user_intent);
     assert_eq!(roundtrip.files_referenced, ctx.files_referenced);
+    assert_eq!(roundtrip.active_goals, ctx.active_goals);
+    assert_eq!(roundtrip.open_tasks, ctx.open_tasks);
 }
 
 // ── merge_compaction_contexts ────────────────────────────────────────
@@ -399,6 +405,7 @@ fn merge_combines_vec_fields() {
         user_intent: None,
         files_referenced: vec!["src/a.rs".into()],
         findings: vec![],
+        ..Default::default()
     })
     .unwrap();
     let new = serde_json::to_value(CompactionContext {
@@ -409,6 +416,7 @@ fn merge_combines_vec_fields() {
         user_intent: None,
         files_referenced: vec!["src/b.rs".into()],
         findings: vec!["finding B".into()],
+        ..Default::default()
     })
     .unwrap();
     let merged: CompactionContext =
@@ -1572,3 +1580,66 @@ fn merge_handles_empty_new_context() {
     assert_eq!(merged.findings, vec!["old finding"]);
 }
 
+// ── Volatile state snapshot ──────────────────────────────────────────
+
+#[test]
+fn merge_replaces_state_with_newest_non_empty_snapshot() {
+    let existing = serde_json::to_value(CompactionContext {
+        active_goals: vec!["old goal".into()],
+        open_tasks: vec!["old task".into()],
+        ..Default::default()
+    })
+    .unwrap();
+    let new = serde_json::to_value(CompactionContext {
+        active_goals: vec!["new goal".into()],
+        decisions: vec!["decided something".into()],
+        ..Default::default()
+    })
+    .unwrap();
+    let merged: CompactionContext =
+        serde_json::from_value(merge_compaction_contexts(&existing, &new)).unwrap();
+    assert_eq!(merged.active_goals, vec!["new goal"]);
+    // A transcript-only context carries no task state, so the old snapshot stays
+    assert_eq!(merged.open_tasks, vec!["old task"]);
+}
+
+#[tokio::test]
+async fn snapshot_state_captures_goals_and_open_tasks() {
+    use crate::db::test_support::{seed_goal, setup_test_pool_with_project};
+
+    let (pool, project_id) = setup_test_pool_with_project().await;
+    pool.interact(move |conn| {
+        seed_goal(conn, project_id, "Ship auth rewrite", "in_progress", 40);
+        seed_goal(conn, project_id, "Old migration", "completed", 100);
+        Ok(())
+    })
+    .await
+    .unwrap();
+
+    let tasks = tempfile::tempdir().unwrap();
+    for (id, subject, status) in [
+        ("1", "Write migration", "in_progress"),
+        ("2", "Add tests", "pending"),
+        ("3", "Read the code", "completed"),
+    ] {
+        std::fs::write(
+            tasks.path().join(format!("{id}.json")),
+            serde_json::json!({"id": id, "subject": subject, "status": status}).to_string(),
+        )
+        .unwrap();
+    }
+
+    let mut client = crate::ipc::client::HookClient::from_pool(pool.clone());
+    let state = snapshot_state(&mut client, Some(project_id), Some(tasks.path())).await;
+    assert_eq!(state.active_goals.len(), 1, "got {:?}", state.active_goals);
+    assert!(
+        state.active_goals[0].starts_with("Ship auth rewrite [in_progress]"),
+        "got {:?}",
+        state.active_goals
+    );
+    assert_eq!(state.open_tasks, vec!["Write migration (in progress)", "Add tests"]);
+    assert!(state.decisions.is_empty());
+
+    // No project and no task list: nothing to snapshot
+    assert!(snapshot_state(&mut client, None, None).await.is_empty());
+}
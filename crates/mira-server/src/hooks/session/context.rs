@@ -5,10 +5,54 @@ use crate::db::pool::DatabasePool;
 use crate::utils::truncate_at_boundary;
 use std::sync::Arc;
 
-/// Hard cap on SessionStart output to prevent bloated context injection.
+/// How much a SessionStart section is worth when the budget is tight.
+/// Sections are dropped lowest-priority (last variant) first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum SectionPriority {
+    /// Post-compaction recap and "you were working on"
+    Recap,
+    /// Active goals and unfinished tasks
+    Goals,
+    /// New-project, database and team notices
+    Notice,
+    /// Previous-session summary and modified files
+    History,
+}
+
+/// One block of SessionStart output
+#[derive(Debug, Clone)]
+pub(crate) struct ContextSection {
+    pub priority: SectionPriority,
+    pub text: String,
+}
+
+impl ContextSection {
+    pub(crate) fn new(priority: SectionPriority, text: String) -> Self {
+        Self { priority, text }
+    }
+}
+
+/// SessionStart output budget from `[sessions] start_context_tokens`,
+/// honouring the project's own `.mira/config.toml`.
 /// A loaded resume with compaction context + goals + incomplete tasks can
-/// exceed 3000 chars without this limit.
-const MAX_SESSION_CONTEXT_CHARS: usize = 2500;
+/// run well past 3000 chars without it.
+pub(crate) fn session_context_budget(project_path: Option<&str>) -> usize {
+    crate::config::MiraConfig::for_project(project_path)
+        .sessions
+        .start_context_chars()
+}
+
+/// Budget for a resolved project, looking its path up in the DB
+async fn project_context_budget(pool: &Arc<DatabasePool>, project_id: i64) -> usize {
+    let path = pool
+        .interact(move |conn| {
+            Ok::<_, anyhow::Error>(crate::db::get_project_path_sync(conn, project_id).ok())
+        })
+        .await
+        .ok()
+        .flatten();
+    session_context_budget(path.as_deref())
+}
 
 /// Build lightweight context for a fresh startup session.
 /// Includes active goals and a brief note about the last session.
@@ -48,7 +92,10 @@ pub(crate) async fn build_startup_context(
                         |row| row.get(0),
                     )
                     .ok();
-                let is_new = existing.is_none();
+                let attached = crate::db::find_project_by_root_sync(conn, &cwd_owned)
+                    .ok()
+                    .flatten();
+                let is_new = existing.is_none() && attached.is_none();
                 Ok::<_, anyhow::Error>(
                     crate::db::get_or_create_project_sync(conn, &cwd_owned, None)
                         .map_err(|e| {
@@ -74,7 +121,28 @@ pub(crate) async fn build_startup_context(
     };
     let project_id = project_id?;
 
-    let mut context_parts: Vec<String> = Vec::new();
+    let mut context_parts: Vec<ContextSection> = Vec::new();
+
+    // After a compaction the session keeps its ID, and PreCompact left a
+    // snapshot for it: lead with that recap so the model can re-anchor.
+    let compaction_recap = match session_id {
+        Some(sid) => {
+            let sid = sid.to_string();
+            pool.interact(move |conn| Ok::<_, anyhow::Error>(get_session_snapshot_sync(conn, &sid)))
+                .await
+                .ok()
+                .flatten()
+                .and_then(|json| serde_json::from_str::<serde_json::Value>(&json).ok())
+                .and_then(|snap| build_compaction_summary(&snap))
+        }
+        None => None,
+    };
+    let recap_has_goals = compaction_recap
+        .as_ref()
+        .is_some_and(|r| r.contains("\nActive goals: "));
+    if let Some(recap) = compaction_recap {
+        context_parts.push(ContextSection::new(SectionPriority::Recap, recap));
+    }
 
     // Surface a message when Mira encounters a new project directory
     if is_new_project {
@@ -82,9 +150,12 @@ pub(crate) async fn build_startup_context(
             .and_then(|p| std::path::Path::new(p).file_name())
             .and_then(|f| f.to_str())
             .unwrap_or("unknown");
-        context_parts.push(format!(
-            "[Mira] New project detected: {}. Memories and goals are scoped to this project.",
-            project_name
+        context_parts.push(ContextSection::new(
+            SectionPriority::Notice,
+            format!(
+                "[Mira] New project detected: {}. Memories and goals are scoped to this project.",
+                project_name
+            ),
         ));
     }
 
@@ -106,7 +177,10 @@ pub(crate) async fn build_startup_context(
 
     if let Some(ref prev_session) = previous_session {
         if let Some(ref summary) = prev_session.summary {
-            context_parts.push(format!("[Mira/resume] Last session: {}", summary));
+            context_parts.push(ContextSection::new(
+                SectionPriority::History,
+                format!("[Mira/resume] Last session: {}", summary),
+            ));
         }
 
         // Check snapshot for a brief "you were working on" note
@@ -125,20 +199,47 @@ pub(crate) async fn build_startup_context(
             && context_parts.is_empty()
         {
             // Only add if we didn't already have a summary
-            context_parts.push(format!("[Mira/resume] Last session: {}", working_on));
+            context_parts.push(ContextSection::new(
+                SectionPriority::History,
+                format!("[Mira/resume] Last session: {}", working_on),
+            ));
         }
     }
 
-    // Active goals
-    let goal_lines = crate::hooks::format_active_goals(&pool, project_id, 5).await;
+    // Active goals (already listed in a post-compaction recap)
+    let goal_lines = if recap_has_goals {
+        Vec::new()
+    } else {
+        crate::hooks::format_active_goals(&pool, project_id, 5).await
+    };
     if !goal_lines.is_empty() {
-        context_parts.push(format!(
-            "[Mira/goals] Active goals:\n{}",
-            goal_lines.join("\n")
+        context_parts.push(ContextSection::new(
+            SectionPriority::Goals,
+            format!("[Mira/goals] Active goals:\n{}", goal_lines.join("\n")),
         ));
         super::mark_goals_shown(session_id);
     }
 
+    // Database growth warning from the background health monitor
+    let db_alert: Option<String> = pool
+        .interact(|conn| {
+            Ok::<_, anyhow::Error>(
+                crate::db::db_health::get_db_health_alert_sync(conn)
+                    .map_err(|e| tracing::debug!("context load: db health alert failed: {e}"))
+                    .ok()
+                    .flatten(),
+            )
+        })
+        .await
+        .ok()
+        .flatten();
+    if let Some(alert) = db_alert {
+        context_parts.push(ContextSection::new(
+            SectionPriority::Notice,
+            format!("[Mira/system] {}", alert),
+        ));
+    }
+
     if context_parts.is_empty() {
         if previous_session.is_none() {
             // First-ever session for this user — show a welcome message
@@ -149,8 +250,11 @@ pub(crate) async fn build_startup_context(
         return None;
     }
 
-    let output = context_parts.join("\n\n");
-    Some(truncate_session_context(output))
+    Some(fit_session_context(
+        None,
+        context_parts,
+        project_context_budget(&pool, project_id).await,
+    ))
 }
 
 /// Build context for a resumed session
@@ -199,7 +303,7 @@ pub(crate) async fn build_resume_context(
     };
     let project_id = project_id?;
 
-    let mut context_parts: Vec<String> = Vec::new();
+    let mut context_parts: Vec<ContextSection> = Vec::new();
 
     // Get the most recent completed session for this project
     let pool_clone = pool.clone();
@@ -253,17 +357,25 @@ pub(crate) async fn build_resume_context(
                     file_names.len() - 5
                 )
             };
-            context_parts.push(format!(
-                "[Mira/resume] Files modified last session: {}",
-                files_str
+            context_parts.push(ContextSection::new(
+                SectionPriority::History,
+                format!("[Mira/resume] Files modified last session: {}", files_str),
             ));
         }
 
         // Add session summary if available
         if let Some(ref summary) = prev_session.summary {
-            context_parts.push(format!(
-                "[Mira/resume] Previous session summary: {}",
-                summary
+            context_parts.push(ContextSection::new(
+                SectionPriority::History,
+                format!("[Mira/resume] Previous session summary: {}", summary),
+            ));
+        }
+
+        // Activity recap stored when the previous session closed
+        if let Some(ref recap) = prev_session.recap {
+            context_parts.push(ContextSection::new(
+                SectionPriority::History,
+                format!("[Mira/resume] Previous session recap:\n{}", recap),
             ));
         }
 
@@ -285,7 +397,10 @@ pub(crate) async fn build_resume_context(
                 // Insert at the beginning for prominence
                 context_parts.insert(
                     0,
-                    format!("[Mira/resume] You were working on: {}", working_on),
+                    ContextSection::new(
+                        SectionPriority::Recap,
+                        format!("[Mira/resume] You were working on: {}", working_on),
+                    ),
                 );
             }
 
@@ -295,9 +410,12 @@ pub(crate) async fn build_resume_context(
                 // Position after "working on" (index 0) if it exists, otherwise at top
                 let insert_pos = context_parts
                     .iter()
-                    .position(|p| p.starts_with("[Mira/resume] You were working on:"))
+                    .position(|p| p.text.starts_with("[Mira/resume] You were working on:"))
                     .map_or(0, |i| i + 1);
-                context_parts.insert(insert_pos, compaction_summary);
+                context_parts.insert(
+                    insert_pos,
+                    ContextSection::new(SectionPriority::Recap, compaction_summary),
+                );
             }
         }
 
@@ -319,10 +437,13 @@ pub(crate) async fn build_resume_context(
                 .map(|t| t.subject.as_str())
                 .take(10)
                 .collect();
-            context_parts.push(format!(
-                "[Mira/resume] Previous session had {} incomplete task(s): {}",
-                incomplete_tasks.len(),
-                subjects.join(", ")
+            context_parts.push(ContextSection::new(
+                SectionPriority::Goals,
+                format!(
+                    "[Mira/resume] Previous session had {} incomplete task(s): {}",
+                    incomplete_tasks.len(),
+                    subjects.join(", ")
+                ),
             ));
         }
     }
@@ -330,9 +451,9 @@ pub(crate) async fn build_resume_context(
     // Get incomplete goals
     let goal_lines = crate::hooks::format_active_goals(&pool, project_id, 3).await;
     if !goal_lines.is_empty() {
-        context_parts.push(format!(
-            "[Mira/goals] Active goals:\n{}",
-            goal_lines.join("\n")
+        context_parts.push(ContextSection::new(
+            SectionPriority::Goals,
+            format!("[Mira/goals] Active goals:\n{}", goal_lines.join("\n")),
         ));
         super::mark_goals_shown(session_id);
     }
@@ -372,18 +493,18 @@ pub(crate) async fn build_resume_context(
                 other_members.join(", ")
             )
         };
-        context_parts.push(team_line);
+        context_parts.push(ContextSection::new(SectionPriority::Notice, team_line));
     }
 
     if context_parts.is_empty() {
         return None;
     }
 
-    let output = format!(
-        "[Mira/resume] Resuming session - context from your previous work:\n\n{}",
-        context_parts.join("\n\n")
-    );
-    Some(truncate_session_context(output))
+    Some(fit_session_context(
+        Some("[Mira/resume] Resuming session - context from your previous work:"),
+        context_parts,
+        project_context_budget(&pool, project_id).await,
+    ))
 }
 
 // get_session_modified_files_sync is now in hooks/mod.rs
@@ -448,8 +569,8 @@ pub(crate) fn build_working_on_summary(snapshot: &serde_json::Value) -> Option<S
 
 /// Build a summary of pre-compaction context from the snapshot's `compaction_context` field.
 ///
-/// Returns a formatted string with user intent, decisions, active work, issues, pending tasks,
-/// and files referenced — ordered most-valuable-first.
+/// Returns a formatted string with user intent, active goals, decisions, active work, issues,
+/// pending and open tasks, and files referenced — ordered most-valuable-first.
 /// Returns None if no compaction context is present or all categories are empty.
 pub(crate) fn build_compaction_summary(snapshot: &serde_json::Value) -> Option<String> {
     let cc = snapshot.get("compaction_context")?;
@@ -462,6 +583,13 @@ pub(crate) fn build_compaction_summary(snapshot: &serde_json::Value) -> Option<S
         parts.push(format!("Original request: {}", intent));
     }
 
+    if let Some(goals) = cc.get("active_goals").and_then(|v| v.as_array()) {
+        let items: Vec<&str> = goals.iter().filter_map(|g| g.as_str()).take(5).collect();
+        if !items.is_empty() {
+            parts.push(format!("Active goals: {}", items.join("; ")));
+        }
+    }
+
     if let Some(decisions) = cc.get("decisions").and_then(|v| v.as_array()) {
         let items: Vec<&str> = decisions
             .iter()
@@ -494,6 +622,13 @@ pub(crate) fn build_compaction_summary(snapshot: &serde_json::Value) -> Option<S
         }
     }
 
+    if let Some(open) = cc.get("open_tasks").and_then(|v| v.as_array()) {
+        let items: Vec<&str> = open.iter().filter_map(|t| t.as_str()).take(5).collect();
+        if !items.is_empty() {
+            parts.push(format!("Open tasks: {}", items.join("; ")));
+        }
+    }
+
     // Files referenced (compact, comma-separated, up to 8)
     if let Some(files) = cc.get("files_referenced").and_then(|v| v.as_array()) {
         let items: Vec<&str> = files.iter().filter_map(|f| f.as_str()).take(8).collect();
@@ -540,15 +675,53 @@ pub(super) fn infer_activity_from_tools(tools: &[&str]) -> String {
     }
 }
 
+/// Join sections (after an optional header) within `budget` chars.
+///
+/// While over budget, the lowest-priority section is dropped, the later one
+/// on ties, so a section is never dropped while a lower-priority one remains.
+/// If the survivor alone is still too long it is cut at a line boundary.
+/// The final output is guaranteed to be <= `budget`.
+pub(crate) fn fit_session_context(
+    header: Option<&str>,
+    mut sections: Vec<ContextSection>,
+    budget: usize,
+) -> String {
+    let joined = |sections: &[ContextSection]| {
+        header
+            .into_iter()
+            .chain(sections.iter().map(|s| s.text.as_str()))
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    };
+
+    let mut output = joined(&sections);
+    while output.len() > budget && sections.len() > 1 {
+        let lowest = sections
+            .iter()
+            .enumerate()
+            .max_by_key(|(i, s)| (s.priority, *i))
+            .map(|(i, _)| i)
+            .expect("non-empty sections");
+        let dropped = sections.remove(lowest);
+        tracing::debug!(
+            priority = ?dropped.priority,
+            chars = dropped.text.len(),
+            "SessionStart context over budget, dropped section"
+        );
+        output = joined(&sections);
+    }
+    truncate_session_context(output, budget)
+}
+
 /// Enforce hard output budget on session context.
 /// Truncates at a UTF-8 safe boundary and appends `\n...` if over the limit.
-/// The final output is guaranteed to be <= MAX_SESSION_CONTEXT_CHARS.
-fn truncate_session_context(output: String) -> String {
-    if output.len() <= MAX_SESSION_CONTEXT_CHARS {
+/// The final output is guaranteed to be <= `max_chars`.
+fn truncate_session_context(output: String, max_chars: usize) -> String {
+    if output.len() <= max_chars {
         return output;
     }
     const SUFFIX: &str = "\n...";
-    let budget = MAX_SESSION_CONTEXT_CHARS.saturating_sub(SUFFIX.len());
+    let budget = max_chars.saturating_sub(SUFFIX.len());
     let mut truncated = truncate_at_boundary(&output, budget).to_string();
     // Avoid mid-line truncation by finding the last newline
     if let Some(pos) = truncated.rfind('\n') {
@@ -563,6 +736,70 @@ mod tests {
     use super::*;
     use serde_json::json;
 
+    fn section(priority: SectionPriority, tag: &str, len: usize) -> ContextSection {
+        ContextSection::new(priority, format!("[{}] {}", tag, "x".repeat(len)))
+    }
+
+    #[test]
+    fn fit_session_context_keeps_everything_under_budget() {
+        let sections = vec![
+            section(SectionPriority::History, "history", 50),
+            section(SectionPriority::Goals, "goals", 50),
+        ];
+        let output = fit_session_context(Some("header"), sections, 1000);
+        assert!(output.starts_with("header\n\n[history]"), "{output}");
+        assert!(output.contains("[goals]"));
+    }
+
+    #[test]
+    fn fit_session_context_drops_lowest_priority_first() {
+        let sections = vec![
+            section(SectionPriority::Recap, "recap", 300),
+            section(SectionPriority::History, "history", 2000),
+            section(SectionPriority::Notice, "notice", 300),
+            section(SectionPriority::Goals, "goals", 300),
+        ];
+        // Room for three small sections, not the history dump
+        let output = fit_session_context(None, sections.clone(), 1000);
+        assert!(!output.contains("[history]"), "{output}");
+        assert!(output.contains("[recap]") && output.contains("[notice]"));
+        assert!(output.contains("[goals]"));
+
+        // Tighter: notice goes before goals, recap is kept last
+        let output = fit_session_context(None, sections.clone(), 700);
+        assert!(!output.contains("[notice]"), "{output}");
+        assert!(output.contains("[recap]") && output.contains("[goals]"));
+
+        let output = fit_session_context(None, sections, 400);
+        assert!(output.starts_with("[recap]") && !output.contains("[goals]"));
+    }
+
+    #[test]
+    fn fit_session_context_never_exceeds_budget() {
+        let sections = (0..40)
+            .map(|i| {
+                let priority = match i % 4 {
+                    0 => SectionPriority::Recap,
+                    1 => SectionPriority::Goals,
+                    2 => SectionPriority::Notice,
+                    _ => SectionPriority::History,
+                };
+                ContextSection::new(priority, format!("[s{i}]\n{}", "line\n".repeat(200)))
+            })
+            .collect::<Vec<_>>();
+        for budget in [100, 800, 2500, 10_000] {
+            let output = fit_session_context(Some("[header]"), sections.clone(), budget);
+            assert!(output.len() <= budget, "{} > {}", output.len(), budget);
+            assert!(output.starts_with("[header]"));
+        }
+        // Only recap sections survive a budget that can't hold them all
+        let output = fit_session_context(None, sections, 10_000);
+        assert!(
+            output.contains("[s0]") && !output.contains("[s3]"),
+            "{output}"
+        );
+    }
+
     #[test]
     fn build_compaction_summary_includes_findings() {
         let snapshot = json!({
@@ -8,6 +8,7 @@ mod team;
 #[cfg(test)]
 pub(crate) use context::{
     build_compaction_summary, build_working_on_summary, get_session_snapshot_sync,
+    session_context_budget,
 };
 pub(crate) use context::{build_resume_context, build_startup_context};
 pub use team::{
@@ -622,6 +622,177 @@ async fn test_project_isolation_sessions() {
     assert_eq!(sessions_b.len(), 1);
 }
 
+// =============================================================================
+// Test 13: PreCompact state snapshot → post-compaction startup recap
+//   snapshot_state captures goals + open tasks → session_snapshots row
+//   SessionStart (source "compact", same session ID) leads with the recap
+// =============================================================================
+
+#[tokio::test]
+async fn test_precompact_snapshot_feeds_post_compaction_recap() {
+    let (pool, project_id) = setup_test_pool_with_project().await;
+    db(&pool, move |conn| {
+        seed_session(conn, "compact-sess", project_id, "active");
+        seed_goal(conn, project_id, "Ship auth rewrite", "in_progress", 40);
+        Ok(())
+    })
+    .await;
+
+    let tasks = tempfile::tempdir().unwrap();
+    std::fs::write(
+        tasks.path().join("1.json"),
+        r#"{"id":"1","subject":"Write session migration","status":"in_progress"}"#,
+    )
+    .unwrap();
+
+    // What save_pre_compaction_state does: state snapshot, then transcript extraction
+    let mut client = crate::ipc::client::HookClient::from_pool(pool.clone());
+    let state =
+        super::precompact::snapshot_state(&mut client, Some(project_id), Some(tasks.path())).await;
+    client
+        .save_compaction_context("compact-sess", serde_json::to_value(&state).unwrap())
+        .await;
+    let transcript =
+        r#"{"role":"assistant","content":"We decided to keep tokens in the session table."}"#;
+    super::precompact::extract_and_save_context(&mut client, "compact-sess", transcript)
+        .await
+        .unwrap();
+
+    let snapshot = db(&pool, |conn| {
+        Ok::<_, anyhow::Error>(super::session::get_session_snapshot_sync(
+            conn,
+            "compact-sess",
+        ))
+    })
+    .await
+    .expect("PreCompact should write a snapshot row");
+    let snap: serde_json::Value = serde_json::from_str(&snapshot).unwrap();
+    let cc = &snap["compaction_context"];
+    assert_eq!(cc["active_goals"].as_array().unwrap().len(), 1, "got {cc}");
+    assert_eq!(
+        cc["open_tasks"],
+        serde_json::json!(["Write session migration (in progress)"])
+    );
+    assert!(
+        cc["decisions"]
+            .to_string()
+            .contains("tokens in the session table"),
+        "got {cc}"
+    );
+
+    let context = super::session::build_startup_context(
+        Some("/test/path"),
+        Some(pool.clone()),
+        Some("compact-sess"),
+    )
+    .await
+    .expect("startup context after compaction");
+    assert!(
+        context.starts_with("[Mira/context] Pre-compaction context:"),
+        "recap should lead, got: {context}"
+    );
+    assert!(
+        context.contains("Active goals: Ship auth rewrite"),
+        "got: {context}"
+    );
+    assert!(
+        context.contains("Open tasks: Write session migration (in progress)"),
+        "got: {context}"
+    );
+    assert!(
+        !context.contains("[Mira/goals]"),
+        "goals should not be listed twice, got: {context}"
+    );
+}
+
+// =============================================================================
+// Test 14: SessionStart output budget on a large project
+//   A huge previous-session summary is dropped before goals are
+// =============================================================================
+
+#[tokio::test]
+async fn test_resume_context_stays_within_budget() {
+    let (pool, project_id) = setup_test_pool_with_project().await;
+    db(&pool, move |conn| {
+        seed_session(conn, "prev-sess", project_id, "completed");
+        conn.execute(
+            "UPDATE sessions SET summary = ?1 WHERE id = 'prev-sess'",
+            [format!(
+                "Refactored everything. {}",
+                "Touched module. ".repeat(1000)
+            )],
+        )?;
+        for i in 0..10 {
+            seed_goal(
+                conn,
+                project_id,
+                &format!("Goal number {i}"),
+                "in_progress",
+                10,
+            );
+        }
+        seed_session(conn, "resume-sess", project_id, "active");
+        Ok(())
+    })
+    .await;
+
+    let context = super::session::build_resume_context(
+        Some("/test/path"),
+        Some("resume-sess"),
+        Some(pool.clone()),
+    )
+    .await
+    .expect("resume context");
+    let budget = super::session::session_context_budget(Some("/test/path"));
+    assert!(
+        context.len() <= budget,
+        "{} chars over budget {}",
+        context.len(),
+        budget
+    );
+    assert!(
+        context.contains("[Mira/goals] Active goals:"),
+        "got: {context}"
+    );
+    assert!(
+        !context.contains("Previous session summary"),
+        "history should be dropped before goals, got: {context}"
+    );
+}
+
+// =============================================================================
+// Test 15: Resume context surfaces the previous session's stored recap
+// =============================================================================
+
+#[tokio::test]
+async fn test_resume_context_includes_previous_session_recap() {
+    let (pool, project_id) = setup_test_pool_with_project().await;
+    db(&pool, move |conn| {
+        seed_session(conn, "prev-sess", project_id, "completed");
+        crate::db::set_session_recap_sync(
+            conn,
+            "prev-sess",
+            "--- Session prev-ses ---\n\nTools used: Edit x4",
+        )?;
+        seed_session(conn, "resume-sess", project_id, "active");
+        Ok(())
+    })
+    .await;
+
+    let context = super::session::build_resume_context(
+        Some("/test/path"),
+        Some("resume-sess"),
+        Some(pool.clone()),
+    )
+    .await
+    .expect("resume context");
+    assert!(
+        context.contains("[Mira/resume] Previous session recap:"),
+        "got: {context}"
+    );
+    assert!(context.contains("Tools used: Edit x4"), "got: {context}");
+}
+
 // =============================================================================
 // Test gap #10: read_session_or_global_cwd
 // =============================================================================
@@ -0,0 +1,180 @@
+// crates/mira-server/src/indexer/freshness.rs
+// Index freshness: how many indexed files changed on disk since they were indexed
+
+use chrono::{DateTime, NaiveDateTime, Utc};
+use sha2::{Digest, Sha256};
+use std::path::Path;
+
+use crate::project_roots::resolve_file;
+
+/// More changed files than this marks the index as stale
+pub const STALE_CHANGED_FILES_THRESHOLD: usize = 10;
+
+/// Freshness of a project's code index
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IndexFreshness {
+    /// Most recent index time across all files (UTC, `YYYY-MM-DD HH:MM:SS`)
+    pub last_indexed_at: Option<String>,
+    /// Files present in the index
+    pub files_indexed: usize,
+    /// Indexed files modified or deleted since they were indexed
+    pub files_changed: usize,
+    /// True when `files_changed` exceeds [`STALE_CHANGED_FILES_THRESHOLD`]
+    pub stale: bool,
+}
+
+/// Hex SHA-256 of a file's contents, as stored in `indexed_files`
+pub(crate) fn content_hash(bytes: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(bytes))
+}
+
+/// Parse an index timestamp: SQLite `CURRENT_TIMESTAMP` format or RFC 3339
+fn parse_indexed_at(s: &str) -> Option<DateTime<Utc>> {
+    NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S")
+        .map(|t| t.and_utc())
+        .ok()
+        .or_else(|| {
+            DateTime::parse_from_rfc3339(s)
+                .ok()
+                .map(|t| t.with_timezone(&Utc))
+        })
+}
+
+/// Compare indexed files against the filesystem.
+///
+/// `files` are `(indexed path, indexed_at, content_hash)` rows as returned by
+/// `get_indexed_files_sync`. A file counts as changed when it no longer
+/// exists, or when its mtime is later than its own index time (or the
+/// project's latest index time when it has none) and its content no longer
+/// matches the stored hash. The hash check keeps `touch` and branch
+/// checkouts that restore the same content from reading as changes.
+pub fn compute_freshness(
+    project_path: &Path,
+    files: &[(String, Option<String>, Option<String>)],
+) -> IndexFreshness {
+    let last_indexed_at = files.iter().filter_map(|(_, t, _)| t.as_deref()).max();
+    let last_indexed = last_indexed_at.and_then(parse_indexed_at);
+
+    let files_changed = files
+        .iter()
+        .filter(|(file_path, indexed_at, hash)| {
+            let Some(full_path) = resolve_file(project_path, file_path) else {
+                return true;
+            };
+            let indexed = indexed_at
+                .as_deref()
+                .and_then(parse_indexed_at)
+                .or(last_indexed);
+            let modified = std::fs::metadata(&full_path)
+                .and_then(|m| m.modified())
+                .map(DateTime::<Utc>::from)
+                .ok();
+            let newer = match (modified, indexed) {
+                // CURRENT_TIMESTAMP has whole-second resolution
+                (Some(modified), Some(indexed)) => modified.timestamp() > indexed.timestamp(),
+                _ => false,
+            };
+            match hash {
+                Some(hash) if newer => std::fs::read(&full_path)
+                    .map(|bytes| content_hash(&bytes) != *hash)
+                    .unwrap_or(true),
+                _ => newer,
+            }
+        })
+        .count();
+
+    IndexFreshness {
+        last_indexed_at: last_indexed_at.map(String::from),
+        files_indexed: files.len(),
+        files_changed,
+        stale: files_changed > STALE_CHANGED_FILES_THRESHOLD,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(path: &str, indexed_at: Option<&str>) -> (String, Option<String>, Option<String>) {
+        (path.to_string(), indexed_at.map(String::from), None)
+    }
+
+    #[test]
+    fn parses_sqlite_and_rfc3339_timestamps() {
+        assert!(parse_indexed_at("2026-01-15 10:30:00").is_some());
+        assert!(parse_indexed_at("2026-01-15T10:30:00Z").is_some());
+        assert!(parse_indexed_at("yesterday").is_none());
+    }
+
+    #[test]
+    fn counts_files_changed_after_index_time() {
+        let dir = tempfile::tempdir().unwrap();
+        for name in ["fresh.rs", "edited.rs", "chunks_only.rs"] {
+            std::fs::write(dir.path().join(name), "fn f() {}").unwrap();
+        }
+
+        let files = vec![
+            // Indexed after the write: unchanged
+            row("fresh.rs", Some("2999-01-01 00:00:00")),
+            // Indexed long before the write: changed
+            row("edited.rs", Some("2000-01-01 00:00:00")),
+            // No symbols: compared against the latest index time
+            row("chunks_only.rs", None),
+            // Deleted since indexing
+            row("removed.rs", Some("2999-01-01 00:00:00")),
+        ];
+
+        let freshness = compute_freshness(dir.path(), &files);
+        assert_eq!(freshness.files_indexed, 4);
+        assert_eq!(freshness.files_changed, 2);
+        assert_eq!(
+            freshness.last_indexed_at.as_deref(),
+            Some("2999-01-01 00:00:00")
+        );
+        assert!(!freshness.stale);
+    }
+
+    #[test]
+    fn touched_files_with_same_content_are_not_changed() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("touched.rs"), "fn f() {}").unwrap();
+        std::fs::write(dir.path().join("edited.rs"), "fn g() { 1 }").unwrap();
+
+        // Both indexed long before their mtime, e.g. after `touch` or a checkout
+        let indexed = Some("2000-01-01 00:00:00".to_string());
+        let files = vec![
+            (
+                "touched.rs".to_string(),
+                indexed.clone(),
+                Some(content_hash(b"fn f() {}")),
+            ),
+            (
+                "edited.rs".to_string(),
+                indexed,
+                Some(content_hash(b"fn g() {}")),
+            ),
+        ];
+
+        let freshness = compute_freshness(dir.path(), &files);
+        assert_eq!(freshness.files_changed, 1);
+    }
+
+    #[test]
+    fn stale_when_changes_exceed_threshold() {
+        let dir = tempfile::tempdir().unwrap();
+        let files: Vec<_> = (0..=STALE_CHANGED_FILES_THRESHOLD)
+            .map(|i| {
+                let name = format!("f{i}.rs");
+                std::fs::write(dir.path().join(&name), "").unwrap();
+                row(&name, Some("2000-01-01 00:00:00"))
+            })
+            .collect();
+
+        let freshness = compute_freshness(dir.path(), &files);
+        assert_eq!(freshness.files_changed, STALE_CHANGED_FILES_THRESHOLD + 1);
+        assert!(freshness.stale);
+
+        let fresh = compute_freshness(dir.path(), &files[..STALE_CHANGED_FILES_THRESHOLD]);
+        assert!(!fresh.stale);
+    }
+}
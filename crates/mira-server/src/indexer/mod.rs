@@ -4,6 +4,7 @@
 #[cfg(feature = "parsers")]
 mod batch;
 mod chunking;
+mod freshness;
 #[cfg(feature = "parsers")]
 pub mod parsers;
 #[cfg(feature = "parsers")]
@@ -14,6 +15,7 @@ pub mod resolver;
 mod types;
 
 // Re-export public types
+pub use freshness::{IndexFreshness, STALE_CHANGED_FILES_THRESHOLD, compute_freshness};
 pub use resolver::{ImportResolver, ResolvedImport, RustImportResolver};
 pub use types::{CodeChunk, FileParseResult, IndexStats, ParsedImport, ParsedSymbol};
 
@@ -46,6 +48,8 @@ mod tests {
             errors: 0,
             skipped: 0,
             skipped_by_extension: std::collections::HashMap::new(),
+            unchanged: 0,
+            removed: 0,
         };
         assert_eq!(stats.files, 0);
         assert_eq!(stats.errors, 0);
@@ -8,13 +8,15 @@ use crate::indexer::batch::{
     SYMBOL_FLUSH_THRESHOLD, flush_chunks, flush_code_batch,
 };
 use crate::indexer::chunking::create_semantic_chunks;
+use crate::indexer::freshness::content_hash;
 use crate::indexer::parsing::{FunctionCall, Import, Symbol, extract_all};
 use crate::indexer::types::{IndexStats, ParsedSymbol};
 use crate::project_files::FileWalker;
+use crate::project_roots::{ProjectRoot, root_file_path};
 use anyhow::Result;
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::Path;
 use std::sync::Arc;
 
@@ -23,6 +25,10 @@ use std::sync::Arc;
 /// provide poor symbol/semantic value and risk OOM.
 const MAX_INDEX_FILE_BYTES: u64 = 1_024 * 1_024;
 
+/// Version of symbol/chunk extraction. Bump it whenever parsers or chunking
+/// change what gets stored, so the next index re-parses every file.
+pub const EXTRACTOR_VERSION: i64 = 1;
+
 /// File extensions supported for indexing
 const SUPPORTED_EXTENSIONS: &[&str] = &["rs", "py", "ts", "tsx", "js", "jsx", "go"];
 
@@ -281,12 +287,119 @@ async fn rebuild_fts_index_if_needed(pool: Arc<DatabasePool>, project_id: Option
     }
 }
 
-/// Index an entire project
+/// Content hash of every file, keyed by its stored path.
+///
+/// Files that cannot be read map to None and are always re-indexed.
+fn hash_files(
+    files: &[std::path::PathBuf],
+    base_path: &Path,
+    root_name: Option<&str>,
+) -> HashMap<String, Option<String>> {
+    #[cfg(feature = "parallel")]
+    let iter = files.par_iter();
+    #[cfg(not(feature = "parallel"))]
+    let iter = files.iter();
+
+    iter.map(|file_path| {
+        let hash = std::fs::read(file_path).map(|b| content_hash(&b)).ok();
+        (stored_path(file_path, base_path, root_name), hash)
+    })
+    .collect()
+}
+
+/// Path a file is stored under: relative to its root, `@name/`-prefixed for attached roots
+fn stored_path(file_path: &Path, base_path: &Path, root_name: Option<&str>) -> String {
+    let relative = file_path
+        .strip_prefix(base_path)
+        .unwrap_or(file_path)
+        .to_string_lossy();
+    match root_name {
+        Some(name) => root_file_path(name, &relative),
+        None => relative.to_string(),
+    }
+}
+
+/// Which files an incremental run re-indexes and which stored files are gone
+struct IndexPlan {
+    changed: HashSet<String>,
+    removed: Vec<String>,
+    unchanged: usize,
+}
+
+/// Compare the walked files against the stored hashes.
+///
+/// Returns None when a full rebuild is needed: nothing stored yet (including
+/// indexes written before hashes were tracked) or any file was indexed by a
+/// different extractor version.
+fn plan_incremental_index(
+    current: &HashMap<String, Option<String>>,
+    stored: &HashMap<String, (String, i64)>,
+) -> Option<IndexPlan> {
+    if stored.is_empty()
+        || stored
+            .values()
+            .any(|(_, version)| *version != EXTRACTOR_VERSION)
+    {
+        return None;
+    }
+
+    let mut changed = HashSet::new();
+    let mut unchanged = 0;
+    for (file_path, hash) in current {
+        match (hash, stored.get(file_path)) {
+            (Some(hash), Some((stored_hash, _))) if hash == stored_hash => unchanged += 1,
+            _ => {
+                changed.insert(file_path.clone());
+            }
+        }
+    }
+    let mut removed: Vec<String> = stored
+        .keys()
+        .filter(|file_path| !current.contains_key(*file_path))
+        .cloned()
+        .collect();
+    removed.sort();
+
+    Some(IndexPlan {
+        changed,
+        removed,
+        unchanged,
+    })
+}
+
+/// Clear index data for files that changed or were removed since the last run
+async fn clear_stale_files(
+    pool: Arc<DatabasePool>,
+    project_id: i64,
+    file_paths: Vec<String>,
+) -> Result<()> {
+    use crate::db::clear_file_index_sync;
+
+    pool.interact(move |conn| {
+        let tx = conn.unchecked_transaction()?;
+        for file_path in &file_paths {
+            clear_file_index_sync(&tx, project_id, file_path)?;
+        }
+        tx.commit()?;
+        Ok(())
+    })
+    .await
+}
+
+/// Index an entire project, including any attached roots.
+///
+/// Files under an attached root are stored as `@name/relative/path`.
+///
+/// Files whose content hash and extractor version match the previous run are
+/// skipped, so re-indexing an unchanged project parses and embeds nothing.
+/// `force` clears the project and re-indexes every file.
 pub async fn index_project(
     path: &Path,
+    roots: &[ProjectRoot],
     pool: Arc<DatabasePool>,
     embeddings: Option<Arc<EmbeddingClient>>,
     project_id: Option<i64>,
+    force: bool,
 ) -> Result<IndexStats> {
     tracing::info!("Starting index_project for {:?}", path);
 
@@ -297,22 +410,91 @@ pub async fn index_project(
         errors: 0,
         skipped: 0,
         skipped_by_extension: HashMap::new(),
+        unchanged: 0,
+        removed: 0,
     };
 
     tracing::info!("Collecting files...");
-    let files = collect_files_to_index(path, &mut stats);
+    let mut files = collect_files_to_index(path, &mut stats);
+    let mut root_files: Vec<_> = roots
+        .iter()
+        .map(|root| (root, collect_files_to_index(&root.path, &mut stats)))
+        .collect();
+
+    tracing::info!(
+        "Found {} files to index",
+        files.len() + root_files.iter().map(|(_, f)| f.len()).sum::<usize>()
+    );
 
-    tracing::info!("Found {} files to index", files.len());
+    let mut hashes = hash_files(&files, path, None);
+    for (root, files) in &root_files {
+        hashes.extend(hash_files(files, &root.path, Some(&root.name)));
+    }
 
-    // Clear existing data for this project, using the configured embedding dims so
-    // that vec_code is recreated with the correct dimension (not the legacy 1536 default).
-    let embedding_dims = embeddings.as_ref().map(|e| e.dimensions()).unwrap_or(1536);
-    clear_existing_project_data(pool.clone(), project_id, embedding_dims).await?;
+    let stored = match project_id {
+        Some(pid) if !force => {
+            pool.interact(move |conn| {
+                crate::db::get_file_hashes_sync(conn, pid).map_err(|e| anyhow::anyhow!(e))
+            })
+            .await?
+        }
+        _ => HashMap::new(),
+    };
+
+    let plan = plan_incremental_index(&hashes, &stored);
+    match (&plan, project_id) {
+        (Some(plan), Some(pid)) => {
+            tracing::info!(
+                "{} files unchanged, {} changed, {} removed since last index",
+                plan.unchanged,
+                plan.changed.len(),
+                plan.removed.len()
+            );
+            stats.unchanged = plan.unchanged;
+            stats.removed = plan.removed.len();
+
+            let stale: Vec<String> = plan
+                .changed
+                .iter()
+                .filter(|file_path| stored.contains_key(*file_path))
+                .chain(plan.removed.iter())
+                .cloned()
+                .collect();
+            if !stale.is_empty() {
+                clear_stale_files(pool.clone(), pid, stale).await?;
+            }
+
+            files.retain(|f| plan.changed.contains(&stored_path(f, path, None)));
+            for (root, files) in &mut root_files {
+                files.retain(|f| {
+                    plan.changed
+                        .contains(&stored_path(f, &root.path, Some(&root.name)))
+                });
+            }
+        }
+        _ => {
+            // Clear existing data for this project, using the configured embedding dims so
+            // that vec_code is recreated with the correct dimension (not the legacy 1536 default).
+            let embedding_dims = embeddings.as_ref().map(|e| e.dimensions()).unwrap_or(1536);
+            clear_existing_project_data(pool.clone(), project_id, embedding_dims).await?;
+        }
+    }
+    let needs_fts_rebuild = plan
+        .as_ref()
+        .is_none_or(|plan| !plan.changed.is_empty() || !plan.removed.is_empty());
 
     // Phase 1: Parse all files in parallel (CPU-bound, uses all cores)
     tracing::info!("Parsing {} files in parallel...", files.len());
     let parse_start = std::time::Instant::now();
-    let (parsed_files, parse_errors) = parse_files_parallel(&files, path);
+    let (mut parsed_files, mut parse_errors) = parse_files_parallel(&files, path);
+    for (root, files) in &root_files {
+        let (root_parsed, root_errors) = parse_files_parallel(files, &root.path);
+        parsed_files.extend(root_parsed.into_iter().map(|mut parsed| {
+            parsed.relative_path = root_file_path(&root.name, &parsed.relative_path);
+            parsed
+        }));
+        parse_errors += root_errors;
+    }
     stats.errors += parse_errors;
     tracing::info!(
         "Parallel parsing complete in {:?} ({} files, {} errors)",
@@ -321,6 +503,18 @@ pub async fn index_project(
         parse_errors
     );
 
+    // Only files that parsed get a stored hash; failures are retried next run
+    let indexed_hashes: Vec<(String, String)> = parsed_files
+        .iter()
+        .filter_map(|parsed| {
+            hashes
+                .get(&parsed.relative_path)
+                .cloned()
+                .flatten()
+                .map(|hash| (parsed.relative_path.clone(), hash))
+        })
+        .collect();
+
     // Phase 2: Process parsed files and batch insert to DB (IO-bound)
     tracing::info!("Processing parsed files...");
     let mut pending_chunks: Vec<PendingChunk> = Vec::new();
@@ -348,8 +542,18 @@ pub async fn index_project(
     )
     .await?;
 
+    if let Some(pid) = project_id {
+        pool.interact(move |conn| {
+            crate::db::record_file_hashes_sync(conn, pid, &indexed_hashes, EXTRACTOR_VERSION)
+                .map_err(|e| anyhow::anyhow!(e))
+        })
+        .await?;
+    }
+
     // Rebuild FTS5 full-text search index for this project
-    rebuild_fts_index_if_needed(pool.clone(), project_id).await;
+    if needs_fts_rebuild {
+        rebuild_fts_index_if_needed(pool.clone(), project_id).await;
+    }
 
     // Build skipped-by-extension summary for logging
     let skipped_ext_summary = if stats.skipped_by_extension.is_empty() {
@@ -363,8 +567,10 @@ pub async fn index_project(
 
     if stats.errors > 0 {
         tracing::warn!(
-            "Indexing complete with errors: {} files, {} symbols, {} chunks, {} errors, {} skipped{}",
+            "Indexing complete with errors: {} files unchanged, {} re-indexed, {} removed, {} symbols, {} chunks, {} errors, {} skipped{}",
+            stats.unchanged,
             stats.files,
+            stats.removed,
             stats.symbols,
             stats.chunks,
             stats.errors,
@@ -373,8 +579,10 @@ pub async fn index_project(
         );
     } else {
         tracing::info!(
-            "Indexing complete: {} files, {} symbols, {} chunks, {} skipped{}",
+            "Indexing complete: {} files unchanged, {} re-indexed, {} removed, {} symbols, {} chunks, {} skipped{}",
+            stats.unchanged,
             stats.files,
+            stats.removed,
             stats.symbols,
             stats.chunks,
             stats.skipped,
@@ -408,6 +616,8 @@ mod tests {
             errors: 0,
             skipped: 0,
             skipped_by_extension: HashMap::new(),
+            unchanged: 0,
+            removed: 0,
         };
 
         let files = collect_files_to_index(dir.path(), &mut stats);
@@ -445,6 +655,8 @@ mod tests {
             errors: 0,
             skipped: 0,
             skipped_by_extension: HashMap::new(),
+            unchanged: 0,
+            removed: 0,
         };
 
         let files = collect_files_to_index(dir.path(), &mut stats);
@@ -476,4 +688,194 @@ mod tests {
             ".py should not appear in skipped_by_extension"
         );
     }
+
+    fn stored(entries: &[(&str, &str, i64)]) -> HashMap<String, (String, i64)> {
+        entries
+            .iter()
+            .map(|(path, hash, version)| (path.to_string(), (hash.to_string(), *version)))
+            .collect()
+    }
+
+    #[test]
+    fn test_plan_incremental_index_skips_unchanged_and_finds_removed() {
+        let current: HashMap<String, Option<String>> = [
+            ("same.rs", Some("aaa")),
+            ("edited.rs", Some("new")),
+            ("added.rs", Some("ccc")),
+            ("unreadable.rs", None),
+        ]
+        .into_iter()
+        .map(|(path, hash)| (path.to_string(), hash.map(String::from)))
+        .collect();
+        let previous = stored(&[
+            ("same.rs", "aaa", EXTRACTOR_VERSION),
+            ("edited.rs", "old", EXTRACTOR_VERSION),
+            ("unreadable.rs", "ddd", EXTRACTOR_VERSION),
+            ("deleted.rs", "eee", EXTRACTOR_VERSION),
+        ]);
+
+        let plan = plan_incremental_index(&current, &previous).unwrap();
+        assert_eq!(plan.unchanged, 1);
+        let mut changed: Vec<_> = plan.changed.iter().map(String::as_str).collect();
+        changed.sort();
+        assert_eq!(changed, vec!["added.rs", "edited.rs", "unreadable.rs"]);
+        assert_eq!(plan.removed, vec!["deleted.rs".to_string()]);
+    }
+
+    #[test]
+    fn test_plan_incremental_index_requires_full_rebuild() {
+        let current: HashMap<String, Option<String>> =
+            [("a.rs".to_string(), Some("aaa".to_string()))].into();
+
+        // Nothing stored (first run, or an index from before hashes were tracked)
+        assert!(plan_incremental_index(&current, &HashMap::new()).is_none());
+
+        // Any file from another extractor version invalidates the whole index
+        let previous = stored(&[
+            ("a.rs", "aaa", EXTRACTOR_VERSION),
+            ("b.rs", "bbb", EXTRACTOR_VERSION - 1),
+        ]);
+        assert!(plan_incremental_index(&current, &previous).is_none());
+    }
+
+    #[cfg(feature = "parsers")]
+    #[tokio::test]
+    async fn test_reindex_unchanged_project_is_a_no_op() {
+        const FILE_COUNT: usize = 500;
+        let dir = tempfile::tempdir().unwrap();
+        for i in 0..FILE_COUNT {
+            std::fs::write(
+                dir.path().join(format!("module_{i}.rs")),
+                format!("pub fn handler_{i}(x: u32) -> u32 {{\n    x + {i}\n}}\n"),
+            )
+            .unwrap();
+        }
+        let pool = Arc::new(DatabasePool::open_code_db_in_memory().await.unwrap());
+        let search = |pool: Arc<DatabasePool>| async move {
+            pool.run(|conn| {
+                Ok::<_, rusqlite::Error>(crate::search::keyword_search(
+                    conn,
+                    "handler_42",
+                    Some(1),
+                    None,
+                    10,
+                ))
+            })
+            .await
+            .unwrap()
+        };
+
+        let first = index_project(dir.path(), &[], pool.clone(), None, Some(1), false)
+            .await
+            .unwrap();
+        assert_eq!(first.files, FILE_COUNT);
+        let first_results = search(pool.clone()).await;
+        assert!(!first_results.is_empty());
+
+        let second = index_project(dir.path(), &[], pool.clone(), None, Some(1), false)
+            .await
+            .unwrap();
+        assert_eq!(second.unchanged, FILE_COUNT);
+        assert_eq!(second.files, 0, "no unchanged file should be parsed again");
+        assert_eq!((second.symbols, second.chunks), (0, 0));
+        assert_eq!(
+            second.file_summary(),
+            "500 files unchanged, 0 re-indexed, 0 removed"
+        );
+        assert_eq!(search(pool.clone()).await, first_results);
+
+        // One edit and one deletion touch only those files
+        std::fs::write(
+            dir.path().join("module_7.rs"),
+            "pub fn renamed_seven() -> u32 {\n    7\n}\n",
+        )
+        .unwrap();
+        std::fs::remove_file(dir.path().join("module_8.rs")).unwrap();
+        let third = index_project(dir.path(), &[], pool.clone(), None, Some(1), false)
+            .await
+            .unwrap();
+        assert_eq!(
+            third.file_summary(),
+            "498 files unchanged, 1 re-indexed, 1 removed"
+        );
+        let (symbols, hashes) = pool
+            .run(|conn| {
+                let symbols: Vec<String> = conn
+                    .prepare(
+                        "SELECT name FROM code_symbols
+                         WHERE file_path IN ('module_7.rs', 'module_8.rs') ORDER BY name",
+                    )?
+                    .query_map([], |row| row.get(0))?
+                    .collect::<rusqlite::Result<_>>()?;
+                let hashes = crate::db::get_file_hashes_sync(conn, 1)?;
+                Ok::<_, rusqlite::Error>((symbols, hashes.len()))
+            })
+            .await
+            .unwrap();
+        assert_eq!(symbols, vec!["renamed_seven".to_string()]);
+        assert_eq!(hashes, FILE_COUNT - 1);
+
+        // --force re-indexes everything regardless of hashes
+        let forced = index_project(dir.path(), &[], pool.clone(), None, Some(1), true)
+            .await
+            .unwrap();
+        assert_eq!((forced.files, forced.unchanged), (FILE_COUNT - 1, 0));
+        let hits = |results: &[(String, String, f32, i64)]| -> Vec<(String, String)> {
+            results.iter().map(|r| (r.0.clone(), r.1.clone())).collect()
+        };
+        assert_eq!(hits(&search(pool).await), hits(&first_results));
+    }
+
+    #[cfg(feature = "parsers")]
+    #[tokio::test]
+    async fn test_index_project_covers_attached_roots() {
+        let api = tempfile::tempdir().unwrap();
+        let web = tempfile::tempdir().unwrap();
+        std::fs::write(
+            api.path().join("orders.rs"),
+            "pub fn checkout_order() -> u32 {\n    42\n}\n",
+        )
+        .unwrap();
+        std::fs::create_dir(web.path().join("src")).unwrap();
+        std::fs::write(
+            web.path().join("src/cart.ts"),
+            "export function checkoutButton() {\n  return \"cart\";\n}\n",
+        )
+        .unwrap();
+        let roots = vec![ProjectRoot {
+            name: "web".into(),
+            path: web.path().to_path_buf(),
+        }];
+        crate::project_roots::register_roots(api.path(), roots.clone());
+
+        let pool = Arc::new(DatabasePool::open_code_db_in_memory().await.unwrap());
+        let stats = index_project(api.path(), &roots, pool.clone(), None, Some(1), false)
+            .await
+            .unwrap();
+        assert_eq!(stats.files, 2);
+
+        // One search over the project id finds symbols from both roots
+        let project_path = api.path().to_string_lossy().to_string();
+        let results = pool
+            .run(move |conn| {
+                Ok::<_, rusqlite::Error>(crate::search::keyword_search(
+                    conn,
+                    "checkout",
+                    Some(1),
+                    Some(&project_path),
+                    10,
+                ))
+            })
+            .await
+            .unwrap();
+        let files: Vec<&str> = results.iter().map(|r| r.0.as_str()).collect();
+        assert!(files.contains(&"orders.rs"), "got {:?}", files);
+        assert!(files.contains(&"@web/src/cart.ts"), "got {:?}", files);
+
+        // Attached-root content is read from the root directory
+        let web_hit = results.iter().find(|r| r.0 == "@web/src/cart.ts").unwrap();
+        assert!(web_hit.1.contains("return \"cart\""), "got {:?}", web_hit.1);
+
+        crate::project_roots::register_roots(api.path(), vec![]);
+    }
 }
@@ -6,6 +6,7 @@ use std::collections::HashMap;
 
 /// Index statistics
 pub struct IndexStats {
+    /// Files parsed and (re-)indexed in this run
     pub files: usize,
     pub symbols: usize,
     pub chunks: usize,
@@ -13,6 +14,20 @@ pub struct IndexStats {
     pub skipped: usize,
     /// Files skipped due to unsupported extension, grouped by extension (e.g. ".java" -> 45)
     pub skipped_by_extension: HashMap<String, usize>,
+    /// Files whose content hash and extractor version were unchanged (not re-parsed)
+    pub unchanged: usize,
+    /// Previously indexed files that no longer exist
+    pub removed: usize,
+}
+
+impl IndexStats {
+    /// "N files unchanged, M re-indexed, K removed"
+    pub fn file_summary(&self) -> String {
+        format!(
+            "{} files unchanged, {} re-indexed, {} removed",
+            self.unchanged, self.files, self.removed
+        )
+    }
 }
 
 /// A code chunk with content and location info
@@ -112,13 +112,14 @@ impl super::HookClient {
         }
     }
 
-    /// Look up a resolved error pattern. Returns the fix description if found.
+    /// Look up a past fix for an error. Returns the fix description and how it
+    /// matched (`exact`, `normalized`, `similar`, or `semantic` via the server).
     pub async fn lookup_resolved_pattern(
         &mut self,
         project_id: i64,
         tool_name: &str,
         fingerprint: &str,
-    ) -> Option<String> {
+    ) -> Option<(String, String)> {
         if self.is_ipc() {
             let params = json!({
                 "project_id": project_id,
@@ -131,10 +132,15 @@ impl super::HookClient {
                     .and_then(|v| v.as_bool())
                     .unwrap_or(false)
                 {
+                    let strategy = result
+                        .get("match")
+                        .and_then(|v| v.as_str())
+                        .unwrap_or("exact")
+                        .to_string();
                     return result
                         .get("fix_description")
                         .and_then(|v| v.as_str())
-                        .map(String::from);
+                        .map(|fix| (fix.to_string(), strategy));
                 }
                 return None;
             }
@@ -145,7 +151,7 @@ impl super::HookClient {
             let fingerprint = fingerprint.to_string();
             let pattern = pool
                 .interact(move |conn| {
-                    Ok::<_, anyhow::Error>(crate::db::lookup_resolved_pattern_sync(
+                    Ok::<_, anyhow::Error>(crate::db::find_similar_fix_sync(
                         conn,
                         project_id,
                         &tool_name,
@@ -155,7 +161,10 @@ impl super::HookClient {
                 .await
                 .ok()
                 .flatten()?;
-            return Some(pattern.fix_description);
+            return Some((
+                pattern.pattern.fix_description,
+                pattern.strategy.as_str().to_string(),
+            ));
         }
         None
     }
@@ -191,6 +200,72 @@ impl super::HookClient {
         0
     }
 
+    /// Count how many times an error fingerprint has failed in the current session.
+    pub async fn count_fingerprint_failures(&mut self, session_id: &str, fingerprint: &str) -> i64 {
+        if self.is_ipc() {
+            let params = json!({"session_id": session_id, "fingerprint": fingerprint});
+            if let Ok(result) = self.call("count_fingerprint_failures", params).await {
+                return result.get("count").and_then(|v| v.as_i64()).unwrap_or(0);
+            }
+        }
+        if let Backend::Direct { pool } = &self.inner {
+            let pool = pool.clone();
+            let session_id = session_id.to_string();
+            let fingerprint = fingerprint.to_string();
+            return pool
+                .interact(move |conn| {
+                    Ok::<_, anyhow::Error>(crate::db::count_fingerprint_failures_sync(
+                        conn,
+                        &session_id,
+                        &fingerprint,
+                    ))
+                })
+                .await
+                .unwrap_or(0);
+        }
+        0
+    }
+
+    /// Count this fingerprint's failures logged after its tool had failed
+    /// `min_tool_failures` times in the current session.
+    pub async fn count_escalated_fingerprint_failures(
+        &mut self,
+        session_id: &str,
+        fingerprint: &str,
+        min_tool_failures: i64,
+    ) -> i64 {
+        if self.is_ipc() {
+            let params = json!({
+                "session_id": session_id,
+                "fingerprint": fingerprint,
+                "min_tool_failures": min_tool_failures,
+            });
+            if let Ok(result) = self
+                .call("count_escalated_fingerprint_failures", params)
+                .await
+            {
+                return result.get("count").and_then(|v| v.as_i64()).unwrap_or(0);
+            }
+        }
+        if let Backend::Direct { pool } = &self.inner {
+            let pool = pool.clone();
+            let session_id = session_id.to_string();
+            let fingerprint = fingerprint.to_string();
+            return pool
+                .interact(move |conn| {
+                    Ok::<_, anyhow::Error>(crate::db::count_escalated_fingerprint_failures_sync(
+                        conn,
+                        &session_id,
+                        &fingerprint,
+                        min_tool_failures,
+                    ))
+                })
+                .await
+                .unwrap_or(0);
+        }
+        0
+    }
+
     /// Resolve error patterns after a successful tool use.
     /// Returns true if a pattern was resolved.
     pub async fn resolve_error_patterns(
@@ -195,9 +195,13 @@ impl super::HookClient {
                     if let Err(e) = crate::hooks::stop::save_session_snapshot(conn, &session_id) {
                         tracing::warn!("[mira] Session snapshot failed: {}", e);
                     }
-                    if let Err(e) =
-                        crate::db::close_session_sync(conn, &session_id, summary.as_deref(), None)
-                    {
+                    if let Err(e) = crate::db::close_session_sync(
+                        conn,
+                        &session_id,
+                        summary.as_deref(),
+                        None,
+                        crate::db::CLOSE_REASON_SESSION_END,
+                    ) {
                         tracing::warn!("[mira] Failed to close session: {e}");
                     }
                     tracing::debug!(
@@ -245,6 +245,12 @@ async fn dispatch(
         "store_error_pattern" => super::ops::store_error_pattern(server, params).await,
         "lookup_resolved_pattern" => super::ops::lookup_resolved_pattern(server, params).await,
         "count_session_failures" => super::ops::count_session_failures(server, params).await,
+        "count_fingerprint_failures" => {
+            super::ops::count_fingerprint_failures(server, params).await
+        }
+        "count_escalated_fingerprint_failures" => {
+            super::ops::count_escalated_fingerprint_failures(server, params).await
+        }
         "resolve_error_patterns" => super::ops::resolve_error_patterns(server, params).await,
         "get_team_membership" => super::ops::get_team_membership(server, params).await,
         "record_file_ownership" => super::ops::record_file_ownership(server, params).await,
@@ -263,7 +263,8 @@ pub async fn store_error_pattern(server: &MiraServer, params: Value) -> Result<V
     Ok(json!({}))
 }
 
-/// Look up a resolved error pattern by fingerprint.
+/// Look up a past fix for an error: exact fingerprint, then normalized or similar
+/// shape, then (when embeddings are configured) semantic similarity.
 pub async fn lookup_resolved_pattern(server: &MiraServer, params: Value) -> Result<Value> {
     let project_id = params
         .get("project_id")
@@ -280,22 +281,106 @@ pub async fn lookup_resolved_pattern(server: &MiraServer, params: Value) -> Resu
         .ok_or_else(|| anyhow::anyhow!("missing required param: fingerprint"))?
         .to_string();
 
-    let pattern = server
+    let (tool, fp) = (tool_name.clone(), fingerprint.clone());
+    let mut pattern = server
         .pool
         .interact(move |conn| {
-            Ok::<_, anyhow::Error>(crate::db::lookup_resolved_pattern_sync(
+            Ok::<_, anyhow::Error>(crate::db::find_similar_fix_sync(
+                conn, project_id, &tool, &fp,
+            ))
+        })
+        .await?;
+
+    if pattern.is_none()
+        && let Some(embeddings) = &server.embeddings
+    {
+        pattern = semantic_fix(server, embeddings, project_id, tool_name, fingerprint)
+            .await
+            .unwrap_or_else(|e| {
+                tracing::debug!("Semantic fix lookup failed: {}", e);
+                None
+            });
+    }
+
+    match pattern {
+        Some(fix) => Ok(json!({
+            "found": true,
+            "fix_description": fix.pattern.fix_description,
+            "match": fix.strategy.as_str(),
+            "score": fix.score,
+        })),
+        None => Ok(json!({"found": false})),
+    }
+}
+
+/// Compare the error's embedding with the tool's resolved patterns, embedding
+/// (and caching) any pattern that has no embedding for the current model yet.
+async fn semantic_fix(
+    server: &MiraServer,
+    embeddings: &crate::embeddings::EmbeddingClient,
+    project_id: i64,
+    tool_name: String,
+    fingerprint: String,
+) -> Result<Option<crate::db::SimilarFix>> {
+    let (template, candidates) = server
+        .pool
+        .interact(move |conn| {
+            let template =
+                crate::db::error_template_sync(conn, project_id, &tool_name, &fingerprint);
+            let candidates = crate::db::semantic_fix_candidates_sync(
                 conn,
                 project_id,
                 &tool_name,
                 &fingerprint,
-            ))
+            )?;
+            Ok::<_, anyhow::Error>((template, candidates))
         })
         .await?;
+    let Some(template) = template else {
+        return Ok(None);
+    };
+    if candidates.is_empty() {
+        return Ok(None);
+    }
 
-    match pattern {
-        Some(p) => Ok(json!({"found": true, "fix_description": p.fix_description})),
-        None => Ok(json!({"found": false})),
+    let dimensions = embeddings.dimensions();
+    let mut scored = Vec::with_capacity(candidates.len());
+    let mut missing = Vec::new();
+    for candidate in candidates {
+        match candidate
+            .embedding
+            .as_deref()
+            .map(crate::search::bytes_to_embedding)
+        {
+            Some(embedding) if embedding.len() == dimensions => {
+                scored.push((candidate.pattern, embedding))
+            }
+            _ => missing.push(candidate.pattern),
+        }
     }
+
+    if !missing.is_empty() {
+        let texts: Vec<String> = missing.iter().map(crate::db::fix_embedding_text).collect();
+        let fresh = embeddings.embed_batch(&texts).await?;
+        let cached: Vec<(i64, Vec<u8>)> = missing
+            .iter()
+            .zip(&fresh)
+            .map(|(p, e)| (p.id, crate::search::embedding_to_bytes(e)))
+            .collect();
+        server
+            .pool
+            .interact(move |conn| {
+                for (id, bytes) in &cached {
+                    crate::db::store_fix_embedding_sync(conn, *id, bytes)?;
+                }
+                Ok::<_, anyhow::Error>(())
+            })
+            .await?;
+        scored.extend(missing.into_iter().zip(fresh));
+    }
+
+    let query = embeddings.embed(&template).await?;
+    Ok(crate::db::best_semantic_fix(&query, scored))
 }
 
 /// Count how many times a tool has failed in the current session.
@@ -330,6 +415,69 @@ pub async fn count_session_failures(server: &MiraServer, params: Value) -> Resul
     Ok(json!({"count": count}))
 }
 
+/// Count how many times an error fingerprint has been seen in the current session.
+pub async fn count_fingerprint_failures(server: &MiraServer, params: Value) -> Result<Value> {
+    let session_id = params
+        .get("session_id")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| anyhow::anyhow!("missing required param: session_id"))?
+        .to_string();
+    let fingerprint = params
+        .get("fingerprint")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| anyhow::anyhow!("missing required param: fingerprint"))?
+        .to_string();
+
+    let count: i64 = server
+        .pool
+        .interact(move |conn| {
+            Ok::<_, anyhow::Error>(crate::db::count_fingerprint_failures_sync(
+                conn,
+                &session_id,
+                &fingerprint,
+            ))
+        })
+        .await?;
+
+    Ok(json!({"count": count}))
+}
+
+/// Count this fingerprint's failures logged after its tool had failed
+/// `min_tool_failures` times in the current session.
+pub async fn count_escalated_fingerprint_failures(
+    server: &MiraServer,
+    params: Value,
+) -> Result<Value> {
+    let session_id = params
+        .get("session_id")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| anyhow::anyhow!("missing required param: session_id"))?
+        .to_string();
+    let fingerprint = params
+        .get("fingerprint")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| anyhow::anyhow!("missing required param: fingerprint"))?
+        .to_string();
+    let min_tool_failures = params
+        .get("min_tool_failures")
+        .and_then(|v| v.as_i64())
+        .ok_or_else(|| anyhow::anyhow!("missing required param: min_tool_failures"))?;
+
+    let count: i64 = server
+        .pool
+        .interact(move |conn| {
+            Ok::<_, anyhow::Error>(crate::db::count_escalated_fingerprint_failures_sync(
+                conn,
+                &session_id,
+                &fingerprint,
+                min_tool_failures,
+            ))
+        })
+        .await?;
+
+    Ok(json!({"count": count}))
+}
+
 /// Resolve error patterns after a successful tool use.
 pub async fn resolve_error_patterns(server: &MiraServer, params: Value) -> Result<Value> {
     let project_id = params
@@ -829,9 +977,13 @@ pub async fn close_session(server: &MiraServer, params: Value) -> Result<Value>
                     tracing::warn!("[mira] Session snapshot failed: {}", e);
                 }
                 // Best-effort: close may fail if session was already closed
-                if let Err(e) =
-                    crate::db::close_session_sync(conn, &session_id, summary.as_deref(), None)
-                {
+                if let Err(e) = crate::db::close_session_sync(
+                    conn,
+                    &session_id,
+                    summary.as_deref(),
+                    None,
+                    crate::db::CLOSE_REASON_SESSION_END,
+                ) {
                     tracing::warn!("[mira] Failed to close session: {e}");
                 }
                 tracing::debug!(
@@ -5,6 +5,7 @@
 #![warn(clippy::expect_used)]
 #![cfg_attr(test, allow(clippy::unwrap_used, clippy::expect_used))]
 
+pub mod audit;
 pub mod background;
 pub mod cartographer;
 pub mod config;
@@ -25,6 +26,7 @@ pub mod mcp;
 #[cfg(unix)]
 pub mod mux;
 pub mod project_files;
+pub mod project_roots;
 pub mod scripting;
 pub mod search;
 pub mod tasks;
@@ -1,6 +1,8 @@
 // crates/mira-server/src/llm/http_client.rs
 // Shared HTTP client configuration for all LLM providers
 
+use crate::error::MiraError;
+use crate::http::retry_after_secs;
 use anyhow::{Result, anyhow};
 use reqwest::Client;
 use std::time::Duration;
@@ -98,6 +100,8 @@ impl LlmHttpClient {
                 Ok(response) => {
                     let status = response.status();
                     if !status.is_success() {
+                        let provider = response.url().host_str().unwrap_or("LLM API").to_string();
+                        let retry_after = retry_after_secs(response.headers());
                         let error_body = response.text().await.unwrap_or_default();
 
                         // Check for transient errors
@@ -117,7 +121,13 @@ impl LlmHttpClient {
                             continue;
                         }
 
-                        return Err(anyhow!("API error {}: {}", status, error_body));
+                        return Err(MiraError::from_http_status(
+                            provider,
+                            status.as_u16(),
+                            error_body,
+                            retry_after,
+                        )
+                        .into());
                     }
 
                     return Ok(response.text().await?);
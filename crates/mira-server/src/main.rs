@@ -8,7 +8,7 @@ use clap::Parser;
 use tracing::Level;
 use tracing_subscriber::FmtSubscriber;
 
-use cli::{Cli, Commands, ConfigAction, HookAction, analyze};
+use cli::{AuditAction, Cli, Commands, ConfigAction, HookAction, analyze};
 
 #[tokio::main]
 async fn main() -> Result<()> {
@@ -60,7 +60,11 @@ async fn main() -> Result<()> {
                 Some(Commands::DebugSession { .. }) => Level::DEBUG,
                 Some(Commands::Config { .. }) => Level::WARN,
                 Some(Commands::Setup { .. }) => Level::WARN,
+                Some(Commands::Migrate { .. }) => Level::WARN,
                 Some(Commands::Cleanup { .. }) => Level::INFO,
+                Some(Commands::Audit { .. }) => Level::WARN,
+                Some(Commands::Export { .. }) | Some(Commands::Import { .. }) => Level::WARN,
+                Some(Commands::TaskSync { .. }) => Level::WARN,
                 Some(Commands::StatusLine) => Level::WARN,
                 Some(Commands::AnalyzeSession { .. }) => Level::WARN,
                 #[cfg(unix)]
@@ -86,9 +90,10 @@ async fn main() -> Result<()> {
         Some(Commands::Index {
             path,
             no_embed,
+            force,
             quiet,
         }) => {
-            cli::run_index(path, no_embed, quiet).await?;
+            cli::run_index(path, no_embed, force, quiet).await?;
         }
         Some(Commands::Hook { action }) => {
             // Hooks must NEVER exit with a non-zero code -- Claude Code
@@ -145,12 +150,21 @@ async fn main() -> Result<()> {
             cli::run_debug_session(path).await?;
         }
         Some(Commands::Config { action }) => match action {
-            ConfigAction::Show => cli::config::run_config_show()?,
+            ConfigAction::Show { effective, project } => {
+                if effective {
+                    cli::config::run_config_show_effective(project)?
+                } else {
+                    cli::config::run_config_show()?
+                }
+            }
             ConfigAction::Set { key, value } => cli::config::run_config_set(&key, &value)?,
         },
         Some(Commands::Setup { check, yes }) => {
             cli::setup::run(check, yes).await?;
         }
+        Some(Commands::Migrate { dry_run }) => {
+            cli::migrate::run_migrate(dry_run).await?;
+        }
         Some(Commands::Cleanup {
             execute,
             yes,
@@ -158,6 +172,25 @@ async fn main() -> Result<()> {
         }) => {
             cli::run_cleanup(!execute, yes, category).await?;
         }
+        Some(Commands::Audit { action }) => match action {
+            AuditAction::Export {
+                since,
+                until,
+                project,
+                tool,
+                out,
+            } => cli::audit::run_audit_export(since, until, project, tool, out).await?,
+            AuditAction::Verify { file } => cli::audit::run_audit_verify(file)?,
+        },
+        Some(Commands::Export { path, out }) => {
+            cli::export::run_export(path, out).await?;
+        }
+        Some(Commands::Import { file, path }) => {
+            cli::export::run_import(file, path).await?;
+        }
+        Some(Commands::TaskSync { path }) => {
+            cli::task_sync::run_task_sync(path).await?;
+        }
         Some(Commands::StatusLine) => {
             cli::statusline::run()?;
         }
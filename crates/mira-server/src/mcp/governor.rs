@@ -0,0 +1,310 @@
+// crates/mira-server/src/mcp/governor.rs
+// Result size governor — stores oversized tool output as an artifact and returns a preview
+
+use rmcp::{
+    ErrorData,
+    model::{CallToolResult, Content},
+};
+
+use super::MiraServer;
+use crate::db::artifacts::store_tool_artifact_sync;
+use crate::utils::{redact_sensitive, truncate_at_boundary};
+
+/// Results with more text than this are stored as artifacts instead of returned inline
+const INLINE_RESULT_MAX_BYTES: usize = 64 * 1024;
+/// Lines and bytes kept from the start of an oversized result
+const PREVIEW_HEAD_LINES: usize = 40;
+const PREVIEW_HEAD_BYTES: usize = 6 * 1024;
+/// Lines and bytes kept from the end of an oversized result
+const PREVIEW_TAIL_LINES: usize = 15;
+const PREVIEW_TAIL_BYTES: usize = 2 * 1024;
+
+/// How a tool's results are treated when they exceed the inline threshold.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum InlinePolicy {
+    /// Store oversized text as an artifact and return a preview plus reference
+    Govern,
+    /// Always return inline: the client parses the structured result itself
+    Inline,
+}
+
+/// Per-tool governor flag. Tools not listed here are returned inline.
+fn inline_policy(tool_name: &str) -> InlinePolicy {
+    match tool_name {
+        "run" => InlinePolicy::Govern,
+        _ => InlinePolicy::Inline,
+    }
+}
+
+/// All text content of a tool result, joined with newlines.
+fn result_text(result: &CallToolResult) -> String {
+    result
+        .content
+        .iter()
+        .filter_map(|c| c.as_text())
+        .map(|t| t.text.as_str())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Head and tail of an oversized result, with the omitted byte count in between.
+/// Cuts on line boundaries where possible and caps each part by bytes, so a
+/// single multi-megabyte line still yields a small preview.
+fn build_preview(content: &str) -> String {
+    let head_end = content
+        .match_indices('\n')
+        .nth(PREVIEW_HEAD_LINES - 1)
+        .map_or(content.len(), |(i, _)| i);
+    let head = truncate_at_boundary(&content[..head_end], PREVIEW_HEAD_BYTES);
+
+    let trimmed = content.trim_end_matches('\n');
+    let mut tail_start = trimmed
+        .rmatch_indices('\n')
+        .nth(PREVIEW_TAIL_LINES - 1)
+        .map_or(0, |(i, _)| i + 1)
+        .max(trimmed.len().saturating_sub(PREVIEW_TAIL_BYTES))
+        .max(head.len())
+        .min(trimmed.len());
+    while !trimmed.is_char_boundary(tail_start) {
+        tail_start += 1;
+    }
+    let tail = &trimmed[tail_start..];
+
+    let omitted = content.len() - head.len() - tail.len();
+    if tail.is_empty() {
+        format!("{head}\n... [{omitted} bytes omitted]")
+    } else {
+        format!("{head}\n... [{omitted} bytes omitted] ...\n{tail}")
+    }
+}
+
+/// Replace an oversized result with a preview and a reference to the stored artifact.
+/// The structured content is replaced too, so the full payload never reaches the client.
+fn governed_result(original: &CallToolResult, artifact_id: i64, content: &str) -> CallToolResult {
+    let preview = build_preview(content);
+    let size_bytes = content.len();
+    let total_lines = content.lines().count();
+    let notice = format!(
+        "[Result too large to return inline: {size_bytes} bytes, {total_lines} lines stored as artifact {artifact_id}. \
+         Read more with artifact({artifact_id}, \"tail\"), artifact({artifact_id}, \"search\", \"text\"), \
+         or artifact({artifact_id}, \"get\", offset).]"
+    );
+    let text = format!("{preview}\n\n{notice}");
+
+    CallToolResult {
+        content: vec![Content::text(text)],
+        structured_content: Some(serde_json::json!({
+            "message": notice,
+            "artifact_id": artifact_id,
+            "size_bytes": size_bytes,
+            "total_lines": total_lines,
+            "preview": preview,
+        })),
+        is_error: original.is_error,
+        meta: original.meta.clone(),
+    }
+}
+
+impl MiraServer {
+    /// Route an oversized tool result through the artifact store.
+    ///
+    /// Router errors, tools flagged `Inline`, and results under
+    /// `INLINE_RESULT_MAX_BYTES` pass through unchanged. Secrets are redacted
+    /// before the result is stored. If storage fails the original result is
+    /// returned, since a large answer beats no answer.
+    pub(crate) async fn govern_tool_result(
+        &self,
+        session_id: &str,
+        tool_name: &str,
+        result: Result<CallToolResult, ErrorData>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let Ok(call) = result else {
+            return result;
+        };
+        if inline_policy(tool_name) == InlinePolicy::Inline {
+            return Ok(call);
+        }
+        let text = result_text(&call);
+        if text.len() <= INLINE_RESULT_MAX_BYTES {
+            return Ok(call);
+        }
+
+        let content = redact_sensitive(&text);
+        let stored_content = content.clone();
+        let sid = session_id.to_string();
+        let tn = tool_name.to_string();
+        let stored = self
+            .pool
+            .run(move |conn| store_tool_artifact_sync(conn, Some(&sid), &tn, &stored_content))
+            .await;
+
+        match stored {
+            Ok(artifact_id) => {
+                tracing::debug!(
+                    tool = tool_name,
+                    artifact_id,
+                    bytes = content.len(),
+                    "Stored oversized tool result as artifact"
+                );
+                Ok(governed_result(&call, artifact_id, &content))
+            }
+            Err(e) => {
+                tracing::warn!(
+                    "Failed to store oversized {} result as artifact: {}",
+                    tool_name,
+                    e
+                );
+                Ok(call)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text_result(text: String) -> CallToolResult {
+        CallToolResult {
+            content: vec![Content::text(text)],
+            structured_content: None,
+            is_error: Some(false),
+            meta: None,
+        }
+    }
+
+    async fn server_with_session(session_id: &str) -> MiraServer {
+        use crate::db::pool::{CodePool, DatabasePool, MainPool};
+        use std::sync::Arc;
+
+        let pool = MainPool::new(Arc::new(DatabasePool::open_in_memory().await.unwrap()));
+        let code_pool = CodePool::new(Arc::new(
+            DatabasePool::open_code_db_in_memory().await.unwrap(),
+        ));
+        let sid = session_id.to_string();
+        pool.interact(move |conn| {
+            crate::db::create_session_sync(conn, &sid, None).map_err(|e| anyhow::anyhow!(e))
+        })
+        .await
+        .unwrap();
+        MiraServer::new(pool, code_pool, None)
+    }
+
+    #[test]
+    fn only_run_is_governed() {
+        assert_eq!(inline_policy("run"), InlinePolicy::Govern);
+        assert_eq!(inline_policy("project"), InlinePolicy::Inline);
+    }
+
+    #[test]
+    fn preview_keeps_head_and_tail_lines() {
+        let content: String = (1..=1000).map(|i| format!("line {i}\n")).collect();
+        let preview = build_preview(&content);
+        assert!(preview.starts_with("line 1\n"));
+        assert!(preview.contains("line 40\n..."));
+        assert!(!preview.contains("line 41\n"));
+        let expected_tail = (986..=1000)
+            .map(|i| format!("line {i}"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        assert!(preview.ends_with(&expected_tail));
+    }
+
+    #[test]
+    fn preview_caps_a_single_huge_line() {
+        let content = "é".repeat(200_000);
+        let preview = build_preview(&content);
+        assert!(preview.len() <= PREVIEW_HEAD_BYTES + PREVIEW_TAIL_BYTES + 64);
+        assert!(preview.contains("bytes omitted"));
+    }
+
+    #[tokio::test]
+    async fn small_results_pass_through() {
+        let server = server_with_session("gov-small").await;
+        let result = server
+            .govern_tool_result("gov-small", "run", Ok(text_result("ok".into())))
+            .await
+            .unwrap();
+        assert_eq!(result.content[0].as_text().unwrap().text, "ok");
+        assert!(result.structured_content.is_none());
+    }
+
+    #[tokio::test]
+    async fn inline_tools_are_never_governed() {
+        let server = server_with_session("gov-inline").await;
+        let big = "x".repeat(INLINE_RESULT_MAX_BYTES * 2);
+        let result = server
+            .govern_tool_result("gov-inline", "project", Ok(text_result(big.clone())))
+            .await
+            .unwrap();
+        assert_eq!(result.content[0].as_text().unwrap().text, big);
+    }
+
+    #[tokio::test]
+    async fn oversized_result_is_stored_redacted_and_sliceable() {
+        use crate::mcp::requests::{SessionAction, SessionRequest};
+        use crate::mcp::responses::SessionData;
+
+        let server = server_with_session("gov-big").await;
+
+        // ~2 MB of output with a secret near the end
+        let mut big: String = (0..40_000)
+            .map(|i| format!("result row {i:>6} {}\n", "-".repeat(40)))
+            .collect();
+        big.push_str("api_key=supersecretvalue\nFINAL LINE\n");
+        assert!(big.len() > 2_000_000);
+
+        let result = server
+            .govern_tool_result("gov-big", "run", Ok(text_result(big)))
+            .await
+            .unwrap();
+
+        let text = &result.content[0].as_text().unwrap().text;
+        assert!(
+            text.len() < INLINE_RESULT_MAX_BYTES / 4,
+            "got {} bytes",
+            text.len()
+        );
+        assert!(text.starts_with("result row      0"));
+        assert!(!text.contains("supersecretvalue"));
+        let structured = result.structured_content.as_ref().unwrap();
+        let artifact_id = structured["artifact_id"].as_i64().unwrap();
+        assert_eq!(result.is_error, Some(false));
+
+        // Fetch the tail back through the session tool
+        let req = SessionRequest {
+            action: SessionAction::Artifact,
+            session_id: None,
+            limit: Some(3),
+            group_by: None,
+            since_days: None,
+            insight_source: None,
+            min_confidence: None,
+            insight_id: None,
+            dry_run: None,
+            category: None,
+            tool_name: None,
+            artifact_id: Some(artifact_id),
+            slice: Some("tail".into()),
+            query: None,
+            offset: None,
+            liveness: None,
+            output: None,
+        };
+        let output = crate::tools::core::handle_session(&server, req)
+            .await
+            .unwrap();
+        match output.0.data {
+            Some(SessionData::Artifact(data)) => {
+                assert_eq!(data.tool_name, "run");
+                assert!(data.size_bytes > 2_000_000);
+                let lines: Vec<&str> = data.content.lines().collect();
+                assert_eq!(lines.len(), 3);
+                assert!(lines[0].starts_with("result row  39999"));
+                assert_eq!(lines[1], "api_key=<REDACTED>");
+                assert_eq!(lines[2], "FINAL LINE");
+            }
+            other => panic!("Expected SessionData::Artifact, got {:?}", other),
+        }
+    }
+}
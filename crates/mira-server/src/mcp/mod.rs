@@ -4,12 +4,14 @@
 pub mod client;
 pub mod elicitation;
 mod extraction;
+mod governor;
 mod handler;
 pub mod requests;
 mod resources;
 pub mod responses;
 mod router;
 mod tasks;
+pub mod validation;
 
 use crate::tools::core as tools;
 use std::sync::Arc;
@@ -176,6 +178,7 @@ impl MiraServer {
             Some(cwd.clone()),
             None, // Auto-detect name
             session_id,
+            None,
         )
         .await
         {
@@ -17,6 +17,8 @@ pub enum ProjectAction {
     Set,
     /// Show current project
     Get,
+    /// Attach another directory to the active project
+    AttachRoot,
 }
 
 #[derive(Debug, Clone, Copy, Deserialize, schemars::JsonSchema)]
@@ -42,6 +44,24 @@ pub enum GoalAction {
     DeleteMilestone,
     /// List sessions that worked on a goal
     Sessions,
+    /// Make a goal wait on another goal
+    AddDependency,
+    /// Remove a dependency between goals
+    RemoveDependency,
+    /// Show what a goal depends on and what depends on it
+    Dependencies,
+    /// List tasks whose dependencies are all completed
+    NextTasks,
+    /// Make a task wait on another task
+    AddTaskDependency,
+    /// Remove a dependency between tasks
+    RemoveTaskDependency,
+    /// Save a reusable task checklist
+    TaskTemplate,
+    /// List saved task checklists
+    TaskTemplates,
+    /// Create tasks from a saved checklist
+    TaskInstantiate,
 }
 
 #[derive(Debug, Clone, Copy, Deserialize, schemars::JsonSchema)]
@@ -80,6 +100,17 @@ pub enum DocumentationAction {
     Scan,
 }
 
+#[derive(Debug, Clone, Copy, Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ModuleAction {
+    /// List module cards for the active project
+    List,
+    /// Get one module's card
+    Get,
+    /// Search module cards by keyword
+    Search,
+}
+
 // ============================================================================
 // Request Structs
 // ============================================================================
@@ -87,15 +118,21 @@ pub enum DocumentationAction {
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
 pub struct ProjectRequest {
     #[schemars(
-        description = "Action: start (initialize session), set (change project), get (show current)"
+        description = "Action: start (initialize session), set (change project), get (show current), attach_root (add a directory to the active project)"
     )]
     pub action: ProjectAction,
-    #[schemars(description = "Project root path (required for start/set)")]
+    #[schemars(
+        description = "Project root path (required for start/set), or the directory to attach (attach_root)"
+    )]
     pub project_path: Option<String>,
-    #[schemars(description = "Project name")]
+    #[schemars(description = "Project name, or the root name for attach_root")]
     pub name: Option<String>,
     #[schemars(description = "Optional session ID (for start action)")]
     pub session_id: Option<String>,
+    #[schemars(
+        description = "Directories to attach as additional project roots (set only). Files under a root appear as @<dir name>/path"
+    )]
+    pub additional_roots: Option<Vec<String>>,
 }
 
 #[derive(Debug, Clone, Copy, Deserialize, schemars::JsonSchema)]
@@ -117,24 +154,30 @@ pub enum CodeAction {
     DeadCode,
     /// Package module summaries, symbols, deps, and code into a context bundle for agent spawning
     Bundle,
+    /// Blast-radius report for renaming a symbol (callers, text mentions, co-change files)
+    Impact,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
 pub struct CodeRequest {
     #[schemars(
-        description = "Action: search, symbols, callers, callees, dependencies, diff, dead_code, bundle"
+        description = "Action: search, symbols, callers, callees, dependencies, diff, dead_code, bundle, impact"
     )]
     pub action: CodeAction,
     #[schemars(description = "Search query (required for search)")]
     pub query: Option<String>,
-    #[schemars(description = "File path (required for symbols)")]
+    #[schemars(description = "File path (required for symbols, optional for impact)")]
     pub file_path: Option<String>,
-    #[schemars(description = "Function name (required for callers/callees)")]
+    #[schemars(description = "Function name (required for callers/callees/impact)")]
     pub function_name: Option<String>,
     #[schemars(
         description = "Symbol type filter (e.g. function, struct, trait, class, method, enum, interface, type)"
     )]
     pub symbol_type: Option<String>,
+    #[schemars(
+        description = "Include signatures, visibility, and doc comment summaries in symbols output. Default: false"
+    )]
+    pub include_docs: Option<bool>,
     #[schemars(
         description = "Max results (default: 20 for search/callers/callees, 50 for symbols)"
     )]
@@ -166,7 +209,7 @@ pub struct CodeRequest {
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
 pub struct GoalRequest {
     #[schemars(
-        description = "Action: create/bulk_create/list/get/update/delete/add_milestone/complete_milestone/delete_milestone/sessions"
+        description = "Action: create/bulk_create/list/get/update/delete/add_milestone/complete_milestone/delete_milestone/sessions/add_dependency/remove_dependency/dependencies/next_tasks/add_task_dependency/remove_task_dependency/task_template/task_templates/task_instantiate"
     )]
     pub action: GoalAction,
     #[schemars(description = "Goal ID")]
@@ -189,12 +232,28 @@ pub struct GoalRequest {
     pub milestone_title: Option<String>,
     #[schemars(description = "Milestone weight (for add_milestone, default: 1)")]
     pub weight: Option<i32>,
+    #[schemars(description = "Task ID (for add_task_dependency/remove_task_dependency)")]
+    pub task_id: Option<i64>,
+    #[schemars(
+        description = "Goal that goal_id waits on (for add_dependency/remove_dependency), or task that task_id waits on (for add_task_dependency/remove_task_dependency)"
+    )]
+    pub depends_on: Option<i64>,
     #[schemars(description = "Max results")]
     pub limit: Option<i64>,
     #[schemars(
         description = "For bulk_create: JSON-encoded string containing an array of goals, e.g. \"[{\\\"title\\\": \\\"Goal A\\\", \\\"priority\\\": \\\"high\\\"}, ...]\"  (title required, description and priority optional)"
     )]
     pub goals: Option<String>,
+    #[schemars(description = "Task template name (for task_template/task_instantiate)")]
+    pub template: Option<String>,
+    #[schemars(
+        description = "For task_template: JSON-encoded array of task titles, which may contain {var} placeholders, e.g. \"[\\\"Bump version to {version}\\\", \\\"Tag v{version}\\\"]\""
+    )]
+    pub items: Option<String>,
+    #[schemars(
+        description = "For task_instantiate: JSON-encoded object of placeholder values, e.g. \"{\\\"version\\\": \\\"1.4.0\\\"}\""
+    )]
+    pub vars: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -205,6 +264,20 @@ pub struct IndexRequest {
     pub path: Option<String>,
     #[schemars(description = "Skip embedding generation (faster indexing)")]
     pub skip_embed: Option<bool>,
+    #[schemars(description = "Re-index every file, even ones unchanged since the last index")]
+    pub force: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct ModuleRequest {
+    #[schemars(description = "Action: list, get, search")]
+    pub action: ModuleAction,
+    #[schemars(description = "Module ID, e.g. \"db/schema\" (required for get)")]
+    pub module_id: Option<String>,
+    #[schemars(description = "Search terms (required for search)")]
+    pub query: Option<String>,
+    #[schemars(description = "Max results (default: 10, for search)")]
+    pub limit: Option<i64>,
 }
 
 #[derive(Debug, Clone, Copy, Deserialize, schemars::JsonSchema)]
@@ -224,12 +297,18 @@ pub enum SessionAction {
     UsageStats,
     /// List recent LLM usage records
     UsageList,
+    /// Spend across LLM and embedding calls by day, source, and project, with month projection
+    UsageCosts,
     /// Query unified insights digest (pondering, proactive, doc gaps)
     Insights,
     /// Dismiss an insight by ID (insight_source required: 'pondering' or 'doc_gap')
     DismissInsight,
+    /// Stale goals, untouched tasks, and untested churn (since_days = stale threshold)
+    Suggestions,
     /// Show database storage status and retention policy
     StorageStatus,
+    /// Daily database size and row-count samples with week-over-week growth
+    DbHealth,
     /// Run data cleanup (dry_run by default)
     Cleanup,
     /// Show learned error patterns and fixes
@@ -240,12 +319,18 @@ pub enum SessionAction {
     Capabilities,
     /// Session injection efficiency report
     Report,
+    /// Recent failed tool calls with error messages (optional tool_name filter)
+    ToolErrors,
+    /// Read a slice (get/head/tail/search) of a tool result stored as an artifact
+    Artifact,
+    /// Write a signed JSONL export of the project's tool executions (needs MIRA_AUDIT_KEY)
+    AuditExport,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
 pub struct SessionRequest {
     #[schemars(
-        description = "Action: current_session, list_sessions, get_history, recap, usage_summary, usage_stats, usage_list, insights, dismiss_insight, storage_status, cleanup, error_patterns, session_lineage, capabilities, report"
+        description = "Action: current_session, list_sessions, get_history, recap, usage_summary, usage_stats, usage_list, usage_costs, insights, dismiss_insight, suggestions, storage_status, db_health, cleanup, error_patterns, session_lineage, capabilities, report, tool_errors, artifact, audit_export"
     )]
     pub action: SessionAction,
     #[schemars(description = "Session ID (for get_history)")]
@@ -274,6 +359,28 @@ pub struct SessionRequest {
         description = "Category to clean: sessions, analytics, behavior, all (default: all, for cleanup action)"
     )]
     pub category: Option<String>,
+    #[schemars(
+        description = "Only show failures of this tool (for tool_errors action); also filters audit_export"
+    )]
+    pub tool_name: Option<String>,
+    #[schemars(description = "Artifact ID from a truncated tool result (for artifact action)")]
+    pub artifact_id: Option<i64>,
+    #[schemars(
+        description = "Which part of the artifact to read: get, head, tail, search (default: head, for artifact action)"
+    )]
+    pub slice: Option<String>,
+    #[schemars(description = "Text to find, case-insensitive (for artifact search)")]
+    pub query: Option<String>,
+    #[schemars(description = "Byte offset to start reading from (for artifact get)")]
+    pub offset: Option<i64>,
+    #[schemars(
+        description = "Filter by liveness: live, idle, or closed (for list_sessions action)"
+    )]
+    pub liveness: Option<String>,
+    #[schemars(
+        description = "Absolute path of the JSONL file to write (for audit_export; the manifest goes to <output>.manifest.json)"
+    )]
+    pub output: Option<String>,
 }
 
 // Documentation request types
@@ -343,11 +450,12 @@ pub struct RunRequest {
     /// Rhai script code to execute. Has access to Mira's full API.
     /// Call help() for the API reference, help("search") for specific functions.
     ///
-    /// Available: search(query), symbols(path), callers(fn), callees(fn),
+    /// Available: search(query), search_all(query), symbols(path, include_docs), callers(fn), callees(fn), impact(symbol),
+    /// modules(), module_get(id), module_search(query),
     /// goal_create/list/get/update/delete, goal_add_milestone, goal_complete_milestone,
-    /// recap(), current_session(), project_init(), project_info(),
-    /// diff(), index_project(), index_status(), insights(), dismiss_insight(id, source),
-    /// launch(team), format(data), summarize(results, max), pick(results, fields), help().
+    /// recap(), current_session(), tool_errors(), costs(), db_health(), artifact(id, slice), audit_export(output), project_init(), project_info(), project_attach_root(path),
+    /// diff(), index_project(), index_status(), list_dead_letters(), retry_dead_letter(id),
+    /// insights(), dismiss_insight(id, source), suggestions(), launch(team), format(data), summarize(results, max), pick(results, fields), help().
     pub code: String,
 }
 
@@ -431,6 +539,10 @@ pub struct McpCodeRequest {
         description = "Symbol type filter (e.g. function, struct, trait, class, method, enum, interface, type)"
     )]
     pub symbol_type: Option<String>,
+    #[schemars(
+        description = "Include signatures, visibility, and doc comment summaries in symbols output. Default: false"
+    )]
+    pub include_docs: Option<bool>,
     #[schemars(
         description = "Max results (default: 20 for search/callers/callees, 50 for symbols)"
     )]
@@ -457,6 +569,7 @@ impl From<McpCodeRequest> for CodeRequest {
             file_path: r.file_path,
             function_name: r.function_name,
             symbol_type: r.symbol_type,
+            include_docs: r.include_docs,
             limit: r.limit,
             from_ref: None,
             to_ref: None,
@@ -565,6 +678,13 @@ impl From<McpSessionRequest> for SessionRequest {
             insight_id: None,
             dry_run: None,
             category: None,
+            tool_name: None,
+            artifact_id: None,
+            slice: None,
+            query: None,
+            offset: None,
+            liveness: None,
+            output: None,
         }
     }
 }
@@ -616,6 +736,13 @@ impl From<McpInsightsRequest> for SessionRequest {
             insight_id: r.insight_id,
             dry_run: None,
             category: None,
+            tool_name: None,
+            artifact_id: None,
+            slice: None,
+            query: None,
+            offset: None,
+            liveness: None,
+            output: None,
         }
     }
 }
@@ -889,6 +1016,7 @@ mod tests {
         assert!(full.insight_id.is_none());
         assert!(full.dry_run.is_none());
         assert!(full.category.is_none());
+        assert!(full.tool_name.is_none());
     }
 
     // ── From<McpInsightsRequest> for SessionRequest ───────────────────
@@ -930,6 +1058,7 @@ mod tests {
             file_path: Some("src/auth.rs".into()),
             function_name: Some("login".into()),
             symbol_type: Some("function".into()),
+            include_docs: Some(true),
             limit: Some(50),
             scope: None,
             budget: None,
@@ -943,6 +1072,7 @@ mod tests {
         assert_eq!(full.file_path.as_deref(), Some("src/auth.rs"));
         assert_eq!(full.function_name.as_deref(), Some("login"));
         assert_eq!(full.symbol_type.as_deref(), Some("function"));
+        assert_eq!(full.include_docs, Some(true));
         assert_eq!(full.limit, Some(50));
         // Diff fields are None — they belong to the standalone diff tool now
         assert!(full.from_ref.is_none());
@@ -186,10 +186,10 @@ impl MiraServer {
             })?;
 
         let Some(goal_json) = result else {
-            return Err(rmcp::ErrorData::invalid_params(
-                format!("Goal not found: {id}"),
-                None,
-            ));
+            return Err(crate::error::MiraError::NotFound(format!(
+                "Goal not found: {id}. Read mira://goals to list available goals."
+            ))
+            .into());
         };
 
         let uri = format!("mira://goals/{id}");
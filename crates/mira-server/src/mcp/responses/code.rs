@@ -12,6 +12,7 @@ pub type CodeOutput = ToolOutput<CodeData>;
 #[serde(untagged)]
 pub enum CodeData {
     Search(SearchResultsData),
+    SearchAll(SearchAllData),
     Symbols(SymbolsData),
     CallGraph(CallGraphData),
     Dependencies(DependenciesData),
@@ -21,6 +22,7 @@ pub enum CodeData {
     Conventions(ConventionsData),
     DebtDelta(DebtDeltaData),
     Bundle(BundleData),
+    Impact(ImpactData),
 }
 
 #[derive(Debug, Serialize, JsonSchema)]
@@ -39,6 +41,25 @@ pub struct CodeSearchResult {
     pub content: String,
 }
 
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct SearchAllData {
+    pub results: Vec<UnifiedSearchResult>,
+    pub total: usize,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct UnifiedSearchResult {
+    /// Collection the hit came from: code, session, or document
+    pub source: String,
+    /// Score normalized to 0.0-1.0 against the best hit from the same source
+    pub score: f32,
+    /// File path, session ID, or doc path
+    pub reference: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line: Option<u32>,
+    pub snippet: String,
+}
+
 #[derive(Debug, Serialize, JsonSchema)]
 pub struct SymbolsData {
     pub symbols: Vec<SymbolInfo>,
@@ -51,6 +72,15 @@ pub struct SymbolInfo {
     pub symbol_type: String,
     pub start_line: usize,
     pub end_line: usize,
+    /// Detail fields below are only populated when `include_docs` is set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub qualified_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signature: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub doc: Option<String>,
 }
 
 #[derive(Debug, Serialize, JsonSchema)]
@@ -69,6 +99,60 @@ pub struct CallGraphEntry {
     pub line: Option<usize>,
 }
 
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct ImpactData {
+    pub symbol: String,
+    pub definitions: Vec<ImpactDefinition>,
+    /// Call-graph callers (depth 1 and 2); renaming breaks these
+    pub hard_references: Vec<ImpactHardReference>,
+    /// Text mentions in docs, tests, config, and strings the compiler won't flag
+    pub soft_references: Vec<ImpactSoftReference>,
+    pub co_change: Vec<ImpactCoChange>,
+    /// Affected files, highest priority first
+    pub files: Vec<ImpactFile>,
+    pub hard_total: usize,
+    pub direct_callers: usize,
+    pub soft_total: usize,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct ImpactDefinition {
+    pub file_path: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line: Option<usize>,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct ImpactHardReference {
+    pub caller: String,
+    pub file_path: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line: Option<usize>,
+    pub depth: u32,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct ImpactSoftReference {
+    pub file_path: String,
+    pub line: usize,
+    /// doc, test, config, or code
+    pub kind: String,
+    pub excerpt: String,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct ImpactCoChange {
+    pub file_path: String,
+    pub commits: usize,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct ImpactFile {
+    pub file_path: String,
+    pub score: u32,
+    pub reasons: Vec<String>,
+}
+
 #[derive(Debug, Serialize, JsonSchema)]
 pub struct DependenciesData {
     pub edges: Vec<DependencyEdge>,
@@ -16,6 +16,11 @@ pub enum GoalData {
     Get(GoalGetData),
     MilestoneProgress(MilestoneProgressData),
     Sessions(GoalSessionsData),
+    Dependencies(GoalDependenciesData),
+    NextTasks(NextTasksData),
+    TaskTemplate(TaskTemplateInfo),
+    TaskTemplates(TaskTemplateListData),
+    TasksCreated(TasksCreatedData),
 }
 
 #[derive(Debug, Serialize, JsonSchema)]
@@ -27,6 +32,9 @@ pub struct GoalCreatedData {
 pub struct GoalModifiedData {
     pub goal_id: i64,
     pub action: String,
+    /// Dependents whose last unfinished dependency was this goal
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub unblocked: Vec<GoalDependencyInfo>,
 }
 
 #[derive(Debug, Serialize, JsonSchema)]
@@ -51,10 +59,18 @@ pub struct GoalSummary {
     pub id: i64,
     pub title: String,
     pub status: String,
+    /// `blocked` while a dependency is unfinished, otherwise `status`
+    pub effective_status: String,
     pub priority: String,
     pub progress_percent: i32,
+    /// progress_percent was set by hand, not rolled up from milestones/tasks
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub progress_manual: bool,
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub milestones: Vec<MilestoneInfo>,
+    /// Unfinished dependencies holding this goal back
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub blocked_by: Vec<GoalDependencyInfo>,
 }
 
 #[derive(Debug, Serialize, JsonSchema)]
@@ -62,12 +78,76 @@ pub struct GoalGetData {
     pub id: i64,
     pub title: String,
     pub status: String,
+    /// `blocked` while a dependency is unfinished, otherwise `status`
+    pub effective_status: String,
     pub priority: String,
     pub progress_percent: i32,
+    /// progress_percent was set by hand, not rolled up from milestones/tasks
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub progress_manual: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     pub created_at: String,
     pub milestones: Vec<MilestoneInfo>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub depends_on: Vec<GoalDependencyInfo>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub dependents: Vec<GoalDependencyInfo>,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct GoalDependencyInfo {
+    pub id: i64,
+    pub title: String,
+    pub status: String,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct GoalDependenciesData {
+    pub goal_id: i64,
+    pub effective_status: String,
+    pub depends_on: Vec<GoalDependencyInfo>,
+    pub dependents: Vec<GoalDependencyInfo>,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct NextTasksData {
+    pub tasks: Vec<TaskSummary>,
+    pub total: usize,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct TaskSummary {
+    pub id: i64,
+    pub title: String,
+    pub status: String,
+    pub priority: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub goal_id: Option<i64>,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct TaskTemplateInfo {
+    pub id: i64,
+    pub name: String,
+    pub items: Vec<String>,
+    /// Saved without a project, so visible from every project
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub global: bool,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct TaskTemplateListData {
+    pub templates: Vec<TaskTemplateInfo>,
+    pub total: usize,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct TasksCreatedData {
+    pub template: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub goal_id: Option<i64>,
+    pub tasks: Vec<TaskSummary>,
 }
 
 #[derive(Debug, Clone, Serialize, JsonSchema)]
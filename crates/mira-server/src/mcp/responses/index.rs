@@ -13,11 +13,15 @@ pub enum IndexData {
     Compact(IndexCompactData),
     Summarize(IndexSummarizeData),
     Health(IndexHealthData),
+    DeadLetters(DeadLettersData),
+    RetryDeadLetter(RetryDeadLetterData),
 }
 
 #[derive(Debug, Serialize, JsonSchema)]
 pub struct IndexProjectData {
     pub files: usize,
+    pub unchanged: usize,
+    pub removed: usize,
     pub symbols: usize,
     pub chunks: usize,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -28,6 +32,20 @@ pub struct IndexProjectData {
 pub struct IndexStatusData {
     pub symbols: usize,
     pub embedded_chunks: usize,
+    pub dead_letters: usize,
+    /// Only reported when a project is active
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub freshness: Option<IndexFreshnessData>,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct IndexFreshnessData {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_indexed_at: Option<String>,
+    pub files_indexed: usize,
+    /// Indexed files modified or deleted since they were indexed
+    pub files_changed: usize,
+    pub stale: bool,
 }
 
 #[derive(Debug, Serialize, JsonSchema)]
@@ -45,3 +63,26 @@ pub struct IndexSummarizeData {
 pub struct IndexHealthData {
     pub issues_found: usize,
 }
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct DeadLettersData {
+    pub dead_letters: Vec<DeadLetterItem>,
+    pub total: usize,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct DeadLetterItem {
+    pub id: i64,
+    pub job_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub job_key: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    pub attempts: i64,
+    pub failed_at: String,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct RetryDeadLetterData {
+    pub requeued: usize,
+}
@@ -14,6 +14,7 @@ mod goal;
 mod index;
 mod insights;
 pub mod launch;
+mod module;
 mod project;
 mod session;
 mod tasks;
@@ -27,6 +28,7 @@ pub use goal::*;
 pub use index::*;
 pub use insights::*;
 pub use launch::*;
+pub use module::*;
 pub use project::*;
 pub use session::*;
 pub use tasks::*;
@@ -0,0 +1,35 @@
+use schemars::JsonSchema;
+use serde::Serialize;
+
+use super::ToolOutput;
+
+pub type ModuleOutput = ToolOutput<ModuleData>;
+
+#[derive(Debug, Serialize, JsonSchema)]
+#[serde(untagged)]
+pub enum ModuleData {
+    List(ModuleListData),
+    Get(ModuleCardItem),
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct ModuleListData {
+    pub modules: Vec<ModuleCardItem>,
+    pub total: usize,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct ModuleCardItem {
+    pub module_id: String,
+    pub name: String,
+    pub path: String,
+    pub summary: String,
+    pub key_types: Vec<String>,
+    pub entry_points: Vec<String>,
+    pub dependencies: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+    /// Search relevance (search action only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub score: Option<usize>,
+}
@@ -18,7 +18,10 @@ pub struct ProjectStartData {
     pub project_id: i64,
     pub project_name: Option<String>,
     pub project_path: String,
+    /// Primary language (most source files)
     pub project_type: String,
+    /// All detected languages, primary first
+    pub languages: Vec<String>,
 }
 
 #[derive(Debug, Serialize, JsonSchema)]
@@ -26,10 +29,27 @@ pub struct ProjectGetData {
     pub project_id: i64,
     pub project_name: Option<String>,
     pub project_path: String,
+    /// Additional directories attached to the project
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub roots: Vec<ProjectRootData>,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct ProjectRootData {
+    /// Prefix for this root's files in results (`@name/...`)
+    pub name: String,
+    pub path: String,
 }
 
 #[derive(Debug, Serialize, JsonSchema)]
 pub struct ProjectSetData {
     pub project_id: i64,
     pub project_name: Option<String>,
+    /// Primary language (most source files)
+    pub project_type: String,
+    /// All detected languages, primary first
+    pub languages: Vec<String>,
+    /// Additional directories attached to the project
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub roots: Vec<ProjectRootData>,
 }
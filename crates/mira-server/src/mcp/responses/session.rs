@@ -15,9 +15,15 @@ pub enum SessionData {
     ListSessions(SessionListData),
     History(SessionHistoryData),
     Insights(InsightsData),
+    Suggestions(SuggestionsData),
+    Costs(CostsData),
+    DbHealth(DbHealthData),
     ErrorPatterns(ErrorPatternsData),
+    ToolErrors(ToolErrorsData),
+    Artifact(ArtifactData),
     SessionLineage(SessionLineageData),
     Capabilities(CapabilitiesData),
+    AuditExport(AuditExportData),
 }
 
 #[derive(Debug, Serialize, JsonSchema)]
@@ -36,8 +42,16 @@ pub struct SessionSummary {
     pub id: String,
     pub started_at: String,
     pub status: String,
+    /// live, idle (active but silent past the idle threshold), or closed
+    pub liveness: String,
+    pub last_activity: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub close_reason: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub summary: Option<String>,
+    /// Goals, tasks, files, tools and decisions from the session, stored when it closed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recap: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub source: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -66,6 +80,76 @@ pub struct InsightsData {
     pub total: usize,
 }
 
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct SuggestionsData {
+    pub suggestions: Vec<SuggestionItem>,
+    pub total: usize,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct SuggestionItem {
+    /// stale_goal, untouched_task, or untested_change
+    pub kind: String,
+    /// Goal or task ID (absent for file suggestions)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+    /// Goal/task title or file path
+    pub subject: String,
+    pub rationale: String,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct CostsData {
+    pub since_days: u32,
+    pub total_cost: f64,
+    pub total_requests: u64,
+    pub by_day: Vec<CostBucket>,
+    pub by_source: Vec<CostBucket>,
+    pub by_project: Vec<CostBucket>,
+    /// Spend so far this calendar month (UTC)
+    pub month_to_date: f64,
+    /// Month-end spend at the current month's daily burn rate
+    pub projected_month: f64,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct CostBucket {
+    /// Day (YYYY-MM-DD), source (llm:provider/role, embeddings:provider), or project name
+    pub key: String,
+    pub cost: f64,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct DbHealthData {
+    pub since_days: u32,
+    /// Daily samples, oldest first
+    pub samples: Vec<DbHealthPoint>,
+    /// Week-over-week file size growth in percent (needs a week of samples)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size_growth_pct: Option<f64>,
+    /// Tables that gained rows over the same week, largest gain first
+    pub top_tables: Vec<DbTableGrowth>,
+    /// Current growth warning, if thresholds were crossed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alert: Option<String>,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct DbHealthPoint {
+    /// Day (YYYY-MM-DD)
+    pub sampled_on: String,
+    pub db_size_bytes: u64,
+    pub wal_size_bytes: u64,
+    pub table_rows: std::collections::BTreeMap<String, i64>,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct DbTableGrowth {
+    pub table: String,
+    pub baseline_rows: i64,
+    pub latest_rows: i64,
+}
+
 #[derive(Debug, Serialize, JsonSchema)]
 pub struct InsightItem {
     /// Row ID for dismissable insights (pondering, doc_gap). Use with dismiss_insight action.
@@ -99,6 +183,56 @@ pub struct ErrorPatternItem {
     pub last_seen: String,
 }
 
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct ToolErrorsData {
+    pub errors: Vec<ToolErrorItem>,
+    pub total: usize,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct ToolErrorItem {
+    pub id: i64,
+    pub tool_name: String,
+    pub created_at: String,
+    /// Secret-redacted, truncated arguments
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub arguments_preview: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_message: Option<String>,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct ArtifactData {
+    pub artifact_id: i64,
+    pub tool_name: String,
+    pub created_at: String,
+    /// Size of the full stored result
+    pub size_bytes: usize,
+    pub total_lines: usize,
+    /// Slice that was read: get, head, tail, or search
+    pub slice: String,
+    pub content: String,
+    /// Byte offset just past the returned content (get only), for paging
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_offset: Option<usize>,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct AuditExportData {
+    /// JSONL file that was written
+    pub path: String,
+    /// Sidecar manifest carrying the signature
+    pub manifest_path: String,
+    pub since: String,
+    pub until: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool: Option<String>,
+    pub row_count: usize,
+    pub hmac_sha256: String,
+}
+
 #[derive(Debug, Serialize, JsonSchema)]
 pub struct SessionLineageData {
     pub sessions: Vec<LineageSession>,
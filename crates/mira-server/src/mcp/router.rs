@@ -12,8 +12,12 @@ use rmcp::{
 
 use super::MiraServer;
 use super::requests::RunRequest;
+use crate::db::ToolCallRecord;
 use crate::utils::truncate;
 
+/// Maximum length of the error message stored for a failed tool call
+const ERROR_MESSAGE_MAX_LEN: usize = 500;
+
 #[tool_router]
 impl MiraServer {
     #[tool(
@@ -69,12 +73,18 @@ impl MiraServer {
 
                 parts.push(e.message.clone());
 
-                let error_value = serde_json::json!({
+                let mut error_value = serde_json::json!({
                     "error": e.message,
                     "line": e.line,
                     "column": e.column,
                     "elapsed_ms": e.elapsed_ms,
                 });
+                // Errors raised by Mira API calls carry a machine-readable kind
+                // and the matching MCP error code.
+                if let Some(kind) = e.kind {
+                    error_value["kind"] = kind.as_str().into();
+                    error_value["code"] = kind.mcp_code().0.into();
+                }
 
                 Ok(CallToolResult {
                     content: vec![Content::text(parts.join("\n"))],
@@ -103,16 +113,18 @@ impl MiraServer {
             .collect()
     }
 
-    /// Extract result text and success status from a tool call result
+    /// Extract result text and success status from a tool call result.
+    /// A result flagged `is_error` (e.g. a failed script) counts as a failure.
     pub(crate) fn extract_result_text(
         result: &Result<CallToolResult, ErrorData>,
     ) -> (bool, String) {
         match result {
             Ok(r) => {
+                let success = r.is_error != Some(true);
                 if let Some(structured) = r.structured_content.as_ref()
                     && let Some(message) = structured.get("message").and_then(|v| v.as_str())
                 {
-                    return (true, message.to_string());
+                    return (success, message.to_string());
                 }
                 let text = r
                     .content
@@ -120,13 +132,14 @@ impl MiraServer {
                     .and_then(|c| c.as_text())
                     .map(|t| t.text.to_string())
                     .unwrap_or_default();
-                (true, text)
+                (success, text)
             }
             Err(e) => (false, e.message.to_string()),
         }
     }
 
-    /// Persist a tool call to the database for history tracking
+    /// Persist a tool call to the database for history tracking.
+    /// Failed calls also record their result text (truncated) as the error message.
     pub(crate) async fn log_tool_call(
         &self,
         session_id: &str,
@@ -134,6 +147,7 @@ impl MiraServer {
         args_json: &str,
         result_text: &str,
         success: bool,
+        duration_ms: i64,
     ) {
         let summary = truncate(result_text, 2000);
         let full_result_str = if result_text.len() > 100 {
@@ -141,19 +155,24 @@ impl MiraServer {
         } else {
             None
         };
+        let error_message = (!success).then(|| truncate(result_text, ERROR_MESSAGE_MAX_LEN));
         let session_id = session_id.to_string();
         let tool_name = tool_name.to_string();
         let args_json = args_json.to_string();
         self.pool
             .try_interact("log tool call", move |conn| {
-                crate::db::log_tool_call_sync(
+                crate::db::record_tool_call_sync(
                     conn,
-                    &session_id,
-                    &tool_name,
-                    &args_json,
-                    &summary,
-                    full_result_str.as_deref(),
-                    success,
+                    &ToolCallRecord {
+                        session_id: &session_id,
+                        tool_name: &tool_name,
+                        arguments: &args_json,
+                        result_summary: &summary,
+                        full_result: full_result_str.as_deref(),
+                        success,
+                        duration_ms: Some(duration_ms),
+                        error_message: error_message.as_deref(),
+                    },
                 )
                 .map_err(|e| anyhow::anyhow!(e))
             })
@@ -296,7 +315,14 @@ impl MiraServer {
             .unwrap_or_default();
 
         let ctx = ToolCallContext::new(self, request, context);
+        let started = std::time::Instant::now();
         let result = self.tool_router.call(ctx).await;
+        let duration_ms = started.elapsed().as_millis() as i64;
+
+        // Oversized results are stored as artifacts and replaced with a preview
+        let result = self
+            .govern_tool_result(&session_id, &tool_name, result)
+            .await;
 
         let (success, result_text) = Self::extract_result_text(&result);
 
@@ -308,7 +334,9 @@ impl MiraServer {
             let aj = args_json.clone();
             let rt = result_text.clone();
             tokio::spawn(async move {
-                server.log_tool_call(&sid, &tn, &aj, &rt, success).await;
+                server
+                    .log_tool_call(&sid, &tn, &aj, &rt, success, duration_ms)
+                    .await;
             });
         }
 
@@ -412,4 +440,90 @@ mod tests {
         assert_eq!(text, "something broke");
     }
 
+    #[test]
+    fn extract_result_text_is_error_counts_as_failure() {
+        let result = Ok(CallToolResult {
+            content: vec![Content::text("Script error: boom")],
+            structured_content: Some(serde_json::json!({"error": "boom"})),
+            is_error: Some(true),
+            meta: None,
+        });
+        let (success, text) = MiraServer::extract_result_text(&result);
+        assert!(!success);
+        assert_eq!(text, "Script error: boom");
+    }
+
+    // ═══════════════════════════════════════
+    // log_tool_call
+    // ═══════════════════════════════════════
+
+    async fn server_with_session(session_id: &str) -> MiraServer {
+        use crate::db::pool::{CodePool, DatabasePool, MainPool};
+        use std::sync::Arc;
+
+        let pool = MainPool::new(Arc::new(DatabasePool::open_in_memory().await.unwrap()));
+        let code_pool = CodePool::new(Arc::new(
+            DatabasePool::open_code_db_in_memory().await.unwrap(),
+        ));
+        let sid = session_id.to_string();
+        pool.interact(move |conn| {
+            crate::db::create_session_sync(conn, &sid, None).map_err(|e| anyhow::anyhow!(e))
+        })
+        .await
+        .unwrap();
+        MiraServer::new(pool, code_pool, None)
+    }
+
+    /// (success, duration_ms, error_message) of each tool_history row for a session
+    async fn history_rows(
+        server: &MiraServer,
+        session_id: &str,
+    ) -> Vec<(bool, Option<i64>, Option<String>)> {
+        let sid = session_id.to_string();
+        server
+            .pool
+            .interact(move |conn| {
+                let mut stmt = conn.prepare(
+                    "SELECT success, duration_ms, error_message FROM tool_history
+                     WHERE session_id = ?1 ORDER BY id",
+                )?;
+                let rows = stmt
+                    .query_map([&sid], |row| {
+                        Ok((row.get::<_, i32>(0)? != 0, row.get(1)?, row.get(2)?))
+                    })?
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(rows)
+            })
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn log_tool_call_records_duration_and_success() {
+        let server = server_with_session("log-ok").await;
+        server
+            .log_tool_call("log-ok", "run", r#"{"code":"1"}"#, "1", true, 42)
+            .await;
+
+        let rows = history_rows(&server, "log-ok").await;
+        assert_eq!(rows, vec![(true, Some(42), None)]);
+    }
+
+    #[tokio::test]
+    async fn log_tool_call_records_error_message_on_failure() {
+        let server = server_with_session("log-err").await;
+        let long_error = format!("Script error: {}", "x".repeat(2000));
+        server
+            .log_tool_call("log-err", "run", "{}", &long_error, false, 7)
+            .await;
+
+        let rows = history_rows(&server, "log-err").await;
+        assert_eq!(rows.len(), 1, "exactly one row per tool call");
+        let (success, duration_ms, error) = &rows[0];
+        assert!(!success);
+        assert_eq!(*duration_ms, Some(7));
+        let error = error.as_deref().expect("failed call should store an error");
+        assert!(error.starts_with("Script error: "));
+        assert!(error.len() <= ERROR_MESSAGE_MAX_LEN + 3);
+    }
 }
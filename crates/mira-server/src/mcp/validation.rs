@@ -0,0 +1,477 @@
+// crates/mira-server/src/mcp/validation.rs
+// Tool argument validation: lenient coercion plus aggregated, actionable errors
+
+use rmcp::schemars;
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+
+use crate::error::MiraError;
+use crate::mcp::requests::{DocumentationRequest, GoalRequest, IndexRequest, ModuleRequest};
+use crate::tools::core::tasks::{TaskAction, TasksRequest};
+
+/// Largest `limit` any tool accepts
+const MAX_LIMIT: i64 = 1000;
+
+/// Expected shape of one request field
+#[derive(Debug, Clone)]
+pub enum FieldKind {
+    /// Integer within an inclusive range; numeric strings are coerced
+    Integer { min: i64, max: i64 },
+    /// `true`/`false`; the strings "true"/"false" are coerced
+    Bool,
+    /// Any string
+    String,
+    /// One of a fixed set of strings
+    OneOf(Vec<String>),
+    /// A path that must exist on disk
+    ExistingPath,
+}
+
+/// One field of a request type
+#[derive(Debug, Clone)]
+pub struct FieldSpec {
+    pub name: &'static str,
+    pub kind: FieldKind,
+    pub required: bool,
+}
+
+impl FieldSpec {
+    pub fn required(name: &'static str, kind: FieldKind) -> Self {
+        Self {
+            name,
+            kind,
+            required: true,
+        }
+    }
+
+    pub fn optional(name: &'static str, kind: FieldKind) -> Self {
+        Self {
+            name,
+            kind,
+            required: false,
+        }
+    }
+}
+
+/// A request type whose JSON arguments are checked before deserialization.
+///
+/// Fields not listed in `fields` are left to serde.
+pub trait ValidateArgs: DeserializeOwned {
+    fn fields() -> Vec<FieldSpec>;
+}
+
+/// Serialized names of a unit enum's variants, read from its JSON schema
+pub fn enum_values<T: schemars::JsonSchema>() -> Vec<String> {
+    let schema = schemars::schema_for!(T);
+    let value = schema.as_value();
+    let strings = |v: &Value| -> Vec<String> {
+        v.as_array()
+            .map(|a| {
+                a.iter()
+                    .filter_map(|s| s.as_str().map(String::from))
+                    .collect()
+            })
+            .unwrap_or_default()
+    };
+
+    let mut values = value.get("enum").map(strings).unwrap_or_default();
+    for variant in value
+        .get("oneOf")
+        .and_then(|v| v.as_array())
+        .into_iter()
+        .flatten()
+    {
+        if let Some(name) = variant.get("const").and_then(|c| c.as_str()) {
+            values.push(name.to_string());
+        }
+        values.extend(variant.get("enum").map(strings).unwrap_or_default());
+    }
+    values
+}
+
+/// Positive row ID
+pub fn id_field() -> FieldKind {
+    FieldKind::Integer {
+        min: 1,
+        max: i64::MAX,
+    }
+}
+
+/// Result count
+pub fn limit_field() -> FieldKind {
+    FieldKind::Integer {
+        min: 1,
+        max: MAX_LIMIT,
+    }
+}
+
+fn describe(value: &Value) -> String {
+    match value {
+        Value::String(s) => format!("string \"{}\"", s),
+        Value::Number(n) => format!("number {}", n),
+        Value::Bool(b) => format!("boolean {}", b),
+        Value::Array(_) => "an array".to_string(),
+        Value::Object(_) => "an object".to_string(),
+        Value::Null => "null".to_string(),
+    }
+}
+
+/// Check one present field, coercing it in place. Returns a problem description.
+fn check_field(spec: &FieldSpec, value: &mut Value) -> Option<String> {
+    let name = spec.name;
+    match &spec.kind {
+        FieldKind::Integer { min, max } => {
+            let parsed = match &*value {
+                Value::Number(n) => n
+                    .as_i64()
+                    .or_else(|| n.as_f64().filter(|f| f.fract() == 0.0).map(|f| f as i64)),
+                Value::String(s) => s.trim().parse::<i64>().ok(),
+                _ => None,
+            };
+            let Some(n) = parsed else {
+                return Some(format!(
+                    "`{}` must be an integer, got {}",
+                    name,
+                    describe(value)
+                ));
+            };
+            *value = Value::from(n);
+            if n < *min || n > *max {
+                return Some(if *max == i64::MAX {
+                    format!("`{}` must be at least {}, got {}", name, min, n)
+                } else {
+                    format!("`{}` must be between {} and {}, got {}", name, min, max, n)
+                });
+            }
+            None
+        }
+        FieldKind::Bool => {
+            let parsed = match &*value {
+                Value::Bool(b) => Some(*b),
+                Value::String(s) => match s.trim().to_ascii_lowercase().as_str() {
+                    "true" => Some(true),
+                    "false" => Some(false),
+                    _ => None,
+                },
+                _ => None,
+            };
+            match parsed {
+                Some(b) => {
+                    *value = Value::Bool(b);
+                    None
+                }
+                None => Some(format!(
+                    "`{}` must be true or false, got {}",
+                    name,
+                    describe(value)
+                )),
+            }
+        }
+        FieldKind::String => (!value.is_string())
+            .then(|| format!("`{}` must be a string, got {}", name, describe(value))),
+        FieldKind::OneOf(allowed) => match value.as_str() {
+            Some(s) if allowed.iter().any(|a| a == s) => None,
+            _ => Some(format!(
+                "`{}` must be one of: {} (got {})",
+                name,
+                allowed.join(", "),
+                describe(value)
+            )),
+        },
+        FieldKind::ExistingPath => match value.as_str() {
+            Some(p) if std::path::Path::new(p).exists() => None,
+            Some(p) => Some(format!("`{}` does not exist: {}", name, p)),
+            None => Some(format!(
+                "`{}` must be a path string, got {}",
+                name,
+                describe(value)
+            )),
+        },
+    }
+}
+
+/// Check and coerce a JSON object against field specs, collecting every problem
+pub fn validate_object(fields: &[FieldSpec], object: &mut Map<String, Value>) -> Vec<String> {
+    let mut problems = Vec::new();
+    for spec in fields {
+        match object.get_mut(spec.name) {
+            None | Some(Value::Null) => {
+                if spec.required {
+                    problems.push(match &spec.kind {
+                        FieldKind::OneOf(allowed) => format!(
+                            "`{}` is required (one of: {})",
+                            spec.name,
+                            allowed.join(", ")
+                        ),
+                        _ => format!("`{}` is required", spec.name),
+                    });
+                }
+            }
+            Some(value) => problems.extend(check_field(spec, value)),
+        }
+    }
+    problems
+}
+
+/// Parse tool arguments into `T`, reporting all invalid fields at once.
+///
+/// Numeric strings and "true"/"false" strings are coerced for fields that
+/// expect them, since some clients serialize loosely.
+pub fn parse_args<T: ValidateArgs>(args: &str) -> Result<T, MiraError> {
+    let mut value: Value = serde_json::from_str(args)
+        .map_err(|e| MiraError::InvalidInput(format!("arguments are not valid JSON: {}", e)))?;
+    let Some(object) = value.as_object_mut() else {
+        return Err(MiraError::InvalidInput(format!(
+            "arguments must be a JSON object, got {}",
+            describe(&value)
+        )));
+    };
+
+    let problems = validate_object(&T::fields(), object);
+    if !problems.is_empty() {
+        let noun = if problems.len() == 1 {
+            "argument"
+        } else {
+            "arguments"
+        };
+        return Err(MiraError::InvalidInput(format!(
+            "{} invalid {}:\n- {}",
+            problems.len(),
+            noun,
+            problems.join("\n- ")
+        )));
+    }
+
+    serde_json::from_value(value).map_err(|e| MiraError::InvalidInput(e.to_string()))
+}
+
+fn strings(values: &[&str]) -> Vec<String> {
+    values.iter().map(|s| s.to_string()).collect()
+}
+
+impl ValidateArgs for GoalRequest {
+    fn fields() -> Vec<FieldSpec> {
+        use crate::tools::core::goals::{VALID_PRIORITIES, VALID_STATUSES};
+        vec![
+            FieldSpec::required(
+                "action",
+                FieldKind::OneOf(enum_values::<crate::mcp::requests::GoalAction>()),
+            ),
+            FieldSpec::optional("goal_id", id_field()),
+            FieldSpec::optional("milestone_id", id_field()),
+            FieldSpec::optional("task_id", id_field()),
+            FieldSpec::optional("depends_on", id_field()),
+            FieldSpec::optional("title", FieldKind::String),
+            FieldSpec::optional("milestone_title", FieldKind::String),
+            FieldSpec::optional("description", FieldKind::String),
+            FieldSpec::optional("status", FieldKind::OneOf(strings(VALID_STATUSES))),
+            FieldSpec::optional("priority", FieldKind::OneOf(strings(VALID_PRIORITIES))),
+            FieldSpec::optional("progress_percent", FieldKind::Integer { min: 0, max: 100 }),
+            FieldSpec::optional("weight", FieldKind::Integer { min: 1, max: 100 }),
+            FieldSpec::optional("include_finished", FieldKind::Bool),
+            FieldSpec::optional("limit", limit_field()),
+            FieldSpec::optional("goals", FieldKind::String),
+            FieldSpec::optional("template", FieldKind::String),
+            FieldSpec::optional("items", FieldKind::String),
+            FieldSpec::optional("vars", FieldKind::String),
+        ]
+    }
+}
+
+impl ValidateArgs for IndexRequest {
+    fn fields() -> Vec<FieldSpec> {
+        vec![
+            FieldSpec::required(
+                "action",
+                FieldKind::OneOf(enum_values::<crate::mcp::requests::IndexAction>()),
+            ),
+            FieldSpec::optional("path", FieldKind::ExistingPath),
+            FieldSpec::optional("skip_embed", FieldKind::Bool),
+            FieldSpec::optional("force", FieldKind::Bool),
+        ]
+    }
+}
+
+impl ValidateArgs for DocumentationRequest {
+    fn fields() -> Vec<FieldSpec> {
+        vec![
+            FieldSpec::required(
+                "action",
+                FieldKind::OneOf(enum_values::<crate::mcp::requests::DocumentationAction>()),
+            ),
+            FieldSpec::optional("task_id", id_field()),
+            FieldSpec::optional("reason", FieldKind::String),
+            FieldSpec::optional("doc_type", FieldKind::String),
+            FieldSpec::optional("priority", FieldKind::String),
+            FieldSpec::optional("status", FieldKind::String),
+            FieldSpec::optional("limit", limit_field()),
+            FieldSpec::optional(
+                "offset",
+                FieldKind::Integer {
+                    min: 0,
+                    max: i64::MAX,
+                },
+            ),
+        ]
+    }
+}
+
+impl ValidateArgs for ModuleRequest {
+    fn fields() -> Vec<FieldSpec> {
+        vec![
+            FieldSpec::required(
+                "action",
+                FieldKind::OneOf(enum_values::<crate::mcp::requests::ModuleAction>()),
+            ),
+            FieldSpec::optional("module_id", FieldKind::String),
+            FieldSpec::optional("query", FieldKind::String),
+            FieldSpec::optional("limit", limit_field()),
+        ]
+    }
+}
+
+impl ValidateArgs for TasksRequest {
+    fn fields() -> Vec<FieldSpec> {
+        vec![
+            FieldSpec::required("action", FieldKind::OneOf(enum_values::<TaskAction>())),
+            FieldSpec::optional("task_id", FieldKind::String),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mcp::requests::{DocumentationAction, GoalAction, IndexAction};
+
+    fn error_message<T: ValidateArgs + std::fmt::Debug>(args: &str) -> String {
+        match parse_args::<T>(args) {
+            Err(MiraError::InvalidInput(msg)) => msg,
+            other => panic!("expected InvalidInput for {args}, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn enum_values_come_from_schema() {
+        let actions = enum_values::<GoalAction>();
+        assert!(actions.contains(&"create".to_string()));
+        assert!(actions.contains(&"bulk_create".to_string()));
+        assert!(enum_values::<IndexAction>().contains(&"status".to_string()));
+    }
+
+    #[test]
+    fn coerces_loosely_serialized_values() {
+        let req: GoalRequest = parse_args(
+            r#"{"action": "list", "limit": "20", "include_finished": "true", "goal_id": 3.0}"#,
+        )
+        .unwrap();
+        assert!(matches!(req.action, GoalAction::List));
+        assert_eq!(req.limit, Some(20));
+        assert_eq!(req.include_finished, Some(true));
+        assert_eq!(req.goal_id, Some(3));
+
+        let req: DocumentationRequest =
+            parse_args(r#"{"action": "list", "offset": "10", "limit": null}"#).unwrap();
+        assert!(matches!(req.action, DocumentationAction::List));
+        assert_eq!(req.offset, Some(10));
+        assert_eq!(req.limit, None);
+    }
+
+    #[test]
+    fn bad_payloads_report_every_problem() {
+        let cases: &[(&str, &[&str])] = &[
+            (
+                r#"{"action": "launch", "limit": "lots"}"#,
+                &[
+                    "2 invalid arguments",
+                    "`action` must be one of: ",
+                    "bulk_create",
+                    "(got string \"launch\")",
+                    "`limit` must be an integer, got string \"lots\"",
+                ],
+            ),
+            (
+                r#"{"limit": 0, "progress_percent": 150, "include_finished": "yes"}"#,
+                &[
+                    "4 invalid arguments",
+                    "`action` is required (one of: ",
+                    "`limit` must be between 1 and 1000, got 0",
+                    "`progress_percent` must be between 0 and 100, got 150",
+                    "`include_finished` must be true or false, got string \"yes\"",
+                ],
+            ),
+            (
+                r#"{"action": "update", "goal_id": -1, "status": "done", "priority": "urgent"}"#,
+                &[
+                    "3 invalid arguments",
+                    "`goal_id` must be at least 1, got -1",
+                    "`status` must be one of: planning, in_progress, blocked, completed, abandoned (got string \"done\")",
+                    "`priority` must be one of: low, medium, high, critical (got string \"urgent\")",
+                ],
+            ),
+            (
+                r#"[1, 2]"#,
+                &["arguments must be a JSON object, got an array"],
+            ),
+            (r#"{"action": "#, &["arguments are not valid JSON"]),
+        ];
+        for (args, expected) in cases {
+            let msg = error_message::<GoalRequest>(args);
+            for fragment in *expected {
+                assert!(
+                    msg.contains(fragment),
+                    "{args}: missing {fragment:?} in {msg:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn other_request_types_are_validated() {
+        let msg = error_message::<IndexRequest>(
+            r#"{"action": "project", "path": "/definitely/not/here", "skip_embed": 1}"#,
+        );
+        assert!(
+            msg.contains("`path` does not exist: /definitely/not/here"),
+            "{msg}"
+        );
+        assert!(
+            msg.contains("`skip_embed` must be true or false, got number 1"),
+            "{msg}"
+        );
+
+        let msg = error_message::<DocumentationRequest>(
+            r#"{"action": "skip", "task_id": "abc", "offset": -5}"#,
+        );
+        assert!(msg.contains("`task_id` must be an integer"), "{msg}");
+        assert!(msg.contains("`offset` must be at least 0, got -5"), "{msg}");
+
+        let msg = error_message::<TasksRequest>(r#"{"action": "stop", "task_id": 42}"#);
+        assert!(
+            msg.contains("`action` must be one of: list, get, cancel"),
+            "{msg}"
+        );
+        assert!(
+            msg.contains("`task_id` must be a string, got number 42"),
+            "{msg}"
+        );
+
+        let msg = error_message::<ModuleRequest>(r#"{"action": "find", "limit": 5000}"#);
+        assert!(
+            msg.contains("`action` must be one of: list, get, search"),
+            "{msg}"
+        );
+        assert!(
+            msg.contains("`limit` must be between 1 and 1000, got 5000"),
+            "{msg}"
+        );
+    }
+
+    #[test]
+    fn serde_errors_for_unchecked_fields_still_surface_as_invalid_input() {
+        // task_ids is left to serde
+        let msg =
+            error_message::<DocumentationRequest>(r#"{"action": "batch_skip", "task_ids": "1,2"}"#);
+        assert!(msg.contains("invalid type"), "{msg}");
+    }
+}
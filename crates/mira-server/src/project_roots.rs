@@ -0,0 +1,165 @@
+// crates/mira-server/src/project_roots.rs
+//! Additional roots for projects that span several directories.
+//!
+//! A project has one primary path (`projects.path`) and any number of attached
+//! roots (`project_roots`). Files under an attached root are indexed as
+//! `@<root name>/<relative path>`, so results show which root a file came from
+//! while primary-root paths stay exactly as they were.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{LazyLock, RwLock};
+
+use crate::utils::safe_join;
+
+/// Marks an indexed file path as belonging to an attached root
+pub const ROOT_PATH_PREFIX: char = '@';
+
+/// An additional directory attached to a project
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProjectRoot {
+    /// Short name used in indexed paths (`@name/...`)
+    pub name: String,
+    /// Absolute, normalized directory path
+    pub path: PathBuf,
+}
+
+/// Attached roots of active projects, keyed by primary project path.
+/// Populated when a project is activated so file reads can resolve `@name/` paths
+/// without a database round-trip.
+static REGISTERED_ROOTS: LazyLock<RwLock<HashMap<PathBuf, Vec<ProjectRoot>>>> =
+    LazyLock::new(|| RwLock::new(HashMap::new()));
+
+/// Build roots from `(name, path)` rows as returned by `get_project_roots_sync`
+pub fn roots_from_rows(rows: Vec<(String, String)>) -> Vec<ProjectRoot> {
+    rows.into_iter()
+        .map(|(name, path)| ProjectRoot {
+            name,
+            path: PathBuf::from(path),
+        })
+        .collect()
+}
+
+/// Indexed path for a file under an attached root: `@name/relative`
+pub fn root_file_path(root_name: &str, relative: &str) -> String {
+    format!("{}{}/{}", ROOT_PATH_PREFIX, root_name, relative)
+}
+
+/// Split an indexed `@name/relative` path into (name, relative).
+/// Returns None for primary-root paths.
+pub fn split_root_file_path(file_path: &str) -> Option<(&str, &str)> {
+    file_path
+        .strip_prefix(ROOT_PATH_PREFIX)?
+        .split_once('/')
+        .filter(|(name, rel)| !name.is_empty() && !rel.is_empty())
+}
+
+/// Check a root name: 1-64 ASCII letters, digits, `-`, `_` or `.`, not starting with `.`
+pub fn validate_root_name(name: &str) -> Result<(), String> {
+    let valid = !name.is_empty()
+        && name.len() <= 64
+        && !name.starts_with('.')
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'));
+    if valid {
+        Ok(())
+    } else {
+        Err(format!(
+            "Invalid root name '{}'. Use 1-64 letters, digits, '-', '_' or '.'",
+            name
+        ))
+    }
+}
+
+/// Default root name: the directory's final component
+pub fn default_root_name(path: &Path) -> Option<String> {
+    path.file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .filter(|n| validate_root_name(n).is_ok())
+}
+
+/// Record the attached roots of a project so indexed `@name/` paths resolve.
+pub fn register_roots(project_path: &Path, roots: Vec<ProjectRoot>) {
+    if let Ok(mut registry) = REGISTERED_ROOTS.write() {
+        if roots.is_empty() {
+            registry.remove(project_path);
+        } else {
+            registry.insert(project_path.to_path_buf(), roots);
+        }
+    }
+}
+
+/// Attached roots registered for a project (empty for single-root projects)
+pub fn registered_roots(project_path: &Path) -> Vec<ProjectRoot> {
+    REGISTERED_ROOTS
+        .read()
+        .ok()
+        .and_then(|registry| registry.get(project_path).cloned())
+        .unwrap_or_default()
+}
+
+/// Resolve an indexed file path to a file on disk.
+///
+/// `@name/relative` paths are joined onto the matching attached root; anything
+/// else is joined onto the project path. Like [`safe_join`], returns None if the
+/// file does not exist or escapes its root.
+pub fn resolve_file(project_path: &Path, file_path: &str) -> Option<PathBuf> {
+    if let Some((name, relative)) = split_root_file_path(file_path) {
+        let roots = registered_roots(project_path);
+        if let Some(root) = roots.iter().find(|r| r.name == name) {
+            return safe_join(&root.path, relative);
+        }
+    }
+    safe_join(project_path, file_path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn root_paths_round_trip() {
+        let indexed = root_file_path("web", "src/app.ts");
+        assert_eq!(indexed, "@web/src/app.ts");
+        assert_eq!(split_root_file_path(&indexed), Some(("web", "src/app.ts")));
+        assert_eq!(split_root_file_path("src/main.rs"), None);
+        assert_eq!(split_root_file_path("@web"), None);
+    }
+
+    #[test]
+    fn root_names_are_validated() {
+        assert!(validate_root_name("web").is_ok());
+        assert!(validate_root_name("api-v2_old.1").is_ok());
+        assert!(validate_root_name("").is_err());
+        assert!(validate_root_name("a/b").is_err());
+        assert!(validate_root_name("..").is_err());
+    }
+
+    #[test]
+    fn resolve_file_uses_attached_root() {
+        let api = tempfile::tempdir().unwrap();
+        let web = tempfile::tempdir().unwrap();
+        std::fs::write(api.path().join("main.rs"), "fn main() {}").unwrap();
+        std::fs::write(web.path().join("app.ts"), "export {}").unwrap();
+
+        register_roots(
+            api.path(),
+            vec![ProjectRoot {
+                name: "web".into(),
+                path: web.path().to_path_buf(),
+            }],
+        );
+
+        let primary = resolve_file(api.path(), "main.rs").unwrap();
+        assert!(primary.ends_with("main.rs"));
+        let attached = resolve_file(api.path(), "@web/app.ts").unwrap();
+        assert_eq!(attached, web.path().join("app.ts").canonicalize().unwrap());
+        // Escaping the attached root is rejected
+        assert!(resolve_file(api.path(), "@web/../outside").is_none());
+        assert!(resolve_file(api.path(), "@other/app.ts").is_none());
+
+        register_roots(api.path(), vec![]);
+        assert!(registered_roots(api.path()).is_empty());
+    }
+}
@@ -1,6 +1,6 @@
 //! Code navigation bindings for Rhai scripts.
 //!
-//! Exposes `search`, `symbols`, `callers`, and `callees` to Rhai scripts,
+//! Exposes `search`, `search_all`, `symbols`, `callers`, `callees`, and `impact` to Rhai scripts,
 //! bridging them to the existing tool implementations in `tools/core/code/`.
 
 use crate::mcp::MiraServer;
@@ -31,23 +31,42 @@ pub fn register(engine: &mut Engine, server: MiraServer) {
         },
     );
 
+    // search_all(query) -> Map
+    let srv = server.clone();
+    engine.register_fn(
+        "search_all",
+        move |query: &str| -> Result<Dynamic, Box<EvalAltResult>> {
+            let srv = srv.clone();
+            let query = query.to_string();
+            call_async_json(async move { core::search_all(&srv, query, None).await })
+        },
+    );
+
+    // search_all(query, limit) -> Map
+    let srv = server.clone();
+    engine.register_fn(
+        "search_all",
+        move |query: &str, limit: i64| -> Result<Dynamic, Box<EvalAltResult>> {
+            let srv = srv.clone();
+            let query = query.to_string();
+            call_async_json(async move { core::search_all(&srv, query, Some(limit)).await })
+        },
+    );
+
     // symbols(file_path) -> Array
     // get_symbols is SYNC — no async bridge needed
     engine.register_fn(
         "symbols",
         |file_path: &str| -> Result<Dynamic, Box<EvalAltResult>> {
-            match core::get_symbols(file_path.to_string(), None) {
-                Ok(json) => crate::scripting::convert::to_dynamic(&json.0).map_err(|e| {
-                    Box::new(EvalAltResult::ErrorRuntime(
-                        Dynamic::from(e),
-                        rhai::Position::NONE,
-                    ))
-                }),
-                Err(e) => Err(Box::new(EvalAltResult::ErrorRuntime(
-                    Dynamic::from(e.to_string()),
-                    rhai::Position::NONE,
-                ))),
-            }
+            symbols_dynamic(file_path, false)
+        },
+    );
+
+    // symbols(file_path, include_docs) -> Array
+    engine.register_fn(
+        "symbols",
+        |file_path: &str, include_docs: bool| -> Result<Dynamic, Box<EvalAltResult>> {
+            symbols_dynamic(file_path, include_docs)
         },
     );
 
@@ -76,4 +95,45 @@ pub fn register(engine: &mut Engine, server: MiraServer) {
             })
         },
     );
+
+    // impact(symbol) -> Map
+    let srv = server.clone();
+    engine.register_fn(
+        "impact",
+        move |symbol: &str| -> Result<Dynamic, Box<EvalAltResult>> {
+            let srv = srv.clone();
+            let symbol = symbol.to_string();
+            call_async_json(async move { core::symbol_impact(&srv, symbol, None, None).await })
+        },
+    );
+
+    // impact(symbol, file_path) -> Map
+    let srv = server.clone();
+    engine.register_fn(
+        "impact",
+        move |symbol: &str, file_path: &str| -> Result<Dynamic, Box<EvalAltResult>> {
+            let srv = srv.clone();
+            let symbol = symbol.to_string();
+            let file_path = file_path.to_string();
+            call_async_json(async move {
+                core::symbol_impact(&srv, symbol, Some(file_path), None).await
+            })
+        },
+    );
+}
+
+/// Run get_symbols and convert the output for Rhai.
+fn symbols_dynamic(file_path: &str, include_docs: bool) -> Result<Dynamic, Box<EvalAltResult>> {
+    match core::get_symbols(file_path.to_string(), None, include_docs) {
+        Ok(json) => crate::scripting::convert::to_dynamic(&json.0).map_err(|e| {
+            Box::new(EvalAltResult::ErrorRuntime(
+                Dynamic::from(e),
+                rhai::Position::NONE,
+            ))
+        }),
+        Err(e) => Err(Box::new(EvalAltResult::ErrorRuntime(
+            Dynamic::from(e.to_string()),
+            rhai::Position::NONE,
+        ))),
+    }
 }
@@ -2,7 +2,10 @@
 //!
 //! Exposes `goal_create`, `goal_list`, `goal_get`, `goal_update`, `goal_delete`,
 //! `goal_sessions`, `goal_bulk_create`, `goal_add_milestone`,
-//! `goal_complete_milestone`, and `goal_delete_milestone` to Rhai scripts,
+//! `goal_complete_milestone`, `goal_delete_milestone`, `goal_add_dependency`,
+//! `goal_remove_dependency`, `goal_dependencies`, `task_next`,
+//! `task_add_dependency`, `task_remove_dependency`, `task_template`,
+//! `task_templates`, and `task_instantiate` to Rhai scripts,
 //! bridging them to the existing tool implementations in `tools/core/goals.rs`.
 
 use crate::mcp::MiraServer;
@@ -17,6 +20,8 @@ fn make_request(action: GoalAction) -> GoalRequest {
         action,
         goal_id: None,
         milestone_id: None,
+        task_id: None,
+        depends_on: None,
         title: None,
         milestone_title: None,
         description: None,
@@ -27,6 +32,9 @@ fn make_request(action: GoalAction) -> GoalRequest {
         limit: None,
         goals: None,
         include_finished: None,
+        template: None,
+        items: None,
+        vars: None,
     }
 }
 
@@ -213,4 +221,164 @@ pub fn register(engine: &mut Engine, server: MiraServer) {
             call_async_json(async move { core::goal(&srv, req).await })
         },
     );
+
+    // goal_add_dependency(goal_id, depends_on) -> Map
+    let srv = server.clone();
+    engine.register_fn(
+        "goal_add_dependency",
+        move |goal_id: i64, depends_on: i64| -> Result<Dynamic, Box<EvalAltResult>> {
+            let srv = srv.clone();
+            let mut req = make_request(GoalAction::AddDependency);
+            req.goal_id = Some(goal_id);
+            req.depends_on = Some(depends_on);
+            call_async_json(async move { core::goal(&srv, req).await })
+        },
+    );
+
+    // goal_remove_dependency(goal_id, depends_on) -> Map
+    let srv = server.clone();
+    engine.register_fn(
+        "goal_remove_dependency",
+        move |goal_id: i64, depends_on: i64| -> Result<Dynamic, Box<EvalAltResult>> {
+            let srv = srv.clone();
+            let mut req = make_request(GoalAction::RemoveDependency);
+            req.goal_id = Some(goal_id);
+            req.depends_on = Some(depends_on);
+            call_async_json(async move { core::goal(&srv, req).await })
+        },
+    );
+
+    // goal_dependencies(goal_id) -> Map
+    let srv = server.clone();
+    engine.register_fn(
+        "goal_dependencies",
+        move |goal_id: i64| -> Result<Dynamic, Box<EvalAltResult>> {
+            let srv = srv.clone();
+            let mut req = make_request(GoalAction::Dependencies);
+            req.goal_id = Some(goal_id);
+            call_async_json(async move { core::goal(&srv, req).await })
+        },
+    );
+
+    // task_next() -> Map
+    let srv = server.clone();
+    engine.register_fn(
+        "task_next",
+        move || -> Result<Dynamic, Box<EvalAltResult>> {
+            let srv = srv.clone();
+            let req = make_request(GoalAction::NextTasks);
+            call_async_json(async move { core::goal(&srv, req).await })
+        },
+    );
+
+    // task_next(limit) -> Map
+    let srv = server.clone();
+    engine.register_fn(
+        "task_next",
+        move |limit: i64| -> Result<Dynamic, Box<EvalAltResult>> {
+            let srv = srv.clone();
+            let mut req = make_request(GoalAction::NextTasks);
+            req.limit = Some(limit);
+            call_async_json(async move { core::goal(&srv, req).await })
+        },
+    );
+
+    // task_add_dependency(task_id, depends_on) -> Map
+    let srv = server.clone();
+    engine.register_fn(
+        "task_add_dependency",
+        move |task_id: i64, depends_on: i64| -> Result<Dynamic, Box<EvalAltResult>> {
+            let srv = srv.clone();
+            let mut req = make_request(GoalAction::AddTaskDependency);
+            req.task_id = Some(task_id);
+            req.depends_on = Some(depends_on);
+            call_async_json(async move { core::goal(&srv, req).await })
+        },
+    );
+
+    // task_remove_dependency(task_id, depends_on) -> Map
+    let srv = server.clone();
+    engine.register_fn(
+        "task_remove_dependency",
+        move |task_id: i64, depends_on: i64| -> Result<Dynamic, Box<EvalAltResult>> {
+            let srv = srv.clone();
+            let mut req = make_request(GoalAction::RemoveTaskDependency);
+            req.task_id = Some(task_id);
+            req.depends_on = Some(depends_on);
+            call_async_json(async move { core::goal(&srv, req).await })
+        },
+    );
+
+    // task_template(name, items: Array) -> Map
+    let srv = server.clone();
+    engine.register_fn(
+        "task_template",
+        move |name: &str, items: rhai::Array| -> Result<Dynamic, Box<EvalAltResult>> {
+            let srv = srv.clone();
+            let mut req = make_request(GoalAction::TaskTemplate);
+            req.template = Some(name.to_string());
+            req.items = Some(to_json_arg("items", Dynamic::from_array(items))?);
+            call_async_json(async move { core::goal(&srv, req).await })
+        },
+    );
+
+    // task_templates() -> Map
+    let srv = server.clone();
+    engine.register_fn(
+        "task_templates",
+        move || -> Result<Dynamic, Box<EvalAltResult>> {
+            let srv = srv.clone();
+            let req = make_request(GoalAction::TaskTemplates);
+            call_async_json(async move { core::goal(&srv, req).await })
+        },
+    );
+
+    // task_instantiate(name) -> Map
+    let srv = server.clone();
+    engine.register_fn(
+        "task_instantiate",
+        move |name: &str| -> Result<Dynamic, Box<EvalAltResult>> {
+            let srv = srv.clone();
+            let mut req = make_request(GoalAction::TaskInstantiate);
+            req.template = Some(name.to_string());
+            call_async_json(async move { core::goal(&srv, req).await })
+        },
+    );
+
+    // task_instantiate(name, vars: Map) -> Map
+    let srv = server.clone();
+    engine.register_fn(
+        "task_instantiate",
+        move |name: &str, vars: Map| -> Result<Dynamic, Box<EvalAltResult>> {
+            let srv = srv.clone();
+            let mut req = make_request(GoalAction::TaskInstantiate);
+            req.template = Some(name.to_string());
+            req.vars = Some(to_json_arg("vars", Dynamic::from_map(vars))?);
+            call_async_json(async move { core::goal(&srv, req).await })
+        },
+    );
+
+    // task_instantiate(name, vars: Map, goal_id) -> Map
+    let srv = server.clone();
+    engine.register_fn(
+        "task_instantiate",
+        move |name: &str, vars: Map, goal_id: i64| -> Result<Dynamic, Box<EvalAltResult>> {
+            let srv = srv.clone();
+            let mut req = make_request(GoalAction::TaskInstantiate);
+            req.template = Some(name.to_string());
+            req.vars = Some(to_json_arg("vars", Dynamic::from_map(vars))?);
+            req.goal_id = Some(goal_id);
+            call_async_json(async move { core::goal(&srv, req).await })
+        },
+    );
+}
+
+/// Encode a script value as the JSON string the goal tool expects.
+fn to_json_arg(name: &str, value: Dynamic) -> Result<String, Box<EvalAltResult>> {
+    serde_json::to_string(&crate::scripting::convert::dynamic_to_value(value)).map_err(|e| {
+        Box::new(EvalAltResult::ErrorRuntime(
+            Dynamic::from(format!("Failed to serialize {name}: {e}")),
+            rhai::Position::NONE,
+        ))
+    })
 }
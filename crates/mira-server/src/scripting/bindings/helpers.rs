@@ -8,9 +8,19 @@ const API_REFERENCE: &str = r#"Mira Script API Reference
 == Code Navigation ==
 search(query)              Semantic code search. Returns array of {file_path, line, score, snippet}.
 search(query, limit)       Same, with result limit.
+search_all(query)          One search over code, session summaries, and docs. Results tagged by source.
+search_all(query, limit)   Same, with result limit.
 symbols(file_path)         List definitions in a file. Returns array of {name, kind, line, end_line}.
+symbols(file_path, true)   Same, plus signature, visibility, and doc comment summary per symbol.
 callers(function_name)     What calls this function? Returns array of {file_path, line, caller}.
 callees(function_name)     What does this function call? Returns array of {file_path, line, callee}.
+impact(symbol)             Blast radius before a rename: callers (depth 2), text mentions, co-change files.
+impact(symbol, file_path)  Same, narrowing definitions to one file.
+
+== Modules ==
+modules()                  One card per module: summary, key types, entry points, dependencies.
+module_get(module_id)      Full card for one module (e.g. "db/schema").
+module_search(query)       Find modules by keyword (optional limit, default 10).
 
 == Goals ==
 goal_create(title)                    Create goal. Returns map with goal details.
@@ -26,25 +36,48 @@ goal_add_milestone(goal_id, title)    Add milestone to goal.
 goal_add_milestone(goal_id, title, weight)  Add weighted milestone.
 goal_complete_milestone(milestone_id) Complete a milestone (auto-updates goal progress).
 goal_delete_milestone(milestone_id)   Delete a milestone.
+goal_add_dependency(goal_id, depends_on)     Make a goal wait on another (cycles rejected).
+goal_remove_dependency(goal_id, depends_on)  Remove a goal dependency.
+goal_dependencies(goal_id)            Show what a goal waits on and what waits on it.
+
+== Tasks ==
+task_next()                           Open tasks whose dependencies are all completed (optional limit, default 10).
+task_add_dependency(task_id, depends_on)     Make a task wait on another (cycles rejected).
+task_remove_dependency(task_id, depends_on)  Remove a task dependency.
+task_template(name, items)            Save a reusable checklist; items may use {var} placeholders.
+task_templates()                      List saved checklists (project and global).
+task_instantiate(name)                Create the checklist's tasks (optional vars map, then goal_id).
 
 == Project ==
 project_init()             Initialize/re-init project context.
 project_init(path)         Initialize with specific path.
 project_info()             Get current project state.
+project_attach_root(path)  Attach another directory to the project (files show as @name/...).
 
 == Session ==
 recap()                    Get session recap with context.
 current_session()          Get current session info.
+tool_errors()              Recent failed tool calls with (redacted) args and error messages.
+tool_errors(tool_name)     Same, filtered to one tool.
+costs()                    LLM + embedding spend by day/source/project, month projection (optional days, default 30).
+db_health()                Daily DB size/row samples, week-over-week growth, growth warning (optional days, default 30).
+artifact(id)               First lines of an oversized result stored as an artifact.
+artifact(id, slice, arg)   Read more: "tail" (lines), "get" (byte offset), "search" (text).
+audit_export(output)       Signed JSONL export of this project's tool calls (optional days, default 30; needs MIRA_AUDIT_KEY).
 
 == Analysis ==
 diff()                     Analyze uncommitted changes.
 diff(from_ref, to_ref)     Analyze changes between refs (includes impact).
 insights()                 Get background analysis insights.
 dismiss_insight(id, source) Dismiss an insight.
+suggestions()              Stale goals, untouched tasks, untested churn (optional stale_days, default 14).
 
 == Index ==
-index_project()            Index/re-index project files.
+index_project()            Index changed project files (optional skip_embed, force).
 index_status()             Get indexing status.
+list_dead_letters()        Background jobs that failed too often (optional limit).
+retry_dead_letter(id)      Requeue one dead-lettered job.
+retry_dead_letters()       Requeue all dead-lettered jobs.
 
 == Teams ==
 launch(team)               Launch a team for collaborative work.
@@ -78,12 +111,36 @@ Example:
   let top5 = summarize(results, 5);
   for r in top5 { print(r.file_path + ":" + r.line); }"#.to_string(),
 
+        "search_all" => r#"search_all(query: String) -> Map
+search_all(query: String, limit: Int) -> Map
+
+Searches code, past session summaries, and tracked documentation at once.
+Each source contributes at most 5 hits; scores are normalized to 0.0-1.0
+against the best hit from the same source, then merged highest first.
+
+Parameters:
+  query  - What you're looking for
+  limit  - (optional) Maximum number of results to return (default: 10)
+
+Returns a map with `results` (array) and `total`. Each result contains:
+  source     - "code", "session", or "document"
+  score      - Normalized relevance score (higher is better)
+  reference  - File path, session ID, or doc path
+  line       - Line number (code results only)
+  snippet    - Matching content or summary
+
+Example:
+  let found = search_all("webhook retry");
+  for r in found.results { print(r.source + " " + r.reference); }"#.to_string(),
+
         "symbols" => r#"symbols(file_path: String) -> Array
+symbols(file_path: String, include_docs: bool) -> Array
 
 List all symbol definitions in a file (functions, structs, enums, traits, etc.).
 
 Parameters:
-  file_path  - Absolute path to the file to inspect
+  file_path     - Absolute path to the file to inspect
+  include_docs  - Also return signatures and doc comments (default: false)
 
 Returns an array of maps, each containing:
   name      - Symbol name
@@ -91,9 +148,16 @@ Returns an array of maps, each containing:
   line      - Start line number
   end_line  - End line number
 
+With include_docs = true, each map may also contain:
+  qualified_name  - e.g. "MyStruct::new" for methods
+  signature       - Declaration header up to the body, on one line
+  visibility      - e.g. "pub", "pub(crate)"
+  doc             - First paragraph of the doc comment
+
 Example:
   let syms = symbols("/path/to/file.rs");
-  for s in syms { print(s.kind + " " + s.name + " at line " + s.line); }"#.to_string(),
+  for s in syms { print(s.kind + " " + s.name + " at line " + s.line); }
+  let api = symbols("/path/to/lib.rs", true);"#.to_string(),
 
         "callers" => r#"callers(function_name: String) -> Array
 
@@ -127,6 +191,47 @@ Example:
   let callees = callees("process_data");
   for c in callees { print("process_data calls " + c.callee); }"#.to_string(),
 
+        "impact" => r#"impact(symbol: String, file_path?: String) -> Map
+
+Blast-radius report for renaming or changing a symbol.
+
+Parameters:
+  symbol     - Function name, or qualified Type::method / Type.method
+  file_path  - Optional: only count definitions in paths ending with this
+
+Returns a map containing:
+  definitions      - Where the symbol is defined
+  hard_references  - Call-graph callers {caller, file_path, line, depth}; depth 2 calls a caller.
+                     These break at compile time.
+  soft_references  - Text mentions {file_path, line, kind, excerpt} in other files;
+                     kind is doc, test, config, or code. The compiler won't flag these.
+  co_change        - Files that usually change with the definition {file_path, commits}
+  files            - Affected files by priority {file_path, score, reasons}
+  hard_total, direct_callers, soft_total - Counts before truncation
+
+Example:
+  let r = impact("Config::load");
+  print(r.hard_total + " callers, " + r.soft_total + " mentions");
+  for f in r.files { print(f.file_path + ": " + f.reasons); }"#.to_string(),
+
+        "module_search" => r#"module_search(query: String) -> Map
+module_search(query: String, limit: Int) -> Map
+
+Keyword search over module cards. Terms matching a module's ID or key types
+rank above matches in its summary or entry points.
+
+Cards are built in the background after indexing and regenerated only when a
+module's indexed content changes. Use modules() to list them all and
+module_get(id) for one card.
+
+Returns a map containing:
+  modules  - Array of {module_id, name, path, summary, key_types, entry_points, dependencies, score}
+  total    - Number of matches
+
+Example:
+  let hits = module_search("migration schema");
+  for m in hits.modules { print(m.module_id + ": " + m.summary); }"#.to_string(),
+
         "goal_create" => r#"goal_create(title: String) -> Map
 goal_create(title: String, priority: String) -> Map
 
@@ -152,12 +257,77 @@ Parameters:
   include_finished  - (optional) If true, include completed/abandoned goals (default: false)
 
 Returns an array of goal maps, each containing:
-  id, title, priority, status, progress_percent, milestone_count
+  id, title, priority, status, effective_status, progress_percent, milestone_count
+  blocked_by  - (when present) unfinished goals this one depends on
+
+effective_status is "blocked" while any dependency is unfinished; the stored
+status is left as-is.
 
 Example:
   let goals = goal_list();
   for g in goals { print(g.title + " - " + g.progress_percent + "%"); }"#.to_string(),
 
+        "goal_add_dependency" => r#"goal_add_dependency(goal_id: Int, depends_on: Int) -> Map
+
+Record that goal_id can't finish until depends_on is completed. Self-links and
+links that would form a cycle are rejected. While depends_on is unfinished,
+goal_id reports effective_status "blocked" in goal_list()/goal_get().
+Completing the last dependency raises a "Goal Unblocked" insight.
+
+Example:
+  goal_add_dependency(api.id, schema.id);
+  goal_dependencies(api.id).depends_on  // [#{id, title, status}]"#.to_string(),
+
+        "task_next" => r#"task_next() -> Map
+task_next(limit: Int) -> Map
+
+Open tasks that can be started now: not blocked, and every task they depend
+on (see task_add_dependency) is completed. Highest priority first, then oldest.
+
+Returns a map with:
+  tasks  - Array of {id, title, status, priority, goal_id}
+  total  - Number of tasks returned
+
+Example:
+  task_add_dependency(api_task, schema_task);
+  for t in task_next(3).tasks { print(t.title); }"#.to_string(),
+
+        "task_instantiate" => r#"task_instantiate(name: String) -> Map
+task_instantiate(name: String, vars: Map) -> Map
+task_instantiate(name: String, vars: Map, goal_id: Int) -> Map
+
+Create one task per item of a template saved with task_template(). Each
+{var} placeholder is filled from vars; a missing value fails before any task
+is created. With goal_id, the tasks are linked to that goal.
+
+Returns a map with:
+  template  - Template name
+  goal_id   - (when given) Goal the tasks were linked to
+  tasks     - Array of {id, title, status, priority, goal_id}
+
+Example:
+  task_template("release", ["Bump version to {version}", "Tag v{version}"]);
+  task_instantiate("release", #{version: "1.4.0"}, goal.id);"#.to_string(),
+
+        "project_attach_root" => r#"project_attach_root(path: String) -> Map
+project_attach_root(path: String, name: String) -> Map
+
+Attach another directory to the active project, for products split across
+sibling repos. Sessions and search stay scoped to the one project; the
+root's files are indexed and shown as `@name/relative/path`. Starting a
+session from inside an attached root resolves to the owning project.
+
+Parameters:
+  path  - Existing directory outside the project path
+  name  - (optional) Root name used in paths (default: directory name)
+
+Run index_project() afterwards to index the new root.
+
+Example:
+  project_attach_root("/work/web");
+  index_project();
+  search("checkout button");  // finds @web/src/... and api files alike"#.to_string(),
+
         "recap" => r#"recap() -> Map
 
 Get the current session recap including context, recent activity, and pending tasks.
@@ -174,6 +344,47 @@ Example:
   print("Session: " + ctx.session_id);
   print("Project: " + ctx.project);"#.to_string(),
 
+        "artifact" => r#"artifact(id: Int) -> Map
+artifact(id: Int, slice: String) -> Map
+artifact(id: Int, slice: String, n: Int) -> Map
+artifact(id: Int, "search", query: String) -> Map
+
+Read part of a tool result that was too large to return inline. Oversized
+run() output is stored as an artifact and replaced with a preview that ends
+with the artifact ID.
+
+Slices:
+  head    - First n lines (default 50)
+  tail    - Last n lines (default 50)
+  get     - 8 KB of content starting at byte offset n (default 0)
+  search  - Numbered lines containing query, case-insensitive (max 50)
+
+Returns a map with data containing:
+  artifact_id, tool_name, size_bytes, total_lines, slice, content
+  next_offset - (get only) Offset to pass to the next get, if more remains
+
+Example:
+  let end = artifact(12, "tail", 20);
+  print(end.data.content);
+
+  let hits = artifact(12, "search", "error");
+  print(hits.data.content);"#.to_string(),
+
+        "audit_export" => r#"audit_export(output: String) -> Map
+audit_export(output: String, since_days: Int) -> Map
+
+Write a signed JSONL export of the current project's tool calls from the
+last since_days days (default 30) to output, an absolute path. The manifest
+goes to <output>.manifest.json and is checked with `mira audit verify`.
+Requires MIRA_AUDIT_KEY.
+
+Returns a map with data containing:
+  path, manifest_path, since, until, project, row_count, hmac_sha256
+
+Example:
+  let r = audit_export("/tmp/audit.jsonl", 90);
+  print(r.data.row_count);"#.to_string(),
+
         "diff" => r#"diff() -> Map
 diff(from_ref: String, to_ref: String) -> Map
 
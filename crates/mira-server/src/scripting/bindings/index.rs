@@ -1,6 +1,7 @@
 //! Index bindings for Rhai scripts.
 //!
-//! Exposes `index_project` and `index_status` to Rhai scripts, bridging them
+//! Exposes `index_project`, `index_status`, and the dead-letter queue
+//! (`list_dead_letters`, `retry_dead_letter`) to Rhai scripts, bridging them
 //! to the existing tool implementation in `tools/core/code/index.rs`.
 
 use crate::mcp::MiraServer;
@@ -17,7 +18,7 @@ pub fn register(engine: &mut Engine, server: MiraServer) {
         move || -> Result<Dynamic, Box<EvalAltResult>> {
             let srv = srv.clone();
             call_async_json(async move {
-                core::index(&srv, IndexAction::Project, None, false).await
+                core::index(&srv, IndexAction::Project, None, false, false).await
             })
         },
     );
@@ -29,7 +30,19 @@ pub fn register(engine: &mut Engine, server: MiraServer) {
         move |skip_embed: bool| -> Result<Dynamic, Box<EvalAltResult>> {
             let srv = srv.clone();
             call_async_json(async move {
-                core::index(&srv, IndexAction::Project, None, skip_embed).await
+                core::index(&srv, IndexAction::Project, None, skip_embed, false).await
+            })
+        },
+    );
+
+    // index_project(skip_embed, force) -> Map
+    let srv = server.clone();
+    engine.register_fn(
+        "index_project",
+        move |skip_embed: bool, force: bool| -> Result<Dynamic, Box<EvalAltResult>> {
+            let srv = srv.clone();
+            call_async_json(async move {
+                core::index(&srv, IndexAction::Project, None, skip_embed, force).await
             })
         },
     );
@@ -41,8 +54,48 @@ pub fn register(engine: &mut Engine, server: MiraServer) {
         move || -> Result<Dynamic, Box<EvalAltResult>> {
             let srv = srv.clone();
             call_async_json(async move {
-                core::index(&srv, IndexAction::Status, None, false).await
+                core::index(&srv, IndexAction::Status, None, false, false).await
             })
         },
     );
+
+    // list_dead_letters() -> Map
+    let srv = server.clone();
+    engine.register_fn(
+        "list_dead_letters",
+        move || -> Result<Dynamic, Box<EvalAltResult>> {
+            let srv = srv.clone();
+            call_async_json(async move { core::list_dead_letters(&srv, None).await })
+        },
+    );
+
+    // list_dead_letters(limit) -> Map
+    let srv = server.clone();
+    engine.register_fn(
+        "list_dead_letters",
+        move |limit: i64| -> Result<Dynamic, Box<EvalAltResult>> {
+            let srv = srv.clone();
+            call_async_json(async move { core::list_dead_letters(&srv, Some(limit)).await })
+        },
+    );
+
+    // retry_dead_letter(id) -> Map
+    let srv = server.clone();
+    engine.register_fn(
+        "retry_dead_letter",
+        move |id: i64| -> Result<Dynamic, Box<EvalAltResult>> {
+            let srv = srv.clone();
+            call_async_json(async move { core::retry_dead_letter(&srv, Some(id)).await })
+        },
+    );
+
+    // retry_dead_letters() -> Map
+    let srv = server;
+    engine.register_fn(
+        "retry_dead_letters",
+        move || -> Result<Dynamic, Box<EvalAltResult>> {
+            let srv = srv.clone();
+            call_async_json(async move { core::retry_dead_letter(&srv, None).await })
+        },
+    );
 }
@@ -1,6 +1,6 @@
 //! Insights bindings for Rhai scripts.
 //!
-//! Exposes `insights` and `dismiss_insight` to Rhai scripts,
+//! Exposes `insights`, `dismiss_insight`, and `suggestions` to Rhai scripts,
 //! bridging them to the existing tool implementations in `tools/core/insights.rs`.
 
 use crate::mcp::MiraServer;
@@ -33,4 +33,26 @@ pub fn register(engine: &mut Engine, server: MiraServer) {
             })
         },
     );
+
+    // suggestions() -> Map
+    let srv = server.clone();
+    engine.register_fn(
+        "suggestions",
+        move || -> Result<Dynamic, Box<EvalAltResult>> {
+            let srv = srv.clone();
+            call_async_json(async move { core::proactive_suggestions(&srv, None).await })
+        },
+    );
+
+    // suggestions(stale_days) -> Map
+    let srv = server.clone();
+    engine.register_fn(
+        "suggestions",
+        move |stale_days: i64| -> Result<Dynamic, Box<EvalAltResult>> {
+            let srv = srv.clone();
+            call_async_json(async move {
+                core::proactive_suggestions(&srv, Some(stale_days)).await
+            })
+        },
+    );
 }
@@ -6,6 +6,7 @@ pub mod goals;
 pub mod helpers;
 pub mod index;
 pub mod insights;
+pub mod modules;
 pub mod project;
 pub mod session;
 pub mod teams;
@@ -23,5 +24,6 @@ pub fn register_all(engine: &mut Engine, server: MiraServer) {
     diff::register(engine, server.clone());
     index::register(engine, server.clone());
     insights::register(engine, server.clone());
+    modules::register(engine, server.clone());
     teams::register(engine, server.clone());
 }
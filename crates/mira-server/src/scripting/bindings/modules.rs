@@ -0,0 +1,71 @@
+//! Module card bindings for Rhai scripts.
+//!
+//! Exposes `modules`, `module_get`, and `module_search` to Rhai scripts,
+//! bridging them to the `module` tool in `tools/core/modules.rs`.
+
+use crate::mcp::MiraServer;
+use crate::mcp::requests::{ModuleAction, ModuleRequest};
+use crate::scripting::bridge::call_async_json;
+use crate::tools::core;
+use rhai::{Dynamic, Engine, EvalAltResult};
+
+fn request(action: ModuleAction) -> ModuleRequest {
+    ModuleRequest {
+        action,
+        module_id: None,
+        query: None,
+        limit: None,
+    }
+}
+
+pub fn register(engine: &mut Engine, server: MiraServer) {
+    // modules() -> Map
+    let srv = server.clone();
+    engine.register_fn("modules", move || -> Result<Dynamic, Box<EvalAltResult>> {
+        let srv = srv.clone();
+        call_async_json(async move { core::module(&srv, request(ModuleAction::List)).await })
+    });
+
+    // module_get(module_id) -> Map
+    let srv = server.clone();
+    engine.register_fn(
+        "module_get",
+        move |module_id: &str| -> Result<Dynamic, Box<EvalAltResult>> {
+            let srv = srv.clone();
+            let req = ModuleRequest {
+                module_id: Some(module_id.to_string()),
+                ..request(ModuleAction::Get)
+            };
+            call_async_json(async move { core::module(&srv, req).await })
+        },
+    );
+
+    // module_search(query) -> Map
+    let srv = server.clone();
+    engine.register_fn(
+        "module_search",
+        move |query: &str| -> Result<Dynamic, Box<EvalAltResult>> {
+            let srv = srv.clone();
+            let req = ModuleRequest {
+                query: Some(query.to_string()),
+                ..request(ModuleAction::Search)
+            };
+            call_async_json(async move { core::module(&srv, req).await })
+        },
+    );
+
+    // module_search(query, limit) -> Map
+    let srv = server;
+    engine.register_fn(
+        "module_search",
+        move |query: &str, limit: i64| -> Result<Dynamic, Box<EvalAltResult>> {
+            let srv = srv.clone();
+            let req = ModuleRequest {
+                query: Some(query.to_string()),
+                limit: Some(limit),
+                ..request(ModuleAction::Search)
+            };
+            call_async_json(async move { core::module(&srv, req).await })
+        },
+    );
+}
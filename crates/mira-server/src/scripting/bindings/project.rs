@@ -1,7 +1,8 @@
 //! Project bindings for Rhai scripts.
 //!
-//! Exposes `project_init`, `project_init(path)`, and `project_info` to Rhai scripts,
-//! bridging them to the existing tool implementations in `tools/core/project/`.
+//! Exposes `project_init`, `project_init(path)`, `project_info`, and
+//! `project_attach_root` to Rhai scripts, bridging them to the existing tool
+//! implementations in `tools/core/project/`.
 
 use crate::mcp::MiraServer;
 use crate::mcp::requests::ProjectAction;
@@ -17,7 +18,7 @@ pub fn register(engine: &mut Engine, server: MiraServer) {
         move || -> Result<Dynamic, Box<EvalAltResult>> {
             let srv = srv.clone();
             call_async_json(async move {
-                core::project(&srv, ProjectAction::Start, None, None, None).await
+                core::project(&srv, ProjectAction::Start, None, None, None, None).await
             })
         },
     );
@@ -30,7 +31,7 @@ pub fn register(engine: &mut Engine, server: MiraServer) {
             let srv = srv.clone();
             let path = path.to_string();
             call_async_json(async move {
-                core::project(&srv, ProjectAction::Start, Some(path), None, None).await
+                core::project(&srv, ProjectAction::Start, Some(path), None, None, None).await
             })
         },
     );
@@ -42,8 +43,31 @@ pub fn register(engine: &mut Engine, server: MiraServer) {
         move || -> Result<Dynamic, Box<EvalAltResult>> {
             let srv = srv.clone();
             call_async_json(async move {
-                core::project(&srv, ProjectAction::Get, None, None, None).await
+                core::project(&srv, ProjectAction::Get, None, None, None, None).await
             })
         },
     );
+
+    // project_attach_root(path) -> Map
+    let srv = server.clone();
+    engine.register_fn(
+        "project_attach_root",
+        move |path: &str| -> Result<Dynamic, Box<EvalAltResult>> {
+            let srv = srv.clone();
+            let path = path.to_string();
+            call_async_json(async move { core::attach_root(&srv, path, None).await })
+        },
+    );
+
+    // project_attach_root(path, name) -> Map
+    let srv = server.clone();
+    engine.register_fn(
+        "project_attach_root",
+        move |path: &str, name: &str| -> Result<Dynamic, Box<EvalAltResult>> {
+            let srv = srv.clone();
+            let path = path.to_string();
+            let name = name.to_string();
+            call_async_json(async move { core::attach_root(&srv, path, Some(name)).await })
+        },
+    );
 }
@@ -1,7 +1,7 @@
 //! Session bindings for Rhai scripts.
 //!
-//! Exposes `recap` and `current_session` to Rhai scripts,
-//! bridging them to the existing tool implementations in `tools/core/session/`.
+//! Exposes `recap`, `current_session`, `tool_errors`, `costs`, `artifact`, and `audit_export`
+//! to Rhai scripts, bridging them to the existing tool implementations in `tools/core/session/`.
 
 use crate::mcp::MiraServer;
 use crate::mcp::requests::{SessionAction, SessionRequest};
@@ -21,9 +21,23 @@ fn make_session_request(action: SessionAction) -> SessionRequest {
         insight_id: None,
         dry_run: None,
         category: None,
+        tool_name: None,
+        artifact_id: None,
+        slice: None,
+        query: None,
+        offset: None,
+        liveness: None,
+        output: None,
     }
 }
 
+fn make_artifact_request(id: i64, slice: &str) -> SessionRequest {
+    let mut req = make_session_request(SessionAction::Artifact);
+    req.artifact_id = Some(id);
+    req.slice = Some(slice.to_string());
+    req
+}
+
 pub fn register(engine: &mut Engine, server: MiraServer) {
     // recap() -> Map
     let srv = server.clone();
@@ -52,4 +66,151 @@ pub fn register(engine: &mut Engine, server: MiraServer) {
             })
         },
     );
+
+    // tool_errors() -> Map
+    let srv = server.clone();
+    engine.register_fn(
+        "tool_errors",
+        move || -> Result<Dynamic, Box<EvalAltResult>> {
+            let srv = srv.clone();
+            call_async_json(async move {
+                core::handle_session(&srv, make_session_request(SessionAction::ToolErrors)).await
+            })
+        },
+    );
+
+    // tool_errors(tool_name) -> Map
+    let srv = server.clone();
+    engine.register_fn(
+        "tool_errors",
+        move |tool_name: &str| -> Result<Dynamic, Box<EvalAltResult>> {
+            let srv = srv.clone();
+            let mut req = make_session_request(SessionAction::ToolErrors);
+            req.tool_name = Some(tool_name.to_string());
+            call_async_json(async move { core::handle_session(&srv, req).await })
+        },
+    );
+
+    // costs() -> Map
+    let srv = server.clone();
+    engine.register_fn(
+        "costs",
+        move || -> Result<Dynamic, Box<EvalAltResult>> {
+            let srv = srv.clone();
+            call_async_json(async move {
+                core::handle_session(&srv, make_session_request(SessionAction::UsageCosts)).await
+            })
+        },
+    );
+
+    // costs(since_days) -> Map
+    let srv = server.clone();
+    engine.register_fn(
+        "costs",
+        move |since_days: i64| -> Result<Dynamic, Box<EvalAltResult>> {
+            let srv = srv.clone();
+            let mut req = make_session_request(SessionAction::UsageCosts);
+            req.since_days = Some(since_days.clamp(1, 365) as u32);
+            call_async_json(async move { core::handle_session(&srv, req).await })
+        },
+    );
+
+    // db_health() -> Map
+    let srv = server.clone();
+    engine.register_fn(
+        "db_health",
+        move || -> Result<Dynamic, Box<EvalAltResult>> {
+            let srv = srv.clone();
+            call_async_json(async move {
+                core::handle_session(&srv, make_session_request(SessionAction::DbHealth)).await
+            })
+        },
+    );
+
+    // db_health(since_days) -> Map
+    let srv = server.clone();
+    engine.register_fn(
+        "db_health",
+        move |since_days: i64| -> Result<Dynamic, Box<EvalAltResult>> {
+            let srv = srv.clone();
+            let mut req = make_session_request(SessionAction::DbHealth);
+            req.since_days = Some(since_days.clamp(1, 365) as u32);
+            call_async_json(async move { core::handle_session(&srv, req).await })
+        },
+    );
+
+    // artifact(id) -> Map (first lines of a stored oversized result)
+    let srv = server.clone();
+    engine.register_fn(
+        "artifact",
+        move |id: i64| -> Result<Dynamic, Box<EvalAltResult>> {
+            let srv = srv.clone();
+            let req = make_artifact_request(id, "head");
+            call_async_json(async move { core::handle_session(&srv, req).await })
+        },
+    );
+
+    // artifact(id, slice) -> Map
+    let srv = server.clone();
+    engine.register_fn(
+        "artifact",
+        move |id: i64, slice: &str| -> Result<Dynamic, Box<EvalAltResult>> {
+            let srv = srv.clone();
+            let req = make_artifact_request(id, slice);
+            call_async_json(async move { core::handle_session(&srv, req).await })
+        },
+    );
+
+    // artifact(id, slice, n) -> Map (n = lines for head/tail, byte offset for get)
+    let srv = server.clone();
+    engine.register_fn(
+        "artifact",
+        move |id: i64, slice: &str, n: i64| -> Result<Dynamic, Box<EvalAltResult>> {
+            let srv = srv.clone();
+            let mut req = make_artifact_request(id, slice);
+            if slice == "get" {
+                req.offset = Some(n);
+            } else {
+                req.limit = Some(n);
+            }
+            call_async_json(async move { core::handle_session(&srv, req).await })
+        },
+    );
+
+    // artifact(id, "search", query) -> Map
+    let srv = server.clone();
+    engine.register_fn(
+        "artifact",
+        move |id: i64, slice: &str, query: &str| -> Result<Dynamic, Box<EvalAltResult>> {
+            let srv = srv.clone();
+            let mut req = make_artifact_request(id, slice);
+            req.query = Some(query.to_string());
+            call_async_json(async move { core::handle_session(&srv, req).await })
+        },
+    );
+
+    // audit_export(output) -> Map
+    let srv = server.clone();
+    engine.register_fn(
+        "audit_export",
+        move |output: &str| -> Result<Dynamic, Box<EvalAltResult>> {
+            let srv = srv.clone();
+            let mut req = make_session_request(SessionAction::AuditExport);
+            req.output = Some(output.to_string());
+            call_async_json(async move { core::handle_session(&srv, req).await })
+        },
+    );
+
+    // audit_export(output, since_days) -> Map
+    let srv = server.clone();
+    engine.register_fn(
+        "audit_export",
+        move |output: &str, since_days: i64| -> Result<Dynamic, Box<EvalAltResult>> {
+            let srv = srv.clone();
+            let mut req = make_session_request(SessionAction::AuditExport);
+            req.output = Some(output.to_string());
+            req.since_days = Some(since_days.clamp(1, 3650) as u32);
+            call_async_json(async move { core::handle_session(&srv, req).await })
+        },
+    );
 }
@@ -19,10 +19,11 @@ where
     handle
         .block_on(future_fn)
         .map_err(|e| {
-            Box::new(EvalAltResult::ErrorRuntime(
-                Dynamic::from(e.to_string()),
-                Position::NONE,
-            ))
+            // Tag the message with the error kind so `execute_script` can
+            // report a structured code to the MCP client.
+            let mut value = Dynamic::from(e.to_string());
+            value.set_tag(e.kind() as _);
+            Box::new(EvalAltResult::ErrorRuntime(value, Position::NONE))
         })
         .and_then(|val| {
             convert(val).map_err(|e| {
@@ -1,7 +1,8 @@
 //! Rhai Engine construction, sandboxing, and script execution.
 
+use crate::error::ErrorKind;
 use crate::mcp::MiraServer;
-use rhai::{Dynamic, Engine, Scope};
+use rhai::{Dynamic, Engine, EvalAltResult, Scope};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex};
 use std::time::Instant;
@@ -43,6 +44,8 @@ pub struct ScriptError {
     pub elapsed_ms: u128,
     /// Lines captured from print()/debug() calls before the error.
     pub print_output: Vec<String>,
+    /// Set when the error came from a Mira API call rather than the script itself.
+    pub kind: Option<ErrorKind>,
 }
 
 /// Create a sandboxed Rhai engine with Mira bindings.
@@ -155,6 +158,7 @@ pub async fn execute_script(
                 column: 0,
                 elapsed_ms,
                 print_output,
+                kind: None,
             });
         }
     };
@@ -169,6 +173,7 @@ pub async fn execute_script(
                 column: 0,
                 elapsed_ms,
                 print_output,
+                kind: None,
             });
         }
     };
@@ -192,11 +197,23 @@ pub async fn execute_script(
                 column: col,
                 elapsed_ms,
                 print_output,
+                kind: tool_error_kind(&err),
             })
         }
     }
 }
 
+/// Recover the `ErrorKind` that `bridge::call_async` tagged onto a tool error.
+fn tool_error_kind(err: &EvalAltResult) -> Option<ErrorKind> {
+    match err {
+        EvalAltResult::ErrorRuntime(value, _) => ErrorKind::ALL
+            .into_iter()
+            .find(|kind| *kind as i64 == i64::from(value.tag())),
+        EvalAltResult::ErrorInFunctionCall(_, _, inner, _) => tool_error_kind(inner),
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -280,6 +297,27 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn script_tool_error_carries_kind() {
+        let server = create_test_server().await;
+        let err = execute_script(&server, "goal_get(424242)").await.unwrap_err();
+        assert_eq!(err.kind, Some(ErrorKind::NotFound));
+        assert!(err.message.contains("Goal not found (id: 424242)"));
+
+        // Still recovered when the call happens inside a script-defined function
+        let err = execute_script(&server, "fn lookup() { goal_get(424242) } lookup()")
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind, Some(ErrorKind::NotFound));
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn script_own_errors_have_no_kind() {
+        let server = create_test_server().await;
+        let err = execute_script(&server, r#"throw "nope""#).await.unwrap_err();
+        assert_eq!(err.kind, None);
+    }
+
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     async fn script_print_captured() {
         let server = create_test_server().await;
@@ -2,7 +2,7 @@
 // Context expansion for search results
 
 use crate::db::get_symbol_bounds_sync;
-use crate::utils::safe_join;
+use crate::project_roots::resolve_file;
 use rusqlite::Connection;
 use std::path::Path;
 
@@ -87,7 +87,7 @@ pub fn expand_context_with_conn(
         && let Some((kind, name)) = parse_symbol_header(chunk_content)
         && let Some((start_line, end_line)) =
             lookup_symbol_bounds_sync(conn, project_id, file_path, &name)
-        && let Some(full_path) = safe_join(Path::new(proj_path), file_path)
+        && let Some(full_path) = resolve_file(Path::new(proj_path), file_path)
         && file_within_size_limit(&full_path)
         && let Ok(file_content) = std::fs::read_to_string(&full_path)
     {
@@ -106,7 +106,7 @@ pub fn expand_context_with_conn(
 
     // Fallback: use original +-5 line approach
     if let Some(proj_path) = project_path
-        && let Some(full_path) = safe_join(Path::new(proj_path), file_path)
+        && let Some(full_path) = resolve_file(Path::new(proj_path), file_path)
         && file_within_size_limit(&full_path)
         && let Ok(file_content) = std::fs::read_to_string(&full_path)
     {
@@ -20,4 +20,4 @@ pub use semantic::{
     hybrid_search, semantic_search,
 };
 pub use skeleton::skeletonize_content;
-pub use utils::{distance_to_score, embedding_to_bytes, format_project_header};
+pub use utils::{bytes_to_embedding, distance_to_score, embedding_to_bytes, format_project_header};
@@ -10,7 +10,8 @@ use crate::db::pool::DatabasePool;
 use crate::db::semantic_code_search_sync;
 use crate::embeddings::EmbeddingClient;
 use crate::fuzzy::FuzzyCache;
-use crate::utils::{safe_join, truncate, truncate_at_boundary};
+use crate::project_roots::resolve_file;
+use crate::utils::{truncate, truncate_at_boundary};
 use std::path::Path;
 use std::sync::Arc;
 use std::time::Duration;
@@ -213,7 +214,7 @@ fn rerank_results_with_intent(
             if cache.contains_key(&result.file_path) {
                 continue;
             }
-            let Some(full_path) = safe_join(Path::new(proj_path), &result.file_path) else {
+            let Some(full_path) = resolve_file(Path::new(proj_path), &result.file_path) else {
                 continue;
             };
             if let Ok(metadata) = std::fs::metadata(&full_path)
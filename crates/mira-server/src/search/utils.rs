@@ -9,6 +9,14 @@ pub fn embedding_to_bytes(embedding: &[f32]) -> Vec<u8> {
     embedding.iter().flat_map(|f| f.to_le_bytes()).collect()
 }
 
+/// Inverse of `embedding_to_bytes`; trailing bytes that don't form an f32 are ignored
+pub fn bytes_to_embedding(bytes: &[u8]) -> Vec<f32> {
+    bytes
+        .chunks_exact(4)
+        .map(|chunk| f32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+        .collect()
+}
+
 /// Format project context header for tool responses
 pub fn format_project_header(project: Option<&ProjectContext>) -> String {
     match project {
@@ -95,13 +103,7 @@ mod tests {
         let original = [0.5f32, -1.0, 2.5, 0.0];
         let bytes = embedding_to_bytes(&original);
 
-        // Convert back
-        let recovered: Vec<f32> = bytes
-            .chunks_exact(4)
-            .map(|chunk| f32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
-            .collect();
-
-        assert_eq!(recovered, original.to_vec());
+        assert_eq!(bytes_to_embedding(&bytes), original.to_vec());
     }
 
     #[test]
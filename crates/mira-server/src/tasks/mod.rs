@@ -3,6 +3,9 @@
 //
 // Reads task JSON files from ~/.claude/tasks/{list-id}/ directories.
 // This is a pure filesystem reader — no database dependency.
+// Markdown checklist sync lives in the todo_sync submodule.
+
+pub mod todo_sync;
 
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
@@ -0,0 +1,338 @@
+// crates/mira-server/src/tasks/todo_sync.rs
+// Markdown checklist files (TODO.md) kept in sync with Mira tasks
+//
+// Parsing and write-back live here; the mapping and conflict policy live in
+// db/todo_sync.rs.
+
+use anyhow::{Context, Result, bail};
+use std::path::{Component, Path, PathBuf};
+use std::sync::Arc;
+
+use crate::config::TodoSyncConfig;
+use crate::db::pool::DatabasePool;
+use crate::db::todo_sync::{TodoItem, TodoSyncReport, sync_todo_items_sync};
+
+const TASK_MARKER_PREFIX: &str = "<!-- mira:task=";
+const TASK_MARKER_SUFFIX: &str = "-->";
+
+/// Byte offset of the checkbox character (` `, `x` or `X`) in a list item
+/// line, or None if the line is not a GitHub-style task list item.
+fn checkbox_offset(line: &str) -> Option<usize> {
+    let indent = line.len() - line.trim_start().len();
+    let rest = &line[indent..];
+
+    let after_bullet = if let Some(r) = rest
+        .strip_prefix("- ")
+        .or_else(|| rest.strip_prefix("* "))
+        .or_else(|| rest.strip_prefix("+ "))
+    {
+        r
+    } else {
+        let digits = rest.bytes().take_while(u8::is_ascii_digit).count();
+        if digits == 0 {
+            return None;
+        }
+        rest[digits..]
+            .strip_prefix(". ")
+            .or_else(|| rest[digits..].strip_prefix(") "))?
+    };
+
+    let spaces = after_bullet.len() - after_bullet.trim_start_matches(' ').len();
+    let item = &after_bullet[spaces..];
+    let bytes = item.as_bytes();
+    if bytes.len() < 3 || bytes[0] != b'[' || bytes[2] != b']' || !b" xX".contains(&bytes[1]) {
+        return None;
+    }
+    if bytes.get(3).is_some_and(|b| !b.is_ascii_whitespace()) {
+        return None;
+    }
+    Some(line.len() - item.len() + 1)
+}
+
+/// Split `<!-- mira:task=N -->` out of item text
+fn strip_task_marker(text: &str) -> (String, Option<i64>) {
+    let Some(start) = text.find(TASK_MARKER_PREFIX) else {
+        return (text.trim().to_string(), None);
+    };
+    let after = &text[start + TASK_MARKER_PREFIX.len()..];
+    let Some(end) = after.find(TASK_MARKER_SUFFIX) else {
+        return (text.trim().to_string(), None);
+    };
+    let task_id = after[..end].trim().parse().ok();
+    let rest = format!(
+        "{} {}",
+        text[..start].trim_end(),
+        after[end + TASK_MARKER_SUFFIX.len()..].trim_start()
+    );
+    (rest.trim().to_string(), task_id)
+}
+
+/// Parse GitHub-style task list items (`- [ ]`, `* [x]`, `1. [ ]`).
+/// Items inside fenced code blocks and items without text are skipped.
+pub fn parse_checklist(content: &str) -> Vec<TodoItem> {
+    let mut items = Vec::new();
+    let mut fence: Option<&str> = None;
+
+    for (line_no, line) in content.lines().enumerate() {
+        let trimmed = line.trim_start();
+        if let Some(open) = fence {
+            if trimmed.starts_with(open) {
+                fence = None;
+            }
+            continue;
+        }
+        if trimmed.starts_with("```") {
+            fence = Some("```");
+            continue;
+        }
+        if trimmed.starts_with("~~~") {
+            fence = Some("~~~");
+            continue;
+        }
+
+        let Some(offset) = checkbox_offset(line) else {
+            continue;
+        };
+        let (text, task_id) = strip_task_marker(&line[offset + 2..]);
+        if text.is_empty() {
+            continue;
+        }
+        items.push(TodoItem {
+            line: line_no,
+            ordinal: items.len(),
+            text,
+            checked: line.as_bytes()[offset] != b' ',
+            task_id,
+        });
+    }
+    items
+}
+
+/// Set the checkbox on the given lines. Only the box character changes;
+/// every other byte, including line endings, is kept as is.
+pub fn apply_checks(content: &str, changes: &[(usize, bool)]) -> String {
+    let mut out = String::with_capacity(content.len());
+    for (line_no, line) in content.split_inclusive('\n').enumerate() {
+        let change = changes.iter().find(|(l, _)| *l == line_no);
+        match (change, checkbox_offset(line)) {
+            (Some((_, checked)), Some(offset)) => {
+                out.push_str(&line[..offset]);
+                out.push(if *checked { 'x' } else { ' ' });
+                out.push_str(&line[offset + 1..]);
+            }
+            _ => out.push_str(line),
+        }
+    }
+    out
+}
+
+/// Replace a file's contents without leaving a partial write behind:
+/// write a sibling temp file, keep the original permissions, then rename.
+pub fn write_atomic(path: &Path, content: &str) -> Result<()> {
+    let dir = path.parent().unwrap_or(Path::new("."));
+    let name = path
+        .file_name()
+        .with_context(|| format!("Not a file path: {}", path.display()))?;
+    let tmp = dir.join(format!(".{}.mira-tmp", name.to_string_lossy()));
+
+    std::fs::write(&tmp, content).with_context(|| format!("Failed to write {}", tmp.display()))?;
+    if let Ok(meta) = std::fs::metadata(path) {
+        let _ = std::fs::set_permissions(&tmp, meta.permissions());
+    }
+    std::fs::rename(&tmp, path).inspect_err(|_| {
+        let _ = std::fs::remove_file(&tmp);
+    })?;
+    Ok(())
+}
+
+/// Resolve a configured checklist path, refusing anything outside the project
+pub fn resolve_todo_path(project_root: &Path, file: &str) -> Result<PathBuf> {
+    let rel = Path::new(file);
+    if rel.is_absolute()
+        || rel
+            .components()
+            .any(|c| !matches!(c, Component::Normal(_) | Component::CurDir))
+    {
+        bail!(
+            "todo_sync file must be relative to the project root: {}",
+            file
+        );
+    }
+    Ok(project_root.join(rel))
+}
+
+/// Sync one checklist file. Missing files are skipped (None).
+///
+/// With `write_back`, boxes whose task status changed in Mira are rewritten,
+/// unless the file was edited while the sync ran; the next sync retries.
+pub async fn sync_todo_file(
+    pool: &Arc<DatabasePool>,
+    project_id: i64,
+    project_root: &Path,
+    file: &str,
+    write_back: bool,
+) -> Result<Option<TodoSyncReport>> {
+    let path = resolve_todo_path(project_root, file)?;
+    let content = match tokio::fs::read_to_string(&path).await {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e).with_context(|| format!("Failed to read {}", path.display())),
+    };
+
+    let items = parse_checklist(&content);
+    let file_key = file.trim_start_matches("./").to_string();
+    let report = pool
+        .interact(move |conn| sync_todo_items_sync(conn, project_id, &file_key, &items))
+        .await?;
+
+    if write_back && !report.write_back.is_empty() {
+        let current = tokio::fs::read_to_string(&path).await?;
+        if current == content {
+            let updated = apply_checks(&content, &report.write_back);
+            tokio::task::spawn_blocking(move || write_atomic(&path, &updated)).await??;
+        } else {
+            tracing::debug!("{} changed during sync, deferring write-back", file);
+        }
+    }
+
+    Ok(Some(report))
+}
+
+/// Sync every checklist file configured for a project
+pub async fn sync_todo_files(
+    pool: &Arc<DatabasePool>,
+    project_id: i64,
+    project_root: &Path,
+    config: &TodoSyncConfig,
+) -> Result<Vec<(String, Option<TodoSyncReport>)>> {
+    let mut results = Vec::with_capacity(config.files.len());
+    for file in &config.files {
+        let report =
+            sync_todo_file(pool, project_id, project_root, file, config.write_back).await?;
+        results.push((file.clone(), report));
+    }
+    Ok(results)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::get_task_by_id_sync;
+    use crate::db::test_support::setup_test_pool_with_project;
+    use crate::db::todo_sync::get_linked_task_ids_sync;
+
+    const FIXTURE: &str = "# TODO\r\n\
+\r\n\
+- [ ] Write the parser\r\n\
+- [x] Pick a name <!-- mira:task=999 -->\r\n\
+  * [ ] Nested item\r\n\
+1. [ ] Numbered item\r\n\
+\r\n\
+```md\r\n\
+- [ ] Not a task, just an example\r\n\
+```\r\n";
+
+    #[test]
+    fn test_parse_checklist_handles_markers_nesting_and_fences() {
+        let items = parse_checklist(FIXTURE);
+        let texts: Vec<&str> = items.iter().map(|i| i.text.as_str()).collect();
+        assert_eq!(
+            texts,
+            vec![
+                "Write the parser",
+                "Pick a name",
+                "Nested item",
+                "Numbered item"
+            ]
+        );
+        assert!(items[1].checked);
+        assert_eq!(items[1].task_id, Some(999));
+        assert_eq!(items[3].line, 5);
+        assert_eq!(items[3].ordinal, 3);
+    }
+
+    #[test]
+    fn test_apply_checks_only_touches_the_box() {
+        let updated = apply_checks(FIXTURE, &[(2, true), (3, false)]);
+        let expected = FIXTURE
+            .replace("- [ ] Write the parser", "- [x] Write the parser")
+            .replace("- [x] Pick a name", "- [ ] Pick a name");
+        assert_eq!(updated, expected);
+    }
+
+    #[test]
+    fn test_resolve_todo_path_rejects_escapes() {
+        let root = Path::new("/repo");
+        assert!(resolve_todo_path(root, "docs/TODO.md").is_ok());
+        assert!(resolve_todo_path(root, "../TODO.md").is_err());
+        assert!(resolve_todo_path(root, "/etc/TODO.md").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_round_trip_with_edits_on_both_sides() {
+        let (pool, project_id) = setup_test_pool_with_project().await;
+        let dir = tempfile::tempdir().unwrap();
+        let todo = dir.path().join("TODO.md");
+        std::fs::write(
+            &todo,
+            "# TODO\n\n- [ ] Write parser\n- [ ] Add docs\n- [ ] Ship it\n",
+        )
+        .unwrap();
+
+        let report = sync_todo_file(&pool, project_id, dir.path(), "TODO.md", true)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(report.created, 3);
+        let ids = pool
+            .interact(move |conn| get_linked_task_ids_sync(conn, project_id, "TODO.md"))
+            .await
+            .unwrap();
+
+        // File side: check one box and reword another
+        std::fs::write(
+            &todo,
+            "# TODO\n\n- [x] Write parser\n- [ ] Add usage docs\n- [ ] Ship it\n",
+        )
+        .unwrap();
+        // Mira side: complete the last task
+        let ship = ids[2];
+        pool.interact(move |conn| {
+            crate::db::update_task_sync(conn, ship, None, Some("completed"), None)
+                .map_err(Into::into)
+        })
+        .await
+        .unwrap();
+
+        let report = sync_todo_file(&pool, project_id, dir.path(), "TODO.md", true)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(report.completed, 1);
+        assert_eq!(report.retitled, 1);
+        assert_eq!(report.write_back, vec![(4, true)]);
+        assert_eq!(
+            std::fs::read_to_string(&todo).unwrap(),
+            "# TODO\n\n- [x] Write parser\n- [ ] Add usage docs\n- [x] Ship it\n"
+        );
+
+        let tasks = pool
+            .interact(move |conn| {
+                ids.iter()
+                    .map(|id| Ok(get_task_by_id_sync(conn, *id)?.unwrap()))
+                    .collect::<Result<Vec<_>>>()
+            })
+            .await
+            .unwrap();
+        assert_eq!(tasks[0].status, "completed");
+        assert_eq!(tasks[1].title, "Add usage docs");
+        assert_eq!(tasks[2].status, "completed");
+
+        // A third pass finds nothing left to do
+        let report = sync_todo_file(&pool, project_id, dir.path(), "TODO.md", true)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(report.is_noop());
+    }
+}
@@ -0,0 +1,825 @@
+// crates/mira-server/src/tools/core/code/impact.rs
+// Blast-radius report for renaming or changing a symbol
+
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+use rusqlite::{Connection, params};
+
+use crate::db::suggestions::is_test_path;
+use crate::error::MiraError;
+use crate::git::CommitWithFiles;
+use crate::mcp::responses::{
+    CodeData, CodeOutput, ImpactCoChange, ImpactData, ImpactDefinition, ImpactFile,
+    ImpactHardReference, ImpactSoftReference, Json,
+};
+use crate::project_files::FileWalker;
+use crate::tools::core::{ToolContext, get_project_info};
+
+/// Caller levels followed for compile-breaking references
+const MAX_CALLER_DEPTH: u32 = 2;
+/// Call edges fetched per target per level
+const CALL_EDGES_PER_TARGET: usize = 200;
+/// Commits scanned for co-change partners
+const CO_CHANGE_COMMITS: usize = 500;
+/// Text files larger than this are not scanned for soft references
+const MAX_SCAN_BYTES: u64 = 1_000_000;
+/// Stop scanning after this many text matches
+const MAX_SOFT_MATCHES: usize = 500;
+/// Excerpt length kept per soft reference
+const EXCERPT_CHARS: usize = 120;
+
+/// Where a symbol is defined
+#[derive(Debug, Clone, PartialEq)]
+pub struct Definition {
+    pub file_path: String,
+    pub line: Option<i64>,
+}
+
+/// A caller that stops compiling if the symbol is renamed
+#[derive(Debug, Clone, PartialEq)]
+pub struct HardReference {
+    pub caller: String,
+    pub file_path: String,
+    pub line: Option<i64>,
+    /// 1 = calls the symbol directly, 2 = calls a direct caller
+    pub depth: u32,
+}
+
+/// A textual mention the compiler won't catch (docs, tests, config, strings)
+#[derive(Debug, Clone, PartialEq)]
+pub struct SoftReference {
+    pub file_path: String,
+    pub line: usize,
+    /// `doc`, `test`, `config`, or `code`
+    pub kind: &'static str,
+    pub excerpt: String,
+}
+
+/// Split `Type::method` / `Type.method` the way the indexer builds qualified
+/// names (`::` for Rust, `.` for Go/Python/TypeScript).
+fn split_qualified(symbol: &str) -> (Option<&str>, &str) {
+    match symbol.rsplit_once("::").or_else(|| symbol.rsplit_once('.')) {
+        Some((parent, name)) if !parent.is_empty() && !name.is_empty() => (Some(parent), name),
+        _ => (None, symbol),
+    }
+}
+
+/// Does a call-graph callee refer to `parent::name`?
+///
+/// The indexer stores call targets as written: `helper`, `method` for
+/// `x.method()`, `Type::new` for scoped calls. A receiver call can't be typed
+/// without type information, so a bare callee always matches; a scoped callee
+/// must end in `name` and, when the symbol is qualified, name the same parent
+/// (or `Self`).
+fn callee_matches(callee: &str, parent: Option<&str>, name: &str) -> bool {
+    if callee == name {
+        return true;
+    }
+    let Some((prefix, last)) = callee.rsplit_once("::").or_else(|| callee.rsplit_once('.')) else {
+        return false;
+    };
+    if last != name {
+        return false;
+    }
+    match parent {
+        Some(parent) => {
+            prefix == parent
+                || prefix == "Self"
+                || prefix.ends_with(&format!("::{}", parent))
+                || parent.ends_with(&format!("::{}", prefix))
+        }
+        None => true,
+    }
+}
+
+/// A function that calls the target: (caller, caller's parent type, file, line)
+type Caller = (String, Option<String>, String, Option<i64>);
+
+/// Functions that call `parent::name`, up to [`CALL_EDGES_PER_TARGET`].
+///
+/// The caller's parent is the innermost type symbol (class, struct, trait,
+/// ...) whose range encloses it in the same file, when there is one.
+fn callers_of(
+    conn: &Connection,
+    project_id: Option<i64>,
+    parent: Option<&str>,
+    name: &str,
+) -> rusqlite::Result<Vec<Caller>> {
+    // LIKE narrows to callees ending in the name; `_` in names over-matches,
+    // which callee_matches filters out before the limit applies.
+    let mut stmt = conn.prepare_cached(
+        "SELECT cg.callee_name, cs.name, cs.file_path, cs.start_line,
+                (SELECT p.name FROM code_symbols p
+                 WHERE p.project_id = cs.project_id AND p.file_path = cs.file_path
+                   AND p.id != cs.id
+                   AND p.symbol_type IN ('class', 'struct', 'enum', 'trait', 'interface', 'impl')
+                   AND p.start_line <= cs.start_line AND p.end_line >= cs.end_line
+                 ORDER BY p.start_line DESC LIMIT 1)
+         FROM call_graph cg
+         JOIN code_symbols cs ON cg.caller_id = cs.id
+         WHERE (cg.callee_name = ?1 OR cg.callee_name LIKE '%' || ?1)
+           AND (?2 IS NULL OR cs.project_id = ?2)
+         ORDER BY cs.file_path, cs.start_line",
+    )?;
+    let rows = stmt.query_map(params![name, project_id], |row| {
+        Ok((
+            row.get::<_, String>(0)?,
+            row.get::<_, String>(1)?,
+            row.get::<_, String>(2)?,
+            row.get::<_, Option<i64>>(3)?,
+            row.get::<_, Option<String>>(4)?,
+        ))
+    })?;
+
+    let mut out = Vec::new();
+    for row in rows {
+        let (callee, caller, file, line, caller_parent) = row?;
+        if !callee_matches(&callee, parent, name) {
+            continue;
+        }
+        out.push((caller, caller_parent, file, line));
+        if out.len() >= CALL_EDGES_PER_TARGET {
+            break;
+        }
+    }
+    Ok(out)
+}
+
+/// Keep the definitions that belong to `parent`: those inside its range
+/// (a class body), else those in a file that declares it (a Rust impl next
+/// to its struct). When neither narrows anything, keep them all.
+fn filter_by_parent(
+    conn: &Connection,
+    project_id: Option<i64>,
+    parent: &str,
+    definitions: Vec<(Definition, Option<i64>)>,
+) -> rusqlite::Result<Vec<(Definition, Option<i64>)>> {
+    let parent = parent.rsplit("::").next().unwrap_or(parent);
+    let mut stmt = conn.prepare(
+        "SELECT file_path, start_line, end_line FROM code_symbols
+         WHERE name = ?1 AND (?2 IS NULL OR project_id = ?2)",
+    )?;
+    let ranges: Vec<(String, Option<i64>, Option<i64>)> = stmt
+        .query_map(params![parent, project_id], |row| {
+            Ok((row.get(0)?, row.get(1)?, row.get(2)?))
+        })?
+        .collect::<rusqlite::Result<_>>()?;
+
+    let encloses = |(def, end): &(Definition, Option<i64>)| {
+        ranges.iter().any(|(file, start, stop)| {
+            *file == def.file_path
+                && matches!(
+                    (*start, *stop, def.line, *end),
+                    (Some(s), Some(e), Some(l), Some(le)) if s <= l && e >= le
+                )
+        })
+    };
+    let enclosed: Vec<_> = definitions
+        .iter()
+        .filter(|d| encloses(d))
+        .cloned()
+        .collect();
+    if !enclosed.is_empty() {
+        return Ok(enclosed);
+    }
+    let same_file: Vec<_> = definitions
+        .iter()
+        .filter(|(def, _)| ranges.iter().any(|(file, _, _)| *file == def.file_path))
+        .cloned()
+        .collect();
+    if !same_file.is_empty() {
+        return Ok(same_file);
+    }
+    Ok(definitions)
+}
+
+/// Definitions of the symbol plus its direct and transitive callers.
+///
+/// `file` narrows definitions to paths ending in it (to pick one of several
+/// same-named symbols); callers are still found by name.
+pub fn collect_hard_references(
+    conn: &Connection,
+    project_id: Option<i64>,
+    symbol: &str,
+    file: Option<&str>,
+) -> rusqlite::Result<(Vec<Definition>, Vec<HardReference>)> {
+    let (parent, name) = split_qualified(symbol);
+
+    let mut stmt = conn.prepare(
+        "SELECT file_path, start_line, end_line FROM code_symbols
+         WHERE name = ?1 AND (?2 IS NULL OR project_id = ?2)
+         ORDER BY file_path, start_line",
+    )?;
+    let mut definitions: Vec<(Definition, Option<i64>)> = stmt
+        .query_map(params![name, project_id], |row| {
+            Ok((
+                Definition {
+                    file_path: row.get(0)?,
+                    line: row.get(1)?,
+                },
+                row.get(2)?,
+            ))
+        })?
+        .collect::<rusqlite::Result<Vec<_>>>()?
+        .into_iter()
+        .filter(|(d, _)| file.is_none_or(|f| d.file_path.ends_with(f)))
+        .collect();
+    if let Some(parent) = parent {
+        definitions = filter_by_parent(conn, project_id, parent, definitions)?;
+    }
+    let definitions: Vec<Definition> = definitions.into_iter().map(|(d, _)| d).collect();
+
+    let mut seen: HashSet<(String, String)> = HashSet::new();
+    let mut hard = Vec::new();
+    let mut targets: Vec<(Option<String>, String)> =
+        vec![(parent.map(str::to_string), name.to_string())];
+
+    for depth in 1..=MAX_CALLER_DEPTH {
+        let mut next = Vec::new();
+        for (target_parent, target) in &targets {
+            for (caller, caller_parent, file_path, line) in
+                callers_of(conn, project_id, target_parent.as_deref(), target)?
+            {
+                // Recursion in the symbol itself isn't a reference
+                if caller == name && definitions.iter().any(|d| d.file_path == file_path) {
+                    continue;
+                }
+                if !seen.insert((caller.clone(), file_path.clone())) {
+                    continue;
+                }
+                next.push((caller_parent, caller.clone()));
+                hard.push(HardReference {
+                    caller,
+                    file_path,
+                    line,
+                    depth,
+                });
+            }
+        }
+        if next.is_empty() {
+            break;
+        }
+        targets = next;
+    }
+
+    Ok((definitions, hard))
+}
+
+/// Classify a soft reference by where it lives
+fn reference_kind(path: &str) -> &'static str {
+    if is_test_path(path) {
+        return "test";
+    }
+    let ext = path.rsplit_once('.').map(|(_, e)| e.to_lowercase());
+    match ext.as_deref() {
+        Some("md" | "mdx" | "rst" | "txt" | "adoc") => "doc",
+        Some("toml" | "json" | "yaml" | "yml" | "ini" | "cfg" | "env") => "config",
+        _ => "code",
+    }
+}
+
+/// Whole-word mentions of the symbol in project text files, skipping files
+/// in `exclude` (definition and call-graph files are already covered). A
+/// qualified symbol is matched in its qualified form (`Config::load` or
+/// `Config.load`), since its bare name is usually shared with other types.
+///
+/// Honors .gitignore and .miraignore; large and non-UTF-8 files are skipped.
+pub fn scan_soft_references(
+    root: &Path,
+    symbol: &str,
+    exclude: &HashSet<String>,
+) -> Vec<SoftReference> {
+    let (parent, name) = split_qualified(symbol);
+    let pattern = match parent {
+        Some(parent) => {
+            let parent = parent.rsplit("::").next().unwrap_or(parent);
+            format!(
+                r"\b{}(?:::|\.){}\b",
+                regex::escape(parent),
+                regex::escape(name)
+            )
+        }
+        None => format!(r"\b{}\b", regex::escape(name)),
+    };
+    let Ok(pattern) = regex::Regex::new(&pattern) else {
+        return Vec::new();
+    };
+
+    let mut out = Vec::new();
+    let walker = FileWalker::new(root);
+    'files: for rel in walker.walk_relative().filter_map(Result::ok) {
+        if exclude.contains(&rel) {
+            continue;
+        }
+        let path = root.join(&rel);
+        if !std::fs::metadata(&path).is_ok_and(|m| m.len() <= MAX_SCAN_BYTES) {
+            continue;
+        }
+        let Ok(text) = std::fs::read_to_string(&path) else {
+            continue;
+        };
+        for (idx, line) in text.lines().enumerate() {
+            if !pattern.is_match(line) {
+                continue;
+            }
+            out.push(SoftReference {
+                kind: reference_kind(&rel),
+                file_path: rel.clone(),
+                line: idx + 1,
+                excerpt: line.trim().chars().take(EXCERPT_CHARS).collect(),
+            });
+            if out.len() >= MAX_SOFT_MATCHES {
+                break 'files;
+            }
+        }
+    }
+    out.sort_by(|a, b| a.file_path.cmp(&b.file_path).then(a.line.cmp(&b.line)));
+    out
+}
+
+/// Files that changed in the same commits as any of `files`, most frequent first
+pub fn co_change_partners(commits: &[CommitWithFiles], files: &[String]) -> Vec<(String, usize)> {
+    let targets: HashSet<&str> = files.iter().map(String::as_str).collect();
+    let mut counts: HashMap<&str, usize> = HashMap::new();
+    for commit in commits {
+        if !commit.files.iter().any(|f| targets.contains(f.as_str())) {
+            continue;
+        }
+        for file in &commit.files {
+            if !targets.contains(file.as_str()) {
+                *counts.entry(file.as_str()).or_insert(0) += 1;
+            }
+        }
+    }
+    let mut partners: Vec<(String, usize)> = counts
+        .into_iter()
+        .map(|(f, n)| (f.to_string(), n))
+        .collect();
+    partners.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    partners
+}
+
+/// Combine the pieces into one report. Every list is capped at `limit`;
+/// totals count everything found.
+pub fn assemble_impact(
+    symbol: &str,
+    definitions: Vec<Definition>,
+    hard: Vec<HardReference>,
+    soft: Vec<SoftReference>,
+    co_change: Vec<(String, usize)>,
+    limit: usize,
+) -> ImpactData {
+    // Per-file score: definitions and direct callers break first, then
+    // transitive callers, text mentions, and habitual co-change partners.
+    let mut files: HashMap<String, (u32, Vec<String>)> = HashMap::new();
+    let mut bump = |file: &str, score: u32, reason: String| {
+        let entry = files.entry(file.to_string()).or_default();
+        entry.0 += score;
+        entry.1.push(reason);
+    };
+    for def in &definitions {
+        bump(&def.file_path, 5, "defines the symbol".to_string());
+    }
+    let mut by_file: HashMap<(&str, u32), usize> = HashMap::new();
+    for h in &hard {
+        *by_file.entry((h.file_path.as_str(), h.depth)).or_insert(0) += 1;
+    }
+    for ((file, depth), n) in by_file {
+        let (weight, label) = if depth == 1 {
+            (3, "direct")
+        } else {
+            (1, "transitive")
+        };
+        bump(
+            file,
+            weight * n as u32,
+            format!("{} {} caller(s)", n, label),
+        );
+    }
+    let mut soft_by_file: HashMap<&str, usize> = HashMap::new();
+    for s in &soft {
+        *soft_by_file.entry(s.file_path.as_str()).or_insert(0) += 1;
+    }
+    for (file, n) in soft_by_file {
+        bump(file, n as u32, format!("{} text mention(s)", n));
+    }
+    for (file, n) in co_change.iter().take(limit) {
+        bump(
+            file,
+            (*n).min(3) as u32,
+            format!("co-changed in {} commit(s)", n),
+        );
+    }
+
+    let mut ranked: Vec<ImpactFile> = files
+        .into_iter()
+        .map(|(file_path, (score, mut reasons))| {
+            reasons.sort();
+            ImpactFile {
+                file_path,
+                score,
+                reasons,
+            }
+        })
+        .collect();
+    ranked.sort_by(|a, b| {
+        b.score
+            .cmp(&a.score)
+            .then_with(|| a.file_path.cmp(&b.file_path))
+    });
+
+    let direct_callers = hard.iter().filter(|h| h.depth == 1).count();
+    ImpactData {
+        symbol: symbol.to_string(),
+        definitions: definitions
+            .into_iter()
+            .map(|d| ImpactDefinition {
+                file_path: d.file_path,
+                line: d.line.map(|l| l as usize),
+            })
+            .collect(),
+        hard_total: hard.len(),
+        direct_callers,
+        soft_total: soft.len(),
+        hard_references: hard
+            .into_iter()
+            .take(limit)
+            .map(|h| ImpactHardReference {
+                caller: h.caller,
+                file_path: h.file_path,
+                line: h.line.map(|l| l as usize),
+                depth: h.depth,
+            })
+            .collect(),
+        soft_references: soft
+            .into_iter()
+            .take(limit)
+            .map(|s| ImpactSoftReference {
+                file_path: s.file_path,
+                line: s.line,
+                kind: s.kind.to_string(),
+                excerpt: s.excerpt,
+            })
+            .collect(),
+        co_change: co_change
+            .into_iter()
+            .take(limit)
+            .map(|(file_path, commits)| ImpactCoChange { file_path, commits })
+            .collect(),
+        files: ranked.into_iter().take(limit).collect(),
+    }
+}
+
+/// Render the report as text
+fn format_impact(data: &ImpactData) -> String {
+    let mut out = format!(
+        "Impact of `{}`: {} definition(s), {} compile-breaking reference(s) ({} direct), {} soft reference(s), {} co-change partner(s)\n",
+        data.symbol,
+        data.definitions.len(),
+        data.hard_total,
+        data.direct_callers,
+        data.soft_total,
+        data.co_change.len()
+    );
+
+    let loc = |file: &str, line: Option<usize>| match line {
+        Some(l) => format!("{}:{}", file, l),
+        None => file.to_string(),
+    };
+
+    if !data.definitions.is_empty() {
+        out.push_str("\nDefinitions:\n");
+        for d in &data.definitions {
+            out.push_str(&format!("  {}\n", loc(&d.file_path, d.line)));
+        }
+    }
+    if !data.hard_references.is_empty() {
+        out.push_str("\nCompile-breaking (call graph):\n");
+        for h in &data.hard_references {
+            out.push_str(&format!(
+                "  [depth {}] {}  {}\n",
+                h.depth,
+                h.caller,
+                loc(&h.file_path, h.line)
+            ));
+        }
+    }
+    if !data.soft_references.is_empty() {
+        out.push_str("\nSoft references (text matches the compiler won't flag):\n");
+        for s in &data.soft_references {
+            out.push_str(&format!(
+                "  [{}] {}:{}  {}\n",
+                s.kind, s.file_path, s.line, s.excerpt
+            ));
+        }
+    }
+    if !data.co_change.is_empty() {
+        out.push_str("\nUsually changed together with the definition:\n");
+        for c in &data.co_change {
+            out.push_str(&format!("  {} ({} commits)\n", c.file_path, c.commits));
+        }
+    }
+    if !data.files.is_empty() {
+        out.push_str("\nFiles by priority:\n");
+        for f in &data.files {
+            out.push_str(&format!(
+                "  {} (score {}): {}\n",
+                f.file_path,
+                f.score,
+                f.reasons.join(", ")
+            ));
+        }
+    }
+    out
+}
+
+/// Blast-radius report for a symbol: call-graph callers to depth 2, text
+/// mentions in docs/tests/config, and files that usually change with it.
+pub async fn symbol_impact<C: ToolContext>(
+    ctx: &C,
+    symbol: String,
+    file_path: Option<String>,
+    limit: Option<i64>,
+) -> Result<Json<CodeOutput>, MiraError> {
+    let symbol = symbol.trim().to_string();
+    if symbol.is_empty() {
+        return Err(MiraError::InvalidInput(
+            "function_name is required for code(action=impact)".to_string(),
+        ));
+    }
+    let limit = limit.unwrap_or(20).clamp(1, 100) as usize;
+    let pi = get_project_info(ctx).await;
+
+    let (definitions, hard) = {
+        let symbol = symbol.clone();
+        let project_id = pi.id;
+        ctx.code_pool()
+            .run(move |conn| {
+                collect_hard_references(conn, project_id, &symbol, file_path.as_deref())
+            })
+            .await?
+    };
+
+    let (soft, co_change) = match pi.path.clone() {
+        Some(root) => {
+            let symbol = symbol.clone();
+            let def_files: Vec<String> = definitions.iter().map(|d| d.file_path.clone()).collect();
+            let exclude: HashSet<String> = def_files
+                .iter()
+                .cloned()
+                .chain(hard.iter().map(|h| h.file_path.clone()))
+                .collect();
+            tokio::task::spawn_blocking(move || {
+                let soft = scan_soft_references(Path::new(&root), &symbol, &exclude);
+                let commits = crate::git::get_commits_with_files(&root, CO_CHANGE_COMMITS);
+                (soft, co_change_partners(&commits, &def_files))
+            })
+            .await?
+        }
+        None => (Vec::new(), Vec::new()),
+    };
+
+    let data = assemble_impact(&symbol, definitions, hard, soft, co_change, limit);
+    Ok(Json(CodeOutput {
+        action: "impact".into(),
+        message: format!("{}{}", pi.header, format_impact(&data)),
+        data: Some(CodeData::Impact(data)),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::pool::DatabasePool;
+    use crate::db::test_support::{seed_call_edge, seed_symbol};
+
+    fn write(root: &Path, rel: &str, contents: &str) {
+        let path = root.join(rel);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, contents).unwrap();
+    }
+
+    fn commit(files: &[&str]) -> CommitWithFiles {
+        CommitWithFiles {
+            hash: "0".repeat(40),
+            timestamp: 0,
+            message: String::new(),
+            files: files.iter().map(|f| f.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_callee_matches_qualified_names() {
+        assert!(callee_matches("load", Some("Config"), "load"));
+        assert!(callee_matches("Config::load", Some("Config"), "load"));
+        assert!(callee_matches("Self::load", Some("Config"), "load"));
+        assert!(callee_matches(
+            "crate::config::Config::load",
+            Some("Config"),
+            "load"
+        ));
+        assert!(!callee_matches("Other::load", Some("Config"), "load"));
+        assert!(!callee_matches("Config::loader", Some("Config"), "load"));
+        assert!(callee_matches("Other::load", None, "load"));
+        assert_eq!(split_qualified("Config::load"), (Some("Config"), "load"));
+        assert_eq!(split_qualified("Client.send"), (Some("Client"), "send"));
+        assert_eq!(split_qualified("load"), (None, "load"));
+    }
+
+    #[tokio::test]
+    async fn test_qualified_symbol_narrows_definitions_and_callers() {
+        let pool = DatabasePool::open_code_db_in_memory().await.unwrap();
+        let (definitions, hard) = pool
+            .interact(|conn| {
+                let pid = 1;
+                seed_symbol(conn, pid, "Config", "src/config.py", "class", 1, 20);
+                seed_symbol(conn, pid, "load", "src/config.py", "function", 3, 5);
+                let refresh = seed_symbol(conn, pid, "refresh", "src/config.py", "function", 7, 9);
+                seed_symbol(conn, pid, "Other", "src/other.py", "class", 1, 20);
+                seed_symbol(conn, pid, "load", "src/other.py", "function", 3, 5);
+                let other_refresh =
+                    seed_symbol(conn, pid, "refresh", "src/other.py", "function", 7, 9);
+                let boot = seed_symbol(conn, pid, "boot", "src/main.py", "function", 1, 5);
+                let other_boot =
+                    seed_symbol(conn, pid, "other_boot", "src/main.py", "function", 7, 9);
+                seed_call_edge(conn, refresh, "Config.load");
+                seed_call_edge(conn, other_refresh, "Other.load");
+                seed_call_edge(conn, boot, "Config.refresh");
+                seed_call_edge(conn, other_boot, "Other.refresh");
+                Ok(collect_hard_references(
+                    conn,
+                    Some(pid),
+                    "Config.load",
+                    None,
+                )?)
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(
+            definitions,
+            vec![Definition {
+                file_path: "src/config.py".into(),
+                line: Some(3)
+            }]
+        );
+        // `refresh` is inside Config, so depth 2 only follows Config.refresh
+        let callers: Vec<(&str, u32)> = hard.iter().map(|h| (h.caller.as_str(), h.depth)).collect();
+        assert_eq!(callers, vec![("refresh", 1), ("boot", 2)]);
+
+        let dir = tempfile::tempdir().unwrap();
+        write(
+            dir.path(),
+            "docs/config.md",
+            "Call Config.load first.\nOther.load is unrelated.\nSo is a bare load.\n",
+        );
+        let soft = scan_soft_references(dir.path(), "Config.load", &HashSet::new());
+        let lines: Vec<usize> = soft.iter().map(|s| s.line).collect();
+        assert_eq!(lines, vec![1]);
+    }
+
+    #[test]
+    fn test_soft_references_sorted_when_capped() {
+        let dir = tempfile::tempdir().unwrap();
+        let body = "parse_config\n".repeat(MAX_SOFT_MATCHES / 2 + 10);
+        for name in ["b.md", "a.md", "c.md"] {
+            write(dir.path(), name, &body);
+        }
+        let soft = scan_soft_references(dir.path(), "parse_config", &HashSet::new());
+        assert_eq!(soft.len(), MAX_SOFT_MATCHES);
+        assert!(
+            soft.windows(2)
+                .all(|w| (&w[0].file_path, w[0].line) <= (&w[1].file_path, w[1].line))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_impact_over_indexed_fixture() {
+        let pool = DatabasePool::open_code_db_in_memory().await.unwrap();
+        let (definitions, hard) = pool
+            .interact(|conn| {
+                let pid = 1;
+                seed_symbol(
+                    conn,
+                    pid,
+                    "parse_config",
+                    "src/config.rs",
+                    "function",
+                    10,
+                    30,
+                );
+                let run = seed_symbol(conn, pid, "run", "src/app.rs", "function", 5, 20);
+                let main = seed_symbol(conn, pid, "main", "src/main.rs", "function", 1, 8);
+                let test = seed_symbol(
+                    conn,
+                    pid,
+                    "test_parse_config",
+                    "tests/config_test.rs",
+                    "function",
+                    3,
+                    9,
+                );
+                // Only shares a prefix with the symbol
+                let other = seed_symbol(conn, pid, "other", "src/other.rs", "function", 1, 4);
+                seed_call_edge(conn, run, "parse_config");
+                seed_call_edge(conn, main, "run");
+                seed_call_edge(conn, test, "config::parse_config");
+                seed_call_edge(conn, other, "parse_config_v2");
+                Ok(collect_hard_references(
+                    conn,
+                    Some(pid),
+                    "parse_config",
+                    None,
+                )?)
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(
+            definitions,
+            vec![Definition {
+                file_path: "src/config.rs".into(),
+                line: Some(10)
+            }]
+        );
+        let callers: Vec<(&str, u32)> = hard.iter().map(|h| (h.caller.as_str(), h.depth)).collect();
+        assert_eq!(
+            callers,
+            vec![("run", 1), ("test_parse_config", 1), ("main", 2)]
+        );
+
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        write(root, "src/config.rs", "pub fn parse_config() {}\n");
+        write(root, "src/app.rs", "fn run() { parse_config(); }\n");
+        write(
+            root,
+            "docs/guide.md",
+            "# Guide\n\nCall `parse_config` before starting.\nparse_configuration is unrelated.\n",
+        );
+        write(root, "mira.toml", "loader = \"parse_config\"\n");
+        let exclude: HashSet<String> = ["src/config.rs", "src/app.rs"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let soft = scan_soft_references(root, "parse_config", &exclude);
+        let found: Vec<(&str, usize, &str)> = soft
+            .iter()
+            .map(|s| (s.file_path.as_str(), s.line, s.kind))
+            .collect();
+        assert_eq!(
+            found,
+            vec![("docs/guide.md", 3, "doc"), ("mira.toml", 1, "config")]
+        );
+
+        let co_change = co_change_partners(
+            &[
+                commit(&["src/config.rs", "docs/guide.md"]),
+                commit(&["src/config.rs", "docs/guide.md", "src/app.rs"]),
+                commit(&["src/unrelated.rs"]),
+            ],
+            &["src/config.rs".to_string()],
+        );
+        assert_eq!(
+            co_change,
+            vec![
+                ("docs/guide.md".to_string(), 2),
+                ("src/app.rs".to_string(), 1)
+            ]
+        );
+
+        // Capped lists keep the full totals
+        let capped = assemble_impact(
+            "parse_config",
+            definitions.clone(),
+            hard.clone(),
+            soft.clone(),
+            co_change.clone(),
+            1,
+        );
+        assert_eq!(capped.hard_references.len(), 1);
+        assert_eq!(capped.hard_total, 3);
+        assert_eq!(capped.files.len(), 1);
+
+        let data = assemble_impact("parse_config", definitions, hard, soft, co_change, 20);
+        assert_eq!(data.hard_total, 3);
+        assert_eq!(data.direct_callers, 2);
+        assert_eq!(data.soft_total, 2);
+        assert_eq!(data.files[0].file_path, "src/config.rs");
+        assert!(
+            data.files
+                .iter()
+                .any(|f| f.file_path == "docs/guide.md" && f.score == 3),
+            "{:?}",
+            data.files
+        );
+        let text = format_impact(&data);
+        assert!(
+            text.contains("3 compile-breaking reference(s) (2 direct)"),
+            "{}",
+            text
+        );
+        assert!(text.contains("[doc] docs/guide.md:3"), "{}", text);
+    }
+}
@@ -9,10 +9,12 @@ use crate::indexer;
 use crate::mcp::requests::IndexAction;
 use crate::mcp::responses::Json;
 use crate::mcp::responses::{
-    IndexCompactData, IndexData, IndexHealthData, IndexOutput, IndexProjectData, IndexStatusData,
-    IndexSummarizeData,
+    DeadLetterItem, DeadLettersData, IndexCompactData, IndexData, IndexFreshnessData,
+    IndexHealthData, IndexOutput, IndexProjectData, IndexStatusData, IndexSummarizeData,
+    RetryDeadLetterData,
 };
 use crate::tools::core::ToolContext;
+use crate::utils::truncate;
 
 /// Index project
 pub async fn index<C: ToolContext>(
@@ -20,6 +22,7 @@ pub async fn index<C: ToolContext>(
     action: IndexAction,
     path: Option<String>,
     skip_embed: bool,
+    force: bool,
 ) -> Result<Json<IndexOutput>, MiraError> {
     match action {
         IndexAction::Project | IndexAction::File => {
@@ -43,7 +46,7 @@ pub async fn index<C: ToolContext>(
 
                 let path = Path::new(&project_path);
                 if !path.exists() {
-                    return Err(MiraError::InvalidInput(format!(
+                    return Err(MiraError::NotFound(format!(
                         "Path not found: {}. Ensure project_path is an absolute path to an existing directory.",
                         project_path
                     )));
@@ -55,11 +58,15 @@ pub async fn index<C: ToolContext>(
                 } else {
                     ctx.embeddings().cloned()
                 };
+                // Attached roots are registered under the primary project path
+                let roots = crate::project_roots::registered_roots(path);
                 let stats = indexer::index_project(
                     path,
+                    &roots,
                     ctx.code_pool().inner().clone(),
                     embeddings,
                     project_id,
+                    force,
                 )
                 .await?;
                 if let Some(cache) = ctx.fuzzy_cache() {
@@ -67,15 +74,17 @@ pub async fn index<C: ToolContext>(
                 }
 
                 let mut response = format!(
-                    "Indexed {} files, {} symbols, {} chunks",
-                    stats.files, stats.symbols, stats.chunks
+                    "{} ({} symbols, {} chunks)",
+                    stats.file_summary(),
+                    stats.symbols,
+                    stats.chunks
                 );
                 if matches!(action, IndexAction::File) {
                     response.push_str("\nNote: file-level indexing runs a full project index.");
                 }
 
-                // Auto-queue health scan after project indexing
-                if let Some(pid) = project_id {
+                // Auto-queue health scan after project indexing, unless nothing changed
+                if let Some(pid) = project_id.filter(|_| stats.files > 0 || stats.removed > 0) {
                     let pool_clone = ctx.pool().clone();
                     if let Err(e) = pool_clone
                         .run(move |conn| {
@@ -92,6 +101,8 @@ pub async fn index<C: ToolContext>(
                     message: response,
                     data: Some(IndexData::Project(IndexProjectData {
                         files: stats.files,
+                        unchanged: stats.unchanged,
+                        removed: stats.removed,
                         symbols: stats.symbols,
                         chunks: stats.chunks,
                         modules_summarized: None,
@@ -124,35 +135,184 @@ pub async fn index<C: ToolContext>(
             return run_health_scan(ctx).await;
         }
         IndexAction::Status => {
-            use crate::db::{count_embedded_chunks_sync, count_symbols_sync};
+            use crate::db::dead_letters::count_dead_letters_sync;
+            use crate::db::{
+                count_embedded_chunks_sync, count_symbols_sync, get_indexed_files_sync,
+            };
 
             let project = ctx.get_project().await;
             let project_id = project.as_ref().map(|p| p.id);
 
-            let (symbols, embedded) = ctx
+            let (symbols, embedded, dead_letters, indexed_files) = ctx
                 .code_pool()
                 .run(move |conn| {
                     let symbols = count_symbols_sync(conn, project_id);
                     let embedded = count_embedded_chunks_sync(conn, project_id);
-                    Ok::<_, MiraError>((symbols, embedded))
+                    let dead_letters = count_dead_letters_sync(conn, project_id)?;
+                    let indexed_files = match project_id {
+                        Some(pid) => Some(get_indexed_files_sync(conn, pid)?),
+                        None => None,
+                    };
+                    Ok::<_, MiraError>((symbols, embedded, dead_letters, indexed_files))
                 })
                 .await?;
 
+            // Stat every indexed file off the async runtime
+            let freshness = match (project, indexed_files) {
+                (Some(project), Some(files)) => tokio::task::spawn_blocking(move || {
+                    indexer::compute_freshness(Path::new(&project.path), &files)
+                })
+                .await
+                .ok(),
+                _ => None,
+            };
+
+            let mut message = format!(
+                "Index status: {} symbols, {} embedded chunks",
+                symbols, embedded
+            );
+            if dead_letters > 0 {
+                message.push_str(&format!(
+                    ", {} dead-lettered jobs (see list_dead_letters())",
+                    dead_letters
+                ));
+            }
+            if let Some(ref f) = freshness {
+                message.push_str(&format!(
+                    "\nLast indexed: {} | {} files, {} changed since",
+                    f.last_indexed_at.as_deref().unwrap_or("never"),
+                    f.files_indexed,
+                    f.files_changed
+                ));
+                if f.stale {
+                    message.push_str(" - index is stale, run index_project() to refresh");
+                }
+            }
+
             Ok(Json(IndexOutput {
                 action: "status".into(),
-                message: format!(
-                    "Index status: {} symbols, {} embedded chunks",
-                    symbols, embedded
-                ),
+                message,
                 data: Some(IndexData::Status(IndexStatusData {
                     symbols: symbols as usize,
                     embedded_chunks: embedded as usize,
+                    dead_letters: dead_letters as usize,
+                    freshness: freshness.map(|f| IndexFreshnessData {
+                        last_indexed_at: f.last_indexed_at,
+                        files_indexed: f.files_indexed,
+                        files_changed: f.files_changed,
+                        stale: f.stale,
+                    }),
                 })),
             }))
         }
     }
 }
 
+/// List background jobs that exhausted their retries (active project only when set)
+pub async fn list_dead_letters<C: ToolContext>(
+    ctx: &C,
+    limit: Option<i64>,
+) -> Result<Json<IndexOutput>, MiraError> {
+    use crate::db::dead_letters::{count_dead_letters_sync, list_dead_letters_sync};
+
+    let project_id = ctx.get_project().await.map(|p| p.id);
+    let limit = limit.unwrap_or(20).clamp(1, 100) as usize;
+
+    let (letters, total) = ctx
+        .code_pool()
+        .run(move |conn| {
+            let letters = list_dead_letters_sync(conn, project_id, limit)?;
+            let total = count_dead_letters_sync(conn, project_id)?;
+            Ok::<_, MiraError>((letters, total))
+        })
+        .await?;
+
+    let message = if letters.is_empty() {
+        "No dead-lettered background jobs.".to_string()
+    } else {
+        let lines: Vec<String> = letters
+            .iter()
+            .map(|d| {
+                format!(
+                    "  #{} {} {} ({} attempts, {}): {}",
+                    d.id,
+                    d.job_type,
+                    d.job_key.as_deref().unwrap_or("-"),
+                    d.attempts,
+                    d.failed_at,
+                    truncate(d.error.as_deref().unwrap_or("unknown error"), 200)
+                )
+            })
+            .collect();
+        format!(
+            "{} dead-lettered jobs (showing {}):\n{}\nRequeue with retry_dead_letter(id) or retry_dead_letters().",
+            total,
+            letters.len(),
+            lines.join("\n")
+        )
+    };
+
+    let items = letters
+        .into_iter()
+        .map(|d| DeadLetterItem {
+            id: d.id,
+            job_type: d.job_type,
+            job_key: d.job_key,
+            error: d.error,
+            attempts: d.attempts,
+            failed_at: d.failed_at,
+        })
+        .collect();
+
+    Ok(Json(IndexOutput {
+        action: "list_dead_letters".into(),
+        message,
+        data: Some(IndexData::DeadLetters(DeadLettersData {
+            dead_letters: items,
+            total: total as usize,
+        })),
+    }))
+}
+
+/// Requeue one dead letter by ID, or all of them (active project only when set)
+pub async fn retry_dead_letter<C: ToolContext>(
+    ctx: &C,
+    id: Option<i64>,
+) -> Result<Json<IndexOutput>, MiraError> {
+    use crate::db::dead_letters::{retry_all_dead_letters_sync, retry_dead_letter_sync};
+
+    let requeued = match id {
+        Some(id) => {
+            let found = ctx
+                .code_pool()
+                .run(move |conn| retry_dead_letter_sync(conn, id))
+                .await?;
+            if !found {
+                return Err(MiraError::NotFound(format!(
+                    "Dead letter {} not found. Use list_dead_letters() to see IDs.",
+                    id
+                )));
+            }
+            1
+        }
+        None => {
+            let project_id = ctx.get_project().await.map(|p| p.id);
+            ctx.code_pool()
+                .run(move |conn| retry_all_dead_letters_sync(conn, project_id))
+                .await?
+        }
+    };
+
+    Ok(Json(IndexOutput {
+        action: "retry_dead_letter".into(),
+        message: format!(
+            "Requeued {} job(s); the background worker will pick them up shortly.",
+            requeued
+        ),
+        data: Some(IndexData::RetryDeadLetter(RetryDeadLetterData { requeued })),
+    }))
+}
+
 /// Summarize codebase modules using heuristic analysis
 pub async fn summarize_codebase<C: ToolContext>(ctx: &C) -> Result<Json<IndexOutput>, MiraError> {
     use crate::background::summaries::generate_heuristic_summaries;
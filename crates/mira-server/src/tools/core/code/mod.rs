@@ -1,14 +1,16 @@
 // crates/mira-server/src/tools/core/code/mod.rs
-// Unified code tools (search, callers, callees, symbols, index, analysis)
+// Unified code tools (search, callers, callees, symbols, impact, index, analysis)
 
 mod analysis;
 mod bundle;
+mod impact;
 mod index;
 mod search;
 
 // Re-export everything for backward compatibility with `pub use code::*;`
 pub use analysis::*;
 pub use bundle::*;
+pub use impact::*;
 pub use index::*;
 pub use search::*;
 
@@ -150,7 +152,11 @@ pub async fn handle_code<C: ToolContext>(
                     )));
                 }
             }
-            get_symbols(file_path, req.symbol_type)
+            get_symbols(
+                file_path,
+                req.symbol_type,
+                req.include_docs.unwrap_or(false),
+            )
         }
         CodeAction::Callers => {
             let function_name = req.function_name.ok_or_else(|| {
@@ -183,6 +189,14 @@ pub async fn handle_code<C: ToolContext>(
             })?;
             generate_bundle(ctx, scope, req.budget, req.depth).await
         }
+        CodeAction::Impact => {
+            let function_name = req.function_name.ok_or_else(|| {
+                MiraError::InvalidInput(
+                    "function_name is required for code(action=impact)".to_string(),
+                )
+            })?;
+            symbol_impact(ctx, function_name, req.file_path, req.limit).await
+        }
     }
 }
 
@@ -199,6 +213,7 @@ mod tests {
             file_path: None,
             function_name: None,
             symbol_type: None,
+            include_docs: None,
             limit: None,
             from_ref: None,
             to_ref: None,
@@ -265,6 +280,19 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_handle_code_impact_missing_function_name() {
+        let ctx = MockToolContext::with_project().await;
+        let req = make_code_request(CodeAction::Impact);
+        match handle_code(&ctx, req).await {
+            Err(e) => assert!(
+                e.to_string().contains("function_name"),
+                "Error should mention 'function_name', got: {e}"
+            ),
+            Ok(_) => panic!("Impact without function_name should fail"),
+        }
+    }
+
     // ========================================================================
     // query_callers / query_callees: empty index returns empty vec
     // ========================================================================
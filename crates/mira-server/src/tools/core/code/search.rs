@@ -410,10 +410,11 @@ pub async fn find_function_callees<C: ToolContext>(
 pub fn get_symbols(
     file_path: String,
     symbol_type: Option<String>,
+    include_docs: bool,
 ) -> Result<Json<CodeOutput>, MiraError> {
     #[cfg(not(feature = "parsers"))]
     {
-        let _ = (file_path, symbol_type);
+        let _ = (file_path, symbol_type, include_docs);
         return Err(MiraError::Other("Symbol extraction requires the 'parsers' feature. Reinstall with: cargo install --git https://github.com/ConaryLabs/Mira.git --features parsers".to_string()));
     }
     #[cfg(feature = "parsers")]
@@ -421,7 +422,7 @@ pub fn get_symbols(
         let path = Path::new(&file_path);
 
         if !path.exists() {
-            return Err(MiraError::InvalidInput(format!(
+            return Err(MiraError::NotFound(format!(
                 "File not found: {}. Check the path exists and is within the project directory.",
                 file_path
             )));
@@ -446,8 +447,13 @@ pub fn get_symbols(
             )));
         }
 
-        // Parse file for symbols
-        let symbols = indexer::extract_symbols(path)?;
+        // Parse file for symbols (content is kept for signature extraction)
+        let (symbols, _, _, content) = indexer::extract_all(path)?;
+        let tree = if include_docs {
+            parse_tree(path, &content)
+        } else {
+            None
+        };
 
         if symbols.is_empty() {
             return Ok(Json(CodeOutput {
@@ -479,6 +485,14 @@ pub fn get_symbols(
                 "  {}:{}({})\n",
                 sym.name, sym.symbol_type, sym.start_line
             ));
+            if include_docs {
+                if let Some(sig) = symbol_signature(tree.as_ref(), &content, sym) {
+                    response.push_str(&format!("    {}\n", sig));
+                }
+                if let Some(doc) = sym.documentation.as_deref().and_then(doc_summary) {
+                    response.push_str(&format!("    /// {}\n", doc));
+                }
+            }
         }
 
         if total > 10 {
@@ -489,11 +503,24 @@ pub fn get_symbols(
         let symbol_items: Vec<SymbolInfo> = symbols
             .iter()
             .take(500)
-            .map(|sym| SymbolInfo {
-                name: sym.name.clone(),
-                symbol_type: sym.symbol_type.clone(),
-                start_line: sym.start_line as usize,
-                end_line: sym.end_line as usize,
+            .map(|sym| {
+                let mut info = SymbolInfo {
+                    name: sym.name.clone(),
+                    symbol_type: sym.symbol_type.clone(),
+                    start_line: sym.start_line as usize,
+                    end_line: sym.end_line as usize,
+                    qualified_name: None,
+                    signature: None,
+                    visibility: None,
+                    doc: None,
+                };
+                if include_docs {
+                    info.qualified_name = sym.qualified_name.clone();
+                    info.signature = symbol_signature(tree.as_ref(), &content, sym);
+                    info.visibility = sym.visibility.clone();
+                    info.doc = sym.documentation.as_deref().and_then(doc_summary);
+                }
+                info
             })
             .collect();
 
@@ -508,6 +535,119 @@ pub fn get_symbols(
     } // #[cfg(feature = "parsers")]
 }
 
+/// Max source lines scanned for a symbol's declaration header
+#[cfg(feature = "parsers")]
+const SIGNATURE_MAX_LINES: usize = 5;
+/// Max characters kept from a signature or doc comment summary
+#[cfg(feature = "parsers")]
+const SYMBOL_DETAIL_MAX_LEN: usize = 300;
+
+/// Syntax tree of `content`, for reading declaration headers.
+/// None for unsupported languages.
+#[cfg(feature = "parsers")]
+fn parse_tree(path: &Path, content: &str) -> Option<tree_sitter::Tree> {
+    let ext = path.extension()?.to_str()?;
+    let lang_parser = indexer::parsers::PARSERS.by_extension(ext)?;
+    let mut parser = tree_sitter::Parser::new();
+    lang_parser.configure_parser(&mut parser).ok()?;
+    parser.parse(content, None)
+}
+
+/// Declaration header of `sym`, joined onto one line: the source from its
+/// syntax node up to the start of the node's body child, so braces inside
+/// parameter defaults or types don't cut it short. Declarations without a
+/// body (or without a tree) fall back to a line scan.
+#[cfg(feature = "parsers")]
+fn symbol_signature(
+    tree: Option<&tree_sitter::Tree>,
+    content: &str,
+    sym: &indexer::Symbol,
+) -> Option<String> {
+    let start_row = (sym.start_line as usize).saturating_sub(1);
+    let end_row = (sym.end_line as usize).saturating_sub(1);
+    let header = tree
+        .and_then(|t| declaration_with_body(t.root_node(), start_row, end_row))
+        .and_then(|(node, body)| content.get(node.start_byte()..body.start_byte()));
+    match header {
+        Some(header) => clean_signature(header),
+        None => line_signature(content, sym.start_line as usize),
+    }
+}
+
+/// Outermost node within rows `start_row..=end_row` that ends on `end_row`
+/// and has a `body` field, with that body. Wrappers such as Python's
+/// `decorated_definition` are descended into.
+#[cfg(feature = "parsers")]
+fn declaration_with_body<'t>(
+    node: tree_sitter::Node<'t>,
+    start_row: usize,
+    end_row: usize,
+) -> Option<(tree_sitter::Node<'t>, tree_sitter::Node<'t>)> {
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        let (child_start, child_end) = (child.start_position().row, child.end_position().row);
+        if child_start > end_row || child_end < end_row {
+            continue;
+        }
+        if child_start >= start_row
+            && child_end == end_row
+            && let Some(body) = child.child_by_field_name("body")
+        {
+            return Some((child, body));
+        }
+        if let Some(found) = declaration_with_body(child, start_row, end_row) {
+            return Some(found);
+        }
+    }
+    None
+}
+
+/// Declaration header of the symbol starting at `start_line` (1-based): the
+/// source up to the body opener, joined onto one line. Covers multi-line
+/// parameter lists and where clauses, capped at `SIGNATURE_MAX_LINES`.
+#[cfg(feature = "parsers")]
+fn line_signature(content: &str, start_line: usize) -> Option<String> {
+    let mut parts = Vec::new();
+    for line in content
+        .lines()
+        .skip(start_line.saturating_sub(1))
+        .take(SIGNATURE_MAX_LINES)
+    {
+        let line = line.trim();
+        if let Some(idx) = line.find('{') {
+            parts.push(&line[..idx]);
+            break;
+        }
+        parts.push(line);
+        // Bodyless declarations (`;`) and Python headers (trailing `:`)
+        if line.ends_with(';') || line.ends_with(':') {
+            break;
+        }
+    }
+    clean_signature(&parts.join(" "))
+}
+
+/// Collapse whitespace and drop a trailing body opener or terminator
+#[cfg(feature = "parsers")]
+fn clean_signature(header: &str) -> Option<String> {
+    let sig = header.split_whitespace().collect::<Vec<_>>().join(" ");
+    let sig = sig.trim_end_matches(['{', ';', ':', ' ']);
+    (!sig.is_empty()).then(|| truncate(sig, SYMBOL_DETAIL_MAX_LEN))
+}
+
+/// First paragraph of a doc comment, joined onto one line.
+#[cfg(feature = "parsers")]
+fn doc_summary(doc: &str) -> Option<String> {
+    let summary = doc
+        .lines()
+        .map(str::trim)
+        .skip_while(|l| l.is_empty())
+        .take_while(|l| !l.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+    (!summary.is_empty()).then(|| truncate(&summary, SYMBOL_DETAIL_MAX_LEN))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -589,7 +729,11 @@ mod tests {
     #[cfg(feature = "parsers")]
     #[test]
     fn test_get_symbols_nonexistent_file_errors() {
-        match get_symbols("/nonexistent/path/that/does/not/exist.rs".to_string(), None) {
+        match get_symbols(
+            "/nonexistent/path/that/does/not/exist.rs".to_string(),
+            None,
+            false,
+        ) {
             Err(e) => {
                 let msg = e.to_string();
                 assert!(
@@ -604,7 +748,7 @@ mod tests {
     #[cfg(feature = "parsers")]
     #[test]
     fn test_get_symbols_directory_path_errors() {
-        match get_symbols("/tmp".to_string(), None) {
+        match get_symbols("/tmp".to_string(), None, false) {
             Err(e) => {
                 let msg = e.to_string();
                 assert!(
@@ -616,10 +760,129 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "parsers")]
+    #[test]
+    fn test_get_symbols_include_docs() {
+        use crate::mcp::responses::CodeData;
+
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("lib.rs");
+        std::fs::write(
+            &file,
+            "/// Fetch a page of rows.\n\
+             ///\n\
+             /// Longer details that should not be in the summary.\n\
+             pub async fn fetch_rows(\n    \
+                 limit: usize,\n    \
+                 offset: usize,\n\
+             ) -> Result<Vec<String>, Error> {\n    \
+                 todo!()\n\
+             }\n",
+        )
+        .unwrap();
+        let path = file.to_string_lossy().to_string();
+
+        let output = get_symbols(path.clone(), None, true).unwrap();
+        let Some(CodeData::Symbols(data)) = output.0.data else {
+            panic!("Expected symbols data");
+        };
+        let sym = data
+            .symbols
+            .iter()
+            .find(|s| s.name == "fetch_rows")
+            .expect("fetch_rows should be extracted");
+        assert_eq!(
+            sym.signature.as_deref(),
+            Some(
+                "pub async fn fetch_rows( limit: usize, offset: usize, ) -> Result<Vec<String>, Error>"
+            )
+        );
+        assert_eq!(sym.doc.as_deref(), Some("Fetch a page of rows."));
+        assert_eq!(sym.visibility.as_deref(), Some("pub"));
+        assert!(output.0.message.contains("/// Fetch a page of rows."));
+
+        // Details are omitted unless requested
+        let output = get_symbols(path, None, false).unwrap();
+        let Some(CodeData::Symbols(data)) = output.0.data else {
+            panic!("Expected symbols data");
+        };
+        assert!(
+            data.symbols
+                .iter()
+                .all(|s| s.signature.is_none() && s.doc.is_none())
+        );
+    }
+
+    #[cfg(feature = "parsers")]
+    #[test]
+    fn test_line_signature_stops_at_body_or_terminator() {
+        let content = "struct A;\nimpl A {\n    fn new() -> Self;\n}\ndef f(x: int):\n    pass\n";
+        assert_eq!(line_signature(content, 1).as_deref(), Some("struct A"));
+        assert_eq!(line_signature(content, 2).as_deref(), Some("impl A"));
+        assert_eq!(
+            line_signature(content, 3).as_deref(),
+            Some("fn new() -> Self")
+        );
+        assert_eq!(line_signature(content, 5).as_deref(), Some("def f(x: int)"));
+        assert_eq!(line_signature(content, 99), None);
+    }
+
+    #[cfg(feature = "parsers")]
+    fn signatures(file_name: &str, content: &str) -> Vec<(String, Option<String>)> {
+        let path = Path::new(file_name);
+        let lang_parser = indexer::parsers::PARSERS
+            .by_extension(path.extension().unwrap().to_str().unwrap())
+            .unwrap();
+        let mut parser = tree_sitter::Parser::new();
+        lang_parser.configure_parser(&mut parser).unwrap();
+        let (symbols, _, _) = lang_parser.parse(&mut parser, content).unwrap();
+        let tree = parse_tree(path, content);
+        symbols
+            .iter()
+            .map(|sym| {
+                (
+                    sym.name.clone(),
+                    symbol_signature(tree.as_ref(), content, sym),
+                )
+            })
+            .collect()
+    }
+
+    #[cfg(feature = "parsers")]
+    #[test]
+    fn test_symbol_signature_ends_at_body_node() {
+        let py = "def connect(opts={}, *, retries: int = 3):\n    pass\n";
+        assert_eq!(
+            signatures("a.py", py),
+            vec![(
+                "connect".to_string(),
+                Some("def connect(opts={}, *, retries: int = 3)".to_string())
+            )]
+        );
+
+        let ts = "function render(opts = {}, cb: () => void) {\n  return 1;\n}\n";
+        assert_eq!(
+            signatures("a.ts", ts),
+            vec![(
+                "render".to_string(),
+                Some("function render(opts = {}, cb: () => void)".to_string())
+            )]
+        );
+
+        let rs = "fn apply<F>(f: F) -> u8\nwhere\n    F: Fn([u8; 2]) -> u8\n{\n    f([0; 2])\n}\n";
+        assert_eq!(
+            signatures("a.rs", rs),
+            vec![(
+                "apply".to_string(),
+                Some("fn apply<F>(f: F) -> u8 where F: Fn([u8; 2]) -> u8".to_string())
+            )]
+        );
+    }
+
     #[cfg(not(feature = "parsers"))]
     #[test]
     fn test_get_symbols_requires_parsers_feature() {
-        match get_symbols("/tmp/any_file.rs".to_string(), None) {
+        match get_symbols("/tmp/any_file.rs".to_string(), None, false) {
             Err(e) => assert!(
                 e.to_string().contains("parsers"),
                 "Error should mention 'parsers' feature, got: {}",
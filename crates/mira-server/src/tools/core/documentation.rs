@@ -150,7 +150,7 @@ async fn fetch_pending_doc_task(
         .run(move |conn| get_doc_task(conn, task_id))
         .await?
         .ok_or_else(|| {
-            MiraError::InvalidInput(format!(
+            MiraError::NotFound(format!(
                 "Task '{}' not found. Use documentation(action=\"list\") to see available tasks.",
                 task_id
             ))
@@ -356,7 +356,7 @@ pub async fn batch_skip_doc_tasks(
                 .pool()
                 .run(move |conn| get_doc_task(conn, id))
                 .await?
-                .ok_or_else(|| MiraError::InvalidInput(format!("Task '{}' not found. Use documentation(action=\"list\") to see available tasks.", id)))?;
+                .ok_or_else(|| MiraError::NotFound(format!("Task '{}' not found. Use documentation(action=\"list\") to see available tasks.", id)))?;
             tasks.push(task);
         }
         tasks
@@ -1,23 +1,37 @@
 //! crates/mira-server/src/tools/core/goals.rs
 //! Goal and milestone tools - split into focused action functions
 
+use crate::db::goal_dependencies::{
+    EFFECTIVE_BLOCKED, GoalLink, add_goal_dependency_sync, effective_status,
+    get_goal_blockers_sync, get_goal_dependencies_sync, get_goal_dependents_sync,
+    record_goal_unblocked_sync, remove_goal_dependency_sync, unblocked_by_completion_sync,
+};
+use crate::db::task_dependencies::{
+    add_task_dependency_sync, get_next_actionable_tasks_sync, remove_task_dependency_sync,
+};
+use crate::db::task_templates::{
+    TaskTemplate, get_task_template_sync, instantiate_task_template_sync, list_task_templates_sync,
+    save_task_template_sync,
+};
 use crate::db::{
     complete_milestone_sync, count_sessions_for_goal_sync, create_goal_sync, create_milestone_sync,
     delete_goal_sync, delete_milestone_sync, get_active_goals_sync, get_goal_by_id_sync,
     get_goals_sync, get_milestone_by_id_sync, get_milestones_for_goal_sync,
-    get_sessions_for_goal_sync, record_session_goal_sync,
+    get_sessions_for_goal_sync, get_task_by_id_sync, record_session_goal_sync,
     update_goal_progress_from_milestones_sync, update_goal_sync,
 };
 use crate::error::MiraError;
 use crate::mcp::requests::{GoalAction, GoalRequest};
 use crate::mcp::responses::Json;
 use crate::mcp::responses::{
-    GoalBulkCreatedData, GoalCreatedData, GoalCreatedEntry, GoalData, GoalGetData, GoalListData,
-    GoalModifiedData, GoalOutput, GoalSessionEntry, GoalSessionsData, GoalSummary, MilestoneInfo,
-    MilestoneProgressData,
+    GoalBulkCreatedData, GoalCreatedData, GoalCreatedEntry, GoalData, GoalDependenciesData,
+    GoalDependencyInfo, GoalGetData, GoalListData, GoalModifiedData, GoalOutput, GoalSessionEntry,
+    GoalSessionsData, GoalSummary, MilestoneInfo, MilestoneProgressData, NextTasksData,
+    TaskSummary, TaskTemplateInfo, TaskTemplateListData, TasksCreatedData,
 };
 use crate::tools::core::ToolContext;
 use serde::Deserialize;
+use std::collections::HashMap;
 
 /// Goal definition for bulk creation
 #[derive(Debug, Deserialize)]
@@ -38,16 +52,26 @@ fn verify_goal_project(
     goal_project_id: Option<i64>,
     ctx_project_id: Option<i64>,
 ) -> Result<(), MiraError> {
-    match (goal_project_id, ctx_project_id) {
+    verify_record_project("goal", goal_project_id, ctx_project_id)
+}
+
+/// Shared project check for goals and tasks; `kind` names the record in errors.
+fn verify_record_project(
+    kind: &str,
+    record_project_id: Option<i64>,
+    ctx_project_id: Option<i64>,
+) -> Result<(), MiraError> {
+    match (record_project_id, ctx_project_id) {
         // Both have project IDs — must match
-        (Some(goal_pid), Some(ctx_pid)) if goal_pid != ctx_pid => Err(MiraError::InvalidInput(
-            "Access denied: goal belongs to a different project".to_string(),
+        (Some(record_pid), Some(ctx_pid)) if record_pid != ctx_pid => Err(MiraError::InvalidInput(
+            format!("Access denied: {} belongs to a different project", kind),
         )),
-        // Goal has a project but no context project — no active project set
-        (Some(_), None) => Err(MiraError::InvalidInput(
-            "Access denied: this goal belongs to a project, but no active project is set. Use project(action=\"start\") to set one.".to_string(),
-        )),
-        // Both match, goal is global, or both are None — allow
+        // Record has a project but no context project — no active project set
+        (Some(_), None) => Err(MiraError::InvalidInput(format!(
+            "Access denied: this {} belongs to a project, but no active project is set. Use project(action=\"start\") to set one.",
+            kind
+        ))),
+        // Both match, record is global, or both are None — allow
         _ => Ok(()),
     }
 }
@@ -62,7 +86,7 @@ async fn get_authorized_goal<C: ToolContext>(
         .run(move |conn| get_goal_by_id_sync(conn, id))
         .await?
         .ok_or_else(|| {
-            MiraError::InvalidInput(format!(
+            MiraError::NotFound(format!(
                 "Goal not found (id: {}). Use goal(action=\"list\") to see available goals.",
                 id
             ))
@@ -74,6 +98,28 @@ async fn get_authorized_goal<C: ToolContext>(
     Ok(goal)
 }
 
+/// Fetch a task by ID and verify project authorization (same rules as goals).
+async fn get_authorized_task<C: ToolContext>(
+    ctx: &C,
+    id: i64,
+) -> Result<crate::db::Task, MiraError> {
+    let task = ctx
+        .pool()
+        .run(move |conn| get_task_by_id_sync(conn, id))
+        .await?
+        .ok_or_else(|| {
+            MiraError::NotFound(format!(
+                "Task not found (id: {}). Use goal(action=\"next_tasks\") to see open tasks.",
+                id
+            ))
+        })?;
+
+    let ctx_project_id = ctx.project_id().await;
+    verify_record_project("task", task.project_id, ctx_project_id)?;
+
+    Ok(task)
+}
+
 /// Look up a milestone's parent goal_id and verify project authorization.
 async fn verify_milestone_project<C: ToolContext>(
     ctx: &C,
@@ -83,7 +129,7 @@ async fn verify_milestone_project<C: ToolContext>(
         .pool()
         .run(move |conn| get_milestone_by_id_sync(conn, milestone_id))
         .await?
-        .ok_or_else(|| MiraError::InvalidInput(format!("Milestone not found (id: {}). Use goal(action=\"get\", goal_id=N) to see milestones for a goal.", milestone_id)))?;
+        .ok_or_else(|| MiraError::NotFound(format!("Milestone not found (id: {}). Use goal(action=\"get\", goal_id=N) to see milestones for a goal.", milestone_id)))?;
 
     let goal_id = milestone
         .goal_id
@@ -94,7 +140,7 @@ async fn verify_milestone_project<C: ToolContext>(
         .run(move |conn| get_goal_by_id_sync(conn, goal_id))
         .await?
         .ok_or_else(|| {
-            MiraError::InvalidInput(format!(
+            MiraError::NotFound(format!(
                 "Goal not found (id: {}). Use goal(action=\"list\") to see available goals.",
                 goal_id
             ))
@@ -107,7 +153,7 @@ async fn verify_milestone_project<C: ToolContext>(
 }
 
 /// Valid goal statuses.
-const VALID_STATUSES: &[&str] = &[
+pub(crate) const VALID_STATUSES: &[&str] = &[
     "planning",
     "in_progress",
     "blocked",
@@ -116,7 +162,7 @@ const VALID_STATUSES: &[&str] = &[
 ];
 
 /// Valid goal priorities.
-const VALID_PRIORITIES: &[&str] = &["low", "medium", "high", "critical"];
+pub(crate) const VALID_PRIORITIES: &[&str] = &["low", "medium", "high", "critical"];
 
 /// Validate a status value, if provided.
 fn validate_status(status: &Option<String>) -> Result<(), MiraError> {
@@ -158,6 +204,11 @@ fn validate_positive_id(id: i64, field: &str) -> Result<i64, MiraError> {
     }
 }
 
+/// Suffix for progress that was set by hand rather than rolled up
+fn manual_marker(manual: bool) -> &'static str {
+    if manual { ", manual" } else { "" }
+}
+
 /// Record a session-goal link (fire-and-forget, never fails the parent operation).
 async fn record_goal_interaction<C: ToolContext>(ctx: &C, goal_id: i64, interaction_type: &str) {
     let session_id = match ctx.get_session_id().await {
@@ -172,6 +223,23 @@ async fn record_goal_interaction<C: ToolContext>(ctx: &C, goal_id: i64, interact
     // Silently ignore errors — this is best-effort tracking
 }
 
+fn dependency_info(link: &GoalLink) -> GoalDependencyInfo {
+    GoalDependencyInfo {
+        id: link.id,
+        title: link.title.clone(),
+        status: link.status.clone(),
+    }
+}
+
+/// "[3] Schema (in_progress), [5] API (planning)"
+fn format_links(links: &[GoalLink]) -> String {
+    links
+        .iter()
+        .map(|l| format!("[{}] {} ({})", l.id, l.title, l.status))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
 // ============================================================================
 // Action-specific functions
 // ============================================================================
@@ -180,10 +248,37 @@ async fn record_goal_interaction<C: ToolContext>(ctx: &C, goal_id: i64, interact
 async fn action_get<C: ToolContext>(ctx: &C, goal_id: i64) -> Result<Json<GoalOutput>, MiraError> {
     let goal = get_authorized_goal(ctx, goal_id).await?;
 
+    let (depends_on, dependents) = ctx
+        .pool()
+        .run(move |conn| -> anyhow::Result<_> {
+            Ok((
+                get_goal_dependencies_sync(conn, goal_id)?,
+                get_goal_dependents_sync(conn, goal_id)?,
+            ))
+        })
+        .await?;
+    let effective = effective_status(&goal.status, &depends_on).to_string();
+
     let mut response = format!("Goal [{}]: {}\n", goal.id, goal.title);
     response.push_str(&format!("  Status: {}\n", goal.status));
+    let blockers: Vec<GoalLink> = depends_on
+        .iter()
+        .filter(|d| d.is_blocking())
+        .cloned()
+        .collect();
+    if effective == EFFECTIVE_BLOCKED && !blockers.is_empty() {
+        response.push_str(&format!(
+            "  Effective status: {} (waiting on {})\n",
+            effective,
+            format_links(&blockers)
+        ));
+    }
     response.push_str(&format!("  Priority: {}\n", goal.priority));
-    response.push_str(&format!("  Progress: {}%\n", goal.progress_percent));
+    response.push_str(&format!(
+        "  Progress: {}%{}\n",
+        goal.progress_percent,
+        manual_marker(goal.progress_manual)
+    ));
     if let Some(desc) = &goal.description {
         response.push_str(&format!("  Description: {}\n", desc));
     }
@@ -215,6 +310,13 @@ async fn action_get<C: ToolContext>(ctx: &C, goal_id: i64) -> Result<Json<GoalOu
         }
     }
 
+    if !depends_on.is_empty() {
+        response.push_str(&format!("\n  Depends on: {}\n", format_links(&depends_on)));
+    }
+    if !dependents.is_empty() {
+        response.push_str(&format!("  Blocks: {}\n", format_links(&dependents)));
+    }
+
     Ok(Json(GoalOutput {
         action: "get".into(),
         message: response,
@@ -222,11 +324,15 @@ async fn action_get<C: ToolContext>(ctx: &C, goal_id: i64) -> Result<Json<GoalOu
             id: goal.id,
             title: goal.title,
             status: goal.status,
+            effective_status: effective,
             priority: goal.priority,
             progress_percent: goal.progress_percent,
+            progress_manual: goal.progress_manual,
             description: goal.description,
             created_at: goal.created_at,
             milestones: milestone_items,
+            depends_on: depends_on.iter().map(dependency_info).collect(),
+            dependents: dependents.iter().map(dependency_info).collect(),
         })),
     }))
 }
@@ -449,6 +555,12 @@ async fn action_list<C: ToolContext>(
             })
             .await?
     };
+    let blockers_by_goal = {
+        let ids = goal_ids.clone();
+        ctx.pool()
+            .run(move |conn| get_goal_blockers_sync(conn, &ids))
+            .await?
+    };
 
     let display_total = if total_count > 0 && total_count > goals.len() {
         format!("{} goals (showing {}):\n", total_count, goals.len())
@@ -470,9 +582,19 @@ async fn action_list<C: ToolContext>(
                 .cloned()
                 .unwrap_or_default();
             response.push_str(&format!(
-                "  {} {} ({}%) - {} [{}]\n",
-                icon, goal.title, goal.progress_percent, goal.priority, goal.id
+                "  {} {} ({}%{}) - {} [{}]\n",
+                icon,
+                goal.title,
+                goal.progress_percent,
+                manual_marker(goal.progress_manual),
+                goal.priority,
+                goal.id
             ));
+            let blockers = blockers_by_goal.get(&goal.id).cloned().unwrap_or_default();
+            let effective = effective_status(&goal.status, &blockers).to_string();
+            if effective == EFFECTIVE_BLOCKED && !blockers.is_empty() {
+                response.push_str(&format!("    blocked by {}\n", format_links(&blockers)));
+            }
             if !ms.is_empty() {
                 for m in &ms {
                     let mi = if m.completed { "[x]" } else { "[ ]" };
@@ -483,9 +605,12 @@ async fn action_list<C: ToolContext>(
                 id: goal.id,
                 title: goal.title,
                 status: goal.status,
+                effective_status: effective,
                 priority: goal.priority,
                 progress_percent: goal.progress_percent,
+                progress_manual: goal.progress_manual,
                 milestones: ms,
+                blocked_by: blockers.iter().map(dependency_info).collect(),
             }
         })
         .collect();
@@ -525,7 +650,9 @@ async fn action_update<C: ToolContext>(
         )));
     }
 
-    get_authorized_goal(ctx, goal_id).await?;
+    let goal = get_authorized_goal(ctx, goal_id).await?;
+    let completing = status.as_deref() == Some("completed") && goal.status != "completed";
+    let notice_project = goal.project_id.or(ctx.project_id().await);
 
     let mut changed = Vec::new();
     if title.is_some() {
@@ -550,8 +677,14 @@ async fn action_update<C: ToolContext>(
     }
     let fields = format!(" ({})", changed.join(", "));
 
-    ctx.pool()
-        .run(move |conn| {
+    let completed = GoalLink {
+        id: goal.id,
+        title: goal.title,
+        status: "completed".into(),
+    };
+    let unblocked = ctx
+        .pool()
+        .run(move |conn| -> anyhow::Result<Vec<GoalLink>> {
             update_goal_sync(
                 conn,
                 goal_id,
@@ -560,19 +693,37 @@ async fn action_update<C: ToolContext>(
                 status.as_deref(),
                 priority.as_deref(),
                 progress_percent.map(|p| p as i64),
-            )
+            )?;
+            if !completing {
+                return Ok(Vec::new());
+            }
+            let unblocked = unblocked_by_completion_sync(conn, goal_id)?;
+            if let Some(pid) = notice_project {
+                for dependent in &unblocked {
+                    if let Err(e) = record_goal_unblocked_sync(conn, pid, dependent, &completed) {
+                        tracing::warn!("Failed to record goal-unblocked notice: {}", e);
+                    }
+                }
+            }
+            Ok(unblocked)
         })
         .await?;
 
     // Record session-goal link
     record_goal_interaction(ctx, goal_id, "updated").await;
 
+    let mut message = format!("Updated goal {}{}", goal_id, fields);
+    if !unblocked.is_empty() {
+        message.push_str(&format!("\nUnblocked: {}", format_links(&unblocked)));
+    }
+
     Ok(Json(GoalOutput {
         action: "update".into(),
-        message: format!("Updated goal {}{}", goal_id, fields),
+        message,
         data: Some(GoalData::Modified(GoalModifiedData {
             goal_id,
             action: "updated".into(),
+            unblocked: unblocked.iter().map(dependency_info).collect(),
         })),
     }))
 }
@@ -594,6 +745,7 @@ async fn action_delete<C: ToolContext>(
         data: Some(GoalData::Modified(GoalModifiedData {
             goal_id,
             action: "deleted".into(),
+            unblocked: Vec::new(),
         })),
     }))
 }
@@ -729,6 +881,353 @@ async fn action_delete_milestone<C: ToolContext>(
     .await
 }
 
+/// Make `goal_id` wait on `depends_on`
+async fn action_add_dependency<C: ToolContext>(
+    ctx: &C,
+    goal_id: i64,
+    depends_on: i64,
+) -> Result<Json<GoalOutput>, MiraError> {
+    get_authorized_goal(ctx, goal_id).await?;
+    let dependency = get_authorized_goal(ctx, depends_on).await?;
+
+    ctx.pool()
+        .interact(move |conn| add_goal_dependency_sync(conn, goal_id, depends_on))
+        .await
+        .map_err(|e| MiraError::InvalidInput(e.to_string()))?;
+
+    record_goal_interaction(ctx, goal_id, "updated").await;
+
+    Ok(Json(GoalOutput {
+        action: "add_dependency".into(),
+        message: format!(
+            "Goal {} now depends on goal {} ({})",
+            goal_id, depends_on, dependency.title
+        ),
+        data: Some(GoalData::Modified(GoalModifiedData {
+            goal_id,
+            action: "dependency_added".into(),
+            unblocked: Vec::new(),
+        })),
+    }))
+}
+
+/// Remove the `goal_id` -> `depends_on` edge
+async fn action_remove_dependency<C: ToolContext>(
+    ctx: &C,
+    goal_id: i64,
+    depends_on: i64,
+) -> Result<Json<GoalOutput>, MiraError> {
+    get_authorized_goal(ctx, goal_id).await?;
+
+    let removed = ctx
+        .pool()
+        .run(move |conn| remove_goal_dependency_sync(conn, goal_id, depends_on))
+        .await?;
+    if !removed {
+        return Err(MiraError::NotFound(format!(
+            "Goal {} does not depend on goal {}. Use goal(action=\"dependencies\", goal_id={}) to see its dependencies.",
+            goal_id, depends_on, goal_id
+        )));
+    }
+
+    Ok(Json(GoalOutput {
+        action: "remove_dependency".into(),
+        message: format!("Goal {} no longer depends on goal {}", goal_id, depends_on),
+        data: Some(GoalData::Modified(GoalModifiedData {
+            goal_id,
+            action: "dependency_removed".into(),
+            unblocked: Vec::new(),
+        })),
+    }))
+}
+
+/// Show both directions of a goal's dependency links
+async fn action_dependencies<C: ToolContext>(
+    ctx: &C,
+    goal_id: i64,
+) -> Result<Json<GoalOutput>, MiraError> {
+    let goal = get_authorized_goal(ctx, goal_id).await?;
+
+    let (depends_on, dependents) = ctx
+        .pool()
+        .run(move |conn| -> anyhow::Result<_> {
+            Ok((
+                get_goal_dependencies_sync(conn, goal_id)?,
+                get_goal_dependents_sync(conn, goal_id)?,
+            ))
+        })
+        .await?;
+    let effective = effective_status(&goal.status, &depends_on).to_string();
+
+    let mut response = format!(
+        "Goal [{}]: {} (status: {}, effective: {})\n",
+        goal.id, goal.title, goal.status, effective
+    );
+    if depends_on.is_empty() && dependents.is_empty() {
+        response.push_str("  No dependencies.\n");
+    }
+    if !depends_on.is_empty() {
+        response.push_str(&format!("  Depends on: {}\n", format_links(&depends_on)));
+    }
+    if !dependents.is_empty() {
+        response.push_str(&format!("  Blocks: {}\n", format_links(&dependents)));
+    }
+
+    Ok(Json(GoalOutput {
+        action: "dependencies".into(),
+        message: response,
+        data: Some(GoalData::Dependencies(GoalDependenciesData {
+            goal_id,
+            effective_status: effective,
+            depends_on: depends_on.iter().map(dependency_info).collect(),
+            dependents: dependents.iter().map(dependency_info).collect(),
+        })),
+    }))
+}
+
+/// Tasks that can be started now, highest priority first
+async fn action_next_tasks<C: ToolContext>(
+    ctx: &C,
+    project_id: Option<i64>,
+    limit: usize,
+) -> Result<Json<GoalOutput>, MiraError> {
+    let tasks = ctx
+        .pool()
+        .run(move |conn| get_next_actionable_tasks_sync(conn, project_id, limit))
+        .await?;
+
+    let mut response = if tasks.is_empty() {
+        "No actionable tasks. Open tasks are all blocked or waiting on dependencies.".to_string()
+    } else {
+        format!("{} actionable task(s):\n", tasks.len())
+    };
+    for task in &tasks {
+        response.push_str(&format!(
+            "  [{}] {} ({}, {})\n",
+            task.id, task.title, task.priority, task.status
+        ));
+    }
+
+    let total = tasks.len();
+    Ok(Json(GoalOutput {
+        action: "next_tasks".into(),
+        message: response,
+        data: Some(GoalData::NextTasks(NextTasksData {
+            tasks: tasks
+                .into_iter()
+                .map(|t| TaskSummary {
+                    id: t.id,
+                    title: t.title,
+                    status: t.status,
+                    priority: t.priority,
+                    goal_id: t.goal_id,
+                })
+                .collect(),
+            total,
+        })),
+    }))
+}
+
+/// Record that `task_id` can't start until `depends_on` is completed
+async fn action_add_task_dependency<C: ToolContext>(
+    ctx: &C,
+    task_id: i64,
+    depends_on: i64,
+) -> Result<Json<GoalOutput>, MiraError> {
+    get_authorized_task(ctx, task_id).await?;
+    let dependency = get_authorized_task(ctx, depends_on).await?;
+
+    ctx.pool()
+        .interact(move |conn| add_task_dependency_sync(conn, task_id, depends_on))
+        .await
+        .map_err(|e| MiraError::InvalidInput(e.to_string()))?;
+
+    Ok(Json(GoalOutput {
+        action: "add_task_dependency".into(),
+        message: format!(
+            "Task {} now depends on task {} ({})",
+            task_id, depends_on, dependency.title
+        ),
+        data: None,
+    }))
+}
+
+/// Remove the `task_id` -> `depends_on` edge
+async fn action_remove_task_dependency<C: ToolContext>(
+    ctx: &C,
+    task_id: i64,
+    depends_on: i64,
+) -> Result<Json<GoalOutput>, MiraError> {
+    get_authorized_task(ctx, task_id).await?;
+
+    let removed = ctx
+        .pool()
+        .run(move |conn| remove_task_dependency_sync(conn, task_id, depends_on))
+        .await?;
+    if !removed {
+        return Err(MiraError::NotFound(format!(
+            "Task {} does not depend on task {}",
+            task_id, depends_on
+        )));
+    }
+
+    Ok(Json(GoalOutput {
+        action: "remove_task_dependency".into(),
+        message: format!("Task {} no longer depends on task {}", task_id, depends_on),
+        data: None,
+    }))
+}
+
+fn template_info(template: TaskTemplate) -> TaskTemplateInfo {
+    TaskTemplateInfo {
+        id: template.id,
+        name: template.name,
+        items: template.items,
+        global: template.project_id.is_none(),
+    }
+}
+
+/// Save (or replace) a named task checklist for the project
+async fn action_task_template<C: ToolContext>(
+    ctx: &C,
+    project_id: Option<i64>,
+    name: String,
+    items_json: &str,
+) -> Result<Json<GoalOutput>, MiraError> {
+    let items: Vec<String> = serde_json::from_str(items_json).map_err(|e| {
+        MiraError::InvalidInput(format!(
+            "Failed to parse items: {}. Expected a JSON-encoded string containing an array of task titles, e.g., '[\"Bump version to {{version}}\", \"Tag v{{version}}\"]'",
+            e
+        ))
+    })?;
+
+    let template = ctx
+        .pool()
+        .interact(move |conn| {
+            save_task_template_sync(conn, project_id, &name, &items)?;
+            get_task_template_sync(conn, project_id, &name)?
+                .ok_or_else(|| anyhow::anyhow!("Task template '{}' was not saved", name))
+        })
+        .await
+        .map_err(|e| MiraError::InvalidInput(e.to_string()))?;
+
+    Ok(Json(GoalOutput {
+        action: "task_template".into(),
+        message: format!(
+            "Saved task template '{}' ({} item(s)). Use goal(action=\"task_instantiate\", template=\"{}\") to create its tasks.",
+            template.name,
+            template.items.len(),
+            template.name
+        ),
+        data: Some(GoalData::TaskTemplate(template_info(template))),
+    }))
+}
+
+/// List task templates visible from the project
+async fn action_task_templates<C: ToolContext>(
+    ctx: &C,
+    project_id: Option<i64>,
+) -> Result<Json<GoalOutput>, MiraError> {
+    let templates = ctx
+        .pool()
+        .run(move |conn| list_task_templates_sync(conn, project_id))
+        .await?;
+
+    let mut response = if templates.is_empty() {
+        "No task templates. Use goal(action=\"task_template\") to save one.".to_string()
+    } else {
+        format!("{} task template(s):\n", templates.len())
+    };
+    for t in &templates {
+        let scope = if t.project_id.is_none() {
+            " (global)"
+        } else {
+            ""
+        };
+        response.push_str(&format!("  {}{}: {}\n", t.name, scope, t.items.join("; ")));
+    }
+
+    let total = templates.len();
+    Ok(Json(GoalOutput {
+        action: "task_templates".into(),
+        message: response,
+        data: Some(GoalData::TaskTemplates(TaskTemplateListData {
+            templates: templates.into_iter().map(template_info).collect(),
+            total,
+        })),
+    }))
+}
+
+/// Create the tasks of a saved template, optionally under a goal
+async fn action_task_instantiate<C: ToolContext>(
+    ctx: &C,
+    project_id: Option<i64>,
+    name: String,
+    goal_id: Option<i64>,
+    vars_json: Option<&str>,
+) -> Result<Json<GoalOutput>, MiraError> {
+    let vars: HashMap<String, String> = match vars_json {
+        Some(json) => serde_json::from_str(json).map_err(|e| {
+            MiraError::InvalidInput(format!(
+                "Failed to parse vars: {}. Expected a JSON-encoded string containing an object of string values, e.g., '{{\"version\": \"1.4.0\"}}'",
+                e
+            ))
+        })?,
+        None => HashMap::new(),
+    };
+    if let Some(gid) = goal_id {
+        get_authorized_goal(ctx, gid).await?;
+    }
+
+    let template_name = name.clone();
+    let tasks = ctx
+        .pool()
+        .interact(move |conn| {
+            let ids = instantiate_task_template_sync(conn, project_id, &name, goal_id, &vars)?;
+            let mut tasks = Vec::with_capacity(ids.len());
+            for id in ids {
+                if let Some(task) = get_task_by_id_sync(conn, id)? {
+                    tasks.push(task);
+                }
+            }
+            Ok(tasks)
+        })
+        .await
+        .map_err(|e| MiraError::InvalidInput(e.to_string()))?;
+
+    if let Some(gid) = goal_id {
+        record_goal_interaction(ctx, gid, "updated").await;
+    }
+
+    let mut response = format!(
+        "Created {} task(s) from template '{}':\n",
+        tasks.len(),
+        template_name
+    );
+    for task in &tasks {
+        response.push_str(&format!("  [{}] {}\n", task.id, task.title));
+    }
+
+    Ok(Json(GoalOutput {
+        action: "task_instantiate".into(),
+        message: response,
+        data: Some(GoalData::TasksCreated(TasksCreatedData {
+            template: template_name,
+            goal_id,
+            tasks: tasks
+                .into_iter()
+                .map(|t| TaskSummary {
+                    id: t.id,
+                    title: t.title,
+                    status: t.status,
+                    priority: t.priority,
+                    goal_id: t.goal_id,
+                })
+                .collect(),
+        })),
+    }))
+}
+
 /// List sessions that worked on a goal
 async fn action_sessions<C: ToolContext>(
     ctx: &C,
@@ -788,7 +1287,8 @@ async fn action_sessions<C: ToolContext>(
 // ============================================================================
 
 /// Unified goal tool with actions: create, bulk_create, list, get, update, progress, delete,
-/// add_milestone, complete_milestone, delete_milestone, sessions
+/// add_milestone, complete_milestone, delete_milestone, sessions, goal and task dependencies,
+/// next_tasks, task_template, task_templates, task_instantiate
 pub async fn goal<C: ToolContext>(
     ctx: &C,
     req: GoalRequest,
@@ -892,6 +1392,81 @@ pub async fn goal<C: ToolContext>(
             let limit = req.limit.unwrap_or(20).max(1) as usize;
             action_sessions(ctx, id, limit).await
         }
+        GoalAction::AddDependency | GoalAction::RemoveDependency => {
+            let name = if matches!(req.action, GoalAction::AddDependency) {
+                "add_dependency"
+            } else {
+                "remove_dependency"
+            };
+            let id = req.goal_id.ok_or_else(|| {
+                MiraError::InvalidInput(format!("goal_id is required for goal(action={}). Use goal(action=\"list\") to see available goals.", name))
+            })?;
+            let id = validate_positive_id(id, "goal_id")?;
+            let dep = req.depends_on.ok_or_else(|| {
+                MiraError::InvalidInput(format!("depends_on is required for goal(action={})", name))
+            })?;
+            let dep = validate_positive_id(dep, "depends_on")?;
+            if matches!(req.action, GoalAction::AddDependency) {
+                action_add_dependency(ctx, id, dep).await
+            } else {
+                action_remove_dependency(ctx, id, dep).await
+            }
+        }
+        GoalAction::Dependencies => {
+            let id = req.goal_id.ok_or_else(|| {
+                MiraError::InvalidInput("goal_id is required for goal(action=dependencies). Use goal(action=\"list\") to see available goals.".to_string())
+            })?;
+            let id = validate_positive_id(id, "goal_id")?;
+            action_dependencies(ctx, id).await
+        }
+        GoalAction::NextTasks => {
+            let limit = req.limit.unwrap_or(10).max(1) as usize;
+            action_next_tasks(ctx, project_id, limit).await
+        }
+        GoalAction::AddTaskDependency | GoalAction::RemoveTaskDependency => {
+            let name = if matches!(req.action, GoalAction::AddTaskDependency) {
+                "add_task_dependency"
+            } else {
+                "remove_task_dependency"
+            };
+            let id = req.task_id.ok_or_else(|| {
+                MiraError::InvalidInput(format!("task_id is required for goal(action={}). Use goal(action=\"next_tasks\") to see open tasks.", name))
+            })?;
+            let id = validate_positive_id(id, "task_id")?;
+            let dep = req.depends_on.ok_or_else(|| {
+                MiraError::InvalidInput(format!("depends_on is required for goal(action={})", name))
+            })?;
+            let dep = validate_positive_id(dep, "depends_on")?;
+            if matches!(req.action, GoalAction::AddTaskDependency) {
+                action_add_task_dependency(ctx, id, dep).await
+            } else {
+                action_remove_task_dependency(ctx, id, dep).await
+            }
+        }
+        GoalAction::TaskTemplate => {
+            let name = req.template.ok_or_else(|| {
+                MiraError::InvalidInput(
+                    "template is required for goal(action=task_template)".to_string(),
+                )
+            })?;
+            let items = req.items.ok_or_else(|| {
+                MiraError::InvalidInput(
+                    "items is required for goal(action=task_template)".to_string(),
+                )
+            })?;
+            action_task_template(ctx, project_id, name, &items).await
+        }
+        GoalAction::TaskTemplates => action_task_templates(ctx, project_id).await,
+        GoalAction::TaskInstantiate => {
+            let name = req.template.ok_or_else(|| {
+                MiraError::InvalidInput("template is required for goal(action=task_instantiate). Use goal(action=\"task_templates\") to see saved templates.".to_string())
+            })?;
+            let goal_id = req
+                .goal_id
+                .map(|id| validate_positive_id(id, "goal_id"))
+                .transpose()?;
+            action_task_instantiate(ctx, project_id, name, goal_id, req.vars.as_deref()).await
+        }
     }
 }
 
@@ -1061,6 +1636,15 @@ mod tests {
             ("complete_milestone", GoalAction::CompleteMilestone),
             ("delete_milestone", GoalAction::DeleteMilestone),
             ("sessions", GoalAction::Sessions),
+            ("add_dependency", GoalAction::AddDependency),
+            ("remove_dependency", GoalAction::RemoveDependency),
+            ("dependencies", GoalAction::Dependencies),
+            ("next_tasks", GoalAction::NextTasks),
+            ("add_task_dependency", GoalAction::AddTaskDependency),
+            ("remove_task_dependency", GoalAction::RemoveTaskDependency),
+            ("task_template", GoalAction::TaskTemplate),
+            ("task_templates", GoalAction::TaskTemplates),
+            ("task_instantiate", GoalAction::TaskInstantiate),
         ];
         for (s, expected) in actions {
             let json = format!(r#"{{"action": "{}"}}"#, s);
@@ -1073,4 +1657,130 @@ mod tests {
             );
         }
     }
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Task dependencies through the tool
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    fn request(action: &str) -> GoalRequest {
+        serde_json::from_value(serde_json::json!({ "action": action })).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_next_tasks_follows_task_dependencies() {
+        use crate::tools::core::test_utils::MockToolContext;
+
+        let ctx = MockToolContext::with_project().await;
+        let project_id = ctx.project_id().await;
+        let (schema, api) = ctx
+            .pool
+            .run(move |conn| -> anyhow::Result<_> {
+                let schema = crate::db::create_task_sync(
+                    conn,
+                    project_id,
+                    None,
+                    "schema",
+                    None,
+                    None,
+                    Some("low"),
+                )?;
+                let api = crate::db::create_task_sync(
+                    conn,
+                    project_id,
+                    None,
+                    "api",
+                    None,
+                    None,
+                    Some("high"),
+                )?;
+                Ok((schema, api))
+            })
+            .await
+            .unwrap();
+
+        let mut req = request("add_task_dependency");
+        req.task_id = Some(api);
+        req.depends_on = Some(schema);
+        goal(&ctx, req).await.unwrap();
+
+        let output = goal(&ctx, request("next_tasks")).await.unwrap();
+        let Some(GoalData::NextTasks(data)) = output.0.data else {
+            panic!("expected NextTasks data");
+        };
+        let ids: Vec<i64> = data.tasks.iter().map(|t| t.id).collect();
+        assert_eq!(ids, vec![schema]);
+
+        // A cycle is refused at the tool layer too
+        let mut req = request("add_task_dependency");
+        req.task_id = Some(schema);
+        req.depends_on = Some(api);
+        let err = goal(&ctx, req).await.unwrap_err().to_string();
+        assert!(err.contains("cycle"), "{}", err);
+
+        let mut req = request("remove_task_dependency");
+        req.task_id = Some(api);
+        req.depends_on = Some(schema);
+        goal(&ctx, req).await.unwrap();
+
+        let output = goal(&ctx, request("next_tasks")).await.unwrap();
+        let Some(GoalData::NextTasks(data)) = output.0.data else {
+            panic!("expected NextTasks data");
+        };
+        let ids: Vec<i64> = data.tasks.iter().map(|t| t.id).collect();
+        assert_eq!(ids, vec![api, schema]);
+    }
+
+    #[tokio::test]
+    async fn test_task_template_instantiates_through_tool() {
+        use crate::tools::core::test_utils::MockToolContext;
+
+        let ctx = MockToolContext::with_project().await;
+        let mut req = request("create");
+        req.title = Some("Release 1.4".into());
+        let output = goal(&ctx, req).await.unwrap();
+        let Some(GoalData::Created(created)) = output.0.data else {
+            panic!("expected Created data");
+        };
+
+        let mut req = request("task_template");
+        req.template = Some("release".into());
+        req.items = Some(r#"["Bump version to {version}", "Tag v{version}"]"#.into());
+        goal(&ctx, req).await.unwrap();
+
+        let output = goal(&ctx, request("task_templates")).await.unwrap();
+        let Some(GoalData::TaskTemplates(list)) = output.0.data else {
+            panic!("expected TaskTemplates data");
+        };
+        assert_eq!(list.total, 1);
+        assert_eq!(list.templates[0].items.len(), 2);
+
+        // A missing variable is reported and nothing is created
+        let mut req = request("task_instantiate");
+        req.template = Some("release".into());
+        let err = goal(&ctx, req).await.unwrap_err().to_string();
+        assert!(err.contains("{version}"), "{}", err);
+
+        let mut req = request("task_instantiate");
+        req.template = Some("release".into());
+        req.goal_id = Some(created.goal_id);
+        req.vars = Some(r#"{"version": "1.4.0"}"#.into());
+        let output = goal(&ctx, req).await.unwrap();
+        let Some(GoalData::TasksCreated(data)) = output.0.data else {
+            panic!("expected TasksCreated data");
+        };
+        let titles: Vec<&str> = data.tasks.iter().map(|t| t.title.as_str()).collect();
+        assert_eq!(titles, vec!["Bump version to 1.4.0", "Tag v1.4.0"]);
+        assert!(
+            data.tasks
+                .iter()
+                .all(|t| t.goal_id == Some(created.goal_id))
+        );
+
+        // The new tasks show up as actionable
+        let output = goal(&ctx, request("next_tasks")).await.unwrap();
+        let Some(GoalData::NextTasks(next)) = output.0.data else {
+            panic!("expected NextTasks data");
+        };
+        assert_eq!(next.total, 2);
+    }
 }
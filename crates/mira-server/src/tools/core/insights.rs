@@ -1,10 +1,13 @@
 // crates/mira-server/src/tools/core/insights.rs
 // Insights tool implementation (extracted from session.rs)
 
+use crate::db::suggestions::get_proactive_suggestions_sync;
 use crate::db::{compute_age_days, dismiss_insight_sync, get_unified_insights_sync};
 use crate::error::MiraError;
 use crate::mcp::responses::Json;
-use crate::mcp::responses::{InsightItem, InsightsData, SessionData, SessionOutput};
+use crate::mcp::responses::{
+    InsightItem, InsightsData, SessionData, SessionOutput, SuggestionItem, SuggestionsData,
+};
 use crate::tools::core::{ToolContext, require_project_id};
 
 /// Category display order and human-readable labels.
@@ -251,6 +254,64 @@ pub async fn dismiss_insight<C: ToolContext>(
     }))
 }
 
+/// Default days without activity before a goal or task counts as stale
+const DEFAULT_STALE_DAYS: i64 = 14;
+/// Cap per suggestion kind
+const SUGGESTIONS_PER_KIND: usize = 5;
+
+/// What's falling through the cracks: stale in-progress goals, untouched
+/// tasks, and files churned recently without test changes.
+pub async fn proactive_suggestions<C: ToolContext>(
+    ctx: &C,
+    stale_days: Option<i64>,
+) -> Result<Json<SessionOutput>, MiraError> {
+    let stale_days = stale_days.unwrap_or(DEFAULT_STALE_DAYS).clamp(1, 365);
+    let project_id = require_project_id(ctx).await?;
+
+    let found = ctx
+        .pool()
+        .run(move |conn| {
+            get_proactive_suggestions_sync(conn, project_id, stale_days, SUGGESTIONS_PER_KIND)
+        })
+        .await?;
+
+    let message = if found.is_empty() {
+        format!(
+            "Nothing falling through the cracks (no goals or tasks idle for {}+ days, no untested churn).",
+            stale_days
+        )
+    } else {
+        let mut message = format!("{} suggestions:\n", found.len());
+        for s in &found {
+            let subject = match s.id {
+                Some(id) => format!("#{} {}", id, s.subject),
+                None => s.subject.clone(),
+            };
+            message.push_str(&format!("  [{}] {}: {}\n", s.kind, subject, s.rationale));
+        }
+        message
+    };
+
+    let suggestions: Vec<SuggestionItem> = found
+        .into_iter()
+        .map(|s| SuggestionItem {
+            kind: s.kind.to_string(),
+            id: s.id,
+            subject: s.subject,
+            rationale: s.rationale,
+        })
+        .collect();
+    let total = suggestions.len();
+    Ok(Json(SessionOutput {
+        action: "suggestions".into(),
+        message,
+        data: Some(SessionData::Suggestions(SuggestionsData {
+            suggestions,
+            total,
+        })),
+    }))
+}
+
 /// Format an insight timestamp as a human-readable age suffix.
 pub(crate) fn format_age(timestamp: &str) -> String {
     let age_days = compute_age_days(timestamp);
@@ -144,6 +144,13 @@ pub async fn get_project_info<C: ToolContext + ?Sized>(ctx: &C) -> ProjectInfo {
     }
 }
 
+/// Effective config for the active project: its `.mira/config.toml` layered
+/// over the global file.
+pub async fn project_config<C: ToolContext + ?Sized>(ctx: &C) -> crate::config::MiraConfig {
+    let path = ctx.get_project().await.map(|p| p.path);
+    crate::config::MiraConfig::for_project(path.as_deref())
+}
+
 // Shared test utilities (only compiled during tests)
 #[cfg(test)]
 pub(crate) mod test_utils;
@@ -155,7 +162,9 @@ pub mod documentation;
 pub mod goals;
 pub mod insights;
 pub mod launch;
+pub mod modules;
 pub mod project;
+pub mod search_all;
 pub mod session;
 pub mod session_notes;
 pub mod tasks;
@@ -164,15 +173,18 @@ pub mod usage;
 
 // Re-export handler functions used by MCP router, CLI, and tests
 pub use code::{
-    find_function_callees, find_function_callers, get_symbols, handle_code, index, query_callees,
-    query_callers, query_search_code, search_code, summarize_codebase,
+    find_function_callees, find_function_callers, get_symbols, handle_code, index,
+    list_dead_letters, query_callees, query_callers, query_search_code, retry_dead_letter,
+    search_code, summarize_codebase, symbol_impact,
 };
 pub use diff::{analyze_diff_tool, list_diff_analyses};
 pub use documentation::documentation;
 pub use goals::goal;
-pub use insights::{dismiss_insight, query_insights};
+pub use insights::{dismiss_insight, proactive_suggestions, query_insights};
 pub use launch::handle_launch;
-pub use project::{get_project, project, session_start, set_project};
+pub use modules::module;
+pub use project::{attach_root, get_project, project, session_start, set_project};
+pub use search_all::search_all;
 pub use session::{ensure_session, get_session_recap, handle_session};
 pub use team::handle_team;
-pub use usage::{usage_list, usage_stats, usage_summary};
+pub use usage::{usage_costs, usage_list, usage_stats, usage_summary};
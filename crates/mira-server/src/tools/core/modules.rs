@@ -0,0 +1,255 @@
+// crates/mira-server/src/tools/core/modules.rs
+// Module cards - per-module summaries kept fresh by the background worker
+
+use crate::background::module_cards::card_one_liner;
+use crate::cartographer::ModuleCard;
+use crate::db::module_cards::{
+    get_module_card_sync, get_module_cards_sync, search_module_cards_sync,
+};
+use crate::error::MiraError;
+use crate::mcp::requests::{ModuleAction, ModuleRequest};
+use crate::mcp::responses::Json;
+use crate::mcp::responses::{ModuleCardItem, ModuleData, ModuleListData, ModuleOutput};
+use crate::tools::core::{ToolContext, require_project_id};
+
+/// Hint shown when a project has no cards yet
+const NO_CARDS_HINT: &str = "No module cards yet. Cards are generated in the background after the project is indexed (index(action=\"project\")).";
+
+fn card_item(card: ModuleCard, score: Option<usize>) -> ModuleCardItem {
+    ModuleCardItem {
+        module_id: card.module_id,
+        name: card.name,
+        path: card.path,
+        summary: card.summary,
+        key_types: card.key_types,
+        entry_points: card.entry_points,
+        dependencies: card.dependencies,
+        updated_at: card.updated_at,
+        score,
+    }
+}
+
+/// Handle module tool actions: list, get, search
+pub async fn module<C: ToolContext>(
+    ctx: &C,
+    req: ModuleRequest,
+) -> Result<Json<ModuleOutput>, MiraError> {
+    let project_id = require_project_id(ctx).await?;
+    match req.action {
+        ModuleAction::List => list_modules(ctx, project_id).await,
+        ModuleAction::Get => {
+            let module_id = req
+                .module_id
+                .filter(|m| !m.trim().is_empty())
+                .ok_or_else(|| {
+                    MiraError::InvalidInput("module_id is required for action 'get'".to_string())
+                })?;
+            get_module(ctx, project_id, module_id).await
+        }
+        ModuleAction::Search => {
+            let query = req.query.filter(|q| !q.trim().is_empty()).ok_or_else(|| {
+                MiraError::InvalidInput("query is required for action 'search'".to_string())
+            })?;
+            let limit = req.limit.unwrap_or(10).clamp(1, 100) as usize;
+            search_modules(ctx, project_id, query, limit).await
+        }
+    }
+}
+
+async fn list_modules<C: ToolContext>(
+    ctx: &C,
+    project_id: i64,
+) -> Result<Json<ModuleOutput>, MiraError> {
+    let cards = ctx
+        .code_pool()
+        .run(move |conn| get_module_cards_sync(conn, project_id))
+        .await?;
+
+    let message = if cards.is_empty() {
+        NO_CARDS_HINT.to_string()
+    } else {
+        let mut out = format!("## Modules ({})\n\n", cards.len());
+        for card in &cards {
+            out.push_str(&format!(
+                "  {} - {}\n",
+                card.module_id,
+                card_one_liner(&card.summary)
+            ));
+        }
+        out
+    };
+
+    let total = cards.len();
+    Ok(Json(ModuleOutput {
+        action: "list".into(),
+        message,
+        data: Some(ModuleData::List(ModuleListData {
+            modules: cards.into_iter().map(|c| card_item(c, None)).collect(),
+            total,
+        })),
+    }))
+}
+
+async fn get_module<C: ToolContext>(
+    ctx: &C,
+    project_id: i64,
+    module_id: String,
+) -> Result<Json<ModuleOutput>, MiraError> {
+    let id = module_id.clone();
+    let card = ctx
+        .code_pool()
+        .run(move |conn| get_module_card_sync(conn, project_id, &id))
+        .await?
+        .ok_or_else(|| {
+            MiraError::NotFound(format!(
+                "No card for module '{}'. Use module(action=\"list\") to see available modules.",
+                module_id
+            ))
+        })?;
+
+    let mut message = format!(
+        "## {} ({})\n\n{}\n",
+        card.module_id, card.path, card.summary
+    );
+    for (label, items) in [
+        ("Key types", &card.key_types),
+        ("Entry points", &card.entry_points),
+        ("Depends on", &card.dependencies),
+    ] {
+        if !items.is_empty() {
+            message.push_str(&format!("\n{}: {}", label, items.join(", ")));
+        }
+    }
+
+    Ok(Json(ModuleOutput {
+        action: "get".into(),
+        message,
+        data: Some(ModuleData::Get(card_item(card, None))),
+    }))
+}
+
+async fn search_modules<C: ToolContext>(
+    ctx: &C,
+    project_id: i64,
+    query: String,
+    limit: usize,
+) -> Result<Json<ModuleOutput>, MiraError> {
+    let q = query.clone();
+    let hits = ctx
+        .code_pool()
+        .run(move |conn| search_module_cards_sync(conn, project_id, &q, limit))
+        .await?;
+
+    let message = if hits.is_empty() {
+        format!("No modules match '{}'.", query)
+    } else {
+        let mut out = format!("## Modules matching '{}'\n\n", query);
+        for (card, _) in &hits {
+            out.push_str(&format!(
+                "  {} - {}\n",
+                card.module_id,
+                card_one_liner(&card.summary)
+            ));
+        }
+        out
+    };
+
+    let total = hits.len();
+    Ok(Json(ModuleOutput {
+        action: "search".into(),
+        message,
+        data: Some(ModuleData::List(ModuleListData {
+            modules: hits
+                .into_iter()
+                .map(|(c, score)| card_item(c, Some(score)))
+                .collect(),
+            total,
+        })),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cartographer::Module;
+    use crate::db::module_cards::upsert_module_card_sync;
+    use crate::tools::core::test_utils::MockToolContext;
+
+    fn request(
+        action: ModuleAction,
+        module_id: Option<&str>,
+        query: Option<&str>,
+    ) -> ModuleRequest {
+        ModuleRequest {
+            action,
+            module_id: module_id.map(String::from),
+            query: query.map(String::from),
+            limit: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_module_actions() {
+        let ctx = MockToolContext::with_project().await;
+        let project_id = ctx.project_id().await.unwrap();
+        ctx.code_pool()
+            .run(move |conn| {
+                crate::db::upsert_module_sync(
+                    conn,
+                    project_id,
+                    &Module::new("db", "db", "src/db"),
+                )?;
+                upsert_module_card_sync(
+                    conn,
+                    project_id,
+                    &ModuleCard {
+                        module_id: "db".into(),
+                        name: "db".into(),
+                        path: "src/db".into(),
+                        summary: "Connection pooling and migrations. Owns the schema.".into(),
+                        key_types: vec!["DatabasePool".into()],
+                        entry_points: vec![],
+                        dependencies: vec!["config".into()],
+                        content_hash: "h".into(),
+                        updated_at: None,
+                    },
+                )
+            })
+            .await
+            .unwrap();
+
+        let list = module(&ctx, request(ModuleAction::List, None, None))
+            .await
+            .unwrap();
+        assert!(
+            list.0
+                .message
+                .contains("db - Connection pooling and migrations")
+        );
+
+        let get = module(&ctx, request(ModuleAction::Get, Some("db"), None))
+            .await
+            .unwrap();
+        assert!(get.0.message.contains("Key types: DatabasePool"));
+        assert!(get.0.message.contains("Depends on: config"));
+
+        let search = module(&ctx, request(ModuleAction::Search, None, Some("pool")))
+            .await
+            .unwrap();
+        match search.0.data {
+            Some(ModuleData::List(data)) => assert_eq!(data.total, 1),
+            other => panic!("Expected ModuleData::List, got {:?}", other),
+        }
+
+        assert!(
+            module(&ctx, request(ModuleAction::Get, Some("nope"), None))
+                .await
+                .is_err()
+        );
+        assert!(
+            module(&ctx, request(ModuleAction::Search, None, None))
+                .await
+                .is_err()
+        );
+    }
+}
@@ -1,9 +1,15 @@
 // tools/core/project/detection.rs
 // Project detection utilities: name, type, and system context gathering
 
+use crate::config::ignore::{load_project_ignore_patterns, should_skip_with_patterns};
+use std::cmp::Reverse;
+use std::collections::HashMap;
 use std::path::Path;
 use std::process::Command;
 
+/// Maximum files examined when ranking languages by source file count
+const LANGUAGE_SCAN_LIMIT: usize = 20_000;
+
 /// Auto-detect project name from path (sync helper)
 pub(super) fn detect_project_name(path: &str) -> Option<String> {
     let path = Path::new(path);
@@ -56,9 +62,12 @@ pub(super) fn detect_project_name(path: &str) -> Option<String> {
 
 /// Detect all project types from path (polyglot support).
 ///
-/// Returns all detected languages based on manifest files present. A monorepo
-/// with both `Cargo.toml` and `package.json` will return `["rust", "node"]`.
-/// Returns `["unknown"]` if no known manifests are found.
+/// Returns all detected languages based on manifest files present, primary
+/// first. When several manifests are present the languages are ranked by how
+/// many source files each has, so a monorepo with a small `Cargo.toml` helper
+/// and a large TypeScript app returns `["node", "rust"]`. Ties keep manifest
+/// order (rust, node, python, go, java). Returns `["unknown"]` if no known
+/// manifests are found.
 pub fn detect_project_types(path: &str) -> Vec<&'static str> {
     let p = Path::new(path);
     let mut types = Vec::new();
@@ -83,13 +92,59 @@ pub fn detect_project_types(path: &str) -> Vec<&'static str> {
 
     if types.is_empty() {
         types.push("unknown");
+    } else if types.len() > 1 {
+        let counts = count_source_files(p);
+        types.sort_by_key(|t| Reverse(counts.get(t).copied().unwrap_or(0)));
     }
     types
 }
 
+/// Map a source file extension to the project type it counts toward
+fn language_for_extension(ext: &str) -> Option<&'static str> {
+    match ext {
+        "rs" => Some("rust"),
+        "js" | "jsx" | "mjs" | "cjs" | "ts" | "tsx" => Some("node"),
+        "py" => Some("python"),
+        "go" => Some("go"),
+        "java" | "kt" => Some("java"),
+        _ => None,
+    }
+}
+
+/// Count source files per project type, honoring the common skip list and
+/// `.miraignore`. Stops after `LANGUAGE_SCAN_LIMIT` files.
+fn count_source_files(root: &Path) -> HashMap<&'static str, usize> {
+    let extra_patterns = load_project_ignore_patterns(root);
+    let mut counts = HashMap::new();
+    let files = walkdir::WalkDir::new(root)
+        .into_iter()
+        .filter_entry(|e| {
+            e.depth() == 0
+                || !e.file_type().is_dir()
+                || !e
+                    .file_name()
+                    .to_str()
+                    .is_some_and(|name| should_skip_with_patterns(name, &extra_patterns))
+        })
+        .filter_map(Result::ok)
+        .filter(|e| e.file_type().is_file())
+        .take(LANGUAGE_SCAN_LIMIT);
+    for entry in files {
+        if let Some(lang) = entry
+            .path()
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .and_then(language_for_extension)
+        {
+            *counts.entry(lang).or_insert(0) += 1;
+        }
+    }
+    counts
+}
+
 /// Detect primary project type from path.
 ///
-/// For polyglot projects, returns the first (highest priority) language.
+/// For polyglot projects, returns the language with the most source files.
 /// Use `detect_project_types` to get all detected languages.
 pub fn detect_project_type(path: &str) -> &'static str {
     detect_project_types(path)
@@ -237,6 +292,52 @@ mod tests {
         assert_eq!(primary, all[0]);
     }
 
+    /// Build a fixture root from (relative path, contents) pairs
+    fn fixture(files: &[(&str, &str)]) -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        for (rel, content) in files {
+            let path = dir.path().join(rel);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, content).unwrap();
+        }
+        dir
+    }
+
+    #[test]
+    fn detect_project_types_mixed_repo_primary_by_file_count() {
+        let dir = fixture(&[
+            ("Cargo.toml", "[package]\nname = \"helper\"\n"),
+            ("src/lib.rs", ""),
+            ("pyproject.toml", "[project]\n"),
+            ("app/__init__.py", ""),
+            ("app/models.py", ""),
+            ("app/views.py", ""),
+            // Ignored directories don't count toward the ranking
+            ("target/debug/build.rs", ""),
+            ("target/debug/gen.rs", ""),
+            ("target/debug/more.rs", ""),
+        ]);
+        let path = dir.path().to_str().unwrap();
+        assert_eq!(detect_project_types(path), vec!["python", "rust"]);
+        assert_eq!(detect_project_type(path), "python");
+    }
+
+    #[test]
+    fn detect_project_types_mixed_repo_respects_miraignore() {
+        let dir = fixture(&[
+            ("go.mod", "module x\n"),
+            ("main.go", ""),
+            ("package.json", "{}"),
+            ("web/a.ts", ""),
+            ("web/b.ts", ""),
+            (".miraignore", "web\n"),
+        ]);
+        assert_eq!(
+            detect_project_types(dir.path().to_str().unwrap()),
+            vec!["go", "node"]
+        );
+    }
+
     /// Verify that gather_system_context_content does NOT leak PII fields.
     /// Commit d99d5a50 intentionally removed User: and Home: from the output.
     #[test]
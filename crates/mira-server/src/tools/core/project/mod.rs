@@ -5,14 +5,24 @@ mod detection;
 mod formatting;
 mod session_start;
 
+use std::path::Path;
+
 use mira_types::ProjectContext;
 
-use crate::db::{get_or_create_project_sync, save_active_project_sync, update_project_name_sync};
+use crate::db::{
+    add_project_root_sync, find_root_conflicts_sync, get_or_create_project_sync,
+    get_project_path_sync, get_project_roots_sync, save_active_project_sync,
+    update_project_languages_sync, update_project_name_sync,
+};
 use crate::error::MiraError;
 use crate::mcp::requests::ProjectAction;
 use crate::mcp::responses::Json;
-use crate::mcp::responses::{ProjectData, ProjectGetData, ProjectOutput, ProjectSetData};
+use crate::mcp::responses::{
+    ProjectData, ProjectGetData, ProjectOutput, ProjectRootData, ProjectSetData,
+};
+use crate::project_roots::{self, ProjectRoot};
 use crate::tools::core::ToolContext;
+use crate::utils::normalize_project_path;
 
 pub use detection::{detect_project_type, detect_project_types};
 pub use session_start::session_start;
@@ -24,11 +34,14 @@ type SessionInfo = (String, String, Option<String>, usize, Vec<String>);
 type RecapData = Vec<(String, i64)>;
 
 /// Shared project initialization logic
+///
+/// Returns (project_id, project_name, languages); languages are primary-first
+/// as returned by `detect_project_types` and are recorded on the project row.
 async fn init_project<C: ToolContext>(
     ctx: &C,
     project_path: &str,
     name: Option<&str>,
-) -> Result<(i64, Option<String>), MiraError> {
+) -> Result<(i64, Option<String>, Vec<&'static str>), MiraError> {
     // Use pool for project creation (ensures same database as memory operations)
     let path_owned = project_path.to_string();
     let name_owned = name.map(|s| s.to_string());
@@ -37,6 +50,18 @@ async fn init_project<C: ToolContext>(
         .run(move |conn| get_or_create_project_sync(conn, &path_owned, name_owned.as_deref()))
         .await?;
 
+    // A path inside an attached root resolves to the owning project; continue
+    // from that project's primary path.
+    let primary_path = ctx
+        .pool()
+        .run(move |conn| get_project_path_sync(conn, project_id))
+        .await?;
+    let project_path = if normalize_project_path(project_path) == primary_path {
+        project_path
+    } else {
+        primary_path.as_str()
+    };
+
     // If we have a stored name, use it; otherwise detect from files
     let project_name = if stored_name.is_some() {
         stored_name
@@ -52,6 +77,17 @@ async fn init_project<C: ToolContext>(
         detected
     };
 
+    // Detect languages off the runtime (ranking walks the source tree)
+    let detect_path = project_path.to_string();
+    let languages =
+        tokio::task::spawn_blocking(move || detection::detect_project_types(&detect_path))
+            .await
+            .unwrap_or_else(|_| vec!["unknown"]);
+    let languages_owned = languages.clone();
+    ctx.pool()
+        .run(move |conn| update_project_languages_sync(conn, project_id, &languages_owned))
+        .await?;
+
     let project_ctx = ProjectContext {
         id: project_id,
         path: project_path.to_string(),
@@ -66,6 +102,7 @@ async fn init_project<C: ToolContext>(
             .watch(project_id, std::path::PathBuf::from(project_path))
             .await;
     }
+    load_project_roots(ctx, project_id, project_path).await?;
 
     // Persist active project for restart recovery
     let path_for_save = project_path.to_string();
@@ -77,24 +114,203 @@ async fn init_project<C: ToolContext>(
         tracing::warn!("Failed to persist active project: {}", e);
     }
 
-    Ok((project_id, project_name))
+    Ok((project_id, project_name, languages))
 }
 
-/// Set current project
+/// Load a project's attached roots, register them for `@name/` path
+/// resolution, and add them to the file watcher.
+async fn load_project_roots<C: ToolContext>(
+    ctx: &C,
+    project_id: i64,
+    project_path: &str,
+) -> Result<Vec<ProjectRoot>, MiraError> {
+    let rows = ctx
+        .pool()
+        .run(move |conn| get_project_roots_sync(conn, project_id))
+        .await?;
+    let roots = project_roots::roots_from_rows(rows);
+    project_roots::register_roots(Path::new(project_path), roots.clone());
+
+    if let Some(watcher) = ctx.watcher() {
+        for root in &roots {
+            watcher
+                .watch_root(project_id, root.name.clone(), root.path.clone())
+                .await;
+        }
+    }
+    Ok(roots)
+}
+
+/// Validate an additional root for a project. Returns (normalized path, root name).
+///
+/// The name defaults to the directory's final component. Roots must be existing
+/// directories that neither contain nor sit inside the primary project path.
+fn validate_root(
+    project_path: &str,
+    root_path: &str,
+    name: Option<&str>,
+) -> Result<(String, String), MiraError> {
+    let normalized = normalize_project_path(root_path);
+    let root = Path::new(&normalized);
+    if !root.is_dir() {
+        return Err(MiraError::InvalidInput(format!(
+            "Root path '{}' is not an existing directory",
+            root_path
+        )));
+    }
+    let primary = normalize_project_path(project_path);
+    if root.starts_with(&primary) || Path::new(&primary).starts_with(root) {
+        return Err(MiraError::InvalidInput(format!(
+            "Root path '{}' overlaps the project path '{}'",
+            root_path, project_path
+        )));
+    }
+
+    let root_name = match name {
+        Some(n) => {
+            project_roots::validate_root_name(n).map_err(MiraError::InvalidInput)?;
+            n.to_string()
+        }
+        None => project_roots::default_root_name(root).ok_or_else(|| {
+            MiraError::InvalidInput(format!(
+                "Cannot derive a root name from '{}'. Provide one with name",
+                root_path
+            ))
+        })?,
+    };
+    Ok((normalized, root_name))
+}
+
+/// Store validated roots for a project in one transaction.
+///
+/// Nothing is attached if any root would take over another project (its path
+/// or one of its roots sits at or below the new root) or reuses a root name of
+/// this project for a different path.
+async fn store_roots<C: ToolContext>(
+    ctx: &C,
+    project_id: i64,
+    roots: Vec<(String, String)>,
+) -> Result<(), MiraError> {
+    ctx.pool()
+        .run(move |conn| {
+            let tx = conn.unchecked_transaction()?;
+            let mut names = get_project_roots_sync(&tx, project_id)?;
+            for (path, name) in &roots {
+                if names.iter().any(|(n, p)| n == name && p != path) {
+                    return Err(MiraError::InvalidInput(format!(
+                        "Root name '{}' is already used by another root of this project",
+                        name
+                    )));
+                }
+                let conflicts = find_root_conflicts_sync(&tx, project_id, path)?;
+                if !conflicts.is_empty() {
+                    return Err(MiraError::InvalidInput(format!(
+                        "Root path '{}' overlaps paths registered to other projects: {}",
+                        path,
+                        conflicts.join(", ")
+                    )));
+                }
+                add_project_root_sync(&tx, project_id, path, name)?;
+                names.push((name.clone(), path.clone()));
+            }
+            tx.commit()?;
+            Ok::<_, MiraError>(())
+        })
+        .await
+}
+
+fn root_data(roots: &[ProjectRoot]) -> Vec<ProjectRootData> {
+    roots
+        .iter()
+        .map(|r| ProjectRootData {
+            name: r.name.clone(),
+            path: r.path.to_string_lossy().to_string(),
+        })
+        .collect()
+}
+
+fn format_roots(roots: &[ProjectRoot]) -> String {
+    roots
+        .iter()
+        .map(|r| format!("\n  Root @{}: {}", r.name, r.path.display()))
+        .collect()
+}
+
+/// Set current project, optionally attaching additional root directories
 pub async fn set_project<C: ToolContext>(
     ctx: &C,
     project_path: String,
     name: Option<String>,
+    additional_roots: Vec<String>,
 ) -> Result<Json<ProjectOutput>, MiraError> {
-    let (project_id, project_name) = init_project(ctx, &project_path, name.as_deref()).await?;
+    let (project_id, project_name, languages) =
+        init_project(ctx, &project_path, name.as_deref()).await?;
+
+    // init_project may have resolved to the primary path of an owning project
+    let primary_path = ctx
+        .get_project()
+        .await
+        .map(|p| p.path)
+        .unwrap_or_else(|| project_path.clone());
+    let new_roots = additional_roots
+        .iter()
+        .map(|root_path| validate_root(&primary_path, root_path, None))
+        .collect::<Result<Vec<_>, _>>()?;
+    store_roots(ctx, project_id, new_roots).await?;
+    let roots = if additional_roots.is_empty() {
+        project_roots::registered_roots(Path::new(&primary_path))
+    } else {
+        load_project_roots(ctx, project_id, &primary_path).await?
+    };
 
     let display_name = project_name.as_deref().unwrap_or(&project_path);
     Ok(Json(ProjectOutput {
         action: "set".into(),
-        message: format!("Project set: {} (id: {})", display_name, project_id),
+        message: format!(
+            "Project set: {} (id: {}, {}){}",
+            display_name,
+            project_id,
+            languages.join(", "),
+            format_roots(&roots)
+        ),
         data: Some(ProjectData::Set(ProjectSetData {
             project_id,
             project_name,
+            project_type: languages[0].to_string(),
+            languages: languages.iter().map(|l| l.to_string()).collect(),
+            roots: root_data(&roots),
+        })),
+    }))
+}
+
+/// Attach a directory to the active project as an additional root.
+///
+/// Memories, sessions and search stay scoped to the project; the root's files
+/// are indexed as `@name/...` on the next `index_project()`.
+pub async fn attach_root<C: ToolContext>(
+    ctx: &C,
+    root_path: String,
+    name: Option<String>,
+) -> Result<Json<ProjectOutput>, MiraError> {
+    let project = ctx.get_project().await.ok_or(MiraError::ProjectNotSet)?;
+    let (normalized, root_name) = validate_root(&project.path, &root_path, name.as_deref())?;
+    store_roots(ctx, project.id, vec![(normalized, root_name.clone())]).await?;
+    let roots = load_project_roots(ctx, project.id, &project.path).await?;
+
+    Ok(Json(ProjectOutput {
+        action: "attach_root".into(),
+        message: format!(
+            "Attached {} to project {} as @{}. Run index_project() to index it.{}",
+            root_path,
+            project.name.as_deref().unwrap_or(&project.path),
+            root_name,
+            format_roots(&roots)
+        ),
+        data: Some(ProjectData::Get(ProjectGetData {
+            project_id: project.id,
+            project_name: project.name,
+            project_path: project.path,
+            roots: root_data(&roots),
         })),
     }))
 }
@@ -104,33 +320,45 @@ pub async fn get_project<C: ToolContext>(ctx: &C) -> Result<Json<ProjectOutput>,
     let project = ctx.get_project().await;
 
     match project {
-        Some(p) => Ok(Json(ProjectOutput {
-            action: "get".into(),
-            message: format!(
-                "Current project:\n  Path: {}\n  Name: {}\n  ID: {}",
-                p.path,
-                p.name.as_deref().unwrap_or("(unnamed)"),
-                p.id
-            ),
-            data: Some(ProjectData::Get(ProjectGetData {
-                project_id: p.id,
-                project_name: p.name,
-                project_path: p.path,
-            })),
-        })),
+        Some(p) => {
+            let roots = project_roots::registered_roots(Path::new(&p.path));
+            Ok(Json(ProjectOutput {
+                action: "get".into(),
+                message: format!(
+                    "Current project:\n  Path: {}\n  Name: {}\n  ID: {}{}",
+                    p.path,
+                    p.name.as_deref().unwrap_or("(unnamed)"),
+                    p.id,
+                    format_roots(&roots)
+                ),
+                data: Some(ProjectData::Get(ProjectGetData {
+                    project_id: p.id,
+                    project_name: p.name,
+                    project_path: p.path,
+                    roots: root_data(&roots),
+                })),
+            }))
+        }
         None => Err(MiraError::ProjectNotSet),
     }
 }
 
 /// Unified project tool with action parameter
-/// Actions: start (session_start), set (set_project), get (get_project)
+/// Actions: start (session_start), set (set_project), get (get_project),
+/// attach_root (attach_root)
 pub async fn project<C: ToolContext>(
     ctx: &C,
     action: ProjectAction,
     project_path: Option<String>,
     name: Option<String>,
     session_id: Option<String>,
+    additional_roots: Option<Vec<String>>,
 ) -> Result<Json<ProjectOutput>, MiraError> {
+    if additional_roots.is_some() && !matches!(action, ProjectAction::Set) {
+        return Err(MiraError::InvalidInput(
+            "additional_roots is only supported for project(action=set)".to_string(),
+        ));
+    }
     match action {
         ProjectAction::Start => {
             let path = project_path.ok_or_else(|| {
@@ -146,9 +374,17 @@ pub async fn project<C: ToolContext>(
                     "project_path is required for project(action=set)".to_string(),
                 )
             })?;
-            set_project(ctx, path, name).await
+            set_project(ctx, path, name, additional_roots.unwrap_or_default()).await
         }
         ProjectAction::Get => get_project(ctx).await,
+        ProjectAction::AttachRoot => {
+            let path = project_path.ok_or_else(|| {
+                MiraError::InvalidInput(
+                    "project_path is required for project(action=attach_root)".to_string(),
+                )
+            })?;
+            attach_root(ctx, path, name).await
+        }
     }
 }
 
@@ -401,4 +637,137 @@ mod tests {
         let result = format_session_insights(&[]);
         assert!(result.is_empty());
     }
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Attached roots
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    #[tokio::test]
+    async fn test_set_project_attaches_additional_roots() {
+        use crate::tools::core::test_utils::MockToolContext;
+
+        // Root names default to the directory name, so use readable ones
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir(dir.path().join("api")).unwrap();
+        std::fs::create_dir(dir.path().join("web")).unwrap();
+        let api_path = normalize_project_path(&dir.path().join("api").to_string_lossy());
+        let web_path = normalize_project_path(&dir.path().join("web").to_string_lossy());
+        let ctx = MockToolContext::new().await;
+
+        let output = project(
+            &ctx,
+            ProjectAction::Set,
+            Some(api_path.clone()),
+            Some("product".into()),
+            None,
+            Some(vec![web_path.clone()]),
+        )
+        .await
+        .unwrap();
+        let api_id = match output.0.data {
+            Some(ProjectData::Set(data)) => {
+                assert_eq!(data.roots.len(), 1);
+                assert_eq!(data.roots[0].path, web_path);
+                data.project_id
+            }
+            other => panic!("Expected ProjectData::Set, got {:?}", other),
+        };
+
+        // Setting the project from inside the attached root resolves to the owner
+        project(&ctx, ProjectAction::Set, Some(web_path), None, None, None)
+            .await
+            .unwrap();
+        let current = ctx.get_project().await.unwrap();
+        assert_eq!(current.id, api_id);
+        assert_eq!(current.path, api_path);
+
+        let output = get_project(&ctx).await.unwrap();
+        assert!(
+            output.0.message.contains("Root @web"),
+            "{}",
+            output.0.message
+        );
+
+        project_roots::register_roots(Path::new(&api_path), vec![]);
+    }
+
+    #[tokio::test]
+    async fn test_attach_root_rejects_overlapping_paths() {
+        use crate::tools::core::test_utils::MockToolContext;
+
+        let api = tempfile::tempdir().unwrap();
+        std::fs::create_dir(api.path().join("sub")).unwrap();
+        let api_path = normalize_project_path(&api.path().to_string_lossy());
+        let ctx = MockToolContext::new().await;
+        project(
+            &ctx,
+            ProjectAction::Set,
+            Some(api_path.clone()),
+            None,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+
+        let nested = format!("{}/sub", api_path);
+        assert!(attach_root(&ctx, nested, None).await.is_err());
+        assert!(
+            attach_root(&ctx, "/definitely/not/here".into(), None)
+                .await
+                .is_err()
+        );
+        // additional_roots is only accepted by set
+        assert!(
+            project(&ctx, ProjectAction::Get, None, None, None, Some(vec![]))
+                .await
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_roots_never_take_over_other_projects() {
+        use crate::tools::core::test_utils::MockToolContext;
+
+        let dir = tempfile::tempdir().unwrap();
+        for sub in ["api", "web", "shared", "shared/tool", "docs"] {
+            std::fs::create_dir(dir.path().join(sub)).unwrap();
+        }
+        let path = |sub: &str| normalize_project_path(&dir.path().join(sub).to_string_lossy());
+        let ctx = MockToolContext::new().await;
+        let set = |p: String, roots: Option<Vec<String>>| {
+            project(&ctx, ProjectAction::Set, Some(p), None, None, roots)
+        };
+
+        set(path("web"), None).await.unwrap();
+        set(path("shared/tool"), None).await.unwrap();
+        set(path("api"), None).await.unwrap();
+        let api_id = ctx.get_project().await.unwrap().id;
+
+        // Another project's primary path, or a directory containing one
+        let err = attach_root(&ctx, path("web"), None).await.unwrap_err();
+        assert!(err.to_string().contains("other projects"), "{err}");
+        assert!(attach_root(&ctx, path("shared"), None).await.is_err());
+
+        // One bad root attaches none of them
+        assert!(
+            set(path("api"), Some(vec![path("docs"), path("shared")]))
+                .await
+                .is_err()
+        );
+        let roots = ctx
+            .pool()
+            .run(move |conn| get_project_roots_sync(conn, api_id))
+            .await
+            .unwrap();
+        assert!(roots.is_empty(), "{roots:?}");
+
+        // Another project's attached root stays with it
+        set(path("shared/tool"), Some(vec![path("docs")]))
+            .await
+            .unwrap();
+        project_roots::register_roots(Path::new(&path("shared/tool")), vec![]);
+        set(path("api"), None).await.unwrap();
+        assert!(attach_root(&ctx, path("docs"), None).await.is_err());
+    }
 }
@@ -1,12 +1,14 @@
 // tools/core/project/session_start.rs
 // Session initialization: start, persist, load history, recap data, onboarding
 
-use crate::cartographer;
+use crate::background::module_cards::card_one_liner;
+use crate::cartographer::{self, ModuleCard};
 use crate::db::documentation::count_doc_tasks_by_status;
+use crate::db::module_cards::get_module_cards_sync;
 use crate::db::{
-    StoreObservationParams, get_project_briefing_sync, get_recent_sessions_sync,
-    get_session_stats_sync, mark_session_for_briefing_sync, set_server_state_sync,
-    store_observation_sync, upsert_session_with_branch_sync,
+    StoreObservationParams, get_project_briefing_sync, get_recent_file_activity_sync,
+    get_recent_sessions_sync, get_session_stats_sync, mark_session_for_briefing_sync,
+    set_server_state_sync, store_observation_sync, upsert_session_with_branch_sync,
 };
 use crate::error::MiraError;
 use crate::git::get_git_branch;
@@ -14,7 +16,7 @@ use crate::mcp::responses::Json;
 use crate::mcp::responses::{ProjectData, ProjectOutput, ProjectStartData};
 use crate::tools::core::ToolContext;
 
-use super::detection::{detect_project_type, detect_project_types, gather_system_context_content};
+use super::detection::gather_system_context_content;
 use super::formatting::{format_recent_sessions, format_session_insights};
 use super::init_project;
 use super::{RecapData, SessionInfo};
@@ -107,6 +109,91 @@ async fn load_recap_data<C: ToolContext>(ctx: &C, project_id: i64) -> Result<Rec
         .await
 }
 
+/// Recently touched files considered when ranking modules
+const RECENT_FILE_LIMIT: i64 = 30;
+/// Modules listed under "Relevant modules"
+const RELEVANT_MODULE_LIMIT: usize = 3;
+
+/// Rank module cards by recent file touches. Each touched file counts toward
+/// the module with the longest matching path, weighted by recency.
+fn rank_modules_by_touches<'a>(
+    cards: &'a [ModuleCard],
+    touched_files: &[String],
+    project_path: &str,
+    limit: usize,
+) -> Vec<&'a ModuleCard> {
+    let root = project_path.trim_end_matches('/');
+    let mut scores = vec![0usize; cards.len()];
+    for (i, file) in touched_files.iter().enumerate() {
+        let rel = file
+            .strip_prefix(root)
+            .map(|r| r.trim_start_matches('/'))
+            .unwrap_or(file);
+        let owner = cards
+            .iter()
+            .enumerate()
+            .filter(|(_, c)| {
+                rel == c.path
+                    || rel
+                        .strip_prefix(c.path.as_str())
+                        .is_some_and(|rest| rest.starts_with('/'))
+            })
+            .max_by_key(|(_, c)| c.path.len());
+        if let Some((idx, _)) = owner {
+            scores[idx] += touched_files.len() - i;
+        }
+    }
+
+    let mut ranked: Vec<(usize, &ModuleCard)> = scores
+        .into_iter()
+        .zip(cards)
+        .filter(|(score, _)| *score > 0)
+        .collect();
+    ranked.sort_by(|a, b| b.0.cmp(&a.0));
+    ranked.into_iter().take(limit).map(|(_, c)| c).collect()
+}
+
+/// One-line cards for the modules most related to recently touched files
+async fn load_relevant_modules<C: ToolContext>(
+    ctx: &C,
+    project_id: i64,
+    project_path: &str,
+) -> Option<String> {
+    let touched = ctx
+        .pool()
+        .run(move |conn| {
+            Ok::<_, String>(get_recent_file_activity_sync(
+                conn,
+                project_id,
+                RECENT_FILE_LIMIT,
+            ))
+        })
+        .await
+        .ok()?;
+    if touched.is_empty() {
+        return None;
+    }
+    let cards = ctx
+        .code_pool()
+        .run(move |conn| get_module_cards_sync(conn, project_id))
+        .await
+        .ok()?;
+
+    let relevant = rank_modules_by_touches(&cards, &touched, project_path, RELEVANT_MODULE_LIMIT);
+    if relevant.is_empty() {
+        return None;
+    }
+    let mut out = String::from("\nRelevant modules (recent files):\n");
+    for card in relevant {
+        out.push_str(&format!(
+            "  {} - {}\n",
+            card.module_id,
+            card_one_liner(&card.summary)
+        ));
+    }
+    Some(out)
+}
+
 /// Initialize session with project
 pub async fn session_start<C: ToolContext>(
     ctx: &C,
@@ -114,7 +201,8 @@ pub async fn session_start<C: ToolContext>(
     name: Option<String>,
     session_id: Option<String>,
 ) -> Result<Json<ProjectOutput>, MiraError> {
-    let (project_id, project_name) = init_project(ctx, &project_path, name.as_deref()).await?;
+    let (project_id, project_name, project_types) =
+        init_project(ctx, &project_path, name.as_deref()).await?;
     let sid = session_id.unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
     let branch = get_git_branch(&project_path);
 
@@ -124,8 +212,7 @@ pub async fn session_start<C: ToolContext>(
     ctx.set_branch(branch.clone()).await;
 
     // Phase 2: Build response
-    let project_types = detect_project_types(&project_path);
-    let project_type = detect_project_type(&project_path);
+    let project_type = project_types[0];
     let display_name = project_name.as_deref().unwrap_or("unnamed");
     let type_label = if project_types.len() > 1 {
         project_types.join(", ")
@@ -175,6 +262,7 @@ pub async fn session_start<C: ToolContext>(
             project_path.clone(),
             display_name.to_string(),
             project_type.to_string(),
+            project_types.iter().map(|t| t.to_string()).collect(),
         )
         .await
         {
@@ -189,6 +277,10 @@ pub async fn session_start<C: ToolContext>(
         }
     }
 
+    if let Some(relevant) = load_relevant_modules(ctx, project_id, &project_path).await {
+        response.push_str(&relevant);
+    }
+
     if let Some(db_path) = ctx.pool().path() {
         response.push_str(&format!("\nDatabase: {}\n", db_path.display()));
     }
@@ -221,7 +313,14 @@ pub async fn session_start<C: ToolContext>(
     // Capability mode detection (background LLM removed; only embeddings matter)
     let has_embeddings = ctx.embeddings().is_some();
 
-    let (mode, mode_detail) = if has_embeddings {
+    let (mode, mode_detail) = if crate::config::env::is_offline() {
+        (
+            "offline",
+            Some(
+                "MIRA_OFFLINE is set: keyword + fuzzy search only, no LLM calls; new embeddings are queued until it is cleared",
+            ),
+        )
+    } else if has_embeddings {
         ("semantic", None)
     } else {
         (
@@ -324,6 +423,7 @@ pub async fn session_start<C: ToolContext>(
             project_name,
             project_path: project_path.clone(),
             project_type: project_type.to_string(),
+            languages: project_types.iter().map(|t| t.to_string()).collect(),
         })),
     }))
 }
@@ -406,6 +506,45 @@ mod tests {
         assert!(!is_safe_datetime_string("2026/01/15"));
     }
 
+    // =========================================================================
+    // rank_modules_by_touches
+    // =========================================================================
+
+    fn card(module_id: &str, path: &str) -> ModuleCard {
+        ModuleCard {
+            module_id: module_id.into(),
+            name: module_id.into(),
+            path: path.into(),
+            summary: format!("{module_id} summary"),
+            key_types: vec![],
+            entry_points: vec![],
+            dependencies: vec![],
+            content_hash: String::new(),
+            updated_at: None,
+        }
+    }
+
+    #[test]
+    fn relevant_modules_follow_recent_touches() {
+        let cards = vec![
+            card("db", "src/db"),
+            card("db/schema", "src/db/schema"),
+            card("cli", "src/cli"),
+            card("dbx", "src/dbx"),
+        ];
+        let touched = vec![
+            "/repo/src/db/schema/code.rs".to_string(),
+            "src/cli/tool.rs".to_string(),
+            "/repo/src/db/pool.rs".to_string(),
+            "/elsewhere/notes.md".to_string(),
+        ];
+        let ranked = rank_modules_by_touches(&cards, &touched, "/repo/", 3);
+        let ids: Vec<&str> = ranked.iter().map(|c| c.module_id.as_str()).collect();
+        // Each file counts toward its deepest module, weighted by recency
+        assert_eq!(ids, vec!["db/schema", "cli", "db"]);
+        assert!(rank_modules_by_touches(&cards, &[], "/repo", 3).is_empty());
+    }
+
     // =========================================================================
     // detect_project_type (re-exported from detection.rs)
     // These tests run against the real Mira repo on disk.
@@ -0,0 +1,347 @@
+// crates/mira-server/src/tools/core/search_all.rs
+// Unified search across code, session summaries, and documentation
+
+use crate::db::documentation::get_doc_inventory;
+use crate::db::get_recent_sessions_sync;
+use crate::error::MiraError;
+use crate::mcp::responses::Json;
+use crate::mcp::responses::{CodeData, CodeOutput, SearchAllData, UnifiedSearchResult};
+use crate::tools::core::{ToolContext, get_project_info};
+use crate::utils::truncate;
+
+use super::code::query_search_code;
+
+/// Most results any single source may contribute
+const PER_SOURCE_CAP: usize = 5;
+/// Recent sessions examined for summary matches
+const SESSION_SCAN_LIMIT: usize = 200;
+/// Snippet length in the unified result list
+const SNIPPET_LEN: usize = 200;
+
+const SOURCE_CODE: &str = "code";
+const SOURCE_SESSION: &str = "session";
+const SOURCE_DOCUMENT: &str = "document";
+
+/// Common words that would otherwise match almost every summary and title
+const STOPWORDS: &[&str] = &[
+    "a", "an", "and", "are", "as", "at", "be", "by", "do", "for", "from", "how", "in", "is", "it",
+    "of", "on", "or", "that", "the", "this", "to", "was", "what", "when", "where", "which", "with",
+];
+
+/// Search code, past session summaries, and tracked docs in one call.
+///
+/// Sources are queried concurrently. Every score is absolute in 0.0..=1.0
+/// (similarity for code, fraction of query terms matched for sessions and
+/// documents), so a weak source's best hit doesn't outrank a strong hit from
+/// another. Each source is capped at `PER_SOURCE_CAP` results, then all are
+/// merged by score. A source that fails is logged and skipped.
+pub async fn search_all<C: ToolContext>(
+    ctx: &C,
+    query: String,
+    limit: Option<i64>,
+) -> Result<Json<CodeOutput>, MiraError> {
+    if query.trim().is_empty() {
+        return Err(MiraError::InvalidInput(
+            "query is required for search_all".to_string(),
+        ));
+    }
+    let limit = limit.unwrap_or(10).clamp(1, 50) as usize;
+    let pi = get_project_info(ctx).await;
+    let terms = query_terms(&query);
+
+    let (code, sessions, documents) = tokio::join!(
+        search_code_source(ctx, &query),
+        search_session_source(ctx, pi.id, terms.clone()),
+        search_document_source(ctx, pi.id, terms),
+    );
+    let results = merge_sources(vec![code, sessions, documents], PER_SOURCE_CAP, limit);
+
+    let mut message = format!("{}Found {} results:\n", pi.header, results.len());
+    for r in &results {
+        let location = match r.line {
+            Some(line) => format!("{}:{}", r.reference, line),
+            None => r.reference.clone(),
+        };
+        message.push_str(&format!(
+            "  [{}] {:.2} {} {}\n",
+            r.source,
+            r.score,
+            location,
+            truncate(&r.snippet.replace('\n', " "), 80)
+        ));
+    }
+
+    let total = results.len();
+    Ok(Json(CodeOutput {
+        action: "search_all".into(),
+        message,
+        data: Some(CodeData::SearchAll(SearchAllData { results, total })),
+    }))
+}
+
+async fn search_code_source<C: ToolContext>(ctx: &C, query: &str) -> Vec<UnifiedSearchResult> {
+    match query_search_code(ctx, query, PER_SOURCE_CAP).await {
+        Ok(found) => found
+            .results
+            .into_iter()
+            .map(|r| UnifiedSearchResult {
+                source: SOURCE_CODE.into(),
+                score: r.score,
+                reference: r.file_path,
+                line: Some(r.start_line),
+                snippet: truncate(&r.content, SNIPPET_LEN),
+            })
+            .collect(),
+        Err(e) => {
+            tracing::warn!("search_all: code search failed: {}", e);
+            Vec::new()
+        }
+    }
+}
+
+async fn search_session_source<C: ToolContext>(
+    ctx: &C,
+    project_id: Option<i64>,
+    terms: Vec<String>,
+) -> Vec<UnifiedSearchResult> {
+    let Some(project_id) = project_id else {
+        return Vec::new();
+    };
+    let sessions = ctx
+        .pool()
+        .run(move |conn| get_recent_sessions_sync(conn, project_id, SESSION_SCAN_LIMIT))
+        .await;
+    match sessions {
+        Ok(sessions) => sessions
+            .into_iter()
+            .filter_map(|s| {
+                let summary = s.summary?;
+                let score = term_score(&summary, &terms);
+                (score > 0.0).then(|| UnifiedSearchResult {
+                    source: SOURCE_SESSION.into(),
+                    score,
+                    reference: s.id,
+                    line: None,
+                    snippet: truncate(&summary, SNIPPET_LEN),
+                })
+            })
+            .collect(),
+        Err(e) => {
+            tracing::warn!("search_all: session search failed: {}", e);
+            Vec::new()
+        }
+    }
+}
+
+async fn search_document_source<C: ToolContext>(
+    ctx: &C,
+    project_id: Option<i64>,
+    terms: Vec<String>,
+) -> Vec<UnifiedSearchResult> {
+    let Some(project_id) = project_id else {
+        return Vec::new();
+    };
+    let docs = ctx
+        .pool()
+        .run(move |conn| get_doc_inventory(conn, project_id))
+        .await;
+    match docs {
+        Ok(docs) => docs
+            .into_iter()
+            .filter_map(|d| {
+                let haystack = format!(
+                    "{} {} {}",
+                    d.doc_path,
+                    d.title.as_deref().unwrap_or_default(),
+                    d.source_symbols.as_deref().unwrap_or_default()
+                );
+                let score = term_score(&haystack, &terms);
+                (score > 0.0).then(|| UnifiedSearchResult {
+                    source: SOURCE_DOCUMENT.into(),
+                    score,
+                    snippet: d.title.unwrap_or_else(|| d.doc_path.clone()),
+                    reference: d.doc_path,
+                    line: None,
+                })
+            })
+            .collect(),
+        Err(e) => {
+            tracing::warn!("search_all: document search failed: {}", e);
+            Vec::new()
+        }
+    }
+}
+
+/// Lowercased, de-duplicated query words of at least two characters,
+/// excluding stopwords
+fn query_terms(query: &str) -> Vec<String> {
+    let mut terms: Vec<String> = Vec::new();
+    for word in query.split(|c: char| !c.is_alphanumeric() && c != '_') {
+        let word = word.to_lowercase();
+        if word.len() >= 2 && !STOPWORDS.contains(&word.as_str()) && !terms.contains(&word) {
+            terms.push(word);
+        }
+    }
+    terms
+}
+
+/// Fraction of query terms present in `text` (case-insensitive)
+fn term_score(text: &str, terms: &[String]) -> f32 {
+    if terms.is_empty() {
+        return 0.0;
+    }
+    let text = text.to_lowercase();
+    let hits = terms.iter().filter(|t| text.contains(t.as_str())).count();
+    hits as f32 / terms.len() as f32
+}
+
+/// Cap each source, then interleave all sources by absolute score.
+///
+/// Each source keeps its best `per_source_cap` hits; scores are clamped to
+/// 0.0..=1.0 but not rescaled. Ties keep source order (code, session, document).
+fn merge_sources(
+    sources: Vec<Vec<UnifiedSearchResult>>,
+    per_source_cap: usize,
+    limit: usize,
+) -> Vec<UnifiedSearchResult> {
+    let mut merged = Vec::new();
+    for mut hits in sources {
+        hits.sort_by(|a, b| b.score.total_cmp(&a.score));
+        hits.truncate(per_source_cap);
+        for mut hit in hits {
+            hit.score = hit.score.clamp(0.0, 1.0);
+            merged.push(hit);
+        }
+    }
+    merged.sort_by(|a, b| b.score.total_cmp(&a.score));
+    merged.truncate(limit);
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::documentation::{DocInventoryParams, upsert_doc_inventory};
+    use crate::tools::core::test_utils::MockToolContext;
+
+    fn hit(source: &str, score: f32, reference: &str) -> UnifiedSearchResult {
+        UnifiedSearchResult {
+            source: source.into(),
+            score,
+            reference: reference.into(),
+            line: None,
+            snippet: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_merge_interleaves_by_absolute_score() {
+        let code = vec![hit("code", 0.9, "a.rs"), hit("code", 0.4, "b.rs")];
+        let sessions = vec![hit("session", 0.75, "s1"), hit("session", 0.5, "s2")];
+        let merged = merge_sources(vec![code, sessions], 5, 10);
+
+        let order: Vec<(&str, &str)> = merged
+            .iter()
+            .map(|r| (r.source.as_str(), r.reference.as_str()))
+            .collect();
+        assert_eq!(
+            order,
+            vec![
+                ("code", "a.rs"),
+                ("session", "s1"),
+                ("session", "s2"),
+                ("code", "b.rs")
+            ]
+        );
+        assert_eq!(merged[0].score, 0.9);
+        assert_eq!(merged[3].score, 0.4);
+    }
+
+    #[test]
+    fn test_merge_does_not_promote_weak_source() {
+        // A source whose best hit is weak must stay below another source's strong hits
+        let code = vec![hit("code", 0.95, "a.rs"), hit("code", 0.85, "b.rs")];
+        let docs = vec![hit("document", 0.25, "README.md")];
+        let merged = merge_sources(vec![code, docs], 5, 10);
+
+        assert_eq!(merged[2].reference, "README.md");
+        assert_eq!(merged[2].score, 0.25);
+    }
+
+    #[test]
+    fn test_merge_caps_each_source() {
+        let code: Vec<_> = (0..20)
+            .map(|i| hit("code", 1.0 - i as f32 * 0.01, "x.rs"))
+            .collect();
+        let docs = vec![hit("document", 0.2, "README.md")];
+        let merged = merge_sources(vec![code, docs], 3, 10);
+        assert_eq!(merged.iter().filter(|r| r.source == "code").count(), 3);
+        assert!(merged.iter().any(|r| r.source == "document"));
+    }
+
+    #[test]
+    fn test_term_score() {
+        let terms = query_terms("Retry the embedding queue");
+        assert_eq!(terms, vec!["retry", "embedding", "queue"]);
+        assert_eq!(term_score("embedding QUEUE retry", &terms), 1.0);
+        assert_eq!(term_score("the embedding queue", &terms), 2.0 / 3.0);
+        assert_eq!(term_score("the and of", &terms), 0.0);
+        assert_eq!(term_score("nothing here", &[]), 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_search_all_tags_sessions_and_documents() {
+        let ctx = MockToolContext::with_project().await;
+        let project_id = ctx.project_id().await.unwrap();
+        ctx.pool
+            .run(move |conn| {
+                conn.execute(
+                    "INSERT INTO sessions (id, project_id, summary) VALUES
+                         ('s-match', ?1, 'Fixed the webhook retry backoff'),
+                         ('s-other', ?1, 'Refactored config loading')",
+                    [project_id],
+                )?;
+                upsert_doc_inventory(
+                    conn,
+                    &DocInventoryParams {
+                        project_id,
+                        doc_path: "docs/webhooks.md",
+                        doc_type: "guide",
+                        doc_category: None,
+                        title: Some("Webhook delivery"),
+                        source_signature_hash: None,
+                        source_symbols: None,
+                        git_commit: None,
+                    },
+                )?;
+                Ok::<_, rusqlite::Error>(())
+            })
+            .await
+            .unwrap();
+
+        let output = search_all(&ctx, "webhook retry".into(), None)
+            .await
+            .unwrap();
+        let Some(CodeData::SearchAll(data)) = output.0.data else {
+            panic!("expected SearchAll data");
+        };
+        let tags: Vec<(&str, &str)> = data
+            .results
+            .iter()
+            .map(|r| (r.source.as_str(), r.reference.as_str()))
+            .collect();
+        assert_eq!(
+            tags,
+            vec![("session", "s-match"), ("document", "docs/webhooks.md")]
+        );
+        // Both terms match the session; only "webhook" matches the document
+        assert_eq!(data.results[0].score, 1.0);
+        assert_eq!(data.results[1].score, 0.5);
+    }
+
+    #[tokio::test]
+    async fn test_search_all_rejects_empty_query() {
+        let ctx = MockToolContext::with_project().await;
+        assert!(search_all(&ctx, "  ".into(), None).await.is_err());
+    }
+}
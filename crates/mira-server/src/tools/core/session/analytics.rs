@@ -1,15 +1,15 @@
 // crates/mira-server/src/tools/core/session/analytics.rs
-//! Analytics queries: error patterns, health trends, session lineage, capabilities.
+//! Analytics queries: error patterns, tool errors, health trends, session lineage, capabilities.
 
-use crate::db::{get_error_patterns_sync, get_session_lineage_sync};
+use crate::db::{get_error_patterns_sync, get_recent_tool_errors_sync, get_session_lineage_sync};
 use crate::error::MiraError;
 use crate::mcp::responses::Json;
 use crate::mcp::responses::{
     CapabilitiesData, CapabilityStatus, ErrorPatternItem, ErrorPatternsData, LineageSession,
-    SessionData, SessionLineageData, SessionOutput,
+    SessionData, SessionLineageData, SessionOutput, ToolErrorItem, ToolErrorsData,
 };
 use crate::tools::core::{ToolContext, require_project_id};
-use crate::utils::truncate_at_boundary;
+use crate::utils::{redact_sensitive, truncate, truncate_at_boundary};
 
 /// Query error patterns for the active project.
 pub(super) async fn get_error_patterns<C: ToolContext>(
@@ -69,6 +69,80 @@ pub(super) async fn get_error_patterns<C: ToolContext>(
     }))
 }
 
+/// Max characters of (redacted) arguments or error text shown per failed call.
+const TOOL_ERROR_PREVIEW_LEN: usize = 200;
+
+/// Recent failed tool calls for the active project, newest first.
+pub(super) async fn get_tool_errors<C: ToolContext>(
+    ctx: &C,
+    tool_name: Option<String>,
+    limit: Option<i64>,
+) -> Result<Json<SessionOutput>, MiraError> {
+    let project_id = require_project_id(ctx).await?;
+
+    let limit = limit.unwrap_or(20).clamp(1, 100) as usize;
+
+    let rows = ctx
+        .pool()
+        .run(move |conn| get_recent_tool_errors_sync(conn, project_id, tool_name.as_deref(), limit))
+        .await?;
+
+    if rows.is_empty() {
+        return Ok(Json(SessionOutput {
+            action: "tool_errors".into(),
+            message: "No failed tool calls recorded.".to_string(),
+            data: Some(SessionData::ToolErrors(ToolErrorsData {
+                errors: vec![],
+                total: 0,
+            })),
+        }));
+    }
+
+    let total = rows.len();
+    let mut output = format!("Recent tool errors ({} total):\n\n", total);
+    let items: Vec<ToolErrorItem> = rows
+        .into_iter()
+        .map(|row| {
+            // Redact before truncating so a secret cut at the boundary can't slip through
+            let arguments_preview = row
+                .arguments
+                .filter(|a| !a.is_empty())
+                .map(|a| truncate(&redact_sensitive(&a), TOOL_ERROR_PREVIEW_LEN));
+            let error_message = row.error_message.map(|e| redact_sensitive(&e));
+            output.push_str(&format!(
+                "  [{}] {} at {}\n",
+                row.id, row.tool_name, row.created_at
+            ));
+            if let Some(ref args) = arguments_preview {
+                output.push_str(&format!("    Args: {}\n", args));
+            }
+            if let Some(ref err) = error_message {
+                output.push_str(&format!(
+                    "    Error: {}\n",
+                    truncate(err, TOOL_ERROR_PREVIEW_LEN)
+                ));
+            }
+            output.push('\n');
+            ToolErrorItem {
+                id: row.id,
+                tool_name: row.tool_name,
+                created_at: row.created_at,
+                arguments_preview,
+                error_message,
+            }
+        })
+        .collect();
+
+    Ok(Json(SessionOutput {
+        action: "tool_errors".into(),
+        message: output,
+        data: Some(SessionData::ToolErrors(ToolErrorsData {
+            errors: items,
+            total,
+        })),
+    }))
+}
+
 /// Query session lineage (resume chains) for the active project.
 pub(super) async fn get_session_lineage<C: ToolContext>(
     ctx: &C,
@@ -0,0 +1,278 @@
+// crates/mira-server/src/tools/core/session/artifacts.rs
+//! Slices of oversized tool results stored as artifacts by the MCP result governor.
+
+use crate::db::artifacts::get_tool_artifact_sync;
+use crate::error::MiraError;
+use crate::mcp::responses::Json;
+use crate::mcp::responses::{ArtifactData, SessionData, SessionOutput};
+use crate::tools::core::ToolContext;
+use crate::utils::{truncate, truncate_at_boundary};
+
+/// Lines returned by head/tail, and matches returned by search, when no limit is given.
+const DEFAULT_SLICE_LINES: usize = 50;
+const MAX_SLICE_LINES: usize = 1000;
+/// Bytes returned by get when no limit is given.
+const DEFAULT_GET_BYTES: usize = 8 * 1024;
+/// Upper bound on the content of any slice. Keeps a slice (which appears in both
+/// the message and the data) well under the governor's inline threshold.
+const MAX_SLICE_BYTES: usize = 16 * 1024;
+/// Max characters shown per matching line in search results.
+const SEARCH_LINE_MAX_LEN: usize = 300;
+
+/// Part of an artifact to return.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum ArtifactSlice {
+    /// Up to `len` bytes starting at byte `offset`
+    Get { offset: usize, len: usize },
+    /// First N lines
+    Head(usize),
+    /// Last N lines
+    Tail(usize),
+    /// Lines containing `query` (case-insensitive), numbered, up to `limit` matches
+    Search { query: String, limit: usize },
+}
+
+impl ArtifactSlice {
+    /// Build a slice from request fields. `limit` is a byte count for get and a
+    /// line/match count for the others.
+    pub(crate) fn parse(
+        slice: Option<&str>,
+        query: Option<String>,
+        offset: Option<i64>,
+        limit: Option<i64>,
+    ) -> Result<Self, MiraError> {
+        let lines = || {
+            limit.map_or(DEFAULT_SLICE_LINES, |n| {
+                n.clamp(1, MAX_SLICE_LINES as i64) as usize
+            })
+        };
+        match slice.unwrap_or("head") {
+            "get" => Ok(Self::Get {
+                offset: offset.unwrap_or(0).max(0) as usize,
+                len: limit.map_or(DEFAULT_GET_BYTES, |n| {
+                    n.clamp(1, MAX_SLICE_BYTES as i64) as usize
+                }),
+            }),
+            "head" => Ok(Self::Head(lines())),
+            "tail" => Ok(Self::Tail(lines())),
+            "search" => {
+                let query = query.filter(|q| !q.trim().is_empty()).ok_or_else(|| {
+                    MiraError::InvalidInput("query is required for artifact search".to_string())
+                })?;
+                Ok(Self::Search {
+                    query,
+                    limit: lines(),
+                })
+            }
+            other => Err(MiraError::InvalidInput(format!(
+                "Unknown artifact slice '{}'. Use get, head, tail, or search",
+                other
+            ))),
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Get { .. } => "get",
+            Self::Head(_) => "head",
+            Self::Tail(_) => "tail",
+            Self::Search { .. } => "search",
+        }
+    }
+}
+
+/// Return the last `max_bytes` bytes of `s`, rounded up to a UTF-8 char boundary.
+fn tail_at_boundary(s: &str, max_bytes: usize) -> &str {
+    if s.len() <= max_bytes {
+        return s;
+    }
+    let mut start = s.len() - max_bytes;
+    while !s.is_char_boundary(start) {
+        start += 1;
+    }
+    &s[start..]
+}
+
+/// Cut a slice out of artifact content. Returns the text and, for get, the byte
+/// offset to continue from when more content remains.
+pub(crate) fn slice_content(content: &str, slice: &ArtifactSlice) -> (String, Option<usize>) {
+    match slice {
+        ArtifactSlice::Get { offset, len } => {
+            let mut start = (*offset).min(content.len());
+            while !content.is_char_boundary(start) {
+                start -= 1;
+            }
+            let mut end = start + truncate_at_boundary(&content[start..], *len).len();
+            // Always make progress, even when `len` is shorter than the next char
+            if end == start {
+                end += content[start..].chars().next().map_or(0, char::len_utf8);
+            }
+            (
+                content[start..end].to_string(),
+                (end < content.len()).then_some(end),
+            )
+        }
+        ArtifactSlice::Head(n) => {
+            let head = content.lines().take(*n).collect::<Vec<_>>().join("\n");
+            (
+                truncate_at_boundary(&head, MAX_SLICE_BYTES).to_string(),
+                None,
+            )
+        }
+        ArtifactSlice::Tail(n) => {
+            let lines: Vec<&str> = content.lines().collect();
+            let tail = lines[lines.len().saturating_sub(*n)..].join("\n");
+            (tail_at_boundary(&tail, MAX_SLICE_BYTES).to_string(), None)
+        }
+        ArtifactSlice::Search { query, limit } => {
+            let needle = query.to_lowercase();
+            let matches: Vec<String> = content
+                .lines()
+                .enumerate()
+                .filter(|(_, line)| line.to_lowercase().contains(&needle))
+                .take(*limit)
+                .map(|(i, line)| format!("{}: {}", i + 1, truncate(line, SEARCH_LINE_MAX_LEN)))
+                .collect();
+            let joined = matches.join("\n");
+            (
+                truncate_at_boundary(&joined, MAX_SLICE_BYTES).to_string(),
+                None,
+            )
+        }
+    }
+}
+
+/// Read a slice of a stored tool result.
+pub(super) async fn get_artifact<C: ToolContext>(
+    ctx: &C,
+    artifact_id: Option<i64>,
+    slice: Option<String>,
+    query: Option<String>,
+    offset: Option<i64>,
+    limit: Option<i64>,
+) -> Result<Json<SessionOutput>, MiraError> {
+    let artifact_id = artifact_id.ok_or_else(|| {
+        MiraError::InvalidInput("artifact_id is required for action 'artifact'".to_string())
+    })?;
+    let slice = ArtifactSlice::parse(slice.as_deref(), query, offset, limit)?;
+
+    let artifact = ctx
+        .pool()
+        .run(move |conn| get_tool_artifact_sync(conn, artifact_id))
+        .await?
+        .ok_or_else(|| {
+            MiraError::NotFound(format!(
+                "Artifact {} not found (it may have been removed by data retention)",
+                artifact_id
+            ))
+        })?;
+
+    let (content, next_offset) = slice_content(&artifact.content, &slice);
+    let size_bytes = artifact.content.len();
+    let total_lines = artifact.content.lines().count();
+
+    let mut message = format!(
+        "Artifact {} ({}, {} bytes, {} lines) - {}:\n\n",
+        artifact.id,
+        artifact.tool_name,
+        size_bytes,
+        total_lines,
+        slice.name()
+    );
+    if content.is_empty() {
+        message.push_str("(no content)");
+    } else {
+        message.push_str(&content);
+    }
+    if let Some(next) = next_offset {
+        message.push_str(&format!("\n\n[More content from offset {}]", next));
+    }
+
+    Ok(Json(SessionOutput {
+        action: "artifact".into(),
+        message,
+        data: Some(SessionData::Artifact(ArtifactData {
+            artifact_id: artifact.id,
+            tool_name: artifact.tool_name,
+            created_at: artifact.created_at,
+            size_bytes,
+            total_lines,
+            slice: slice.name().to_string(),
+            content,
+            next_offset,
+        })),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_defaults_to_head() {
+        assert_eq!(
+            ArtifactSlice::parse(None, None, None, None).unwrap(),
+            ArtifactSlice::Head(DEFAULT_SLICE_LINES)
+        );
+    }
+
+    #[test]
+    fn parse_rejects_unknown_slice_and_empty_search() {
+        assert!(ArtifactSlice::parse(Some("middle"), None, None, None).is_err());
+        assert!(ArtifactSlice::parse(Some("search"), Some("  ".into()), None, None).is_err());
+    }
+
+    #[test]
+    fn parse_clamps_get_length() {
+        let slice = ArtifactSlice::parse(Some("get"), None, Some(-5), Some(10_000_000)).unwrap();
+        assert_eq!(
+            slice,
+            ArtifactSlice::Get {
+                offset: 0,
+                len: MAX_SLICE_BYTES
+            }
+        );
+    }
+
+    #[test]
+    fn head_and_tail_take_lines() {
+        let content = "a\nb\nc\nd";
+        assert_eq!(slice_content(content, &ArtifactSlice::Head(2)).0, "a\nb");
+        assert_eq!(slice_content(content, &ArtifactSlice::Tail(2)).0, "c\nd");
+        assert_eq!(slice_content(content, &ArtifactSlice::Tail(10)).0, content);
+    }
+
+    #[test]
+    fn get_pages_through_content() {
+        let content = "0123456789";
+        let (first, next) = slice_content(content, &ArtifactSlice::Get { offset: 0, len: 4 });
+        assert_eq!((first.as_str(), next), ("0123", Some(4)));
+        let (last, next) = slice_content(content, &ArtifactSlice::Get { offset: 8, len: 4 });
+        assert_eq!((last.as_str(), next), ("89", None));
+        let (past_end, next) = slice_content(content, &ArtifactSlice::Get { offset: 50, len: 4 });
+        assert_eq!((past_end.as_str(), next), ("", None));
+    }
+
+    #[test]
+    fn get_respects_char_boundaries() {
+        // 'é' is two bytes; an offset or length landing inside it must not panic
+        let content = "aéb";
+        let (chunk, _) = slice_content(content, &ArtifactSlice::Get { offset: 2, len: 1 });
+        assert_eq!(chunk, "é");
+        let (chunk, next) = slice_content(content, &ArtifactSlice::Get { offset: 0, len: 2 });
+        assert_eq!((chunk.as_str(), next), ("a", Some(1)));
+    }
+
+    #[test]
+    fn search_numbers_matching_lines() {
+        let content = "ok\nERROR one\nok\nerror two\nerror three";
+        let slice = ArtifactSlice::Search {
+            query: "Error".into(),
+            limit: 2,
+        };
+        assert_eq!(
+            slice_content(content, &slice).0,
+            "2: ERROR one\n4: error two"
+        );
+    }
+}
@@ -0,0 +1,96 @@
+// crates/mira-server/src/tools/core/session/audit.rs
+//! Signed audit export of tool executions (the tool-side twin of `mira audit export`).
+
+use crate::audit::{audit_key_from_env, manifest_path, write_export};
+use crate::db::audit::AuditFilter;
+use crate::error::MiraError;
+use crate::mcp::responses::Json;
+use crate::mcp::responses::{AuditExportData, SessionData, SessionOutput};
+use crate::tools::core::ToolContext;
+use anyhow::Context;
+use std::io::BufWriter;
+use std::path::PathBuf;
+
+/// Export the active project's tool executions from the last `since_days` days
+/// (all projects when none is active), signed with `MIRA_AUDIT_KEY`.
+pub(super) async fn audit_export<C: ToolContext>(
+    ctx: &C,
+    since_days: Option<u32>,
+    tool_name: Option<String>,
+    output: Option<String>,
+) -> Result<Json<SessionOutput>, MiraError> {
+    let key = audit_key_from_env().map_err(|e| MiraError::Config(e.to_string()))?;
+    export_with_key(ctx, since_days, tool_name, output, key).await
+}
+
+pub(super) async fn export_with_key<C: ToolContext>(
+    ctx: &C,
+    since_days: Option<u32>,
+    tool_name: Option<String>,
+    output: Option<String>,
+    key: Vec<u8>,
+) -> Result<Json<SessionOutput>, MiraError> {
+    let out = output
+        .filter(|o| !o.trim().is_empty())
+        .map(PathBuf::from)
+        .ok_or_else(|| {
+            MiraError::InvalidInput(
+                "output is required for audit_export (absolute path of the JSONL file)".into(),
+            )
+        })?;
+    if !out.is_absolute() {
+        return Err(MiraError::InvalidInput(format!(
+            "output must be an absolute path, got '{}'",
+            out.display()
+        )));
+    }
+
+    let today = chrono::Utc::now().date_naive();
+    let since_days = since_days.unwrap_or(30).clamp(1, 3650);
+    let filter = AuditFilter {
+        since: (today - chrono::Duration::days(i64::from(since_days)))
+            .format("%Y-%m-%d")
+            .to_string(),
+        until: today.format("%Y-%m-%d").to_string(),
+        project_path: ctx.get_project().await.map(|p| p.path),
+        tool_name,
+    };
+
+    let export_path = out.clone();
+    let manifest = ctx
+        .pool()
+        .interact(move |conn| {
+            let file = std::fs::File::create(&export_path)
+                .with_context(|| format!("creating {}", export_path.display()))?;
+            let manifest = write_export(conn, &filter, &key, BufWriter::new(file))?;
+            let manifest_file = manifest_path(&export_path);
+            std::fs::write(&manifest_file, serde_json::to_string_pretty(&manifest)?)
+                .with_context(|| format!("writing {}", manifest_file.display()))?;
+            Ok(manifest)
+        })
+        .await?;
+
+    let manifest_file = manifest_path(&out);
+    let message = format!(
+        "Exported {} tool executions ({} .. {}) to {}\nManifest: {}",
+        manifest.row_count,
+        manifest.since,
+        manifest.until,
+        out.display(),
+        manifest_file.display()
+    );
+    Ok(Json(SessionOutput {
+        action: "audit_export".into(),
+        message,
+        data: Some(SessionData::AuditExport(AuditExportData {
+            path: out.display().to_string(),
+            manifest_path: manifest_file.display().to_string(),
+            since: manifest.since,
+            until: manifest.until,
+            project: manifest.project,
+            tool: manifest.tool,
+            row_count: manifest.row_count,
+            hmac_sha256: manifest.hmac_sha256,
+        })),
+    }))
+}
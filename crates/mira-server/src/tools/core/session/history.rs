@@ -1,7 +1,7 @@
 // crates/mira-server/src/tools/core/session/history.rs
 //! Session history queries: current session, list sessions, get tool history.
 
-use crate::db::{get_recent_sessions_sync, get_session_history_scoped_sync};
+use crate::db::{get_session_history_scoped_sync, get_sessions_with_liveness_sync};
 use crate::error::MiraError;
 use crate::mcp::responses::Json;
 use crate::mcp::responses::{
@@ -14,10 +14,16 @@ use crate::utils::{truncate, truncate_at_boundary};
 /// Internal kind enum for session history queries (replaces deleted SessionHistoryAction)
 pub enum HistoryKind {
     Current,
-    List,
+    /// Recent sessions, optionally filtered by liveness (live/idle/closed)
+    List {
+        liveness: Option<String>,
+    },
     GetHistory,
 }
 
+/// Liveness states reported by list_sessions
+const LIVENESS_STATES: [&str; 3] = ["live", "idle", "closed"];
+
 /// Query session history
 pub async fn session_history<C: ToolContext>(
     ctx: &C,
@@ -43,12 +49,32 @@ pub async fn session_history<C: ToolContext>(
                 })),
             }
         }
-        HistoryKind::List => {
+        HistoryKind::List { liveness } => {
             let project_id = require_project_id(ctx).await?;
+            if let Some(ref state) = liveness
+                && !LIVENESS_STATES.contains(&state.as_str())
+            {
+                return Err(MiraError::InvalidInput(format!(
+                    "Invalid liveness '{}'. Use live, idle, or closed",
+                    state
+                )));
+            }
 
+            let idle_minutes = crate::tools::core::project_config(ctx)
+                .await
+                .sessions
+                .idle_minutes();
             let sessions = ctx
                 .pool()
-                .run(move |conn| get_recent_sessions_sync(conn, project_id, limit))
+                .run(move |conn| {
+                    get_sessions_with_liveness_sync(
+                        conn,
+                        project_id,
+                        limit,
+                        idle_minutes,
+                        liveness.as_deref(),
+                    )
+                })
                 .await?;
 
             if sessions.is_empty() {
@@ -65,7 +91,7 @@ pub async fn session_history<C: ToolContext>(
             let mut output = format!("{} sessions:\n", sessions.len());
             let items: Vec<SessionSummary> = sessions
                 .into_iter()
-                .map(|s| {
+                .map(|(s, liveness)| {
                     let source_info = match (&s.source, &s.resumed_from) {
                         (Some(src), Some(from)) => {
                             format!(" [{}←{}]", src, truncate_at_boundary(from, 8))
@@ -73,19 +99,34 @@ pub async fn session_history<C: ToolContext>(
                         (Some(src), None) => format!(" [{}]", src),
                         _ => String::new(),
                     };
+                    let reason_info = s
+                        .close_reason
+                        .as_deref()
+                        .map(|r| format!(" ({})", r))
+                        .unwrap_or_default();
                     output.push_str(&format!(
-                        "  [{}] {} - {}{} ({})\n",
+                        "  [{}] {} - {}{}{} ({})\n",
                         truncate_at_boundary(&s.id, 8),
                         s.started_at,
-                        s.status,
+                        liveness,
+                        reason_info,
                         source_info,
                         s.summary.as_deref().unwrap_or("no summary")
                     ));
+                    if let Some(recap) = &s.recap {
+                        for line in recap.lines().filter(|l| !l.is_empty()) {
+                            output.push_str(&format!("      {}\n", line));
+                        }
+                    }
                     SessionSummary {
                         id: s.id,
                         started_at: s.started_at,
                         status: s.status,
+                        liveness,
+                        last_activity: s.last_activity,
+                        close_reason: s.close_reason,
                         summary: s.summary,
+                        recap: s.recap,
                         source: s.source,
                         resumed_from: s.resumed_from,
                     }
@@ -2,12 +2,15 @@
 //! Unified session management tools.
 
 mod analytics;
+mod artifacts;
+mod audit;
 mod history;
 mod storage;
 
 pub use history::{HistoryKind, session_history};
 
-use crate::db::{build_session_recap_sync, create_session_ext_sync};
+use crate::db::session_delta::get_session_delta_sync;
+use crate::db::{build_session_recap_with_delta_sync, create_session_ext_sync};
 use crate::error::MiraError;
 use crate::hooks::session::{read_claude_session_id, read_source_info};
 use crate::mcp::responses::Json;
@@ -27,7 +30,15 @@ pub async fn handle_session<C: ToolContext>(
             session_history(ctx, HistoryKind::Current, req.session_id, req.limit).await
         }
         SessionAction::ListSessions => {
-            session_history(ctx, HistoryKind::List, req.session_id, req.limit).await
+            session_history(
+                ctx,
+                HistoryKind::List {
+                    liveness: req.liveness,
+                },
+                req.session_id,
+                req.limit,
+            )
+            .await
         }
         SessionAction::GetHistory => {
             session_history(ctx, HistoryKind::GetHistory, req.session_id, req.limit).await
@@ -64,6 +75,7 @@ pub async fn handle_session<C: ToolContext>(
                 data: None,
             }))
         }
+        SessionAction::UsageCosts => super::usage_costs(ctx, req.since_days).await,
         SessionAction::Insights => {
             super::insights::query_insights(
                 ctx,
@@ -77,9 +89,30 @@ pub async fn handle_session<C: ToolContext>(
         SessionAction::DismissInsight => {
             super::insights::dismiss_insight(ctx, req.insight_id, req.insight_source).await
         }
+        SessionAction::Suggestions => {
+            super::insights::proactive_suggestions(ctx, req.since_days).await
+        }
         SessionAction::StorageStatus => storage::storage_status(ctx).await,
+        SessionAction::DbHealth => storage::db_health(ctx, req.since_days).await,
         SessionAction::Cleanup => storage::cleanup(ctx, req.dry_run, req.category).await,
         SessionAction::ErrorPatterns => analytics::get_error_patterns(ctx, req.limit).await,
+        SessionAction::ToolErrors => {
+            analytics::get_tool_errors(ctx, req.tool_name, req.limit).await
+        }
+        SessionAction::Artifact => {
+            artifacts::get_artifact(
+                ctx,
+                req.artifact_id,
+                req.slice,
+                req.query,
+                req.offset,
+                req.limit,
+            )
+            .await
+        }
+        SessionAction::AuditExport => {
+            audit::audit_export(ctx, req.since_days, req.tool_name, req.output).await
+        }
         SessionAction::SessionLineage => analytics::get_session_lineage(ctx, req.limit).await,
         SessionAction::Capabilities => analytics::get_capabilities(ctx).await,
         SessionAction::Report => {
@@ -168,14 +201,39 @@ async fn find_previous_session_heuristic<C: ToolContext>(
 }
 
 /// Get session recap for MCP clients
-/// Returns recent context, preferences, project state, and Claude Code session notes
+/// Returns what changed since the previous session, recent context, project
+/// state, and Claude Code session notes
 pub async fn get_session_recap<C: ToolContext>(ctx: &C) -> Result<String, MiraError> {
     let project = ctx.get_project().await;
     let project_id = project.as_ref().map(|p| p.id);
+    let session_id = ctx.get_session_id().await;
+
+    let mut delta = match project_id {
+        Some(pid) => {
+            ctx.pool()
+                .run(move |conn| get_session_delta_sync(conn, pid, session_id.as_deref()))
+                .await?
+        }
+        None => None,
+    };
+    if let (Some(delta), Some(proj)) = (delta.as_mut(), &project) {
+        let path = proj.path.clone();
+        let since = delta.ended_at_unix;
+        delta.changed_files =
+            tokio::task::spawn_blocking(move || crate::git::get_files_changed_since(&path, since))
+                .await
+                .unwrap_or_default();
+    }
 
     let mut recap = ctx
         .pool()
-        .run(move |conn| Ok::<_, String>(build_session_recap_sync(conn, project_id)))
+        .run(move |conn| {
+            Ok::<_, String>(build_session_recap_with_delta_sync(
+                conn,
+                project_id,
+                delta.as_ref(),
+            ))
+        })
         .await?;
 
     // Add Claude Code session notes if available
@@ -278,6 +336,7 @@ mod tests {
     use crate::mcp::requests::{SessionAction, SessionRequest};
     use crate::mcp::responses::SessionData;
     use crate::tools::core::test_utils::MockToolContext;
+    use mira_types::ProjectContext;
 
     /// Helper: build a SessionRequest for a given action with all other fields None.
     fn make_request(action: SessionAction) -> SessionRequest {
@@ -292,6 +351,13 @@ mod tests {
             insight_id: None,
             dry_run: None,
             category: None,
+            tool_name: None,
+            artifact_id: None,
+            slice: None,
+            query: None,
+            offset: None,
+            liveness: None,
+            output: None,
         }
     }
 
@@ -515,6 +581,97 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_list_sessions_liveness_filter() {
+        let ctx = MockToolContext::with_project().await;
+        let pid = ctx.project_id().await.unwrap();
+        insert_session(&ctx.pool, "sess-live", pid, "active", None).await;
+        insert_session(&ctx.pool, "sess-idle", pid, "active", None).await;
+        insert_session(&ctx.pool, "sess-done", pid, "completed", None).await;
+        ctx.pool
+            .run(|conn| {
+                conn.execute(
+                    "UPDATE sessions SET last_activity = datetime('now', '-2 days') WHERE id = 'sess-idle'",
+                    [],
+                )
+            })
+            .await
+            .unwrap();
+
+        for (state, expected) in [
+            ("live", "sess-live"),
+            ("idle", "sess-idle"),
+            ("closed", "sess-done"),
+        ] {
+            let mut req = make_request(SessionAction::ListSessions);
+            req.liveness = Some(state.into());
+            let result = handle_session(&ctx, req).await.unwrap();
+            match result.0.data {
+                Some(SessionData::ListSessions(data)) => {
+                    assert_eq!(data.sessions.len(), 1, "filter {state}");
+                    assert_eq!(data.sessions[0].id, expected);
+                    assert_eq!(data.sessions[0].liveness, state);
+                }
+                other => panic!("Expected SessionData::ListSessions, got {:?}", other),
+            }
+        }
+
+        let mut req = make_request(SessionAction::ListSessions);
+        req.liveness = Some("zombie".into());
+        assert!(handle_session(&ctx, req).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_list_sessions_idle_threshold_follows_active_project_config() {
+        let ctx = MockToolContext::new().await;
+        let dirs = [tempfile::tempdir().unwrap(), tempfile::tempdir().unwrap()];
+        let mut projects = Vec::new();
+        for (dir, idle_minutes) in dirs.iter().zip([5, 60]) {
+            std::fs::create_dir_all(dir.path().join(".mira")).unwrap();
+            std::fs::write(
+                dir.path().join(".mira/config.toml"),
+                format!("[sessions]\nidle_after_minutes = {idle_minutes}\n"),
+            )
+            .unwrap();
+            let path = dir.path().to_string_lossy().into_owned();
+            let path_clone = path.clone();
+            let id = ctx
+                .pool
+                .run(move |conn| {
+                    conn.execute("INSERT INTO projects (path) VALUES (?1)", [&path_clone])?;
+                    let id = conn.last_insert_rowid();
+                    conn.execute(
+                        "INSERT INTO sessions (id, project_id, status, started_at, last_activity)
+                         VALUES (?1, ?2, 'active', datetime('now', '-20 minutes'), datetime('now', '-20 minutes'))",
+                        rusqlite::params![format!("sess-{id}"), id],
+                    )?;
+                    Ok::<_, rusqlite::Error>(id)
+                })
+                .await
+                .unwrap();
+            projects.push(ProjectContext {
+                id,
+                path,
+                name: None,
+            });
+        }
+
+        // Same 20-minute-old session: idle under a 5 minute threshold, live under 60
+        for (project, expected) in projects.into_iter().zip(["idle", "live"]) {
+            ctx.set_project(project).await;
+            let result = handle_session(&ctx, make_request(SessionAction::ListSessions))
+                .await
+                .unwrap();
+            match result.0.data {
+                Some(SessionData::ListSessions(data)) => {
+                    assert_eq!(data.sessions.len(), 1);
+                    assert_eq!(data.sessions[0].liveness, expected);
+                }
+                other => panic!("Expected SessionData::ListSessions, got {:?}", other),
+            }
+        }
+    }
+
     #[tokio::test]
     async fn test_list_sessions_respects_limit() {
         let ctx = MockToolContext::with_project().await;
@@ -658,6 +815,126 @@ mod tests {
         }
     }
 
+    // ========================================================================
+    // ToolErrors
+    // ========================================================================
+
+    /// Helper: insert a tool_history row with explicit arguments, error and timestamp.
+    async fn insert_tool_call(
+        pool: &MainPool,
+        session_id: &str,
+        tool_name: &str,
+        arguments: &str,
+        success: bool,
+        error_message: Option<&str>,
+        created_at: &str,
+    ) {
+        let params = (
+            session_id.to_string(),
+            tool_name.to_string(),
+            arguments.to_string(),
+            error_message.map(|e| e.to_string()),
+            created_at.to_string(),
+        );
+        pool.run(move |conn| {
+            conn.execute(
+                "INSERT INTO tool_history (session_id, tool_name, arguments, success, error_message, created_at)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                rusqlite::params![params.0, params.1, params.2, success, params.3, params.4],
+            )?;
+            Ok::<_, rusqlite::Error>(())
+        })
+        .await
+        .expect("Failed to insert tool call");
+    }
+
+    #[tokio::test]
+    async fn test_tool_errors_no_project() {
+        let ctx = MockToolContext::new().await;
+        let result = handle_session(&ctx, make_request(SessionAction::ToolErrors)).await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_tool_errors_only_failures_newest_first_redacted() {
+        let ctx = MockToolContext::with_project().await;
+        let pid = ctx.project_id().await.unwrap();
+        insert_session(&ctx.pool, "sess-err", pid, "active", None).await;
+        insert_tool_call(
+            &ctx.pool,
+            "sess-err",
+            "run",
+            r#"{"code":"index_project()"}"#,
+            false,
+            Some("older failure"),
+            "2026-01-01 10:00:00",
+        )
+        .await;
+        insert_tool_call(
+            &ctx.pool,
+            "sess-err",
+            "run",
+            "{}",
+            true,
+            None,
+            "2026-01-01 11:00:00",
+        )
+        .await;
+        insert_tool_call(
+            &ctx.pool,
+            "sess-err",
+            "index",
+            r#"{"token":"Bearer abc123secret"}"#,
+            false,
+            Some("newer failure"),
+            "2026-01-01 12:00:00",
+        )
+        .await;
+
+        let result = handle_session(&ctx, make_request(SessionAction::ToolErrors))
+            .await
+            .unwrap();
+        assert_eq!(result.0.action, "tool_errors");
+        assert!(!result.0.message.contains("abc123secret"));
+        match result.0.data {
+            Some(SessionData::ToolErrors(data)) => {
+                assert_eq!(data.total, 2, "successful calls must be excluded");
+                assert_eq!(data.errors[0].tool_name, "index");
+                assert_eq!(
+                    data.errors[0].error_message.as_deref(),
+                    Some("newer failure")
+                );
+                let args = data.errors[0].arguments_preview.as_deref().unwrap();
+                assert!(!args.contains("abc123secret"), "got: {args}");
+                assert_eq!(
+                    data.errors[1].error_message.as_deref(),
+                    Some("older failure")
+                );
+            }
+            other => panic!("Expected SessionData::ToolErrors, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_tool_errors_filter_by_tool() {
+        let ctx = MockToolContext::with_project().await;
+        let pid = ctx.project_id().await.unwrap();
+        insert_session(&ctx.pool, "sess-filter", pid, "active", None).await;
+        insert_tool_history(&ctx.pool, "sess-filter", "run", false).await;
+        insert_tool_history(&ctx.pool, "sess-filter", "index", false).await;
+
+        let mut req = make_request(SessionAction::ToolErrors);
+        req.tool_name = Some("index".into());
+        let result = handle_session(&ctx, req).await.unwrap();
+        match result.0.data {
+            Some(SessionData::ToolErrors(data)) => {
+                assert_eq!(data.total, 1);
+                assert_eq!(data.errors[0].tool_name, "index");
+            }
+            other => panic!("Expected SessionData::ToolErrors, got {:?}", other),
+        }
+    }
+
     // ========================================================================
     // SessionLineage
     // ========================================================================
@@ -799,6 +1076,54 @@ mod tests {
         assert!(result.0.message.contains("Storage Status"));
         assert!(result.0.message.contains("Row Counts"));
         assert!(result.0.message.contains("Retention Policy"));
+        assert!(result.0.message.contains("Growth"));
+    }
+
+    // ========================================================================
+    // DbHealth
+    // ========================================================================
+
+    #[tokio::test]
+    async fn test_db_health_returns_time_series() {
+        use crate::db::db_health::{DbHealthSample, record_db_health_sample_sync};
+
+        let ctx = MockToolContext::with_project().await;
+        ctx.pool()
+            .run(|conn| {
+                for (days_ago, rows) in [(8, 100), (0, 900)] {
+                    let sampled_on: String = conn.query_row(
+                        "SELECT date('now', ?1)",
+                        [format!("-{} days", days_ago)],
+                        |row| row.get(0),
+                    )?;
+                    record_db_health_sample_sync(
+                        conn,
+                        &DbHealthSample {
+                            sampled_on,
+                            db_size_bytes: 1_048_576,
+                            wal_size_bytes: 0,
+                            table_rows: [("tool_history".to_string(), rows)].into(),
+                        },
+                    )?;
+                }
+                Ok::<_, rusqlite::Error>(())
+            })
+            .await
+            .unwrap();
+
+        let result = handle_session(&ctx, make_request(SessionAction::DbHealth))
+            .await
+            .unwrap();
+        assert_eq!(result.0.action, "db_health");
+        assert!(result.0.message.contains("tool_history +800"));
+        match result.0.data {
+            Some(SessionData::DbHealth(data)) => {
+                assert_eq!(data.samples.len(), 2);
+                assert_eq!(data.size_growth_pct, Some(0.0));
+                assert_eq!(data.top_tables[0].table, "tool_history");
+            }
+            other => panic!("Expected SessionData::DbHealth, got {:?}", other),
+        }
     }
 
     // ========================================================================
@@ -868,11 +1193,14 @@ mod tests {
             SessionAction::SessionLineage,
             SessionAction::Capabilities,
             SessionAction::StorageStatus,
+            SessionAction::DbHealth,
             SessionAction::Cleanup,
             SessionAction::UsageSummary,
             SessionAction::UsageStats,
             SessionAction::UsageList,
+            SessionAction::UsageCosts,
             SessionAction::Insights,
+            SessionAction::Suggestions,
         ];
 
         for action in succeeding_actions {
@@ -916,7 +1244,7 @@ mod tests {
     #[tokio::test]
     async fn test_session_history_list_kind() {
         let ctx = MockToolContext::with_project().await;
-        let result = session_history(&ctx, HistoryKind::List, None, None)
+        let result = session_history(&ctx, HistoryKind::List { liveness: None }, None, None)
             .await
             .unwrap();
         assert_eq!(result.0.action, "list_sessions");
@@ -993,4 +1321,128 @@ mod tests {
         let recap = get_session_recap(&ctx).await.unwrap();
         assert!(!recap.is_empty());
     }
+
+    // ========================================================================
+    // Artifact
+    // ========================================================================
+
+    #[tokio::test]
+    async fn test_artifact_requires_id() {
+        let ctx = MockToolContext::new().await;
+        let result = handle_session(&ctx, make_request(SessionAction::Artifact)).await;
+        assert!(matches!(result, Err(MiraError::InvalidInput(_))));
+    }
+
+    #[tokio::test]
+    async fn test_artifact_not_found() {
+        let ctx = MockToolContext::new().await;
+        let mut req = make_request(SessionAction::Artifact);
+        req.artifact_id = Some(4242);
+        let result = handle_session(&ctx, req).await;
+        assert!(matches!(result, Err(MiraError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_artifact_tail_and_search() {
+        let ctx = MockToolContext::new().await;
+        let content: String = (1..=500).map(|i| format!("row {i}\n")).collect();
+        let artifact_id = ctx
+            .pool
+            .run(move |conn| {
+                crate::db::artifacts::store_tool_artifact_sync(conn, None, "run", &content)
+            })
+            .await
+            .unwrap();
+
+        let mut req = make_request(SessionAction::Artifact);
+        req.artifact_id = Some(artifact_id);
+        req.slice = Some("tail".into());
+        req.limit = Some(2);
+        let result = handle_session(&ctx, req).await.unwrap();
+        assert_eq!(result.0.action, "artifact");
+        match result.0.data {
+            Some(SessionData::Artifact(data)) => {
+                assert_eq!(data.content, "row 499\nrow 500");
+                assert_eq!(data.total_lines, 500);
+                assert_eq!(data.slice, "tail");
+            }
+            other => panic!("Expected SessionData::Artifact, got {:?}", other),
+        }
+
+        let mut req = make_request(SessionAction::Artifact);
+        req.artifact_id = Some(artifact_id);
+        req.slice = Some("search".into());
+        req.query = Some("row 25".into());
+        let result = handle_session(&ctx, req).await.unwrap();
+        match result.0.data {
+            Some(SessionData::Artifact(data)) => {
+                let lines: Vec<&str> = data.content.lines().collect();
+                assert_eq!(lines.first(), Some(&"25: row 25"));
+                assert_eq!(lines.len(), 11, "row 25 and row 250-259");
+            }
+            other => panic!("Expected SessionData::Artifact, got {:?}", other),
+        }
+    }
+
+    // ========================================================================
+    // AuditExport
+    // ========================================================================
+
+    #[tokio::test]
+    async fn test_audit_export_requires_absolute_output() {
+        let ctx = MockToolContext::with_project().await;
+        let result = audit::export_with_key(&ctx, None, None, None, b"k".to_vec()).await;
+        assert!(matches!(result, Err(MiraError::InvalidInput(_))));
+
+        let result =
+            audit::export_with_key(&ctx, None, None, Some("audit.jsonl".into()), b"k".to_vec())
+                .await;
+        assert!(matches!(result, Err(MiraError::InvalidInput(_))));
+    }
+
+    #[tokio::test]
+    async fn test_audit_export_writes_signed_project_scoped_file() {
+        let ctx = MockToolContext::with_project().await;
+        let pid = ctx.project_id().await.unwrap();
+        insert_session(&ctx.pool, "sess-audit", pid, "active", None).await;
+        insert_tool_history(&ctx.pool, "sess-audit", "run", true).await;
+        insert_tool_history(&ctx.pool, "sess-audit", "index", false).await;
+        let other_pid = ctx
+            .pool
+            .run(|conn| {
+                conn.execute(
+                    "INSERT INTO projects (path, name) VALUES ('/other/project', 'other')",
+                    [],
+                )?;
+                Ok::<_, rusqlite::Error>(conn.last_insert_rowid())
+            })
+            .await
+            .unwrap();
+        insert_session(&ctx.pool, "sess-other", other_pid, "active", None).await;
+        insert_tool_history(&ctx.pool, "sess-other", "run", true).await;
+
+        let dir = tempfile::tempdir().unwrap();
+        let out = dir.path().join("audit.jsonl");
+        let result = audit::export_with_key(
+            &ctx,
+            Some(7),
+            None,
+            Some(out.display().to_string()),
+            b"tool-key".to_vec(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(result.0.action, "audit_export");
+        match result.0.data {
+            Some(SessionData::AuditExport(data)) => {
+                assert_eq!(data.row_count, 2, "other project's calls must be excluded");
+                assert_eq!(data.project.as_deref(), Some("/test/project"));
+            }
+            other => panic!("Expected SessionData::AuditExport, got {:?}", other),
+        }
+
+        let report = crate::audit::verify_export(&out, b"tool-key").unwrap();
+        assert!(report.is_valid(), "problems: {:?}", report.problems);
+        assert_eq!(report.row_count, 2);
+    }
 }
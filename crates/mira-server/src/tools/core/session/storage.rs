@@ -1,12 +1,16 @@
 // crates/mira-server/src/tools/core/session/storage.rs
 //! Storage status, retention policy display, and data cleanup operations.
 
-use crate::config::file::MiraConfig;
+use crate::db::db_health::{
+    format_growth, get_db_health_alert_sync, get_db_health_samples_sync, week_over_week_growth,
+};
 use crate::db::retention::{cleanup_orphans, count_retention_candidates, run_data_retention_sync};
 use crate::error::MiraError;
 use crate::mcp::responses::Json;
-use crate::mcp::responses::SessionOutput;
-use crate::tools::core::ToolContext;
+use crate::mcp::responses::{
+    DbHealthData, DbHealthPoint, DbTableGrowth, SessionData, SessionOutput,
+};
+use crate::tools::core::{ToolContext, project_config};
 
 /// Compile-time-safe enumeration of tables that may be counted.
 /// Prevents SQL injection via table name interpolation.
@@ -149,7 +153,7 @@ pub(super) async fn storage_status<C: ToolContext>(
     ) = counts_and_activity;
 
     // Load retention config and count candidates
-    let config = MiraConfig::load();
+    let config = project_config(ctx).await;
     let retention = config.retention;
 
     let retention_clone = retention.clone();
@@ -159,6 +163,18 @@ pub(super) async fn storage_status<C: ToolContext>(
         .run(move |conn| Ok::<_, String>(count_retention_candidates(conn, &retention_clone)))
         .await?;
 
+    // Week-over-week growth from the background health samples
+    let (growth, db_alert) = ctx
+        .pool()
+        .run(|conn| {
+            let samples = get_db_health_samples_sync(conn, 14)?;
+            Ok::<_, rusqlite::Error>((
+                week_over_week_growth(&samples),
+                get_db_health_alert_sync(conn)?,
+            ))
+        })
+        .await?;
+
     // Build report
     let mut report = String::new();
     report.push_str("## Storage Status\n\n");
@@ -171,6 +187,18 @@ pub(super) async fn storage_status<C: ToolContext>(
         format_bytes(main_db_size + code_db_size)
     ));
 
+    report.push_str("### Growth\n");
+    match growth {
+        Some(ref growth) => {
+            report.push_str(&format!("- mira.db: {}\n", format_growth(growth)));
+        }
+        None => report.push_str("- Not enough history yet (sampled daily)\n"),
+    }
+    if let Some(ref alert) = db_alert {
+        report.push_str(&format!("- **Warning:** {}\n", alert));
+    }
+    report.push('\n');
+
     report.push_str("### Row Counts\n");
     report.push_str(&format!("- Sessions: {}\n", sessions));
     report.push_str(&format!("- Tool history: {}\n", tool_history));
@@ -345,6 +373,80 @@ pub(super) async fn storage_status<C: ToolContext>(
     }))
 }
 
+/// Daily database health samples with week-over-week growth.
+pub(super) async fn db_health<C: ToolContext>(
+    ctx: &C,
+    since_days: Option<u32>,
+) -> Result<Json<SessionOutput>, MiraError> {
+    let since_days = since_days.unwrap_or(30).clamp(1, 365);
+    let (samples, alert) = ctx
+        .pool()
+        .run(move |conn| {
+            Ok::<_, rusqlite::Error>((
+                get_db_health_samples_sync(conn, since_days)?,
+                get_db_health_alert_sync(conn)?,
+            ))
+        })
+        .await?;
+    let growth = week_over_week_growth(&samples);
+
+    let mut report = format!("## Database Health (last {} days)\n\n", since_days);
+    if samples.is_empty() {
+        report.push_str("No samples yet. The background worker samples the database once a day.\n");
+    } else {
+        for sample in &samples {
+            let rows: i64 = sample.table_rows.values().sum();
+            report.push_str(&format!(
+                "- {}: {} (WAL {}), {} rows\n",
+                sample.sampled_on,
+                format_bytes(sample.db_size_bytes),
+                format_bytes(sample.wal_size_bytes),
+                rows
+            ));
+        }
+    }
+    if let Some(ref growth) = growth {
+        report.push_str(&format!("\nWeek over week: {}\n", format_growth(growth)));
+    }
+    if let Some(ref alert) = alert {
+        report.push_str(&format!("\n**Warning:** {}\n", alert));
+    }
+
+    let data = DbHealthData {
+        since_days,
+        size_growth_pct: growth.as_ref().map(|g| g.size_growth_pct()),
+        top_tables: growth
+            .map(|g| {
+                g.tables
+                    .into_iter()
+                    .take(10)
+                    .map(|t| DbTableGrowth {
+                        table: t.table,
+                        baseline_rows: t.baseline_rows,
+                        latest_rows: t.latest_rows,
+                    })
+                    .collect()
+            })
+            .unwrap_or_default(),
+        samples: samples
+            .into_iter()
+            .map(|s| DbHealthPoint {
+                sampled_on: s.sampled_on,
+                db_size_bytes: s.db_size_bytes,
+                wal_size_bytes: s.wal_size_bytes,
+                table_rows: s.table_rows,
+            })
+            .collect(),
+        alert,
+    };
+
+    Ok(Json(SessionOutput {
+        action: "db_health".into(),
+        message: report,
+        data: Some(SessionData::DbHealth(data)),
+    }))
+}
+
 /// Run data cleanup with dry-run preview by default.
 pub(super) async fn cleanup<C: ToolContext>(
     ctx: &C,
@@ -355,7 +457,7 @@ pub(super) async fn cleanup<C: ToolContext>(
 
     if dry_run {
         // Preview mode: show what WOULD be deleted
-        let config = MiraConfig::load();
+        let config = project_config(ctx).await;
         let retention = config.retention;
         let retention_clone = retention.clone();
 
@@ -423,7 +525,7 @@ pub(super) async fn cleanup<C: ToolContext>(
         }))
     } else {
         // Execute mode
-        let config = MiraConfig::load();
+        let config = project_config(ctx).await;
         let retention = config.retention;
         let retention_enabled = retention.is_enabled();
         let retention_clone = retention.clone();
@@ -11,6 +11,7 @@ use crate::mcp::responses::{
     Json, TaskSummary, TasksData, TasksListData, TasksOutput, TasksStatusData,
 };
 use crate::utils::truncate;
+use rmcp::schemars;
 use rmcp::task_manager::ToolCallTaskResult;
 use serde::Deserialize;
 use std::time::{Duration, Instant};
@@ -19,7 +20,7 @@ use std::time::{Duration, Instant};
 const CACHE_RETENTION: Duration = Duration::from_secs(5 * 60);
 
 /// Action enum for the tasks tool (decoupled from SessionAction).
-#[derive(Debug, Clone, Copy, Deserialize)]
+#[derive(Debug, Clone, Copy, Deserialize, schemars::JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum TaskAction {
     /// List all running and recently completed tasks
@@ -30,6 +31,13 @@ pub enum TaskAction {
     Cancel,
 }
 
+/// Arguments for the tasks tool when called from the CLI
+#[derive(Debug, Deserialize)]
+pub struct TasksRequest {
+    pub action: TaskAction,
+    pub task_id: Option<String>,
+}
+
 pub async fn handle_tasks(
     server: &MiraServer,
     action: TaskAction,
@@ -196,7 +204,7 @@ async fn handle_get(server: &MiraServer, task_id: &str) -> Result<Json<TasksOutp
     // Try to find the requested task in freshly completed results
     if let Some(idx) = position {
         let Some(task_result) = freshly_completed.into_iter().nth(idx) else {
-            return Err(MiraError::InvalidInput(format!(
+            return Err(MiraError::NotFound(format!(
                 "Task '{}' not found. Use tasks(action=\"list\") to see available tasks.",
                 task_id
             )));
@@ -240,7 +248,7 @@ async fn handle_get(server: &MiraServer, task_id: &str) -> Result<Json<TasksOutp
         }));
     }
 
-    Err(MiraError::InvalidInput(format!(
+    Err(MiraError::NotFound(format!(
         "Task '{}' not found. Use tasks(action=\"list\") to see available tasks.",
         task_id
     )))
@@ -314,7 +322,7 @@ async fn handle_cancel(server: &MiraServer, task_id: &str) -> Result<Json<TasksO
             data: None,
         }))
     } else {
-        Err(MiraError::InvalidInput(format!(
+        Err(MiraError::NotFound(format!(
             "Task '{}' not found or already completed. Use tasks(action=\"list\") to see current tasks.",
             task_id
         )))
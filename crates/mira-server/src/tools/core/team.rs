@@ -153,16 +153,16 @@ async fn team_review<C: ToolContext + ?Sized>(
                 .ok_or_else(|| {
                     let available: Vec<&str> =
                         members.iter().map(|m| m.member_name.as_str()).collect();
-                    anyhow::anyhow!(
+                    MiraError::NotFound(format!(
                         "Teammate '{}' not found. Active members: {}",
                         target_name,
                         available.join(", ")
-                    )
+                    ))
                 })?;
 
             let files = crate::db::get_member_files_sync(conn, tid, &member.session_id);
 
-            Ok::<_, anyhow::Error>((member.member_name.clone(), files))
+            Ok::<_, MiraError>((member.member_name.clone(), files))
         })
         .await?;
 
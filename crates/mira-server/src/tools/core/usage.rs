@@ -1,9 +1,14 @@
 // crates/mira-server/src/tools/core/usage.rs
 // LLM usage analytics tool
 
+use chrono::{Datelike, NaiveDate, Utc};
+
 use super::ToolContext;
-use crate::db::{get_llm_usage_summary, query_llm_usage_stats};
+use crate::db::{
+    CostRollup, get_llm_usage_summary, project_month_cost, query_daily_costs, query_llm_usage_stats,
+};
 use crate::error::MiraError;
+use crate::mcp::responses::{CostBucket, CostsData, Json, SessionData, SessionOutput};
 use crate::utils::{format_period, truncate};
 
 /// Get LLM usage summary
@@ -111,3 +116,160 @@ pub async fn usage_list<C: ToolContext>(
 ) -> Result<String, MiraError> {
     usage_stats(ctx, Some("role".to_string()), since_days, limit).await
 }
+
+/// Unified cost report across LLM and embedding usage
+pub async fn usage_costs<C: ToolContext>(
+    ctx: &C,
+    since_days: Option<u32>,
+) -> Result<Json<SessionOutput>, MiraError> {
+    let project_id = ctx.project_id().await;
+    let since_days = since_days.unwrap_or(30).max(1);
+    let today = Utc::now().date_naive();
+    let day_of_month = today.day();
+
+    let (rows, month_rows) = ctx
+        .pool()
+        .run(move |conn| {
+            let rows = query_daily_costs(conn, project_id, since_days)?;
+            let month_rows = query_daily_costs(conn, project_id, day_of_month)?;
+            Ok::<_, anyhow::Error>((rows, month_rows))
+        })
+        .await?;
+
+    let rollup = CostRollup::from_rows(&rows);
+    let month_to_date = CostRollup::from_rows(&month_rows).total;
+    let projected_month = project_month_cost(month_to_date, day_of_month, days_in_month(today));
+
+    let message = format_cost_report(&rollup, since_days, month_to_date, projected_month);
+    let bucket = |key: String, cost: f64| CostBucket { key, cost };
+    let data = CostsData {
+        since_days,
+        total_cost: rollup.total,
+        total_requests: rollup.requests,
+        by_day: rollup
+            .by_day
+            .into_iter()
+            .map(|(k, c)| bucket(k, c))
+            .collect(),
+        by_source: rollup
+            .by_source
+            .into_iter()
+            .map(|(k, c)| bucket(k, c))
+            .collect(),
+        by_project: rollup
+            .by_project
+            .into_iter()
+            .map(|(k, c)| bucket(k.unwrap_or_else(|| "(none)".to_string()), c))
+            .collect(),
+        month_to_date,
+        projected_month,
+    };
+
+    Ok(Json(SessionOutput {
+        action: "usage_costs".into(),
+        message,
+        data: Some(SessionData::Costs(data)),
+    }))
+}
+
+fn days_in_month(date: NaiveDate) -> u32 {
+    let (year, month) = if date.month() == 12 {
+        (date.year() + 1, 1)
+    } else {
+        (date.year(), date.month() + 1)
+    };
+    NaiveDate::from_ymd_opt(year, month, 1)
+        .and_then(|next| next.pred_opt())
+        .map(|last| last.day())
+        .unwrap_or(30)
+}
+
+fn format_cost_report(
+    rollup: &CostRollup,
+    since_days: u32,
+    month_to_date: f64,
+    projected_month: f64,
+) -> String {
+    let mut output = format!(
+        "Costs ({})\n\n\
+         Total: ${:.4} across {} requests\n\
+         This month: ${:.4} so far, ~${:.2} projected\n",
+        format_period(Some(since_days)),
+        rollup.total,
+        rollup.requests,
+        month_to_date,
+        projected_month
+    );
+    if rollup.requests == 0 {
+        output.push_str("\nNo LLM or embedding usage recorded in this period.\n");
+        return output;
+    }
+
+    push_cost_table(&mut output, "SOURCE", &rollup.by_source);
+    let projects: Vec<(String, f64)> = rollup
+        .by_project
+        .iter()
+        .map(|(p, c)| (p.clone().unwrap_or_else(|| "(none)".to_string()), *c))
+        .collect();
+    push_cost_table(&mut output, "PROJECT", &projects);
+    push_cost_table(&mut output, "DAY", &rollup.by_day);
+    output
+}
+
+fn push_cost_table(output: &mut String, heading: &str, rows: &[(String, f64)]) {
+    output.push_str(&format!("\n{:<40} {:>10}\n", heading, "COST"));
+    output.push_str(&"-".repeat(51));
+    output.push('\n');
+    for (key, cost) in rows {
+        output.push_str(&format!("{:<40} ${:>9.4}\n", truncate(key, 37), cost));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::{EmbeddingUsageRecord, insert_embedding_usage_sync};
+    use crate::tools::core::test_utils::MockToolContext;
+
+    #[test]
+    fn test_days_in_month() {
+        let d = |y, m, day| NaiveDate::from_ymd_opt(y, m, day).unwrap();
+        assert_eq!(days_in_month(d(2026, 2, 10)), 28);
+        assert_eq!(days_in_month(d(2024, 2, 1)), 29);
+        assert_eq!(days_in_month(d(2026, 12, 31)), 31);
+        assert_eq!(days_in_month(d(2026, 4, 30)), 30);
+    }
+
+    #[tokio::test]
+    async fn test_usage_costs_reports_seeded_spend() {
+        let ctx = MockToolContext::with_project().await;
+        let project_id = ctx.project_id().await;
+        ctx.pool
+            .run(move |conn| {
+                insert_embedding_usage_sync(
+                    conn,
+                    &EmbeddingUsageRecord {
+                        provider: "openai".to_string(),
+                        model: "text-embedding-3-small".to_string(),
+                        tokens: 1000,
+                        text_count: 4,
+                        cost_estimate: Some(0.25),
+                        project_id,
+                    },
+                )
+            })
+            .await
+            .unwrap();
+
+        let output = usage_costs(&ctx, Some(7)).await.unwrap();
+        let Some(SessionData::Costs(data)) = output.0.data else {
+            panic!("expected Costs data");
+        };
+        assert_eq!(data.total_requests, 1);
+        assert_eq!(data.total_cost, 0.25);
+        assert_eq!(data.month_to_date, 0.25);
+        assert!(data.projected_month >= data.month_to_date);
+        assert_eq!(data.by_source[0].key, "embeddings:openai");
+        assert!(output.0.message.contains("embeddings:openai"));
+    }
+}
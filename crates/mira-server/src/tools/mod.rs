@@ -25,11 +25,16 @@ pub use core::{
     handle_session,
     handle_team,
     index,
+    list_dead_letters,
+    module,
     project,
+    retry_dead_letter,
+    search_all,
     search_code,
     session_start,
     set_project,
     summarize_codebase,
+    usage_costs,
     usage_list,
     usage_stats,
     usage_summary,
@@ -386,6 +386,7 @@ async fn test_session_history_current() {
         insight_id: None,
         dry_run: None,
         category: None,
+        tool_name: None,
     };
     let result = handle_session(&ctx, req).await;
     assert!(
@@ -423,6 +424,7 @@ async fn test_session_history_current() {
         insight_id: None,
         dry_run: None,
         category: None,
+        tool_name: None,
     };
     let result = handle_session(&ctx, req).await;
     assert!(
@@ -465,6 +467,7 @@ async fn test_session_history_list_sessions() {
         insight_id: None,
         dry_run: None,
         category: None,
+        tool_name: None,
     };
     let result = handle_session(&ctx, req).await;
     // Should succeed even if no sessions in database (maybe there is one now)
@@ -2553,6 +2556,7 @@ async fn test_dismiss_insight_success() {
         insight_id: Some(row_id),
         dry_run: None,
         category: None,
+        tool_name: None,
     };
     let result = handle_session(&ctx, req).await;
     assert!(result.is_ok(), "dismiss_insight failed: {:?}", result.err());
@@ -2611,6 +2615,7 @@ async fn test_dismiss_insight_cross_project_blocked() {
         insight_id: Some(row_id),
         dry_run: None,
         category: None,
+        tool_name: None,
     };
     let result = handle_session(&ctx, req).await;
     assert!(
@@ -2662,6 +2667,7 @@ async fn test_dismiss_insight_non_insight_pattern_blocked() {
         insight_id: Some(row_id),
         dry_run: None,
         category: None,
+        tool_name: None,
     };
     let result = handle_session(&ctx, req).await;
     assert!(result.is_ok());
@@ -2695,6 +2701,7 @@ async fn test_dismiss_insight_requires_project() {
         insight_id: Some(999),
         dry_run: None,
         category: None,
+        tool_name: None,
     };
     let result = handle_session(&ctx, req).await;
     assert!(result.is_err(), "Should fail without active project");
@@ -2779,6 +2786,7 @@ async fn test_insights_empty_no_data_shows_setup_instructions() {
         insight_id: None,
         dry_run: None,
         category: None,
+        tool_name: None,
     };
     let result = handle_session(&ctx, req).await;
     assert!(result.is_ok());
@@ -2819,6 +2827,7 @@ async fn test_insights_empty_with_filters_shows_filter_message() {
         insight_id: None,
         dry_run: None,
         category: None,
+        tool_name: None,
     };
     let result = handle_session(&ctx, req).await;
     assert!(result.is_ok());
@@ -2847,6 +2856,7 @@ async fn test_insights_empty_with_filters_shows_filter_message() {
         insight_id: None,
         dry_run: None,
         category: None,
+        tool_name: None,
     };
     let result2 = handle_session(&ctx, req2).await;
     assert!(result2.is_ok());
@@ -2870,6 +2880,7 @@ async fn test_insights_empty_with_filters_shows_filter_message() {
         insight_id: None,
         dry_run: None,
         category: None,
+        tool_name: None,
     };
     let result3 = handle_session(&ctx, req3).await;
     assert!(result3.is_ok());